@@ -0,0 +1,357 @@
+//! Differences between two schemas, e.g. two layers of `api.tl`
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::schema::{Param, Schema};
+
+/// Changes of constructors and methods, matched by name
+#[derive(Debug, Serialize)]
+pub struct SchemaDiff {
+    pub old_layer: Option<u32>,
+    pub new_layer: Option<u32>,
+    pub constructors: Vec<Change>,
+    pub methods: Vec<Change>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Change {
+    Added {
+        name: String,
+        id: i32,
+        return_type: String,
+    },
+    Removed {
+        name: String,
+        id: i32,
+        return_type: String,
+    },
+    Changed {
+        name: String,
+        old_id: i32,
+        new_id: i32,
+        old_return_type: String,
+        return_type: String,
+        params: Vec<ParamChange>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ParamChange {
+    Added {
+        name: String,
+        kind: String,
+    },
+    Removed {
+        name: String,
+        kind: String,
+    },
+    Retyped {
+        name: String,
+        old_kind: String,
+        new_kind: String,
+    },
+    /// Same parameters in another order, which changes the encoding
+    Reordered,
+}
+
+/// Name, id, params and type of a constructor or method
+struct Declaration<'a> {
+    name: &'a str,
+    id: i32,
+    params: &'a [Param],
+    return_type: &'a str,
+}
+
+impl SchemaDiff {
+    pub fn new(old: &Schema, new: &Schema) -> Self {
+        SchemaDiff {
+            old_layer: old.layer,
+            new_layer: new.layer,
+            constructors: diff_declarations(constructors(old), constructors(new)),
+            methods: diff_declarations(methods(old), methods(new)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.constructors.is_empty() && self.methods.is_empty()
+    }
+}
+
+impl Change {
+    /// Type in the newer schema, or in the older one if removed
+    pub fn return_type(&self) -> &str {
+        match self {
+            Change::Added { return_type, .. }
+            | Change::Removed { return_type, .. }
+            | Change::Changed { return_type, .. } => return_type,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Change::Added { name, .. }
+            | Change::Removed { name, .. }
+            | Change::Changed { name, .. } => name,
+        }
+    }
+}
+
+fn constructors(schema: &Schema) -> Vec<Declaration<'_>> {
+    schema
+        .constructors
+        .iter()
+        .map(|x| Declaration {
+            name: x.predicate.as_str(),
+            id: x.id,
+            params: &x.params,
+            return_type: x.return_type.as_str(),
+        })
+        .collect()
+}
+
+fn methods(schema: &Schema) -> Vec<Declaration<'_>> {
+    schema
+        .methods
+        .iter()
+        .map(|x| Declaration {
+            name: x.method.as_str(),
+            id: x.id,
+            params: &x.params,
+            return_type: x.return_type.as_str(),
+        })
+        .collect()
+}
+
+/// Changes sorted by type then name
+fn diff_declarations(old: Vec<Declaration>, new: Vec<Declaration>) -> Vec<Change> {
+    let old: HashMap<&str, Declaration> = old.into_iter().map(|x| (x.name, x)).collect();
+    let new: HashMap<&str, Declaration> = new.into_iter().map(|x| (x.name, x)).collect();
+    let mut result = vec![];
+    for (name, x) in new.iter() {
+        let y = match old.get(name) {
+            Some(y) => y,
+            None => {
+                result.push(Change::Added {
+                    name: name.to_string(),
+                    id: x.id,
+                    return_type: x.return_type.to_string(),
+                });
+                continue;
+            }
+        };
+        let params = diff_params(y.params, x.params);
+        if x.id != y.id || x.return_type != y.return_type || !params.is_empty() {
+            result.push(Change::Changed {
+                name: name.to_string(),
+                old_id: y.id,
+                new_id: x.id,
+                old_return_type: y.return_type.to_string(),
+                return_type: x.return_type.to_string(),
+                params,
+            });
+        }
+    }
+    for (name, y) in old.iter().filter(|x| !new.contains_key(x.0)) {
+        result.push(Change::Removed {
+            name: name.to_string(),
+            id: y.id,
+            return_type: y.return_type.to_string(),
+        });
+    }
+    result.sort_by(|a, b| (a.return_type(), a.name()).cmp(&(b.return_type(), b.name())));
+    result
+}
+
+fn diff_params(old: &[Param], new: &[Param]) -> Vec<ParamChange> {
+    let mut result = vec![];
+    for x in new {
+        match old.iter().find(|y| y.name == x.name) {
+            None => result.push(ParamChange::Added {
+                name: x.name.as_str().to_string(),
+                kind: x.kind.as_str().to_string(),
+            }),
+            Some(y) if y.kind != x.kind => result.push(ParamChange::Retyped {
+                name: x.name.as_str().to_string(),
+                old_kind: y.kind.as_str().to_string(),
+                new_kind: x.kind.as_str().to_string(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for y in old.iter().filter(|y| new.iter().all(|x| x.name != y.name)) {
+        result.push(ParamChange::Removed {
+            name: y.name.as_str().to_string(),
+            kind: y.kind.as_str().to_string(),
+        });
+    }
+    let names = |params: &[Param]| params.iter().map(|x| x.name.clone()).collect_vec();
+    if result.is_empty() && names(old) != names(new) {
+        result.push(ParamChange::Reordered);
+    }
+    result
+}
+
+/// Grouped by type, `+` added, `-` removed, `~` changed
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let layer = |x: Option<u32>| x.map_or("unknown".to_string(), |x| x.to_string());
+        writeln!(
+            f,
+            "layer {} -> {}",
+            layer(self.old_layer),
+            layer(self.new_layer)
+        )?;
+        for (title, changes) in [("types", &self.constructors), ("functions", &self.methods)].iter()
+        {
+            writeln!(f, "\n---{}---", title)?;
+            let mut groups: BTreeMap<&str, Vec<&Change>> = BTreeMap::new();
+            for change in changes.iter() {
+                groups.entry(change.return_type()).or_default().push(change);
+            }
+            for (return_type, changes) in groups {
+                writeln!(f, "{}", return_type)?;
+                for change in changes {
+                    write!(f, "{}", change)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added { name, id, .. } => writeln!(f, "  + {}#{:08x}", name, *id as u32),
+            Change::Removed { name, id, .. } => writeln!(f, "  - {}#{:08x}", name, *id as u32),
+            Change::Changed {
+                name,
+                old_id,
+                new_id,
+                old_return_type,
+                return_type,
+                params,
+            } => {
+                writeln!(f, "  ~ {}#{:08x}", name, *new_id as u32)?;
+                if old_id != new_id {
+                    writeln!(
+                        f,
+                        "      id {:08x} -> {:08x}",
+                        *old_id as u32, *new_id as u32
+                    )?;
+                }
+                if old_return_type != return_type {
+                    writeln!(f, "      type {} -> {}", old_return_type, return_type)?;
+                }
+                for param in params {
+                    match param {
+                        ParamChange::Added { name, kind } => {
+                            writeln!(f, "      + {}:{}", name, kind)?
+                        }
+                        ParamChange::Removed { name, kind } => {
+                            writeln!(f, "      - {}:{}", name, kind)?
+                        }
+                        ParamChange::Retyped {
+                            name,
+                            old_kind,
+                            new_kind,
+                        } => writeln!(f, "      ~ {}:{} -> {}", name, old_kind, new_kind)?,
+                        ParamChange::Reordered => writeln!(f, "      params reordered")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let old = Schema::from_tl_unchecked(
+            r#"
+// LAYER 104
+inputPeerEmpty#7f3b18ea = InputPeer;
+inputPeerSelf#7da07ec9 = InputPeer;
+peerSettings#818426cd flags:# report_spam:flags.0?true = PeerSettings;
+---functions---
+help.getConfig#c4f9186b = Config;
+"#,
+        )
+        .unwrap();
+        let new = Schema::from_tl_unchecked(
+            r#"
+// LAYER 105
+inputPeerEmpty#7f3b18ea = InputPeer;
+peerSettings#18c65f37 flags:# add_contact:flags.0?true report_spam:flags.1?true = PeerSettings;
+inputPeerChat#179be863 chat_id:int = InputPeer;
+"#,
+        )
+        .unwrap();
+        let diff = SchemaDiff::new(&old, &new);
+        assert_eq!(
+            diff.constructors,
+            vec![
+                Change::Added {
+                    name: "inputPeerChat".to_string(),
+                    id: 0x179b_e863,
+                    return_type: "InputPeer".to_string(),
+                },
+                Change::Removed {
+                    name: "inputPeerSelf".to_string(),
+                    id: 0x7da0_7ec9,
+                    return_type: "InputPeer".to_string(),
+                },
+                Change::Changed {
+                    name: "peerSettings".to_string(),
+                    old_id: -0x7e7b_d933,
+                    new_id: 0x18c6_5f37,
+                    old_return_type: "PeerSettings".to_string(),
+                    return_type: "PeerSettings".to_string(),
+                    params: vec![
+                        ParamChange::Added {
+                            name: "add_contact".to_string(),
+                            kind: "flags.0?true".to_string(),
+                        },
+                        ParamChange::Retyped {
+                            name: "report_spam".to_string(),
+                            old_kind: "flags.0?true".to_string(),
+                            new_kind: "flags.1?true".to_string(),
+                        },
+                    ],
+                },
+            ]
+        );
+        assert_eq!(diff.methods.len(), 1);
+        assert_eq!(
+            diff.to_string(),
+            "layer 104 -> 105
+
+---types---
+InputPeer
+  + inputPeerChat#179be863
+  - inputPeerSelf#7da07ec9
+PeerSettings
+  ~ peerSettings#18c65f37
+      id 818426cd -> 18c65f37
+      + add_contact:flags.0?true
+      ~ report_spam:flags.0?true -> flags.1?true
+
+---functions---
+Config
+  - help.getConfig#c4f9186b
+"
+        );
+    }
+}
@@ -1,7 +1,37 @@
-use crate::tl_ident::TLIdent;
+use proc_macro2::TokenStream;
+
+use crate::{tl_ident::TLIdent, utils::doc};
 
 #[derive(Debug, Clone)]
 pub struct Arg {
     pub name: TLIdent,
     pub kind: TLIdent,
+    pub description: Option<String>,
+}
+
+impl Arg {
+    /// `flags:#` is computed from conditional fields, so it never becomes a Rust field
+    pub fn is_field(&self) -> bool {
+        !self.kind.is_flags()
+    }
+
+    /// Generate `///` doc of the field from its description
+    pub fn compile_doc(&self) -> TokenStream {
+        self.description
+            .as_ref()
+            .map(|x| doc(x))
+            .unwrap_or_default()
+    }
+
+    /// Conditional fields controlled by the `flags` field, along with their bit
+    pub fn conditional_of<'a>(
+        flags: &'a TLIdent,
+        args: &'a [Arg],
+    ) -> impl Iterator<Item = (&'a Arg, u32)> + 'a {
+        args.iter()
+            .filter_map(move |x| match x.kind.conditional_split() {
+                Some((ref name, bit, _)) if name == flags => Some((x, bit)),
+                _ => None,
+            })
+    }
 }
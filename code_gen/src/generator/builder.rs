@@ -0,0 +1,212 @@
+use itertools::Itertools;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::{
+    generator::tl_type::{Constructor, TLType},
+    utils::ident,
+};
+
+/// Generate `builder_name`, taking required fields in `new` and conditional fields as setters,
+/// `build` gives `target { ... }` of type `built`, e.g. `SentCode::SentAppCode` of `SentCode`
+///
+/// Nothing is generated for constructors without fields.
+pub fn compile(
+    constructor: &Constructor,
+    builder_name: &Ident,
+    target: &TokenStream,
+    built: &Ident,
+) -> TokenStream {
+    let fields = constructor
+        .fields
+        .iter()
+        .filter(|x| x.is_field())
+        .collect_vec();
+    if fields.is_empty() {
+        return quote!();
+    }
+    let (impl_generics, type_generics) = TLType::compile_generics(constructor);
+    let field_names = &fields
+        .iter()
+        .map(|x| ident(x.name.as_field_name()))
+        .collect_vec();
+    let field_types = fields
+        .iter()
+        .map(|x| x.kind.as_rust_type_path().parse::<TokenStream>().unwrap())
+        .collect_vec();
+
+    let mut params = vec![];
+    let mut inits = vec![];
+    let mut setters = vec![];
+    for (x, (name, ty)) in fields
+        .iter()
+        .zip(field_names.iter().zip(field_types.iter()))
+    {
+        match x.kind.conditional_split() {
+            None => {
+                params.push(quote!(#name: #ty));
+                inits.push(quote!(#name));
+            }
+            Some(_) if x.kind.is_true_flag() => {
+                inits.push(quote!(#name: false));
+                setters.push(quote! {
+                    pub fn #name(mut self, value: bool) -> Self {
+                        self.#name = value;
+                        self
+                    }
+                });
+            }
+            Some((_, _, inner)) => {
+                let inner = inner.as_rust_type_path().parse::<TokenStream>().unwrap();
+                inits.push(quote!(#name: None));
+                setters.push(quote! {
+                    pub fn #name(mut self, value: #inner) -> Self {
+                        self.#name = Some(value);
+                        self
+                    }
+                });
+            }
+        }
+    }
+
+    let moves = field_names.iter().map(|x| quote!(#x: self.#x));
+    // required fields of a constructor all go to `new`, however many there are
+    let allow = if params.len() > 7 {
+        quote!(#[allow(clippy::too_many_arguments)])
+    } else {
+        quote!()
+    };
+    let default = if params.is_empty() {
+        quote! {
+            impl #impl_generics Default for #builder_name #type_generics {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+    quote! {
+        pub struct #builder_name #impl_generics {
+            #(#field_names: #field_types,)*
+        }
+
+        #default
+
+        impl #impl_generics #builder_name #type_generics {
+            #allow
+            pub fn new(#(#params),*) -> Self {
+                #builder_name {
+                    #(#inits,)*
+                }
+            }
+
+            #(#setters)*
+
+            pub fn build(self) -> #built #type_generics {
+                #target {
+                    #(#moves,)*
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_builder_compile() {
+        let document = json!({
+            "id": "-2122046771",
+            "predicate": "peerSettings",
+            "params": [
+                {"name": "flags", "type": "#"},
+                {"name": "report_spam", "type": "flags.0?true"},
+                {"name": "geo_distance", "type": "flags.1?int"},
+                {"name": "peer_id", "type": "long"}
+            ],
+            "type": "PeerSettings"
+        });
+        let constructor = Constructor::new(&serde_json::from_value(document).unwrap());
+        let target = quote! {
+            pub struct PeerSettingsBuilder {
+                report_spam: bool,
+                geo_distance: Option<i32>,
+                peer_id: i64,
+            }
+
+            impl PeerSettingsBuilder {
+                pub fn new(peer_id: i64) -> Self {
+                    PeerSettingsBuilder {
+                        report_spam: false,
+                        geo_distance: None,
+                        peer_id,
+                    }
+                }
+
+                pub fn report_spam(mut self, value: bool) -> Self {
+                    self.report_spam = value;
+                    self
+                }
+
+                pub fn geo_distance(mut self, value: i32) -> Self {
+                    self.geo_distance = Some(value);
+                    self
+                }
+
+                pub fn build(self) -> PeerSettings {
+                    PeerSettings {
+                        report_spam: self.report_spam,
+                        geo_distance: self.geo_distance,
+                        peer_id: self.peer_id,
+                    }
+                }
+            }
+        };
+        let type_name = ident("PeerSettings");
+        assert_eq!(
+            target.to_string(),
+            compile(
+                &constructor,
+                &ident("PeerSettingsBuilder"),
+                &quote!(#type_name),
+                &type_name
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_builder_default() {
+        let document = json!({
+            "id": "-2122046771",
+            "predicate": "peerSettings",
+            "params": [
+                {"name": "flags", "type": "#"},
+                {"name": "report_spam", "type": "flags.0?true"}
+            ],
+            "type": "PeerSettings"
+        });
+        let constructor = Constructor::new(&serde_json::from_value(document).unwrap());
+        let type_name = ident("PeerSettings");
+        let target = quote! {
+            impl Default for PeerSettingsBuilder {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        };
+        let builder = compile(
+            &constructor,
+            &ident("PeerSettingsBuilder"),
+            &quote!(#type_name),
+            &type_name,
+        );
+        assert!(builder.to_string().contains(&target.to_string()));
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{generator::tl_type::Constructor, schema, tl_ident::TLIdent};
+
+/// Derived by every generated type unless configured otherwise
+pub const DEFAULT_DERIVES: &[&str] = &["Debug", "Clone"];
+
+/// Traits derived by generated types
+///
+/// `Eq` and `Hash` are skipped for types containing `double`, `Default` for enums and
+/// structs with a field of enum type, as those can not be derived.
+#[derive(Debug, Clone)]
+pub struct Derives {
+    pub traits: Vec<String>,
+    /// Derive `Serialize` and `Deserialize` when feature `serde` is enabled
+    pub serde: bool,
+    /// Schema types containing `double`, directly or through other types
+    float_types: HashSet<TLIdent>,
+    /// Schema types which can not derive `Default`
+    non_default_types: HashSet<TLIdent>,
+}
+
+impl Default for Derives {
+    fn default() -> Self {
+        Derives {
+            traits: DEFAULT_DERIVES.iter().map(|x| x.to_string()).collect(),
+            serde: false,
+            float_types: HashSet::new(),
+            non_default_types: HashSet::new(),
+        }
+    }
+}
+
+impl Derives {
+    /// `constructors` as generated, `Object` is never `Default` and is `Eq` only if every type is,
+    /// which is unknown if it is `linked` to `Object` of another schema
+    pub fn new(constructors: &[schema::Constructor], linked: bool) -> Self {
+        let object = TLIdent::new("Object");
+        let double = TLIdent::new("double");
+        let mut float_types = HashSet::new();
+        if linked {
+            float_types.insert(object.clone());
+        }
+        loop {
+            let count = float_types.len();
+            for c in constructors {
+                if c.params.iter().any(|x| {
+                    x.kind.base_type() == double || float_types.contains(&x.kind.base_type())
+                }) {
+                    float_types.insert(c.return_type.clone());
+                    float_types.insert(object.clone());
+                }
+            }
+            if float_types.len() == count {
+                break;
+            }
+        }
+
+        // enums, i.e. types with several constructors
+        let mut seen = HashSet::new();
+        let mut non_default_types: HashSet<TLIdent> = constructors
+            .iter()
+            .filter(|x| !seen.insert(&x.return_type))
+            .map(|x| x.return_type.clone())
+            .chain(Some(object))
+            .collect();
+        loop {
+            let count = non_default_types.len();
+            for c in constructors {
+                if c.params
+                    .iter()
+                    .any(|x| Self::is_required(&x.kind, &non_default_types))
+                {
+                    non_default_types.insert(c.return_type.clone());
+                }
+            }
+            if non_default_types.len() == count {
+                break;
+            }
+        }
+
+        Derives {
+            float_types,
+            non_default_types,
+            ..Derives::default()
+        }
+    }
+
+    /// Whether the value of `kind` must be given, rather than an empty vector or `None`
+    fn is_required(kind: &TLIdent, non_default_types: &HashSet<TLIdent>) -> bool {
+        !kind.is_vector()
+            && kind.conditional_split().is_none()
+            && non_default_types.contains(&kind.base_type())
+    }
+
+    /// Generate derives of a struct (single constructor) or an enum
+    pub fn compile(&self, constructors: &[&Constructor]) -> TokenStream {
+        let kinds = constructors
+            .iter()
+            .flat_map(|x| x.fields.iter())
+            .filter(|x| x.is_field())
+            .map(|x| &x.kind)
+            .collect_vec();
+        let has_float = kinds.iter().any(|x| {
+            let base = x.base_type();
+            base.as_str() == "double" || self.float_types.contains(&base)
+        });
+        let has_default = constructors.len() == 1
+            && !kinds
+                .iter()
+                .any(|x| Self::is_required(x, &self.non_default_types));
+        self.compile_filtered(|x| match x {
+            "Eq" | "Hash" => !has_float,
+            "Default" => has_default,
+            _ => true,
+        })
+    }
+
+    /// Generate derives of `Object`
+    pub fn compile_object(&self) -> TokenStream {
+        let has_float = self.float_types.contains(&TLIdent::new("Object"));
+        self.compile_filtered(|x| match x {
+            "Eq" | "Hash" => !has_float,
+            "Default" => false,
+            _ => true,
+        })
+    }
+
+    /// Generate attributes of a field or variant of Rust type `type_path`
+    ///
+    /// Fields of `...Ref<'a>` types borrow from input when deserialized.
+    pub fn compile_field_attrs(&self, type_path: &str) -> TokenStream {
+        if self.serde && type_path.contains("'a") {
+            quote!(#[cfg_attr(feature = "serde", serde(borrow))])
+        } else {
+            quote!()
+        }
+    }
+
+    fn compile_filtered(&self, filter: impl Fn(&str) -> bool) -> TokenStream {
+        let traits = self
+            .traits
+            .iter()
+            .filter(|x| filter(x.as_str()))
+            .map(|x| x.parse::<TokenStream>().unwrap())
+            .collect_vec();
+        let derive = if traits.is_empty() {
+            quote!()
+        } else {
+            quote!(#[derive(#(#traits),*)])
+        };
+        if !self.serde {
+            return derive;
+        }
+        quote! {
+            #derive
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::Schema;
+
+    #[test]
+    fn test_filtered_derives() {
+        let schema = Schema::from_tl(
+            r#"
+boolTrue#997275b5 = Bool;
+inputPeerEmpty = InputPeer;
+inputPeerSelf = InputPeer;
+geoPoint long:double lat:double = GeoPoint;
+location point:GeoPoint peer:InputPeer = Location;
+"#,
+        )
+        .unwrap();
+        let mut derives = Derives::new(&schema.constructors, false);
+        derives.traits = ["Debug", "PartialEq", "Eq", "Default"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let compile = |name: &str| {
+            let constructors = schema
+                .constructors
+                .iter()
+                .filter(|x| x.return_type.as_str() == name)
+                .map(Constructor::new)
+                .collect_vec();
+            derives
+                .compile(&constructors.iter().collect_vec())
+                .to_string()
+        };
+        assert_eq!(
+            compile("InputPeer"),
+            quote!(#[derive(Debug, PartialEq, Eq)]).to_string()
+        );
+        assert_eq!(
+            compile("GeoPoint"),
+            quote!(#[derive(Debug, PartialEq, Default)]).to_string()
+        );
+        assert_eq!(
+            compile("Location"),
+            quote!(#[derive(Debug, PartialEq)]).to_string()
+        );
+        assert_eq!(
+            derives.compile_object().to_string(),
+            quote!(#[derive(Debug, PartialEq)]).to_string()
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    generator::{
+        arg::Arg,
+        builder,
+        derives::Derives,
+        tl_type::{Constructor, TLType},
+    },
+    schema,
+    utils::ident,
+};
+
+/// RPC method, generated as a request struct implementing `RemoteCall`
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub constructor: Constructor,
+}
+
+impl Function {
+    pub fn new(info: &schema::Method) -> Self {
+        Function {
+            constructor: Constructor {
+                name: info.method.clone(),
+                fields: info
+                    .params
+                    .iter()
+                    .cloned()
+                    .map(|x| Arg {
+                        name: x.name,
+                        kind: x.kind,
+                        description: x.description,
+                    })
+                    .collect_vec(),
+                return_type: info.return_type.clone(),
+                id: info.id,
+                description: info.description.clone(),
+                signature: info.signature(),
+            },
+        }
+    }
+
+    /// Generate `XBuilder` of the method struct
+    pub fn compile_builder(&self) -> TokenStream {
+        let type_name = &ident(self.constructor.name.as_function_name());
+        let builder_name = ident(format!("{}Builder", type_name));
+        builder::compile(
+            &self.constructor,
+            &builder_name,
+            &quote!(#type_name),
+            type_name,
+        )
+    }
+
+    pub fn compile(&self, derives: &Derives) -> TokenStream {
+        let constructor = &self.constructor;
+        let type_name = &ident(constructor.name.as_function_name());
+        let definition =
+            TLType::compile_struct(constructor, type_name, constructor.name.as_str(), derives);
+        let (impl_generics, type_generics) = TLType::compile_generics(constructor);
+        let is_generic_return = constructor
+            .fields
+            .iter()
+            .any(|x| x.kind.generic_param().as_ref() == Some(&constructor.return_type));
+        let return_type = if is_generic_return {
+            // `invokeWithLayer {X:Type} layer:int query:!X = X` answers what `query` answers
+            format!("{}::Return", constructor.return_type.as_str())
+        } else {
+            constructor.return_type.as_rust_type_path()
+        }
+        .parse::<TokenStream>()
+        .unwrap();
+        quote! {
+            #definition
+
+            impl #impl_generics RemoteCall for #type_name #type_generics {
+                type Return = #return_type;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_function_compile() {
+        let method = json!({
+            "id": "1615239032",
+            "method": "req_pq",
+            "params": [
+                {
+                  "name": "nonce",
+                  "type": "int128"
+                }
+            ],
+            "type": "ResPQ"
+        });
+        let function = Function::new(&serde_json::from_value(method).unwrap());
+        let target = quote! {
+            #[doc = " `req_pq#60469778 nonce:int128 = ResPQ`"]
+            #[derive(Debug, Clone)]
+            pub struct ReqPq {
+                pub nonce: [u8; 16],
+            }
+            impl TLType for ReqPq {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    if id != 0x6046_9778i32 {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "req_pq"
+                        });
+                    }
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    (0x6046_9778i32).tl_write(output)?;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+            impl TLBareType for ReqPq {
+                fn tl_read_bare_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = ReqPq {
+                        nonce: TLType::tl_read_with_context(input, context)?,
+                    };
+                    context.leave();
+                    Ok(result)
+                }
+
+                fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    let mut result = 0usize;
+                    result += self.nonce.tl_write(output)?;
+                    Ok(result)
+                }
+
+                fn bare_serialized_len(&self) -> usize {
+                    let mut result = 0usize;
+                    result += self.nonce.serialized_len();
+                    result
+                }
+            }
+            impl RemoteCall for ReqPq {
+                type Return = super::tl::ResPq;
+            }
+        };
+        assert_eq!(
+            target.to_string(),
+            function.compile(&Derives::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_generic_function_compile() {
+        let method = json!({
+            "id": "-627372787",
+            "method": "invokeWithLayer",
+            "params": [
+                {
+                  "name": "layer",
+                  "type": "int"
+                },
+                {
+                  "name": "query",
+                  "type": "!X"
+                }
+            ],
+            "type": "X"
+        });
+        let function = Function::new(&serde_json::from_value(method).unwrap());
+        let target = quote! {
+            #[doc = " `invokeWithLayer#da9b0d0d {X:Type} layer:int query:!X = X`"]
+            #[derive(Debug, Clone)]
+            pub struct InvokeWithLayer<X: RemoteCall> {
+                pub layer: i32,
+                pub query: X,
+            }
+            impl<X: RemoteCall> TLType for InvokeWithLayer<X> {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    if id != -0x2564_f2f3i32 {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "invokeWithLayer"
+                        });
+                    }
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    (-0x2564_f2f3i32).tl_write(output)?;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+            impl<X: RemoteCall> TLBareType for InvokeWithLayer<X> {
+                fn tl_read_bare_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = InvokeWithLayer {
+                        layer: TLType::tl_read_with_context(input, context)?,
+                        query: TLType::tl_read_with_context(input, context)?,
+                    };
+                    context.leave();
+                    Ok(result)
+                }
+
+                fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    let mut result = 0usize;
+                    result += self.layer.tl_write(output)?;
+                    result += self.query.tl_write(output)?;
+                    Ok(result)
+                }
+
+                fn bare_serialized_len(&self) -> usize {
+                    let mut result = 0usize;
+                    result += self.layer.serialized_len();
+                    result += self.query.serialized_len();
+                    result
+                }
+            }
+            impl<X: RemoteCall> RemoteCall for InvokeWithLayer<X> {
+                type Return = X::Return;
+            }
+        };
+        assert_eq!(
+            target.to_string(),
+            function.compile(&Derives::default()).to_string()
+        );
+    }
+}
@@ -1,43 +1,241 @@
 use crate::{
-    generator::tl_type::{Constructor, TLType},
-    schema::Schema,
+    generator::{
+        derives::Derives,
+        function::Function,
+        object::{Link, Object},
+        tl_type::{Constructor, TLType},
+    },
+    schema::{self, Schema},
     tl_ident::TLIdent,
 };
-use std::collections::HashMap;
+use heck::CamelCase;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 pub mod arg;
+pub mod builder;
+pub mod derives;
+pub mod function;
+pub mod object;
+pub mod registry;
 pub mod tl_type;
 
 pub struct Generator {
-    pub types: HashMap<String, Vec<TLType>>, // pub methods
+    /// Types of each namespace (e.g. `tl`, `auth` or `a.b`), both sorted by name
+    pub types: BTreeMap<String, Vec<TLType>>,
+    /// Methods whose types are all generated, in schema order
+    pub functions: Vec<Function>,
+    pub object: Object,
+    /// Rust paths of types with a zero-copy `...Ref<'a>` variant
+    pub borrowed_types: HashSet<String>,
+    pub derives: Derives,
+    /// Generate builders of structs, enum variants and methods
+    pub builders: bool,
 }
 
 impl Generator {
-    pub fn new(schema: &Schema) -> Self {
-        let mut namespace: HashMap<String, HashMap<TLIdent, TLType>> = HashMap::new();
-        for c in schema
-            .constructors
-            .iter()
-            .filter(|x| x.return_type.should_generate())
-        {
+    /// `link` is the `Object` of another schema which `Object` of this one falls back to
+    pub fn new(original: &Schema, link: Option<Link>) -> Self {
+        let schema = &Self::resolve_collisions(original);
+        let constructors = Self::resolve_constructors(schema);
+        let mut namespace: BTreeMap<String, BTreeMap<TLIdent, TLType>> = BTreeMap::new();
+        for c in constructors.iter() {
             let ns = c
                 .return_type
                 .namespace_split()
                 .0
                 .unwrap_or_else(|| TLIdent::TOP_MOD.to_string());
-            let module = namespace.entry(ns).or_insert_with(HashMap::new);
+            let module = namespace.entry(ns).or_default();
             module
                 .entry(c.return_type.clone())
-                .or_insert_with(TLType::default)
+                .or_default()
                 .constructors
                 .push(Constructor::new(c));
         }
 
-        Generator {
-            types: namespace
-                .into_iter()
-                .map(|(k, v)| (k, v.into_iter().map(|x| x.1).collect()))
-                .collect(),
+        let defined: HashSet<TLIdent> = constructors
+            .iter()
+            .map(|x| x.return_type.clone())
+            .chain(Some(TLIdent::new("Object")))
+            .collect();
+        let functions = schema
+            .methods
+            .iter()
+            .filter(|x| {
+                let generics = x
+                    .params
+                    .iter()
+                    .filter_map(|x| x.kind.generic_param())
+                    .collect_vec();
+                x.params
+                    .iter()
+                    .map(|x| &x.kind)
+                    .chain(Some(&x.return_type))
+                    .map(|x| x.base_type())
+                    .all(|x| x.is_built_in_type() || defined.contains(&x) || generics.contains(&x))
+            })
+            .map(Function::new)
+            .collect();
+
+        let types: BTreeMap<String, Vec<TLType>> = namespace
+            .into_iter()
+            .map(|(k, v)| (k, v.into_iter().map(|x| x.1).collect()))
+            .collect();
+        let mut object = Object::new(types.values().flat_map(|x| x.iter()));
+        object.link = link;
+        let mut generator = Generator {
+            functions,
+            derives: Derives::new(&constructors, object.link.is_some()),
+            object,
+            types,
+            borrowed_types: Self::borrowed_types(&constructors),
+            builders: false,
+        };
+        generator.set_signatures(original);
+        generator
+    }
+
+    /// Show declarations of `schema` in generated docs, e.g. the schema before types are renamed
+    pub fn set_signatures(&mut self, schema: &Schema) {
+        let signatures = schema.signatures();
+        let constructors = self
+            .types
+            .values_mut()
+            .flat_map(|x| x.iter_mut())
+            .flat_map(|x| x.constructors.iter_mut())
+            .chain(self.functions.iter_mut().map(|x| &mut x.constructor));
+        for c in constructors {
+            if let Some(signature) = signatures.get(&c.id) {
+                c.signature = signature.clone();
+            }
+        }
+    }
+
+    /// Rename types, variants and methods which map to the same Rust name
+    ///
+    /// Sorted by schema name, the first one keeps its name and the others get a number, e.g.
+    /// `foo_bar` and `fooBar` of one type become variants `FooBar` and `FooBar2`.
+    fn resolve_collisions(schema: &Schema) -> Schema {
+        let mut schema = schema.clone();
+
+        let types = schema
+            .constructors
+            .iter()
+            .map(|x| x.return_type.clone())
+            .filter(|x| x.should_generate() && !x.is_object())
+            .unique()
+            .collect_vec();
+        let renames = rename_colliding(
+            &types,
+            |x| (x.namespace_split().0, x.as_rust_type_name()),
+            |x, n| {
+                let name = format!("{}{}", x.namespace_split().1.to_camel_case(), n);
+                match x.namespace_split().0 {
+                    Some(namespace) => TLIdent::new(format!("{}.{}", namespace, name)),
+                    None => TLIdent::new(name),
+                }
+            },
+        );
+        for (from, to) in renames {
+            schema
+                .rename_type(from.as_str(), &to.namespace_split().1)
+                .unwrap();
+        }
+
+        let variants = schema
+            .constructors
+            .iter()
+            .filter(|x| !x.return_type.is_object())
+            .map(|x| (x.return_type.clone(), x.predicate.clone()))
+            .into_group_map();
+        for (return_type, predicates) in variants {
+            let renames = rename_colliding(&predicates, TLIdent::as_variant_name, |x, n| {
+                TLIdent::new(format!("{}{}", x.as_str(), n))
+            });
+            for (from, to) in renames {
+                for x in schema.constructors.iter_mut() {
+                    if x.return_type == return_type && x.predicate == from {
+                        x.predicate = to.clone();
+                    }
+                }
+            }
+        }
+
+        let methods = schema
+            .methods
+            .iter()
+            .map(|x| x.method.clone())
+            .collect_vec();
+        let renames = rename_colliding(&methods, TLIdent::as_function_name, |x, n| {
+            TLIdent::new(format!("{}{}", x.as_str(), n))
+        });
+        for (from, to) in renames {
+            for x in schema.methods.iter_mut().filter(|x| x.method == from) {
+                x.method = to.clone();
+            }
+        }
+        schema
+    }
+
+    /// Constructors to generate, those of `Object` (e.g. `gzip_packed`) become their own type
+    fn resolve_constructors(schema: &Schema) -> Vec<schema::Constructor> {
+        schema
+            .constructors
+            .iter()
+            .cloned()
+            .map(|mut x| {
+                if x.return_type.is_object() {
+                    x.return_type = x
+                        .predicate
+                        .bare_type()
+                        .unwrap_or_else(|| x.predicate.clone());
+                }
+                x
+            })
+            .filter(|x| x.return_type.should_generate())
+            .collect()
+    }
+
+    /// Types containing `bytes`, `string` or `Object`, directly or through other types
+    fn borrowed_types(constructors: &[schema::Constructor]) -> HashSet<String> {
+        let mut result = HashSet::new();
+        result.insert(TLIdent::new("Object").as_rust_type_path());
+        loop {
+            let count = result.len();
+            for c in constructors {
+                if c.params.iter().any(|x| x.kind.is_borrowed(&result)) {
+                    result.insert(c.return_type.as_rust_type_path());
+                }
+            }
+            if result.len() == count {
+                return result;
+            }
+        }
+    }
+}
+
+/// New names of `names` whose `key` is taken by a name sorted before them, `rename(x, n)` with the
+/// smallest `n` from 2 whose key is free
+fn rename_colliding<K: Ord>(
+    names: &[TLIdent],
+    key: impl Fn(&TLIdent) -> K,
+    rename: impl Fn(&TLIdent, usize) -> TLIdent,
+) -> Vec<(TLIdent, TLIdent)> {
+    let mut groups: BTreeMap<K, Vec<&TLIdent>> = BTreeMap::new();
+    for x in names.iter().unique() {
+        groups.entry(key(x)).or_default().push(x);
+    }
+    let mut used: BTreeSet<K> = names.iter().map(&key).collect();
+    let mut result = vec![];
+    for group in groups.values_mut() {
+        group.sort();
+        for x in group.iter().skip(1) {
+            let to = (2..)
+                .map(|n| rename(x, n))
+                .find(|to| used.insert(key(to)))
+                .unwrap();
+            result.push(((*x).clone(), to));
         }
     }
+    result
 }
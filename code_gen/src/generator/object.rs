@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::{
+    generator::{derives::Derives, tl_type::TLType},
+    tl_ident::TLIdent,
+    utils::{doc, i32_suffixed, ident},
+};
+
+const BOOL_TRUE: i32 = -1_720_552_011;
+const BOOL_FALSE: i32 = -1_132_882_121;
+const VECTOR: i32 = 0x1cb5_c415;
+
+/// `Object` of another generated tree, tried when no constructor id of this tree matches
+#[derive(Debug, Clone)]
+pub struct Link {
+    /// Variant name in `Object`
+    pub variant: String,
+    /// Rust path of the `object` module in the other tree, relative to this `object` module
+    pub module: String,
+}
+
+/// Boxed `Object`, any type of the schema dispatched on its constructor id
+///
+/// Variants are boxed, as types like `message` contain `Object` themselves.
+/// `Vector t` is read as `Vector(Vec<Object>)`, which only holds boxed items: bare ones (e.g. of
+/// `Vector<long>`) must be read as the declared type, e.g. the `Return` type of the method an
+/// `rpc_result` answers.
+#[derive(Debug, Clone, Default)]
+pub struct Object {
+    /// Variant name, schema type and its constructor ids, `Bool` excluded
+    pub variants: Vec<(Ident, TLIdent, Vec<i32>)>,
+    pub link: Option<Link>,
+}
+
+impl Object {
+    pub fn new<'a>(types: impl Iterator<Item = &'a TLType>) -> Self {
+        let mut variants = types
+            .map(|x| {
+                let return_type = &x.constructors[0].return_type;
+                (
+                    ident(return_type.as_function_name()),
+                    return_type.clone(),
+                    x.constructors.iter().map(|x| x.id).collect_vec(),
+                )
+            })
+            .collect_vec();
+        variants.sort_by(|a, b| a.0.cmp(&b.0));
+        Object {
+            variants,
+            link: None,
+        }
+    }
+
+    /// Generate `Object` implementing `TLType`, and `ObjectRef<'a>` implementing `TLDeserialize`
+    pub fn compile(&self, borrowed_types: &HashSet<String>, derives: &Derives) -> TokenStream {
+        let owned = self.compile_type(&ident("Object"), &quote!(), derives, |x| {
+            x.as_rust_type_path()
+        });
+        let borrowed = self.compile_type(&ident("ObjectRef"), &quote!(<'a>), derives, |x| {
+            x.as_rust_borrowed_type_path(borrowed_types)
+        });
+        let owned_read =
+            self.compile_match(&ident("Object"), &quote!(TLType::tl_read_with_context));
+        let borrowed_read = self.compile_match(
+            &ident("ObjectRef"),
+            &quote!(TLDeserialize::tl_deserialize_with_context),
+        );
+        let variant_names = &self.variant_names();
+        quote! {
+            #owned
+
+            impl TLType for Object {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    // the id is read again by the chosen type
+                    let mut id = [0u8; 4];
+                    input.read_exact(&mut id)?;
+                    let input = &mut std::io::Read::chain(&id[..], input);
+                    let id = i32::from_le_bytes(id);
+                    #owned_read
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    match self {
+                        #(Object::#variant_names(x) => x.tl_write(output),)*
+                    }
+                }
+
+                fn serialized_len(&self) -> usize {
+                    match self {
+                        #(Object::#variant_names(x) => x.serialized_len(),)*
+                    }
+                }
+            }
+
+            #borrowed
+
+            impl<'a> TLDeserialize<'a> for ObjectRef<'a> {
+                fn tl_deserialize_with_context(
+                    input: &mut &'a [u8],
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    if input.len() < 4 {
+                        return Err(MyError::Truncated);
+                    }
+                    let id = i32::from_le_bytes([input[0], input[1], input[2], input[3]]);
+                    #borrowed_read
+                }
+            }
+        }
+    }
+
+    fn variant_names(&self) -> Vec<Ident> {
+        vec![ident("Bool"), ident("Vector")]
+            .into_iter()
+            .chain(self.variants.iter().map(|x| x.0.clone()))
+            .chain(self.link.iter().map(|x| ident(&x.variant)))
+            .collect_vec()
+    }
+
+    fn compile_type(
+        &self,
+        type_name: &Ident,
+        generics: &TokenStream,
+        derives: &Derives,
+        type_path: impl Fn(&TLIdent) -> String,
+    ) -> TokenStream {
+        let variants = self
+            .variants
+            .iter()
+            .map(|(name, ty, _)| {
+                let path = type_path(ty);
+                let attrs = derives.compile_field_attrs(&path);
+                let path = path.parse::<TokenStream>().unwrap();
+                quote!(#name(#attrs Box<#path>))
+            })
+            .chain(self.link.iter().map(|x| {
+                let name = ident(&x.variant);
+                let path = format!("{}::{}{}", x.module, type_name, generics)
+                    .parse::<TokenStream>()
+                    .unwrap();
+                let attrs = derives.compile_field_attrs(&generics.to_string());
+                quote!(#name(#attrs #path))
+            }))
+            .collect_vec();
+        let vector_path = format!("Vec<{}{}>", type_name, generics);
+        let vector_attrs = derives.compile_field_attrs(&vector_path);
+        let vector_path = vector_path.parse::<TokenStream>().unwrap();
+        let derive = derives.compile_object();
+        let doc = doc(
+            "Any boxed type of the schema, dispatched on its constructor id\n\n\
+             `Vector` only holds boxed items, read a vector of bare ones as its declared type",
+        );
+        quote! {
+            #doc
+            #derive
+            pub enum #type_name #generics {
+                Bool(bool),
+                Vector(#vector_attrs #vector_path),
+                #(#variants,)*
+            }
+        }
+    }
+
+    /// Generate dispatch on `id`, `read` is the function reading the chosen type
+    fn compile_match(&self, type_name: &Ident, read: &TokenStream) -> TokenStream {
+        let bool_ids = [i32_suffixed(BOOL_TRUE), i32_suffixed(BOOL_FALSE)];
+        let vector_id = i32_suffixed(VECTOR);
+        let arms = self
+            .variants
+            .iter()
+            .map(|(name, _, ids)| {
+                let ids = ids.iter().map(|x| i32_suffixed(*x)).collect_vec();
+                quote!(#(#ids)|* => #type_name::#name(Box::new(#read(input, context)?)))
+            })
+            .collect_vec();
+        let fallback = match &self.link {
+            Some(link) => {
+                let name = ident(&link.variant);
+                quote!(_ => #type_name::#name(#read(input, context)?))
+            }
+            None => quote!(_ => return Err(MyError::UnknownConstructor { id, expected: "Object" })),
+        };
+        quote! {
+            Ok(match id {
+                #(#bool_ids)|* => #type_name::Bool(#read(input, context)?),
+                #(#arms,)*
+                #vector_id => {
+                    context.enter()?;
+                    let result = #type_name::Vector(#read(input, context)?);
+                    context.leave();
+                    result
+                }
+                #fallback,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_match_compile() {
+        let object = Object {
+            variants: vec![(ident("ResPq"), TLIdent::new("ResPQ"), vec![0x0516_2463])],
+            link: Some(Link {
+                variant: "Rpc".to_string(),
+                module: "super::super::generate_rpc::object".to_string(),
+            }),
+        };
+        let target = quote! {
+            Ok(match id {
+                -0x668d_8a4bi32 | -0x4386_68c9i32 => Object::Bool(TLType::tl_read_with_context(input, context)?),
+                0x0516_2463i32 => Object::ResPq(Box::new(TLType::tl_read_with_context(input, context)?)),
+                0x1cb5_c415i32 => {
+                    context.enter()?;
+                    let result = Object::Vector(TLType::tl_read_with_context(input, context)?);
+                    context.leave();
+                    result
+                }
+                _ => Object::Rpc(TLType::tl_read_with_context(input, context)?),
+            })
+        };
+        assert_eq!(
+            target.to_string(),
+            object
+                .compile_match(&ident("Object"), &quote!(TLType::tl_read_with_context))
+                .to_string()
+        );
+    }
+}
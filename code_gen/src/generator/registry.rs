@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use crate::{
+    generator::Generator,
+    schema::{Param, Schema},
+    tl_ident::TLIdent,
+    utils::i32_suffixed,
+};
+
+/// Entry of `CONSTRUCTORS`, see `ConstructorInfo` in `tl_types`
+struct Entry<'a> {
+    id: i32,
+    name: &'a TLIdent,
+    params: &'a [Param],
+    return_type: &'a TLIdent,
+    is_method: bool,
+    layer: Option<u32>,
+}
+
+/// Generate `CONSTRUCTORS` of every constructor and method sorted by id, along with lookups
+pub fn compile(schema: &Schema, generator: &Generator) -> TokenStream {
+    let rust_paths = rust_paths(generator);
+    let mut entries = schema
+        .constructors
+        .iter()
+        .map(|x| Entry {
+            id: x.id,
+            name: &x.predicate,
+            params: &x.params,
+            return_type: &x.return_type,
+            is_method: false,
+            layer: x.layer,
+        })
+        .chain(schema.methods.iter().map(|x| Entry {
+            id: x.id,
+            name: &x.method,
+            params: &x.params,
+            return_type: &x.return_type,
+            is_method: true,
+            layer: x.layer,
+        }))
+        .collect_vec();
+    entries.sort_by_key(|x| x.id);
+    // only the same declaration repeated by merged schemas, see `Schema::check_duplicates`
+    entries.dedup_by_key(|x| x.id);
+    let entries = entries
+        .iter()
+        .map(|x| {
+            let id = i32_suffixed(x.id);
+            let name = x.name.as_str();
+            let return_type = x.return_type.as_str();
+            let is_method = x.is_method;
+            let rust_path = match rust_paths.get(&x.id) {
+                Some(path) => quote!(Some(#path)),
+                None => quote!(None),
+            };
+            let layer = compile_layer(x.layer);
+            let params = x
+                .params
+                .iter()
+                .map(|x| {
+                    let name = x.name.as_str();
+                    let kind = x.kind.as_str();
+                    quote!(ParamInfo { name: #name, kind: #kind })
+                })
+                .collect_vec();
+            quote! {
+                ConstructorInfo {
+                    id: #id,
+                    name: #name,
+                    params: &[#(#params),*],
+                    return_type: #return_type,
+                    rust_path: #rust_path,
+                    is_method: #is_method,
+                    layer: #layer,
+                }
+            }
+        })
+        .collect_vec();
+    let layer = schema.layer.map(|x| {
+        let x = Literal::u32_unsuffixed(x);
+        quote! {
+            /// Layer of the schema, e.g. for `invokeWithLayer`
+            pub const LAYER: u32 = #x;
+        }
+    });
+
+    quote! {
+        #layer
+
+        /// Every constructor and method of the schema, sorted by id
+        pub static CONSTRUCTORS: &[ConstructorInfo] = &[#(#entries),*];
+
+        pub fn by_id(id: i32) -> Option<&'static ConstructorInfo> {
+            CONSTRUCTORS
+                .binary_search_by_key(&id, |x| x.id)
+                .ok()
+                .map(|x| &CONSTRUCTORS[x])
+        }
+
+        pub fn by_name(name: &str) -> Option<&'static ConstructorInfo> {
+            CONSTRUCTORS.iter().find(|x| x.name == name)
+        }
+
+        /// Layer which defined type `return_type` (e.g. `auth.SentCode`), the lowest of its constructors
+        pub fn type_layer(return_type: &str) -> Option<u32> {
+            CONSTRUCTORS
+                .iter()
+                .filter(|x| !x.is_method && x.return_type == return_type)
+                .filter_map(|x| x.layer)
+                .min()
+        }
+    }
+}
+
+fn compile_layer(layer: Option<u32>) -> TokenStream {
+    match layer {
+        Some(x) => {
+            let x = Literal::u32_unsuffixed(x);
+            quote!(Some(#x))
+        }
+        None => quote!(None),
+    }
+}
+
+/// Rust path of each generated constructor and method, relative to the generated module
+fn rust_paths(generator: &Generator) -> HashMap<i32, String> {
+    let mut result = HashMap::new();
+    for (namespace, types) in generator.types.iter() {
+        let namespace = TLIdent::as_module_names(namespace).join("::");
+        for ty in types {
+            let type_name = ty.constructors[0].return_type.as_rust_type_name();
+            for c in ty.constructors.iter() {
+                let path = if ty.constructors.len() == 1 {
+                    format!("{}::{}", namespace, type_name)
+                } else {
+                    format!("{}::{}::{}", namespace, type_name, c.name.as_variant_name())
+                };
+                result.insert(c.id, path);
+            }
+        }
+    }
+    for function in generator.functions.iter() {
+        let c = &function.constructor;
+        let path = format!("{}::{}", TLIdent::FUNCTIONS_MOD, c.name.as_function_name());
+        result.insert(c.id, path);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rust_paths() {
+        let schema = Schema::from_tl(
+            r#"
+boolTrue#997275b5 = Bool;
+resPQ#05162463 nonce:int128 server_nonce:int128 pq:bytes
+    server_public_key_fingerprints:Vector<long> = ResPQ;
+auth.sentCode phone_registered:Bool phone_code_hash:string = auth.SentCode;
+auth.sentAppCode phone_registered:Bool phone_code_hash:string = auth.SentCode;
+---functions---
+req_pq#60469778 nonce:int128 = ResPQ;
+"#,
+        )
+        .unwrap();
+        let paths = rust_paths(&Generator::new(&schema, None));
+        let paths = paths.iter().sorted();
+        assert_eq!(
+            paths,
+            vec![
+                (&-0x1c6c_ddff, &"auth::SentCode::SentAppCode".to_string()),
+                (&0x0516_2463, &"tl::ResPq".to_string()),
+                (&0x2215_bcbd, &"auth::SentCode::SentCode".to_string()),
+                (&0x6046_9778, &"functions::ReqPq".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust_paths_of_escaped_names() {
+        let schema = Schema::from_tl(
+            r#"
+a.b.deep id:int = a.b.Deep;
+static.thing self:int = static.Self;
+foo_bar = Foo;
+fooBar = Foo;
+foo_bar_ = Foo_bar;
+foo_bar_x = FooBar;
+---functions---
+get_it = Foo;
+getIt = Foo;
+"#,
+        )
+        .unwrap();
+        let paths = rust_paths(&Generator::new(&schema, None));
+        let paths = paths.values().sorted();
+        assert_eq!(
+            paths,
+            vec![
+                "a::b::Deep",
+                "functions::GetIt",
+                "functions::GetIt2",
+                "static_::Self_",
+                "tl::Foo::FooBar",
+                "tl::Foo::FooBar2",
+                "tl::FooBar",
+                "tl::FooBar2",
+            ]
+        );
+    }
+}
@@ -1,12 +1,14 @@
+use std::collections::HashSet;
+
 use itertools::Itertools;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::quote;
 
 use crate::{
-    generator::arg::Arg,
+    generator::{arg::Arg, builder, derives::Derives},
     schema,
     tl_ident::TLIdent,
-    utils::{i32_suffixed, ident},
+    utils::{doc, i32_suffixed, ident},
 };
 
 #[derive(Debug, Clone)]
@@ -15,6 +17,9 @@ pub struct Constructor {
     pub fields: Vec<Arg>,
     pub return_type: TLIdent,
     pub id: i32,
+    pub description: Option<String>,
+    /// TL declaration, shown in generated docs
+    pub signature: String,
 }
 
 impl Constructor {
@@ -28,12 +33,33 @@ impl Constructor {
                 .map(|x| Arg {
                     name: x.name,
                     kind: x.kind,
+                    description: x.description,
                 })
                 .collect_vec(),
             return_type: info.return_type.clone(),
             id: info.id,
+            description: info.description.clone(),
+            signature: info.signature(),
         }
     }
+
+    /// Generate `///` doc from the description, followed by the TL declaration
+    pub fn compile_doc(&self) -> TokenStream {
+        let text = match &self.description {
+            Some(x) => format!("{}\n\n`{}`", x, self.signature),
+            None => format!("`{}`", self.signature),
+        };
+        doc(&text)
+    }
+
+    /// Type parameters from `!X` fields, e.g. `X` of `invokeWithLayer`
+    pub fn generics(&self) -> Vec<Ident> {
+        self.fields
+            .iter()
+            .filter_map(|x| x.kind.generic_param())
+            .map(|x| ident(x.as_str()))
+            .collect_vec()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -42,11 +68,80 @@ pub struct TLType {
 }
 
 impl TLType {
-    pub fn compile(&self) -> TokenStream {
+    pub fn compile(&self, derives: &Derives) -> TokenStream {
         match self.constructors.len() {
             0 => unreachable!(),
-            1 => self.compile_as_struct(),
-            _ => self.compile_as_enum(),
+            1 => self.compile_as_struct(derives),
+            _ => self.compile_as_enum(derives),
+        }
+    }
+
+    /// Generate `TLDeserialize`, along with a `...Ref<'a>` definition if any field borrows from input
+    pub fn compile_borrowed(
+        &self,
+        borrowed_types: &HashSet<String>,
+        derives: &Derives,
+    ) -> TokenStream {
+        let read = quote!(TLDeserialize::tl_deserialize_with_context);
+        let (definition, type_name, self_type) =
+            if borrowed_types.contains(&self.constructors[0].return_type.as_rust_type_path()) {
+                let type_name = ident(format!("{}Ref", self.type_name()));
+                let definition = self.compile_definition(&type_name, &quote!(<'a>), derives, |x| {
+                    x.as_rust_borrowed_type_path(borrowed_types)
+                });
+                let self_type = quote!(#type_name<'a>);
+                (definition, type_name, self_type)
+            } else {
+                let type_name = self.type_name();
+                let self_type = quote!(#type_name);
+                (quote!(), type_name, self_type)
+            };
+
+        if self.constructors.len() > 1 {
+            let read_body = self.compile_enum_read_body(&type_name, &read);
+            return quote! {
+                #definition
+
+                impl<'a> TLDeserialize<'a> for #self_type {
+                    fn tl_deserialize_with_context(
+                        input: &mut &'a [u8],
+                        context: &mut DecodeContext,
+                    ) -> MyResult<Self> {
+                        #read_body
+                    }
+                }
+            };
+        }
+
+        let constructor = &self.constructors[0];
+        let check_id = Self::compile_check_id(constructor, self.tl_name(), &read);
+        let bare_read_body = Self::compile_bare_read_body(constructor, &type_name, &read);
+        let input = ident(if constructor.fields.is_empty() {
+            "_input"
+        } else {
+            "input"
+        });
+        quote! {
+            #definition
+
+            impl<'a> TLDeserialize<'a> for #self_type {
+                fn tl_deserialize_with_context(
+                    input: &mut &'a [u8],
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    #check_id
+                    TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+                }
+            }
+
+            impl<'a> TLBareDeserialize<'a> for #self_type {
+                fn tl_deserialize_bare_with_context(
+                    #input: &mut &'a [u8],
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    #bare_read_body
+                }
+            }
         }
     }
 
@@ -54,55 +149,157 @@ impl TLType {
         ident(self.constructors[0].return_type.as_rust_type_name())
     }
 
-    fn compile_as_enum(&self) -> TokenStream {
+    /// Generate `XBuilder` of a struct, or `{Enum}{Variant}Builder` of each enum variant
+    pub fn compile_builders(&self) -> TokenStream {
+        let type_name = &self.type_name();
+        if self.constructors.len() == 1 {
+            let builder_name = ident(format!("{}Builder", type_name));
+            return builder::compile(
+                &self.constructors[0],
+                &builder_name,
+                &quote!(#type_name),
+                type_name,
+            );
+        }
+        let builders = self.constructors.iter().map(|x| {
+            let variant_name = ident(x.name.as_variant_name());
+            let builder_name = ident(format!("{}{}Builder", type_name, variant_name));
+            builder::compile(
+                x,
+                &builder_name,
+                &quote!(#type_name::#variant_name),
+                type_name,
+            )
+        });
+        quote!(#(#builders)*)
+    }
+
+    /// Type name in schema, reported by `MyError::UnknownConstructor`
+    fn tl_name(&self) -> &str {
+        self.constructors[0].return_type.as_str()
+    }
+
+    fn compile_as_enum(&self, derives: &Derives) -> TokenStream {
         let type_name = self.type_name();
-        let definition = self.compile_enum_definition();
+        let definition =
+            self.compile_definition(&type_name, &quote!(), derives, |x| x.as_rust_type_path());
         let write_impl = self.compile_enum_write_impl();
-        let read_impl = self.compile_enum_read_impl();
+        let serialized_len_impl = self.compile_enum_serialized_len_impl();
+        let read_body =
+            self.compile_enum_read_body(&type_name, &quote!(TLType::tl_read_with_context));
         quote! {
             #definition
 
             impl TLType for #type_name {
-                #read_impl
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    #read_body
+                }
+
                 #write_impl
+
+                #serialized_len_impl
             }
         }
     }
 
-    /// Generate enum definition
-    fn compile_enum_definition(&self) -> TokenStream {
-        let type_name = self.type_name();
+    /// Generate struct or enum definition, `field_type` maps schema type to Rust type path
+    fn compile_definition(
+        &self,
+        type_name: &Ident,
+        generics: &TokenStream,
+        derives: &Derives,
+        field_type: impl Fn(&TLIdent) -> String,
+    ) -> TokenStream {
+        if self.constructors.len() == 1 {
+            return Self::compile_struct_definition(
+                &self.constructors[0],
+                type_name,
+                generics,
+                derives,
+                field_type,
+            );
+        }
         let variants: Vec<TokenStream> = self
             .constructors
             .iter()
             .map(|x| {
                 let variant_name = ident(x.name.as_variant_name());
-                let fields = x
-                    .fields
-                    .iter()
-                    .map(|x| {
-                        let field_name = ident(x.name.as_field_name());
-                        let type_path = x.kind.as_rust_type_path().parse::<TokenStream>().unwrap();
-                        quote!(
-                            #field_name: #type_path
-                        )
-                    })
-                    .collect_vec();
+                let doc = x.compile_doc();
+                let fields = Self::compile_fields(x, &quote!(), derives, &field_type);
+                if fields.is_empty() {
+                    return quote!(#doc #variant_name);
+                }
                 quote! {
+                    #doc
                     #variant_name {
                         #(#fields,)*
                     }
                 }
             })
             .collect_vec();
+        let derive = derives.compile(&self.constructors.iter().collect_vec());
         quote! {
-            #[derive(Debug, Clone)]
-            pub enum #type_name {
+            #derive
+            pub enum #type_name #generics {
                 #(#variants,)*
             }
         }
     }
 
+    fn compile_struct_definition(
+        constructor: &Constructor,
+        type_name: &Ident,
+        generics: &TokenStream,
+        derives: &Derives,
+        field_type: impl Fn(&TLIdent) -> String,
+    ) -> TokenStream {
+        let fields = Self::compile_fields(constructor, &quote!(pub), derives, &field_type);
+        let derive = derives.compile(&[constructor]);
+        let doc = constructor.compile_doc();
+        // e.g. `true = True`, `null = Null`, still built and matched by `Type {}`
+        if fields.is_empty() {
+            return quote! {
+                #doc
+                #derive
+                pub struct #type_name #generics;
+            };
+        }
+        quote! {
+            #doc
+            #derive
+            pub struct #type_name #generics {
+                #(#fields, )*
+            }
+        }
+    }
+
+    /// Generate fields, `visibility` is empty for enum variants
+    fn compile_fields(
+        constructor: &Constructor,
+        visibility: &TokenStream,
+        derives: &Derives,
+        field_type: &impl Fn(&TLIdent) -> String,
+    ) -> Vec<TokenStream> {
+        constructor
+            .fields
+            .iter()
+            .filter(|x| x.is_field())
+            .map(|x| {
+                let field_name = ident(x.name.as_field_name());
+                let type_path = field_type(&x.kind);
+                let doc = x.compile_doc();
+                let attrs = derives.compile_field_attrs(&type_path);
+                let type_path = type_path.parse::<TokenStream>().unwrap();
+                quote!(
+                    #doc #attrs #visibility #field_name: #type_path
+                )
+            })
+            .collect_vec()
+    }
+
     /// Generate tl_write
     fn compile_enum_write_impl(&self) -> TokenStream {
         let type_name = &self.type_name();
@@ -110,25 +307,29 @@ impl TLType {
             .constructors
             .iter()
             .map(|x| {
-                let variant_name = ident(x.name.as_variant_name());
                 let id = i32_suffixed(x.id);
-                let field_names = &x
-                    .fields
-                    .iter()
-                    .map(|x| ident(x.name.as_field_name()))
-                    .collect_vec();
+                let pattern = Self::compile_variant_pattern(x, type_name);
+                let writes = Self::compile_write_fields(x, false);
                 quote! {
-                    #type_name::#variant_name {
-                        #(#field_names,)*
-                    } => {
+                    #pattern => {
                         (#id).tl_write(output)?;
-                        #(result += #field_names.tl_write(output)?;)*
+                        #(#writes)*
                     }
                 }
             })
             .collect_vec();
+        if !self.has_fields() {
+            return quote! {
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    match self {
+                        #(#variants)*
+                    }
+                    Ok(4usize)
+                }
+            };
+        }
         quote! {
-            fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+            fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
                 let mut result = 4usize;
                 match self {
                     #(#variants)*
@@ -138,78 +339,364 @@ impl TLType {
         }
     }
 
-    /// Generate tl_read
-    fn compile_enum_read_impl(&self) -> TokenStream {
+    /// Generate serialized_len
+    fn compile_enum_serialized_len_impl(&self) -> TokenStream {
+        if !self.has_fields() {
+            return quote! {
+                fn serialized_len(&self) -> usize {
+                    4usize
+                }
+            };
+        }
         let type_name = &self.type_name();
         let variants: Vec<TokenStream> = self
             .constructors
             .iter()
             .map(|x| {
-                let id = i32_suffixed(x.id);
-                let variant_name = ident(x.name.as_variant_name());
-                let field_names = x
-                    .fields
-                    .iter()
-                    .map(|x| ident(x.name.as_field_name()))
-                    .collect_vec();
+                let pattern = Self::compile_variant_pattern(x, type_name);
+                let lens = Self::compile_serialized_len_fields(x, false);
                 quote! {
-                    #id => #type_name::#variant_name {
-                        #(#field_names: TLType::tl_read(input)?,)*
+                    #pattern => {
+                        #(#lens)*
                     }
                 }
             })
             .collect_vec();
         quote! {
-            fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-                let id:i32 = TLType::tl_read(input)?;
-                Ok(match id{
-                    #(#variants,)*
-                    _ => unreachable!(),
-                })
+            fn serialized_len(&self) -> usize {
+                let mut result = 4usize;
+                match self {
+                    #(#variants)*
+                }
+                result
             }
         }
     }
 
-    fn compile_as_struct(&self) -> TokenStream {
-        let type_name = &self.type_name();
-        let constructor = &self.constructors[0];
-        let field_exprs: Vec<TokenStream> = constructor
-            .fields
+    /// Whether any constructor has fields to read and write, `flags:#` included
+    fn has_fields(&self) -> bool {
+        self.constructors.iter().any(|x| !x.fields.is_empty())
+    }
+
+    /// Generate constructor id check of a single constructor type
+    fn compile_check_id(
+        constructor: &Constructor,
+        tl_name: &str,
+        read: &TokenStream,
+    ) -> TokenStream {
+        let id = &i32_suffixed(constructor.id);
+        quote! {
+            let id: i32 = #read(input, context)?;
+            if id != #id {
+                return Err(MyError::UnknownConstructor { id, expected: #tl_name });
+            }
+        }
+    }
+
+    /// Generate body of bare read, `read` is the function used for each field
+    fn compile_bare_read_body(
+        constructor: &Constructor,
+        type_name: &Ident,
+        read: &TokenStream,
+    ) -> TokenStream {
+        let result = Self::compile_read_constructor(constructor, &quote!(#type_name), read);
+        quote! {
+            context.enter()?;
+            let result = #result;
+            context.leave();
+            Ok(result)
+        }
+    }
+
+    /// Generate body of enum read, `read` is the function used for each field
+    fn compile_enum_read_body(&self, type_name: &Ident, read: &TokenStream) -> TokenStream {
+        let tl_name = self.tl_name();
+        let variants: Vec<TokenStream> = self
+            .constructors
             .iter()
             .map(|x| {
-                let field_name = ident(x.name.as_field_name());
-                let field_path = x.kind.as_rust_type_path().parse::<TokenStream>().unwrap();
+                let id = i32_suffixed(x.id);
+                let variant_name = ident(x.name.as_variant_name());
+                let result =
+                    Self::compile_read_constructor(x, &quote!(#type_name::#variant_name), read);
                 quote! {
-                    #field_name: #field_path
+                    #id => #result
                 }
             })
             .collect_vec();
-        let field_names = &constructor
+        quote! {
+            context.enter()?;
+            let id: i32 = #read(input, context)?;
+            let result = match id {
+                #(#variants,)*
+                _ => return Err(MyError::UnknownConstructor { id, expected: #tl_name }),
+            };
+            context.leave();
+            Ok(result)
+        }
+    }
+
+    /// Names of fields stored in Rust, `flags:#` excluded
+    fn field_names(constructor: &Constructor) -> Vec<Ident> {
+        constructor
             .fields
             .iter()
+            .filter(|x| x.is_field())
             .map(|x| ident(x.name.as_field_name()))
+            .collect_vec()
+    }
+
+    /// Generate the pattern binding fields of an enum variant, a unit variant has no braces
+    fn compile_variant_pattern(constructor: &Constructor, type_name: &Ident) -> TokenStream {
+        let variant_name = ident(constructor.name.as_variant_name());
+        let field_names = Self::field_names(constructor);
+        if field_names.is_empty() {
+            return quote!(#type_name::#variant_name);
+        }
+        quote! {
+            #type_name::#variant_name {
+                #(#field_names,)*
+            }
+        }
+    }
+
+    /// Generate the expression building `path` from input, `read` is the function used for each field
+    ///
+    /// Constructors with `flags:#` read each field into a local first,
+    /// as conditional fields depend on the flags read before them
+    fn compile_read_constructor(
+        constructor: &Constructor,
+        path: &TokenStream,
+        read: &TokenStream,
+    ) -> TokenStream {
+        let field_names = Self::field_names(constructor);
+        if constructor.fields.iter().all(|x| x.is_field()) {
+            let fields = field_names
+                .iter()
+                .map(|x| quote!(#x: #read(input, context)?))
+                .collect_vec();
+            return quote! {
+                #path {
+                    #(#fields,)*
+                }
+            };
+        }
+        let fields = constructor
+            .fields
+            .iter()
+            .map(|x| {
+                let field_name = ident(x.name.as_field_name());
+                if x.kind.is_flags() {
+                    return quote!(let #field_name: u32 = #read(input, context)?;);
+                }
+                match x.kind.conditional_split() {
+                    Some((flags, bit, _)) => {
+                        let flags = ident(flags.as_field_name());
+                        let bit = Literal::u32_unsuffixed(bit);
+                        if x.kind.is_true_flag() {
+                            quote!(let #field_name = #flags & (1 << #bit) != 0;)
+                        } else {
+                            quote! {
+                                let #field_name = if #flags & (1 << #bit) != 0 {
+                                    Some(#read(input, context)?)
+                                } else {
+                                    None
+                                };
+                            }
+                        }
+                    }
+                    None => quote!(let #field_name = #read(input, context)?;),
+                }
+            })
             .collect_vec();
-        let id = &i32_suffixed(constructor.id);
         quote! {
-            #[derive(Debug, Clone)]
-            pub struct #type_name{
-                #(#field_exprs, )*
+            {
+                #(#fields)*
+                #path {
+                    #(#field_names,)*
+                }
             }
+        }
+    }
 
-            impl TLType for #type_name {
-                fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-                    let id: i32 = TLType::tl_read(input)?;
-                    assert_eq!(#id, id);
-                    Ok(#type_name {
-                        #(#field_names: TLType::tl_read(input)?,)*
-                    })
+    /// Generate the flags word from the conditional fields it controls
+    fn compile_flags(constructor: &Constructor, flags: &TLIdent, in_self: bool) -> TokenStream {
+        let bits = Arg::conditional_of(flags, &constructor.fields)
+            .map(|(x, bit)| {
+                let field_name = ident(x.name.as_field_name());
+                let bit = Literal::u32_unsuffixed(bit);
+                let present = match (x.kind.is_true_flag(), in_self) {
+                    (true, true) => quote!(self.#field_name),
+                    (true, false) => quote!(*#field_name),
+                    (false, true) => quote!(self.#field_name.is_some()),
+                    (false, false) => quote!(#field_name.is_some()),
+                };
+                quote!(if #present { 1u32 << #bit } else { 0 })
+            })
+            .collect_vec();
+        if bits.is_empty() {
+            return quote!(0u32);
+        }
+        quote!(#(#bits)|*)
+    }
+
+    /// Generate statements adding each written field to `result`,
+    /// fields are `self.field` if `in_self`, otherwise bound by reference
+    fn compile_write_fields(constructor: &Constructor, in_self: bool) -> Vec<TokenStream> {
+        constructor
+            .fields
+            .iter()
+            .filter(|x| !x.kind.is_true_flag())
+            .map(|x| {
+                let field_name = ident(x.name.as_field_name());
+                let (field, field_ref) = if in_self {
+                    (quote!(self.#field_name), quote!(&self.#field_name))
+                } else {
+                    (quote!(#field_name), quote!(#field_name))
+                };
+                if x.kind.is_flags() {
+                    let flags = Self::compile_flags(constructor, &x.name, in_self);
+                    quote!(result += (#flags).tl_write(output)?;)
+                } else if x.kind.conditional_split().is_some() {
+                    quote! {
+                        if let Some(value) = #field_ref {
+                            result += value.tl_write(output)?;
+                        }
+                    }
+                } else {
+                    quote!(result += #field.tl_write(output)?;)
                 }
+            })
+            .collect_vec()
+    }
 
-                fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
-                    (#id).tl_write(output)?;
-                    let mut result = 4usize;
-                    #(result += self.#field_names.tl_write(output)?;)*
+    /// Generate statements adding each field length to `result`, see `compile_write_fields`
+    fn compile_serialized_len_fields(constructor: &Constructor, in_self: bool) -> Vec<TokenStream> {
+        constructor
+            .fields
+            .iter()
+            .filter(|x| !x.kind.is_true_flag())
+            .map(|x| {
+                let field_name = ident(x.name.as_field_name());
+                let (field, field_ref) = if in_self {
+                    (quote!(self.#field_name), quote!(&self.#field_name))
+                } else {
+                    (quote!(#field_name), quote!(#field_name))
+                };
+                if x.kind.is_flags() {
+                    quote!(result += 4;)
+                } else if x.kind.conditional_split().is_some() {
+                    quote! {
+                        if let Some(value) = #field_ref {
+                            result += value.serialized_len();
+                        }
+                    }
+                } else {
+                    quote!(result += #field.serialized_len();)
+                }
+            })
+            .collect_vec()
+    }
+
+    fn compile_as_struct(&self, derives: &Derives) -> TokenStream {
+        Self::compile_struct(
+            &self.constructors[0],
+            &self.type_name(),
+            self.tl_name(),
+            derives,
+        )
+    }
+
+    /// Generate `(<X: RemoteCall>, <X>)` for `!X` fields, empty if there is none
+    pub fn compile_generics(constructor: &Constructor) -> (TokenStream, TokenStream) {
+        let generics = &constructor.generics();
+        if generics.is_empty() {
+            return (quote!(), quote!());
+        }
+        (
+            quote!(<#(#generics: RemoteCall),*>),
+            quote!(<#(#generics),*>),
+        )
+    }
+
+    /// Generate a struct implementing `TLType` and `TLBareType`, `tl_name` is reported on unknown id
+    pub fn compile_struct(
+        constructor: &Constructor,
+        type_name: &Ident,
+        tl_name: &str,
+        derives: &Derives,
+    ) -> TokenStream {
+        let read = quote!(TLType::tl_read_with_context);
+        let (impl_generics, type_generics) = Self::compile_generics(constructor);
+        let definition =
+            Self::compile_struct_definition(constructor, type_name, &impl_generics, derives, |x| {
+                x.as_rust_type_path()
+            });
+        let check_id = Self::compile_check_id(constructor, tl_name, &read);
+        let bare_read_body = Self::compile_bare_read_body(constructor, type_name, &read);
+        let writes = Self::compile_write_fields(constructor, true);
+        let lens = Self::compile_serialized_len_fields(constructor, true);
+        let id = &i32_suffixed(constructor.id);
+        // without fields, bare read and write do not touch `input` and `output`
+        let (input, output, bare_write_body, bare_len_body) = if constructor.fields.is_empty() {
+            (
+                ident("_input"),
+                ident("_output"),
+                quote!(Ok(0usize)),
+                quote!(0usize),
+            )
+        } else {
+            (
+                ident("input"),
+                ident("output"),
+                quote! {
+                    let mut result = 0usize;
+                    #(#writes)*
                     Ok(result)
+                },
+                quote! {
+                    let mut result = 0usize;
+                    #(#lens)*
+                    result
+                },
+            )
+        };
+        quote! {
+            #definition
+
+            impl #impl_generics TLType for #type_name #type_generics {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    #check_id
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    (#id).tl_write(output)?;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+
+            impl #impl_generics TLBareType for #type_name #type_generics {
+                fn tl_read_bare_with_context(
+                    #input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    #bare_read_body
+                }
+
+                fn tl_write_bare(&self, #output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    #bare_write_body
+                }
+
+                fn bare_serialized_len(&self) -> usize {
+                    #bare_len_body
                 }
             }
         }
@@ -300,6 +787,7 @@ mod test {
         quote! {
             #[derive(Debug, Clone)]
             pub enum PQInnerData {
+                #[doc = " `p_q_inner_data_temp#3c6a84d4 pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 expires_in:int = P_Q_inner_data`"]
                 PQInnerDataTemp {
                     pq: TLBytes,
                     p: TLBytes,
@@ -309,6 +797,7 @@ mod test {
                     new_nonce: [u8; 32],
                     expires_in: i32,
                 },
+                #[doc = " `p_q_inner_data#83c95aec pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 = P_Q_inner_data`"]
                 PQInnerData {
                     pq: TLBytes,
                     p: TLBytes,
@@ -319,31 +808,40 @@ mod test {
                 },
             }
             impl TLType for PQInnerData {
-                fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-                    let id: i32 = TLType::tl_read(input)?;
-                    Ok(match id {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    let result = match id {
                         0x3c6a_84d4i32 => PQInnerData::PQInnerDataTemp {
-                            pq: TLType::tl_read(input)?,
-                            p: TLType::tl_read(input)?,
-                            q: TLType::tl_read(input)?,
-                            nonce: TLType::tl_read(input)?,
-                            server_nonce: TLType::tl_read(input)?,
-                            new_nonce: TLType::tl_read(input)?,
-                            expires_in: TLType::tl_read(input)?,
+                            pq: TLType::tl_read_with_context(input, context)?,
+                            p: TLType::tl_read_with_context(input, context)?,
+                            q: TLType::tl_read_with_context(input, context)?,
+                            nonce: TLType::tl_read_with_context(input, context)?,
+                            server_nonce: TLType::tl_read_with_context(input, context)?,
+                            new_nonce: TLType::tl_read_with_context(input, context)?,
+                            expires_in: TLType::tl_read_with_context(input, context)?,
                         },
                         #pq_inner_data_temp_id => PQInnerData::PQInnerData {
-                            pq: TLType::tl_read(input)?,
-                            p: TLType::tl_read(input)?,
-                            q: TLType::tl_read(input)?,
-                            nonce: TLType::tl_read(input)?,
-                            server_nonce: TLType::tl_read(input)?,
-                            new_nonce: TLType::tl_read(input)?,
+                            pq: TLType::tl_read_with_context(input, context)?,
+                            p: TLType::tl_read_with_context(input, context)?,
+                            q: TLType::tl_read_with_context(input, context)?,
+                            nonce: TLType::tl_read_with_context(input, context)?,
+                            server_nonce: TLType::tl_read_with_context(input, context)?,
+                            new_nonce: TLType::tl_read_with_context(input, context)?,
                         },
-                        _ => unreachable!(),
-                    })
+                        _ => return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "P_Q_inner_data"
+                        }),
+                    };
+                    context.leave();
+                    Ok(result)
                 }
 
-                fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
                     let mut result = 4usize;
                     match self {
                         PQInnerData::PQInnerDataTemp {
@@ -383,6 +881,45 @@ mod test {
                     }
                     Ok(result)
                 }
+
+                fn serialized_len(&self) -> usize {
+                    let mut result = 4usize;
+                    match self {
+                        PQInnerData::PQInnerDataTemp {
+                            pq,
+                            p,
+                            q,
+                            nonce,
+                            server_nonce,
+                            new_nonce,
+                            expires_in,
+                        } => {
+                            result += pq.serialized_len();
+                            result += p.serialized_len();
+                            result += q.serialized_len();
+                            result += nonce.serialized_len();
+                            result += server_nonce.serialized_len();
+                            result += new_nonce.serialized_len();
+                            result += expires_in.serialized_len();
+                        }
+                        PQInnerData::PQInnerData {
+                            pq,
+                            p,
+                            q,
+                            nonce,
+                            server_nonce,
+                            new_nonce,
+                        } => {
+                            result += pq.serialized_len();
+                            result += p.serialized_len();
+                            result += q.serialized_len();
+                            result += nonce.serialized_len();
+                            result += server_nonce.serialized_len();
+                            result += new_nonce.serialized_len();
+                        }
+                    }
+                    result
+                }
             }
         }
     }
@@ -390,32 +927,60 @@ mod test {
     fn target_struct() -> TokenStream {
         let pq_inner_data_temp_id = &i32_suffixed(-2083955988);
         quote! {
+            #[doc = " `p_q_inner_data#83c95aec pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 = P_Q_inner_data`"]
             #[derive(Debug, Clone)]
             pub struct PQInnerData {
-                pq: TLBytes,
-                p: TLBytes,
-                q: TLBytes,
-                nonce: [u8; 16],
-                server_nonce: [u8; 16],
-                new_nonce: [u8; 32],
+                pub pq: TLBytes,
+                pub p: TLBytes,
+                pub q: TLBytes,
+                pub nonce: [u8; 16],
+                pub server_nonce: [u8; 16],
+                pub new_nonce: [u8; 32],
             }
             impl TLType for PQInnerData {
-                fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-                    let id: i32 = TLType::tl_read(input)?;
-                    assert_eq!(#pq_inner_data_temp_id, id);
-                    Ok(PQInnerData {
-                        pq: TLType::tl_read(input)?,
-                        p: TLType::tl_read(input)?,
-                        q: TLType::tl_read(input)?,
-                        nonce: TLType::tl_read(input)?,
-                        server_nonce: TLType::tl_read(input)?,
-                        new_nonce: TLType::tl_read(input)?,
-                    })
-                }
-
-                fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    if id != #pq_inner_data_temp_id {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "P_Q_inner_data"
+                        });
+                    }
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
                     (#pq_inner_data_temp_id).tl_write(output)?;
-                    let mut result = 4usize;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+            impl TLBareType for PQInnerData {
+                fn tl_read_bare_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = PQInnerData {
+                        pq: TLType::tl_read_with_context(input, context)?,
+                        p: TLType::tl_read_with_context(input, context)?,
+                        q: TLType::tl_read_with_context(input, context)?,
+                        nonce: TLType::tl_read_with_context(input, context)?,
+                        server_nonce: TLType::tl_read_with_context(input, context)?,
+                        new_nonce: TLType::tl_read_with_context(input, context)?,
+                    };
+                    context.leave();
+                    Ok(result)
+                }
+
+                fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    let mut result = 0usize;
                     result += self.pq.tl_write(output)?;
                     result += self.p.tl_write(output)?;
                     result += self.q.tl_write(output)?;
@@ -424,6 +989,171 @@ mod test {
                     result += self.new_nonce.tl_write(output)?;
                     Ok(result)
                 }
+
+                fn bare_serialized_len(&self) -> usize {
+                    let mut result = 0usize;
+                    result += self.pq.serialized_len();
+                    result += self.p.serialized_len();
+                    result += self.q.serialized_len();
+                    result += self.nonce.serialized_len();
+                    result += self.server_nonce.serialized_len();
+                    result += self.new_nonce.serialized_len();
+                    result
+                }
+            }
+        }
+    }
+
+    fn target_borrowed_struct() -> TokenStream {
+        let pq_inner_data_temp_id = &i32_suffixed(-2083955988);
+        // parsed like generated field types, keeping `&'a` joint
+        let bytes = "&'a [u8]".parse::<TokenStream>().unwrap();
+        quote! {
+            #[doc = " `p_q_inner_data#83c95aec pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 = P_Q_inner_data`"]
+            #[derive(Debug, Clone)]
+            pub struct PQInnerDataRef<'a> {
+                pub pq: #bytes,
+                pub p: #bytes,
+                pub q: #bytes,
+                pub nonce: [u8; 16],
+                pub server_nonce: [u8; 16],
+                pub new_nonce: [u8; 32],
+            }
+
+            impl<'a> TLDeserialize<'a> for PQInnerDataRef<'a> {
+                fn tl_deserialize_with_context(
+                    input: &mut &'a [u8],
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+                    if id != #pq_inner_data_temp_id {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "P_Q_inner_data"
+                        });
+                    }
+                    TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+                }
+            }
+
+            impl<'a> TLBareDeserialize<'a> for PQInnerDataRef<'a> {
+                fn tl_deserialize_bare_with_context(
+                    input: &mut &'a [u8],
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = PQInnerDataRef {
+                        pq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                        p: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                        q: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                        nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                        server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                        new_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                    };
+                    context.leave();
+                    Ok(result)
+                }
+            }
+        }
+    }
+
+    fn flags_document() -> Value {
+        json!({
+            "id": "-2122046771",
+            "predicate": "peerSettings",
+            "params": [
+                {
+                  "name": "flags",
+                  "type": "#"
+                },
+                {
+                  "name": "report_spam",
+                  "type": "flags.0?true"
+                },
+                {
+                  "name": "geo_distance",
+                  "type": "flags.1?int"
+                }
+            ],
+            "type": "PeerSettings"
+        })
+    }
+
+    fn target_flags_struct() -> TokenStream {
+        let peer_settings_id = &i32_suffixed(-2122046771);
+        quote! {
+            #[doc = " `peerSettings#818422cd flags:# report_spam:flags.0?true geo_distance:flags.1?int = PeerSettings`"]
+            #[derive(Debug, Clone)]
+            pub struct PeerSettings {
+                pub report_spam: bool,
+                pub geo_distance: Option<i32>,
+            }
+            impl TLType for PeerSettings {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    if id != #peer_settings_id {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "PeerSettings"
+                        });
+                    }
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    (#peer_settings_id).tl_write(output)?;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+            impl TLBareType for PeerSettings {
+                fn tl_read_bare_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = {
+                        let flags: u32 = TLType::tl_read_with_context(input, context)?;
+                        let report_spam = flags & (1 << 0) != 0;
+                        let geo_distance = if flags & (1 << 1) != 0 {
+                            Some(TLType::tl_read_with_context(input, context)?)
+                        } else {
+                            None
+                        };
+                        PeerSettings {
+                            report_spam,
+                            geo_distance,
+                        }
+                    };
+                    context.leave();
+                    Ok(result)
+                }
+
+                fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    let mut result = 0usize;
+                    result += (if self.report_spam { 1u32 << 0 } else { 0 }
+                        | if self.geo_distance.is_some() { 1u32 << 1 } else { 0 })
+                    .tl_write(output)?;
+                    if let Some(value) = &self.geo_distance {
+                        result += value.tl_write(output)?;
+                    }
+                    Ok(result)
+                }
+
+                fn bare_serialized_len(&self) -> usize {
+                    let mut result = 0usize;
+                    result += 4;
+                    if let Some(value) = &self.geo_distance {
+                        result += value.serialized_len();
+                    }
+                    result
+                }
             }
         }
     }
@@ -438,7 +1168,10 @@ mod test {
                 .map(|x| Constructor::new(&x))
                 .collect_vec(),
         };
-        assert_eq!(target_enum().to_string(), ty.compile().to_string());
+        assert_eq!(
+            target_enum().to_string(),
+            ty.compile(&Derives::default()).to_string()
+        );
     }
 
     #[test]
@@ -446,11 +1179,186 @@ mod test {
         let documents = &documents()[1..];
         let ty = TLType {
             constructors: documents
-                .into_iter()
+                .iter()
                 .map(|x| serde_json::from_value(x.clone()).unwrap())
                 .map(|x| Constructor::new(&x))
                 .collect_vec(),
         };
-        assert_eq!(target_struct().to_string(), ty.compile().to_string());
+        assert_eq!(
+            target_struct().to_string(),
+            ty.compile(&Derives::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_borrowed_struct_compile() {
+        let documents = &documents()[1..];
+        let ty = TLType {
+            constructors: documents
+                .iter()
+                .map(|x| serde_json::from_value(x.clone()).unwrap())
+                .map(|x| Constructor::new(&x))
+                .collect_vec(),
+        };
+        let borrowed_types = vec![ty.constructors[0].return_type.as_rust_type_path()]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            target_borrowed_struct().to_string(),
+            ty.compile_borrowed(&borrowed_types, &Derives::default())
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_flags_struct_compile() {
+        let ty = TLType {
+            constructors: vec![Constructor::new(
+                &serde_json::from_value(flags_document()).unwrap(),
+            )],
+        };
+        assert_eq!(
+            target_flags_struct().to_string(),
+            ty.compile(&Derives::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_unit_struct_compile() {
+        let document = json!({
+            "id": "1072550713",
+            "predicate": "true",
+            "params": [],
+            "type": "True"
+        });
+        let ty = TLType {
+            constructors: vec![Constructor::new(&serde_json::from_value(document).unwrap())],
+        };
+        let target = quote! {
+            #[doc = " `true#3fedd339 = True`"]
+            #[derive(Debug, Clone)]
+            pub struct True;
+            impl TLType for True {
+                fn tl_read_with_context(
+                    input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    let id: i32 = TLType::tl_read_with_context(input, context)?;
+                    if id != 0x3fed_d339i32 {
+                        return Err(MyError::UnknownConstructor {
+                            id,
+                            expected: "True"
+                        });
+                    }
+                    TLBareType::tl_read_bare_with_context(input, context)
+                }
+
+                fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    (0x3fed_d339i32).tl_write(output)?;
+                    Ok(4 + self.tl_write_bare(output)?)
+                }
+
+                fn serialized_len(&self) -> usize {
+                    4 + self.bare_serialized_len()
+                }
+            }
+            impl TLBareType for True {
+                fn tl_read_bare_with_context(
+                    _input: &mut dyn std::io::Read,
+                    context: &mut DecodeContext,
+                ) -> MyResult<Self> {
+                    context.enter()?;
+                    let result = True {};
+                    context.leave();
+                    Ok(result)
+                }
+
+                fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+                    Ok(0usize)
+                }
+
+                fn bare_serialized_len(&self) -> usize {
+                    0usize
+                }
+            }
+        };
+        assert_eq!(
+            target.to_string(),
+            ty.compile(&Derives::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_documented_fields() {
+        let document = json!({
+            "id": "-1720552011",
+            "predicate": "boolTrue",
+            "params": [
+                {"name": "reason", "type": "string", "description": "Why it is true"}
+            ],
+            "type": "Bool",
+            "description": "The true value\nof the schema"
+        });
+        let constructor = Constructor::new(&serde_json::from_value(document).unwrap());
+        let type_name = ident("Bool");
+        let target = quote! {
+            #[doc = " The true value"]
+            #[doc = " of the schema"]
+            #[doc = ""]
+            #[doc = " `boolTrue#997275b5 reason:string = Bool`"]
+            #[derive(Debug, Clone)]
+            pub struct Bool {
+                #[doc = " Why it is true"]
+                pub reason: String,
+            }
+        };
+        assert_eq!(
+            target.to_string(),
+            TLType::compile_struct_definition(
+                &constructor,
+                &type_name,
+                &quote!(),
+                &Derives::default(),
+                |x| x.as_rust_type_path()
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_unit_enum_write() {
+        let documents = vec![
+            json!({"id": "-1132882121", "predicate": "boolFalse", "params": [], "type": "Bool"}),
+            json!({"id": "-1720552011", "predicate": "boolTrue", "params": [], "type": "Bool"}),
+        ];
+        let ty = TLType {
+            constructors: documents
+                .into_iter()
+                .map(|x| Constructor::new(&serde_json::from_value(x).unwrap()))
+                .collect_vec(),
+        };
+        let target = quote! {
+            fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+                match self {
+                    Bool::BoolFalse => {
+                        (-0x4386_68c9i32).tl_write(output)?;
+                    }
+                    Bool::BoolTrue => {
+                        (-0x668d_8a4bi32).tl_write(output)?;
+                    }
+                }
+                Ok(4usize)
+            }
+        };
+        assert_eq!(target.to_string(), ty.compile_enum_write_impl().to_string());
+        let target = quote! {
+            fn serialized_len(&self) -> usize {
+                4usize
+            }
+        };
+        assert_eq!(
+            target.to_string(),
+            ty.compile_enum_serialized_len_impl().to_string()
+        );
     }
 }
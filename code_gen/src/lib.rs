@@ -0,0 +1,353 @@
+#![recursion_limit = "128"]
+//! Generate Rust code from MTProto schema
+//!
+//! Besides the `code_gen` binary, which regenerates `src/generate_*` of this repository,
+//! generation can run from a build script of any crate depending on `i_am_mt`:
+//!
+//! ```no_run
+//! // build.rs
+//! use code_gen::Config;
+//!
+//! fn main() {
+//!     println!("cargo:rerun-if-changed=schema/api.tl");
+//!     Config::new(std::env::var("OUT_DIR").unwrap())
+//!         .schema_path("schema/api.tl")
+//!         .module_name("api")
+//!         .derives(&["Debug", "Clone", "PartialEq", "Eq", "Hash", "Default"])
+//!         .serde(true)
+//!         .rename_type("InputPeer", "Peer")
+//!         .single_file(true)
+//!         .generate()
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! and in the crate, `pub mod api { include!(concat!(env!("OUT_DIR"), "/api.rs")); }`
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use failure::bail;
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    generator::{derives::DEFAULT_DERIVES, object::Link, registry, Generator},
+    output::Output,
+    schema::Schema,
+    tl_ident::TLIdent,
+    utils::{ident, reroot, MyResult},
+};
+
+pub mod diff;
+pub mod generator;
+pub mod output;
+pub mod schema;
+pub mod tl_ident;
+pub mod tl_parser;
+pub mod utils;
+
+/// `prelude` of generated modules, resolving names used by generated code from `i_am_mt`
+pub const DEFAULT_PRELUDE: &str = "pub use i_am_mt::{
+    tl_types::{
+        constructor_info::{ConstructorInfo, ParamInfo},
+        decode_context::DecodeContext,
+        tl_bare::TLBare,
+        tl_bare_vector::TLBareVector,
+        tl_bytes::TLBytes,
+        RemoteCall, TLBareDeserialize, TLBareType, TLDeserialize, TLType,
+    },
+    utils::{MyError, MyResult},
+};
+";
+
+/// Where and how to generate a module from one or more schemas
+#[derive(Debug, Clone)]
+pub struct Config {
+    out_dir: PathBuf,
+    module_name: String,
+    schema_paths: Vec<PathBuf>,
+    schemas: Vec<Schema>,
+    derives: Vec<String>,
+    serde: bool,
+    builders: bool,
+    layer: Option<u32>,
+    require_layer: bool,
+    type_names: BTreeMap<String, String>,
+    link: Option<Link>,
+    single_file: bool,
+    prelude: String,
+    skip_format: bool,
+}
+
+impl Config {
+    /// Generate into `out_dir`, as module `generated` by default
+    pub fn new(out_dir: impl Into<PathBuf>) -> Self {
+        Config {
+            out_dir: out_dir.into(),
+            module_name: "generated".to_string(),
+            schema_paths: vec![],
+            schemas: vec![],
+            derives: DEFAULT_DERIVES.iter().map(|x| x.to_string()).collect(),
+            serde: false,
+            builders: false,
+            layer: None,
+            require_layer: false,
+            type_names: BTreeMap::new(),
+            link: None,
+            single_file: false,
+            prelude: DEFAULT_PRELUDE.to_string(),
+            skip_format: false,
+        }
+    }
+
+    /// Add a `.tl` or JSON schema file, all schemas are merged into one module
+    pub fn schema_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.schema_paths.push(path.into());
+        self
+    }
+
+    /// Add an already loaded schema, e.g. `Schema::rpc_schema()`
+    pub fn schema(mut self, schema: Schema) -> Self {
+        self.schemas.push(schema);
+        self
+    }
+
+    pub fn module_name(mut self, name: impl Into<String>) -> Self {
+        self.module_name = name.into();
+        self
+    }
+
+    /// Traits derived by every generated type, instead of `Debug, Clone`
+    ///
+    /// `Eq`, `Hash` and `Default` are left out on types which can not derive them.
+    pub fn derives(mut self, derives: &[&str]) -> Self {
+        self.derives = derives.iter().map(|x| x.to_string()).collect();
+        self
+    }
+
+    /// Derive `Serialize` and `Deserialize` on every generated type when feature `serde` of the
+    /// generated crate is enabled
+    pub fn serde(mut self, serde: bool) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Generate `XBuilder` of each struct and method, and `{Enum}{Variant}Builder` of each enum
+    /// variant, taking required fields in `new` and conditional fields as setters
+    pub fn builders(mut self, builders: bool) -> Self {
+        self.builders = builders;
+        self
+    }
+
+    /// Layer of the schema, instead of the one it records
+    pub fn layer(mut self, layer: u32) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Fail when neither `layer` nor the schema gives a layer, which `LAYER` and
+    /// `invokeWithLayer` need
+    pub fn require_layer(mut self, require_layer: bool) -> Self {
+        self.require_layer = require_layer;
+        self
+    }
+
+    /// Generate schema type `tl_name` (e.g. `auth.SentCode`) as `rust_name`
+    pub fn rename_type(mut self, tl_name: impl Into<String>, rust_name: impl Into<String>) -> Self {
+        self.type_names.insert(tl_name.into(), rust_name.into());
+        self
+    }
+
+    /// Fall back to `Object` at `module` (relative to this `object` module) as `variant`
+    pub fn link(mut self, variant: impl Into<String>, module: impl Into<String>) -> Self {
+        self.link = Some(Link {
+            variant: variant.into(),
+            module: module.into(),
+        });
+        self
+    }
+
+    /// Write `<module_name>.rs` with inline modules, which can be `include!`d from `OUT_DIR`,
+    /// instead of directory `<module_name>/`
+    pub fn single_file(mut self, single_file: bool) -> Self {
+        self.single_file = single_file;
+        self
+    }
+
+    /// Content of the `prelude` module, instead of `DEFAULT_PRELUDE`
+    ///
+    /// In directory mode an existing `prelude.rs` is never overwritten.
+    pub fn prelude(mut self, prelude: impl Into<String>) -> Self {
+        self.prelude = prelude.into();
+        self
+    }
+
+    /// Do not run `rustfmt` on generated sources
+    ///
+    /// Sources are also written unformatted when `rustfmt` can not be run, but `check` fails, as
+    /// it compares with formatted files.
+    pub fn skip_format(mut self, skip_format: bool) -> Self {
+        self.skip_format = skip_format;
+        self
+    }
+
+    /// Generate and write files
+    pub fn generate(&self) -> MyResult<()> {
+        self.output(false)?.write()
+    }
+
+    /// Generate and compare with files on disk, returning paths which are out of date or would
+    /// not be generated any more
+    pub fn check(&self) -> MyResult<Vec<PathBuf>> {
+        Ok(self.output(true)?.check())
+    }
+
+    fn output(&self, require_format: bool) -> MyResult<Output> {
+        let mut output = Output {
+            prelude: self.prelude.clone(),
+            skip_format: self.skip_format,
+            require_format,
+            ..Output::default()
+        };
+        let modules = self.compile()?;
+        if !self.single_file {
+            let module_dir = self.out_dir.join(&self.module_name);
+            for (path, tokens) in modules.iter() {
+                output.add_module(&module_dir, path, tokens)?;
+            }
+            return Ok(output);
+        }
+
+        let prelude_module_name = ident(TLIdent::PRELUDE_MOD);
+        let prelude = match self.prelude.parse::<TokenStream>() {
+            Ok(x) => x,
+            Err(_) => bail!("prelude is not valid Rust"),
+        };
+        let modules = nest_modules(&modules);
+        let tokens = quote! {
+            pub mod #prelude_module_name { #prelude }
+            #modules
+        };
+        output.add_file(
+            self.out_dir.join(format!("{}.rs", self.module_name)),
+            &tokens,
+        )?;
+        Ok(output)
+    }
+
+    /// Generated modules by path, e.g. `[auth]` or `[a, b]` of namespace `a.b`
+    fn compile(&self) -> MyResult<Vec<(Vec<String>, TokenStream)>> {
+        if self.schema_paths.is_empty() && self.schemas.is_empty() {
+            bail!("no schema to generate `{}` from", self.module_name);
+        }
+        for derive in self.derives.iter() {
+            if derive.parse::<TokenStream>().is_err() {
+                bail!("`{}` is not a valid derive", derive);
+            }
+        }
+
+        let mut schema = Schema::default();
+        for path in self.schema_paths.iter() {
+            schema.extend(Schema::from_path(path)?);
+        }
+        for other in self.schemas.iter() {
+            schema.extend(other.clone());
+        }
+        schema.check_duplicates()?;
+        schema.layer = self.layer.or(schema.layer);
+        if self.require_layer && schema.layer.is_none() {
+            bail!(
+                "schema of `{}` records no layer, set it with `Config::layer`",
+                self.module_name
+            );
+        }
+        // the registry keeps names of the schema, only Rust paths are renamed
+        let mut renamed = schema.clone();
+        for (tl_name, rust_name) in self.type_names.iter() {
+            renamed.rename_type(tl_name, rust_name)?;
+        }
+
+        let mut generator = Generator::new(&renamed, self.link.clone());
+        generator.set_signatures(&schema);
+        generator.builders = self.builders;
+        generator.derives.traits = self.derives.clone();
+        generator.derives.serde = self.serde;
+        Ok(compile_modules(&schema, &generator))
+    }
+}
+
+fn compile_modules(schema: &Schema, generator: &Generator) -> Vec<(Vec<String>, TokenStream)> {
+    let prelude_module_name = ident(TLIdent::PRELUDE_MOD);
+    let derives = &generator.derives;
+    let mut result = vec![];
+    for (namespace, types) in generator.types.iter() {
+        let types = types.iter().map(|x| {
+            let owned = x.compile(derives);
+            let borrowed = x.compile_borrowed(&generator.borrowed_types, derives);
+            let builders = if generator.builders {
+                x.compile_builders()
+            } else {
+                quote!()
+            };
+            quote!(#owned #borrowed #builders)
+        });
+        let tokens = quote!(
+            use super::#prelude_module_name::*;
+            #(#types)*
+        );
+        let path = TLIdent::as_module_names(namespace);
+        let tokens = reroot(tokens, path.len());
+        result.push((path, tokens));
+    }
+    if !generator.functions.is_empty() {
+        let functions = generator.functions.iter().map(|x| {
+            let function = x.compile(derives);
+            let builder = if generator.builders {
+                x.compile_builder()
+            } else {
+                quote!()
+            };
+            quote!(#function #builder)
+        });
+        let tokens = quote!(
+            use super::#prelude_module_name::*;
+            #(#functions)*
+        );
+        result.push((vec![TLIdent::FUNCTIONS_MOD.to_string()], tokens));
+    }
+    let object = generator.object.compile(&generator.borrowed_types, derives);
+    let tokens = quote!(
+        use super::#prelude_module_name::*;
+        #object
+    );
+    result.push((vec![TLIdent::OBJECT_MOD.to_string()], tokens));
+
+    let registry = registry::compile(schema, generator);
+    let tokens = quote!(
+        use super::#prelude_module_name::*;
+        #registry
+    );
+    result.push((vec![TLIdent::REGISTRY_MOD.to_string()], tokens));
+    result
+}
+
+/// Inline modules, e.g. `pub mod a { ... pub mod b { ... } }` of paths `[a]` and `[a, b]`
+fn nest_modules(modules: &[(Vec<String>, TokenStream)]) -> TokenStream {
+    let names = modules.iter().map(|x| &x.0[0]).unique();
+    let modules = names.map(|name| {
+        let own = modules
+            .iter()
+            .filter(|x| x.0.len() == 1 && &x.0[0] == name)
+            .map(|x| &x.1);
+        let children = modules
+            .iter()
+            .filter(|x| x.0.len() > 1 && &x.0[0] == name)
+            .map(|x| (x.0[1..].to_vec(), x.1.clone()))
+            .collect::<Vec<_>>();
+        let children = nest_modules(&children);
+        let name = ident(name);
+        quote!(pub mod #name { #(#own)* #children })
+    });
+    quote!(#(#modules)*)
+}
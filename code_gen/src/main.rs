@@ -1,82 +1,80 @@
-#![recursion_limit = "128"]
-//! Generate code from MTProto schema to Rust code
+//! Regenerate `src/generate_proto` and `src/generate_rpc` from the embedded schemas
+//!
+//! `code_gen diff OLD NEW [--json]` reports changes between two schemas instead.
 
-use std::{
-    env::current_dir,
-    fs::{create_dir_all, File, OpenOptions},
-    io::Write,
-    path::Path,
-};
+use std::{env::current_dir, process::exit};
 
-use proc_macro2::TokenStream;
-use quote::quote;
+use code_gen::{diff::SchemaDiff, schema::Schema, Config, DEFAULT_PRELUDE};
 
-use crate::{
-    generator::Generator,
-    schema::Schema,
-    tl_ident::TLIdent,
-    utils::{ident, MyResult},
-};
-
-pub mod generator;
-pub mod schema;
-pub mod tl_ident;
-pub mod utils;
+/// Layer of the embedded `rpc.json`, which does not record it
+const RPC_LAYER: u32 = 23;
 
 fn main() {
-    generate(
-        &current_dir().unwrap().join("src").join("generate_proto"),
-        &Schema::proto_schema(),
-    )
-    .unwrap();
-
-    generate(
-        &current_dir().unwrap().join("src").join("generate_rpc"),
-        &Schema::rpc_schema(),
-    )
-    .unwrap();
-}
-
-fn generate(module_dir: &Path, schema: &Schema) -> MyResult<()> {
-    create_dir_all(module_dir)?; // create module dir
-
-    File::create(module_dir.join("mod.rs"))?
-        .write_all(format!("pub mod {};\n", TLIdent::PRELUDE_MOD).as_bytes())?; // init mod.rs
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("diff") {
+        diff(&args[1..]);
+        return;
+    }
+    let check = args.iter().any(|x| x == "--check");
 
-    OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(module_dir.join(format!("{}.rs", TLIdent::PRELUDE_MOD)))?
-        .write_all(&[][..])?; // create prelude module, if not exist
+    let src_dir = current_dir().unwrap().join("src");
+    // generated modules are part of `i_am_mt` itself
+    let prelude = DEFAULT_PRELUDE.replacen("i_am_mt::", "crate::", 1);
+    let configs = [
+        // `rpc_result` of proto carries rpc types, so proto `Object` falls back to rpc `Object`
+        Config::new(&src_dir)
+            .module_name("generate_proto")
+            .schema(Schema::proto_schema())
+            .prelude(&prelude)
+            .serde(true)
+            .builders(true)
+            .link("Rpc", "super::super::generate_rpc::object"),
+        Config::new(&src_dir)
+            .module_name("generate_rpc")
+            .schema(Schema::rpc_schema())
+            .layer(RPC_LAYER)
+            .require_layer(true)
+            .prelude(&prelude)
+            .serde(true)
+            .builders(true),
+    ];
 
-    let generator = Generator::new(schema);
-    for (namespace, types) in generator.types.iter() {
-        let prelude_module_name = ident(TLIdent::PRELUDE_MOD);
-        let types = types.into_iter().map(|x| x.compile());
-        let tokens = quote!(
-            use super::#prelude_module_name::*;
-            #(#types)*
-        );
-        new_module(module_dir, namespace, &tokens)?;
+    if !check {
+        for config in configs.iter() {
+            config.generate().unwrap();
+        }
+        return;
+    }
+    let mut stale = vec![];
+    for config in configs.iter() {
+        stale.extend(config.check().unwrap());
+    }
+    for path in stale.iter() {
+        eprintln!("{} is out of date or not generated", path.display());
+    }
+    if !stale.is_empty() {
+        exit(1);
     }
-    Ok(())
 }
 
-pub fn new_module(
-    module_dir: &Path,
-    module_name: impl AsRef<str>,
-    content: &TokenStream,
-) -> MyResult<()> {
-    let target_path = module_dir.join(format!("{}.rs", module_name.as_ref()));
-    let mut target_file = File::create(target_path)?;
-    target_file.write_all(content.to_string().as_bytes())?;
-
-    let module_path = module_dir.join("mod.rs");
-    let mut module_file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(module_path)?;
-    module_file.write_all(format!("pub mod {};\n", module_name.as_ref()).as_bytes())?;
-
-    Ok(())
+/// Print changes from schema `OLD` to `NEW`, each a `.tl` or JSON file
+fn diff(args: &[String]) {
+    let json = args.iter().any(|x| x == "--json");
+    let paths: Vec<&String> = args.iter().filter(|x| !x.starts_with("--")).collect();
+    if paths.len() != 2 {
+        eprintln!("usage: code_gen diff OLD NEW [--json]");
+        exit(2);
+    }
+    let load = |path: &str| {
+        Schema::from_path(path).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(1);
+        })
+    };
+    let diff = SchemaDiff::new(&load(paths[0]), &load(paths[1]));
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff).unwrap());
+    } else {
+        print!("{}", diff);
+    }
 }
@@ -0,0 +1,357 @@
+//! Generated files, kept in memory until written or compared with files on disk
+
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, read_dir, read_to_string, File},
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use failure::bail;
+use proc_macro2::TokenStream;
+
+use crate::{tl_ident::TLIdent, utils::MyResult};
+
+pub const HEADER: &str =
+    "// Generated by code_gen from the MTProto schema, do not edit by hand.\n\n";
+
+/// Files of generated modules, keyed by path
+#[derive(Debug, Default)]
+pub struct Output {
+    pub files: BTreeMap<PathBuf, String>,
+    /// Content of `prelude.rs` created along with each `mod.rs`
+    pub prelude: String,
+    /// Leave sources as printed by `quote` instead of running `rustfmt`
+    pub skip_format: bool,
+    /// Fail when `rustfmt` can not be run, instead of leaving sources unformatted
+    pub require_format: bool,
+}
+
+impl Output {
+    /// Add a single file containing `content`
+    pub fn add_file(&mut self, path: PathBuf, content: &TokenStream) -> MyResult<()> {
+        let content = self.format(content)?;
+        self.files.insert(path, content);
+        Ok(())
+    }
+
+    /// Add module `module_path` under `module_dir`, along with its declaration in `mod.rs`,
+    /// e.g. `a/b.rs` declared in `a.rs` of `[a, b]`
+    ///
+    /// A module must be added before its submodules.
+    pub fn add_module(
+        &mut self,
+        module_dir: &Path,
+        module_path: &[String],
+        content: &TokenStream,
+    ) -> MyResult<()> {
+        let path = module_file(module_dir, module_path);
+        let content = self.format(content)?;
+        self.files.insert(path, content);
+        self.declare_module(module_dir, module_path);
+        Ok(())
+    }
+
+    /// Declare `module_path` in its parent, creating empty parents as needed
+    fn declare_module(&mut self, module_dir: &Path, module_path: &[String]) {
+        let (name, parent) = module_path.split_last().unwrap();
+        if parent.is_empty() {
+            let mod_file = self
+                .files
+                .entry(module_dir.join("mod.rs"))
+                .or_insert_with(|| format!("{}pub mod {};\n", HEADER, TLIdent::PRELUDE_MOD));
+            add_declaration(mod_file, name);
+            return;
+        }
+        let parent_file = module_file(module_dir, parent);
+        if !self.files.contains_key(&parent_file) {
+            self.files.insert(parent_file.clone(), HEADER.to_string());
+            self.declare_module(module_dir, parent);
+        }
+        add_declaration(self.files.get_mut(&parent_file).unwrap(), name);
+    }
+
+    /// Write all files, `prelude.rs` next to `mod.rs` is created if missing and never overwritten
+    pub fn write(&self) -> MyResult<()> {
+        for (path, content) in self.files.iter() {
+            let module_dir = path.parent().unwrap();
+            create_dir_all(module_dir)?;
+            let prelude = module_dir.join(format!("{}.rs", TLIdent::PRELUDE_MOD));
+            if path.ends_with("mod.rs") && !prelude.exists() {
+                File::create(prelude)?.write_all(self.prelude.as_bytes())?;
+            }
+            File::create(path)?.write_all(content.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Paths of files on disk which differ from the generated ones, and of `.rs` files in module
+    /// directories which are not generated any more, e.g. namespaces of an older schema
+    pub fn check(&self) -> Vec<PathBuf> {
+        let mut stale: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(path, content)| read_to_string(path).ok().as_ref() != Some(content))
+            .map(|(path, _)| path.clone())
+            .collect();
+        let module_dirs = self
+            .files
+            .keys()
+            .filter(|x| x.ends_with("mod.rs"))
+            .map(|x| x.parent().unwrap());
+        for module_dir in module_dirs {
+            let prelude = module_dir.join(format!("{}.rs", TLIdent::PRELUDE_MOD));
+            stale.extend(
+                rust_files(module_dir)
+                    .into_iter()
+                    .filter(|x| *x != prelude && !self.files.contains_key(x)),
+            );
+        }
+        stale
+    }
+
+    fn format(&self, content: &TokenStream) -> MyResult<String> {
+        let source = content.to_string();
+        let source = if self.skip_format {
+            source
+        } else {
+            match format_source(&source)? {
+                Some(formatted) => doc_comments(&formatted),
+                None if self.require_format => {
+                    bail!("failed to run rustfmt, generated files are formatted")
+                }
+                None => source,
+            }
+        };
+        Ok(format!("{}{}", HEADER, source))
+    }
+}
+
+/// Add `pub mod name;` to a file of declarations, sorted as `rustfmt` orders them
+fn add_declaration(file: &mut String, name: &str) {
+    let mut declarations: Vec<&str> = file[HEADER.len()..].lines().collect();
+    let declaration = format!("pub mod {};", name);
+    declarations.push(&declaration);
+    declarations.sort();
+    *file = format!("{}{}\n", HEADER, declarations.join("\n"));
+}
+
+/// `a/b.rs` of module `[a, b]`
+fn module_file(module_dir: &Path, module_path: &[String]) -> PathBuf {
+    let mut path = module_dir.join(module_path.join("/"));
+    path.set_extension("rs");
+    path
+}
+
+/// `.rs` files under `dir` and its subdirectories, none if it does not exist
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for entry in read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(rust_files(&path));
+        } else if path.extension().map_or(false, |x| x == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Lines `#[doc = " text"]` of formatted `source` as `/// text`, which `quote` can not print
+fn doc_comments(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    for line in source.lines() {
+        let code = line.trim_start();
+        let indent = &line[..line.len() - code.len()];
+        match doc_text(code) {
+            Some(text) => {
+                for text in text.split('\n') {
+                    result.push_str(indent);
+                    result.push_str("///");
+                    result.push_str(text);
+                    result.push('\n');
+                }
+            }
+            None => {
+                result.push_str(line);
+                result.push('\n');
+            }
+        }
+    }
+    result
+}
+
+/// Text of `#[doc = "..."]` or `#[doc = r"..."]`, unless `///` would not make it a doc comment
+fn doc_text(code: &str) -> Option<String> {
+    const PREFIX: &str = "#[doc = ";
+    if !code.starts_with(PREFIX) || !code.ends_with(']') {
+        return None;
+    }
+    let literal = &code[PREFIX.len()..code.len() - 1];
+    let text = if literal.starts_with('r') {
+        let hashes = &literal[1..literal.find('"')?];
+        if hashes.chars().any(|x| x != '#') || literal.len() < 3 + 2 * hashes.len() {
+            return None;
+        }
+        literal[2 + hashes.len()..literal.len() - 1 - hashes.len()].to_string()
+    } else if literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"') {
+        unescape(&literal[1..literal.len() - 1])?
+    } else {
+        return None;
+    };
+    // `////` is a plain comment
+    if text.starts_with('/') {
+        return None;
+    }
+    Some(text)
+}
+
+/// Value of the content of a string literal, `None` for escapes `quote` does not print
+fn unescape(content: &str) -> Option<String> {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(x) = chars.next() {
+        if x != '\\' {
+            result.push(x);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '\'' => result.push('\''),
+            '"' => result.push('"'),
+            'u' => {
+                let code: String = chars.by_ref().take_while(|x| *x != '}').collect();
+                let code = u32::from_str_radix(code.trim_start_matches('{'), 16).ok()?;
+                result.push(std::char::from_u32(code)?);
+            }
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+/// Pretty print with `rustfmt`, `None` if it can not be run, e.g. is not in `PATH`
+pub fn format_source(source: &str) -> MyResult<Option<String>> {
+    run_formatter("rustfmt", source)
+}
+
+fn run_formatter(program: &str, source: &str) -> MyResult<Option<String>> {
+    let mut child = match Command::new(program)
+        .args(&["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return Ok(None),
+    };
+    child.stdin.take().unwrap().write_all(source.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(Some(String::from_utf8(output.stdout)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let module_dir =
+            std::env::temp_dir().join(format!("code_gen_check_{}", std::process::id()));
+        let mut output = Output {
+            skip_format: true,
+            ..Output::default()
+        };
+        output
+            .add_module(
+                &module_dir,
+                &["a".to_string(), "b".to_string()],
+                &quote::quote!(),
+            )
+            .unwrap();
+        output.write().unwrap();
+        assert!(output.check().is_empty());
+
+        // files of an older schema are reported, the hand written prelude is not
+        create_dir_all(module_dir.join("old")).unwrap();
+        File::create(module_dir.join("old.rs")).unwrap();
+        File::create(module_dir.join("old/c.rs")).unwrap();
+        File::create(module_dir.join("a/notes.txt")).unwrap();
+        File::create(module_dir.join("a/b.rs")).unwrap();
+        assert_eq!(
+            output.check(),
+            vec![
+                module_dir.join("a/b.rs"),
+                module_dir.join("old/c.rs"),
+                module_dir.join("old.rs"),
+            ]
+        );
+        std::fs::remove_dir_all(&module_dir).unwrap();
+    }
+
+    #[test]
+    fn test_declarations() {
+        let module_dir = PathBuf::from("generated");
+        let mut output = Output::default();
+        for name in &["tl", "functions", "a_b", "a"] {
+            output.declare_module(&module_dir, &[name.to_string()]);
+        }
+        assert_eq!(
+            output.files[&module_dir.join("mod.rs")],
+            format!(
+                "{}pub mod a;\npub mod a_b;\npub mod functions;\npub mod prelude;\npub mod tl;\n",
+                HEADER
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_formatter() {
+        assert!(run_formatter("code_gen_missing_rustfmt", "fn f(){}")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        let source = r###"#[doc = " Any \"boxed\" type"]
+pub enum Object {
+    #[doc = r" `a\b`"]
+    #[doc = r#" "quoted""#]
+    #[doc = ""]
+    #[doc = " two\nlines \u{e9}"]
+    A,
+    #[doc = "/not a doc comment"]
+    #[doc = " unknown \x41"]
+    B,
+}
+"###;
+        let target = r###"/// Any "boxed" type
+pub enum Object {
+    /// `a\b`
+    /// "quoted"
+    ///
+    /// two
+    ///lines é
+    A,
+    #[doc = "/not a doc comment"]
+    #[doc = " unknown \x41"]
+    B,
+}
+"###;
+        assert_eq!(doc_comments(source), target);
+    }
+}
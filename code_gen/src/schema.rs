@@ -1,4 +1,8 @@
-use crate::tl_ident::TLIdent;
+use std::{collections::HashMap, fs::read_to_string, path::Path};
+
+use crate::{tl_ident::TLIdent, tl_parser, utils::MyResult};
+use failure::{bail, format_err};
+use heck::CamelCase;
 use serde::Deserialize;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -6,6 +10,8 @@ pub struct Param {
     pub name: TLIdent,
     #[serde(rename = "type")]
     pub kind: TLIdent,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -16,6 +22,12 @@ pub struct Constructor {
     pub params: Vec<Param>,
     #[serde(rename = "type")]
     pub return_type: TLIdent,
+    /// Layer which introduced it, `===N===` in TL text
+    #[serde(default)]
+    pub layer: Option<u32>,
+    /// `//` comment before it in TL text
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -26,17 +38,177 @@ pub struct Method {
     pub params: Vec<Param>,
     #[serde(rename = "type")]
     pub return_type: TLIdent,
+    #[serde(default)]
+    pub layer: Option<u32>,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl Constructor {
+    /// TL declaration, e.g. `boolTrue#997275b5 = Bool`
+    pub fn signature(&self) -> String {
+        signature(&self.predicate, self.id, &self.params, &self.return_type)
+    }
+}
+
+impl Method {
+    pub fn signature(&self) -> String {
+        signature(&self.method, self.id, &self.params, &self.return_type)
+    }
+}
+
+fn signature(name: &TLIdent, id: i32, params: &[Param], return_type: &TLIdent) -> String {
+    let generics = params
+        .iter()
+        .filter_map(|x| x.kind.generic_param())
+        .map(|x| format!(" {{{}:Type}}", x.as_str()));
+    let params = params
+        .iter()
+        .map(|x| format!(" {}:{}", x.name.as_str(), x.kind.as_str()));
+    format!(
+        "{}#{:08x}{} = {}",
+        name.as_str(),
+        id as u32,
+        generics.chain(params).collect::<String>(),
+        return_type.as_str()
+    )
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct Schema {
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    /// `// LAYER N` in TL text, otherwise the highest `===N===`
+    #[serde(default)]
+    pub layer: Option<u32>,
 }
 
 impl Schema {
     pub fn new(s: &str) -> Schema {
-        serde_json::from_str(&s).unwrap()
+        serde_json::from_str(s).unwrap()
+    }
+
+    /// Parse TL text schema, explicit ids must match the ones computed from declarations
+    pub fn from_tl(s: &str) -> MyResult<Schema> {
+        tl_parser::parse(s, true)
+    }
+
+    /// Parse TL text schema, keeping explicit ids even if they differ from computed ones
+    pub fn from_tl_unchecked(s: &str) -> MyResult<Schema> {
+        tl_parser::parse(s, false)
+    }
+
+    /// Load `.tl` text (explicit ids are checked, see `from_tl`) or JSON schema from `path`
+    pub fn from_path(path: impl AsRef<Path>) -> MyResult<Schema> {
+        let path = path.as_ref();
+        let text = read_to_string(path)
+            .map_err(|e| format_err!("failed to read {}: {}", path.display(), e))?;
+        let schema = if path.extension().map_or(false, |x| x == "tl") {
+            Self::from_tl(&text)
+        } else {
+            serde_json::from_str(&text)
+                .map_err(failure::Error::from)
+                .and_then(Self::check_types)
+        };
+        schema.map_err(|e| format_err!("{}: {}", path.display(), e))
+    }
+
+    /// Reject generic and conditional types the generator does not support, see
+    /// `TLIdent::check_generic` and `TLIdent::check_conditional`
+    fn check_types(self) -> MyResult<Schema> {
+        let constructors = self
+            .constructors
+            .iter()
+            .map(|x| (&x.params, &x.return_type));
+        let methods = self.methods.iter().map(|x| (&x.params, &x.return_type));
+        for (params, return_type) in constructors.chain(methods) {
+            return_type.check_generic()?;
+            for param in params {
+                param.kind.check_generic()?;
+                param.kind.check_conditional()?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Append constructors and methods of `other`, the higher layer is kept
+    pub fn extend(&mut self, other: Schema) {
+        self.constructors.extend(other.constructors);
+        self.methods.extend(other.methods);
+        self.layer = self.layer.max(other.layer);
+    }
+
+    /// Generate type `tl_name` (e.g. `auth.SentCode`) as `rust_name`, staying in its namespace
+    pub fn rename_type(&mut self, tl_name: &str, rust_name: &str) -> MyResult<()> {
+        if rust_name.is_empty() || rust_name.to_camel_case() != rust_name {
+            bail!("`{}` is not a CamelCase type name", rust_name);
+        }
+        let from = TLIdent::new(tl_name);
+        if !self.constructors.iter().any(|x| x.return_type == from) {
+            bail!("type `{}` is not defined in schema", tl_name);
+        }
+        let to = match from.namespace_split() {
+            (Some(namespace), _) => TLIdent::new(format!("{}.{}", namespace, rust_name)),
+            (None, _) => TLIdent::new(rust_name),
+        };
+        if self.constructors.iter().any(|x| x.return_type == to) {
+            bail!(
+                "type `{}` already exists, can not rename `{}`",
+                to.as_str(),
+                tl_name
+            );
+        }
+        let params = self
+            .constructors
+            .iter_mut()
+            .map(|x| {
+                x.return_type = x.return_type.replace_type(&from, &to);
+                &mut x.params
+            })
+            .chain(self.methods.iter_mut().map(|x| {
+                x.return_type = x.return_type.replace_type(&from, &to);
+                &mut x.params
+            }));
+        for params in params {
+            for param in params.iter_mut() {
+                param.kind = param.kind.replace_type(&from, &to);
+            }
+        }
+        Ok(())
+    }
+
+    /// Ids must be unique, unless declared again the same way, e.g. `vector` by several schemas
+    pub fn check_duplicates(&self) -> MyResult<()> {
+        let mut signatures: HashMap<i32, String> = HashMap::new();
+        let declarations = self
+            .constructors
+            .iter()
+            .map(|x| (x.id, x.signature()))
+            .chain(self.methods.iter().map(|x| (x.id, x.signature())));
+        for (id, signature) in declarations {
+            match signatures.get(&id) {
+                Some(other) if *other != signature => bail!(
+                    "id {:08x} of `{}` is already used by `{}`",
+                    id as u32,
+                    signature,
+                    other
+                ),
+                Some(_) => {}
+                None => {
+                    signatures.insert(id, signature);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// TL declaration of each constructor and method by id
+    pub fn signatures(&self) -> HashMap<i32, String> {
+        self.constructors
+            .iter()
+            .map(|x| (x.id, x.signature()))
+            .chain(self.methods.iter().map(|x| (x.id, x.signature())))
+            .collect()
     }
 
     pub fn proto_schema() -> Schema {
@@ -47,3 +219,17 @@ impl Schema {
         Self::new(include_str!("./rpc.json"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_duplicates() {
+        let mut schema = Schema::from_tl("boolTrue#997275b5 = Bool;").unwrap();
+        schema.extend(Schema::from_tl("boolTrue#997275b5 = Bool;").unwrap());
+        schema.check_duplicates().unwrap();
+        schema.extend(Schema::from_tl_unchecked("boolYes#997275b5 = Bool;").unwrap());
+        assert!(schema.check_duplicates().is_err());
+    }
+}
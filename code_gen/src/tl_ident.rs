@@ -1,68 +1,223 @@
+use std::collections::HashSet;
+
 use derive_more::FromStr;
+use failure::bail;
 use heck::{CamelCase, SnakeCase};
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Deserializer};
 
+use crate::utils::MyResult;
+
+lazy_static! {
+    static ref GENERIC_PATTERN: Regex =
+        Regex::new(r"(?i)^(?P<outer>.+?)<(?P<inner>.+?)>$").unwrap();
+    static ref CONDITIONAL_PATTERN: Regex =
+        Regex::new(r"^(?P<flags>\w+)\.(?P<bit>\d+)\?(?P<inner>.+)$").unwrap();
+}
+
 /// Variable name and Type name in TL language
 ///
 /// # Warning
 ///
-/// + Generic support: only includes Vector
-/// + Namespace support: maximum two layers
-#[derive(Debug, FromStr, Clone, Eq, PartialEq, Hash)]
+/// + Generic support: only includes Vector, bare vector and `!X` query parameters
+/// + Conditional fields (`flags.N?Type`) can not be nested, see `check_conditional`
+/// + Namespaces of any depth, `a.b.Type` is generated in module `a::b`
+/// + Bare constructor reference (`future_salt`) is mapped to its type by naming convention,
+///   which only holds for types with a single constructor
+#[derive(Debug, FromStr, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct TLIdent(String);
 
 impl TLIdent {
     pub const PRELUDE_MOD: &'static str = "prelude";
     pub const TOP_MOD: &'static str = "tl";
+    pub const FUNCTIONS_MOD: &'static str = "functions";
+    pub const OBJECT_MOD: &'static str = "object";
+    pub const REGISTRY_MOD: &'static str = "registry";
+
+    pub fn new(name: impl Into<String>) -> Self {
+        TLIdent(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
 
     pub fn is_built_in_type(&self) -> bool {
         [
             "int", "long", "double", "bytes", "string", "int128", "int256", "Vector t", "Bool",
+            "#", "true",
         ]
         .contains(&self.0.as_str())
     }
 
-    pub fn is_special(&self) -> bool {
-        ["True", "Null", "PeerSettings", "MessageContainer"].contains(&self.0.as_str())
+    pub fn should_generate(&self) -> bool {
+        !self.is_built_in_type() && !self.is_vector()
     }
 
-    pub fn should_generate(&self) -> bool {
-        !self.is_built_in_type() && !self.is_special() && !self.is_vector()
+    /// Boxed value of any type, see `generator::object`
+    pub fn is_object(&self) -> bool {
+        self.0 == "Object"
     }
 
-    fn generic_split(&self) -> (Option<String>, String) {
-        lazy_static! {
-            static ref GENERIC_PATTERN: Regex =
-                Regex::new(r"(?i)^(?P<outer>.+?)<(?P<inner>.+?)>$").unwrap();
+    /// `flags:#`
+    pub fn is_flags(&self) -> bool {
+        self.0 == "#"
+    }
+
+    /// `flags.N?true`, presence is the value itself
+    pub fn is_true_flag(&self) -> bool {
+        self.conditional_split()
+            .map_or(false, |(_, _, inner)| inner.0 == "true")
+    }
+
+    /// flags.3?Vector<int> -> Some((flags, 3, Vector<int>))
+    /// int -> None
+    pub fn conditional_split(&self) -> Option<(TLIdent, u32, TLIdent)> {
+        CONDITIONAL_PATTERN
+            .captures(self.0.as_str())
+            .and_then(|cap| {
+                Some((
+                    TLIdent(cap["flags"].to_string()),
+                    cap["bit"].parse().ok()?,
+                    TLIdent(cap["inner"].to_string()),
+                ))
+            })
+    }
+
+    /// Bits of `#` are 0 to 31, and a conditional type can not be conditional again
+    pub fn check_conditional(&self) -> MyResult<()> {
+        if let Some(cap) = CONDITIONAL_PATTERN.captures(self.0.as_str()) {
+            match self.conditional_split() {
+                Some((_, bit, _)) if bit < 32 => {}
+                _ => bail!("flag bit `{}` of `{}` is out of 0..32", &cap["bit"], self.0),
+            }
+            if CONDITIONAL_PATTERN.is_match(&cap["inner"]) {
+                bail!("nested conditional type `{}`", self.0);
+            }
+        }
+        Ok(())
+    }
+
+    /// !X -> Some(X), a method of any type
+    /// X, int -> None
+    pub fn generic_param(&self) -> Option<TLIdent> {
+        if self.0.starts_with('!') {
+            Some(TLIdent(self.0[1..].to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Type that must be defined for `self` to compile
+    ///
+    /// flags.0?Vector<%Message> -> Message
+    /// Vector<long> -> long
+    /// !X -> X
+    pub fn base_type(&self) -> TLIdent {
+        if let Some((_, _, inner)) = self.conditional_split() {
+            return inner.base_type();
+        }
+        if let Some(param) = self.generic_param() {
+            return param;
+        }
+        let (_, inner) = self.generic_split();
+        let inner = TLIdent(inner);
+        inner.bare_type().unwrap_or(inner)
+    }
+
+    /// Generic types other than `Vector<t>` and `vector<t>` (e.g. `Maybe<int>`) and nested ones
+    /// are not supported
+    pub fn check_generic(&self) -> MyResult<()> {
+        if let Some((_, _, inner)) = self.conditional_split() {
+            return inner.check_generic();
         }
+        if let Some(cap) = GENERIC_PATTERN.captures(self.0.as_str()) {
+            if cap["outer"].to_lowercase() != "vector" || cap["inner"].contains('<') {
+                bail!("unsupported generic type `{}`", self.0);
+            }
+        }
+        Ok(())
+    }
+
+    fn generic_split(&self) -> (Option<String>, String) {
         if let Some(cap) = GENERIC_PATTERN.captures(self.0.as_str()) {
             let inner: &str = cap.name("inner").unwrap().as_str();
             let outer: &str = cap.name("outer").unwrap().as_str();
-            assert_eq!(outer.to_lowercase(), "vector".to_string());
-            assert!(!inner.contains('<'));
+            debug_assert!(self.check_generic().is_ok());
             (Some(outer.to_string()), inner.to_string())
         } else {
             (None, self.0.clone())
         }
     }
 
+    /// Replace type `from` wherever `self` refers to it
+    ///
+    /// flags.0?Vector<auth.SentCode>, auth.SentCode -> auth.Code -> flags.0?Vector<auth.Code>
+    pub fn replace_type(&self, from: &TLIdent, to: &TLIdent) -> TLIdent {
+        let pattern = Regex::new(&format!(r"(^|[<?%]){}($|>)", regex::escape(&from.0))).unwrap();
+        TLIdent(
+            pattern
+                .replace(&self.0, format!("${{1}}{}${{2}}", to.0).as_str())
+                .into_owned(),
+        )
+    }
+
+    /// a.b.Type -> (Some(a.b), Type)
+    /// Type -> (None, Type)
     pub fn namespace_split(&self) -> (Option<String>, String) {
         assert!(!self.0.contains('<'));
-        let parts: Vec<&str> = self.0.split('.').collect();
-        match parts.len() {
-            1 => (None, parts[0].to_string()),
-            2 => (Some(parts[0].to_string()), parts[1].to_string()),
-            _ => unreachable!(),
-        }
+        let mut parts = self.0.rsplitn(2, '.');
+        let name = parts.next().unwrap().to_string();
+        (parts.next().map(|x| x.to_string()), name)
+    }
+
+    /// Rust modules of a namespace, a.b -> [a, b]
+    pub fn as_module_names(namespace: &str) -> Vec<String> {
+        namespace
+            .split('.')
+            .map(|x| match x {
+                Self::PRELUDE_MOD | Self::FUNCTIONS_MOD | Self::OBJECT_MOD | Self::REGISTRY_MOD => {
+                    format!("{}_", x)
+                }
+                _ => escape_keyword(x.to_string()),
+            })
+            .collect()
     }
 
-    fn is_vector(&self) -> bool {
+    /// Vector<t> or vector<t>
+    pub fn is_vector(&self) -> bool {
         let (outer, _) = self.generic_split();
         outer.map(|x| x.to_lowercase()) == Some("vector".to_string())
     }
 
+    /// vector<t>, Vector t without constructor id
+    fn is_bare_vector(&self) -> bool {
+        let (outer, _) = self.generic_split();
+        outer == Some("vector".to_string())
+    }
+
+    /// %Message -> Some(Message)
+    /// future_salt -> Some(FutureSalt)
+    /// FutureSalt, int, Vector<long> -> None
+    pub fn bare_type(&self) -> Option<TLIdent> {
+        if self.0.starts_with('%') {
+            return Some(TLIdent(self.0[1..].to_string()));
+        }
+        if self.is_built_in_type() || self.is_vector() {
+            return None;
+        }
+        let (ns, name) = self.namespace_split();
+        if !name.starts_with(char::is_lowercase) {
+            return None;
+        }
+        let name = name.to_camel_case();
+        Some(TLIdent(match ns {
+            Some(ns) => format!("{}.{}", ns, name),
+            None => name,
+        }))
+    }
+
     /// aaa.Bbb -> Bbb
     /// Vector<aaa.Bbb> -> Vec<super::aaa::Bbb>
     /// Vector<Bbb> -> Vec<super::prelude::Bbb>
@@ -73,24 +228,46 @@ impl TLIdent {
         if self.is_vector() {
             format!("Vec<{}>", inner.as_rust_type_path())
         } else {
-            inner.namespace_split().1.to_camel_case()
+            escape_keyword(inner.namespace_split().1.to_camel_case())
         }
     }
 
     /// aaa.Bbb -> super::aaa::Bbb
+    /// aaa.bbb.Ccc -> super::aaa::bbb::Ccc
     /// Vector<aaa.Bbb> -> Vec<super::aaa::Bbb>
     /// Vector<Bbb> -> Vec<super::prelude::Bbb>
+    /// vector<%Bbb> -> TLBareVector<TLBare<super::tl::Bbb>>
+    /// flags.0?true -> bool
+    /// flags.1?int -> Option<i32>
+    /// !X -> X
     pub fn as_rust_type_path(&self) -> String {
+        if let Some(param) = self.generic_param() {
+            return param.0;
+        }
+        if let Some((_, _, inner)) = self.conditional_split() {
+            if self.is_true_flag() {
+                return "bool".to_string();
+            }
+            return format!("Option<{}>", inner.as_rust_type_path());
+        }
         let (_, inner) = self.generic_split();
         let inner = TLIdent(inner);
-        if self.is_vector() {
+        if self.is_bare_vector() {
+            format!("TLBareVector<{}>", inner.as_rust_type_path())
+        } else if self.is_vector() {
             format!("Vec<{}>", inner.as_rust_type_path())
+        } else if let Some(boxed) = self.bare_type() {
+            format!("TLBare<{}>", boxed.as_rust_type_path())
         } else {
             assert!(!self.0.contains('<'));
             assert!(!self.0.contains('>'));
             let (ns, name) = inner.namespace_split();
-            if ns.is_some() {
-                return format!("super::{}::{}", ns.unwrap(), name);
+            if let Some(ns) = ns {
+                return format!(
+                    "super::{}::{}",
+                    Self::as_module_names(&ns).join("::"),
+                    inner.as_rust_type_name()
+                );
             }
             match name.as_str() {
                 "int" => "i32".to_string(),
@@ -101,9 +278,66 @@ impl TLIdent {
                 "int128" => "[u8; 16]".to_string(),
                 "int256" => "[u8; 32]".to_string(),
                 "Bool" => "bool".to_string(),
-                "PeerSettings" => unreachable!(),
-                _ => format!("super::{}::{}", Self::TOP_MOD, name.to_camel_case()),
+                "#" => "u32".to_string(),
+                "Object" => format!("super::{}::Object", Self::OBJECT_MOD),
+                _ => format!("super::{}::{}", Self::TOP_MOD, inner.as_rust_type_name()),
+            }
+        }
+    }
+
+    /// Whether a zero-copy read of `self` borrows from input,
+    /// `borrowed_types` holds Rust paths of generated types that have a `...Ref<'a>` variant
+    pub fn is_borrowed(&self, borrowed_types: &HashSet<String>) -> bool {
+        if let Some((_, _, inner)) = self.conditional_split() {
+            return inner.is_borrowed(borrowed_types);
+        }
+        let (_, inner) = self.generic_split();
+        let inner = TLIdent(inner);
+        if let Some(boxed) = inner.bare_type() {
+            return boxed.is_borrowed(borrowed_types);
+        }
+        match inner.0.as_str() {
+            "bytes" | "string" => true,
+            _ => borrowed_types.contains(&inner.as_rust_type_path()),
+        }
+    }
+
+    /// bytes -> &'a [u8]
+    /// string -> &'a str
+    /// Vector<aaa.Bbb> -> Vec<super::aaa::BbbRef<'a>>, if aaa.Bbb is in `borrowed_types`
+    pub fn as_rust_borrowed_type_path(&self, borrowed_types: &HashSet<String>) -> String {
+        if let Some((_, _, inner)) = self.conditional_split() {
+            if self.is_true_flag() {
+                return "bool".to_string();
             }
+            return format!(
+                "Option<{}>",
+                inner.as_rust_borrowed_type_path(borrowed_types)
+            );
+        }
+        let (_, inner) = self.generic_split();
+        let inner = TLIdent(inner);
+        if self.is_bare_vector() {
+            return format!(
+                "TLBareVector<{}>",
+                inner.as_rust_borrowed_type_path(borrowed_types)
+            );
+        }
+        if self.is_vector() {
+            return format!("Vec<{}>", inner.as_rust_borrowed_type_path(borrowed_types));
+        }
+        if let Some(boxed) = self.bare_type() {
+            return format!(
+                "TLBare<{}>",
+                boxed.as_rust_borrowed_type_path(borrowed_types)
+            );
+        }
+        let path = self.as_rust_type_path();
+        match self.0.as_str() {
+            "bytes" => "&'a [u8]".to_string(),
+            "string" => "&'a str".to_string(),
+            _ if borrowed_types.contains(&path) => format!("{}Ref<'a>", path),
+            _ => path,
         }
     }
 
@@ -113,16 +347,40 @@ impl TLIdent {
         assert!(!self.0.contains('>'));
         match self.0.as_str() {
             "type" => "ty".to_string(),
-            _ => self.0.to_snake_case(),
+            _ => escape_keyword(self.0.to_snake_case()),
         }
     }
 
+    /// Methods are generated in a flat module, so namespace is kept as prefix
+    ///
+    /// auth.sendCode -> AuthSendCode
+    /// req_DH_params -> ReqDhParams
+    pub fn as_function_name(&self) -> String {
+        assert!(!self.0.contains('<'));
+        escape_keyword(self.0.replace('.', "_").to_camel_case())
+    }
+
     pub fn as_variant_name(&self) -> String {
         assert!(!self.0.contains('<'));
         assert!(!self.0.contains('>'));
         let (_, inner) = self.namespace_split();
-        assert!(!inner.contains('.'));
-        inner.to_camel_case()
+        escape_keyword(inner.to_camel_case())
+    }
+}
+
+/// Suffix Rust keywords with `_`, e.g. `static` -> `static_`, `Self` -> `Self_`
+fn escape_keyword(name: String) -> String {
+    const KEYWORDS: &[&str] = &[
+        "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+        "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+        "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+        "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+        "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    if KEYWORDS.contains(&name.as_str()) {
+        name + "_"
+    } else {
+        name
     }
 }
 
@@ -134,3 +392,21 @@ impl<'de> Deserialize<'de> for TLIdent {
         Ok(TLIdent(Deserialize::deserialize(deserializer)?))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_borrowed_type_path() {
+        let borrowed_types = ["super::object::Object".to_string()]
+            .iter()
+            .cloned()
+            .collect();
+        let path = |x: &str| TLIdent::new(x).as_rust_borrowed_type_path(&borrowed_types);
+        assert_eq!(path("bytes"), "&'a [u8]");
+        assert_eq!(path("flags.0?string"), "Option<&'a str>");
+        assert_eq!(path("Vector<bytes>"), "Vec<&'a [u8]>");
+        assert_eq!(path("Object"), "super::object::ObjectRef<'a>");
+    }
+}
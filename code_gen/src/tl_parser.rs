@@ -0,0 +1,427 @@
+//! Parse schema in TL text format, e.g. `mtproto.tl` and `api.tl`
+
+use failure::{bail, format_err};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    schema::{Constructor, Method, Param, Schema},
+    tl_ident::TLIdent,
+    utils::{crc32, MyResult},
+};
+
+/// Constructors of the official schemas whose ids are not computed from their declaration
+const SPECIAL_IDS: &[(&str, u32)] = &[("msg_container", 0x73f1_f8dc)];
+
+enum Section {
+    Types,
+    Functions,
+}
+
+/// Declaration of a constructor or method, without its section
+struct Declaration {
+    id: i32,
+    name: TLIdent,
+    params: Vec<Param>,
+    return_type: TLIdent,
+}
+
+/// Parse TL text, `check_ids` rejects explicit ids which differ from the computed ones, except
+/// `SPECIAL_IDS`
+///
+/// `// LAYER N` gives the layer of schema, `===N===` the layer of declarations following it.
+/// `//` lines right before a declaration describe it, see `parse_comment`.
+pub fn parse(text: &str, check_ids: bool) -> MyResult<Schema> {
+    lazy_static! {
+        static ref LAYER_PATTERN: Regex = Regex::new(r"^//\s*LAYER\s+(\d+)\s*$").unwrap();
+        static ref LAYER_MARKER_PATTERN: Regex = Regex::new(r"^===(\d+)===$").unwrap();
+    }
+    let mut schema = Schema::default();
+    let mut section = Section::Types;
+    let mut layer = None;
+    let mut declaration = String::new();
+    let mut start = 0;
+    let mut comment = vec![];
+
+    for (number, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(cap) = LAYER_PATTERN.captures(trimmed) {
+            schema.layer = Some(parse_layer(&cap[1], number + 1)?);
+        } else if trimmed.is_empty() {
+            comment.clear();
+        } else if trimmed.starts_with("//") && declaration.is_empty() {
+            comment.push(trimmed.trim_start_matches('/').trim().to_string());
+        }
+        let line = line.split("//").next().unwrap().trim();
+        if declaration.is_empty() {
+            start = number + 1;
+            if let Some(cap) = LAYER_MARKER_PATTERN.captures(line) {
+                layer = Some(parse_layer(&cap[1], start)?);
+                comment.clear();
+                continue;
+            }
+            match line {
+                "" => continue,
+                "---types---" => {
+                    section = Section::Types;
+                    comment.clear();
+                    continue;
+                }
+                "---functions---" => {
+                    section = Section::Functions;
+                    comment.clear();
+                    continue;
+                }
+                _ if line.starts_with("---") => bail!("line {}: unknown section {}", start, line),
+                _ => {}
+            }
+        }
+
+        let mut rest = line;
+        while let Some(end) = rest.find(';') {
+            declaration.push(' ');
+            declaration.push_str(&rest[..end]);
+            let parsed = parse_declaration(&declaration, check_ids)
+                .map_err(|e| format_err!("line {}: {}", start, e))?;
+            let (description, param_descriptions) = parse_comment(&comment);
+            comment.clear();
+            let parsed = parsed.map(|mut x| {
+                for param in x.params.iter_mut() {
+                    param.description = param_descriptions
+                        .iter()
+                        .find(|(name, _)| name == param.name.as_str())
+                        .map(|(_, text)| text.clone());
+                }
+                x
+            });
+            match (parsed, &section) {
+                (Some(x), Section::Types) => schema.constructors.push(Constructor {
+                    id: x.id,
+                    predicate: x.name,
+                    params: x.params,
+                    return_type: x.return_type,
+                    layer,
+                    description,
+                }),
+                (Some(x), Section::Functions) => schema.methods.push(Method {
+                    id: x.id,
+                    method: x.name,
+                    params: x.params,
+                    return_type: x.return_type,
+                    layer,
+                    description,
+                }),
+                (None, _) => {}
+            }
+            declaration.clear();
+            start = number + 1;
+            rest = &rest[end + 1..];
+        }
+        if !rest.trim().is_empty() {
+            declaration.push(' ');
+            declaration.push_str(rest);
+        }
+    }
+
+    if !declaration.is_empty() {
+        bail!("line {}: missing `;` after declaration", start);
+    }
+    schema.layer = schema.layer.or(layer);
+    Ok(schema)
+}
+
+fn parse_layer(text: &str, line: usize) -> MyResult<u32> {
+    text.parse()
+        .map_err(|_| format_err!("line {}: invalid layer {}", line, text))
+}
+
+/// Description of a declaration and of its params from the `//` lines before it, either plain
+/// text or tdlib style `@description Text @param_name Text`, where `-` continues a line
+///
+/// Comments with `@class` describe a type rather than the declaration after them.
+fn parse_comment(lines: &[String]) -> (Option<String>, Vec<(String, String)>) {
+    if lines.is_empty() {
+        return (None, vec![]);
+    }
+    if !lines[0].starts_with('@') {
+        return (Some(lines.join("\n")), vec![]);
+    }
+    let text = lines
+        .iter()
+        .map(|x| x.trim_start_matches('-').trim())
+        .join(" ");
+    let mut description = None;
+    let mut params = vec![];
+    for tag in text[1..].split(" @") {
+        let mut parts = tag.splitn(2, ' ');
+        let name = parts.next().unwrap();
+        let text = parts.next().unwrap_or_default().trim().to_string();
+        match name {
+            "class" => return (None, vec![]),
+            "description" => description = Some(text),
+            _ => params.push((name.to_string(), text)),
+        }
+    }
+    (description, params)
+}
+
+/// Parse `name#id {X:Type} param:Type ... = Type`, built-in type declarations give `None`
+fn parse_declaration(text: &str, check_ids: bool) -> MyResult<Option<Declaration>> {
+    let mut halves = text.splitn(2, '=');
+    let tokens = halves.next().unwrap().split_whitespace().collect_vec();
+    let return_type = halves
+        .next()
+        .map(|x| x.split_whitespace().join(" "))
+        .unwrap_or_default();
+    if tokens.is_empty() || return_type.is_empty() {
+        bail!("invalid declaration `{}`", text.trim());
+    }
+
+    let (name, explicit_id) = match tokens[0].find('#') {
+        Some(index) => {
+            let id = u32::from_str_radix(&tokens[0][index + 1..], 16)
+                .map_err(|_| format_err!("invalid constructor id `{}`", tokens[0]))?;
+            (&tokens[0][..index], Some(id))
+        }
+        None => (tokens[0], None),
+    };
+
+    // `{X:Type}` only declares type variables
+    let mut params = vec![];
+    let mut built_in = false;
+    for token in tokens[1..].iter().filter(|x| !x.starts_with('{')) {
+        match token.find(':') {
+            Some(index) => params.push(Param {
+                name: TLIdent::new(&token[..index]),
+                kind: TLIdent::new(&token[index + 1..]),
+                description: None,
+            }),
+            None => built_in = true,
+        }
+    }
+    for param in &params {
+        param.kind.check_generic()?;
+        param.kind.check_conditional()?;
+    }
+    // e.g. `int ? = Int`, `vector {t:Type} # [ t ] = Vector t`,
+    // only `vector` is kept, the generator knows it as `Vector t`
+    if built_in {
+        if name != "vector" {
+            return Ok(None);
+        }
+        params.clear();
+    }
+
+    let signature = format!(
+        "{} = {}",
+        Some(name)
+            .into_iter()
+            .chain(tokens[1..].iter().cloned())
+            .join(" "),
+        return_type
+    );
+    let return_type = TLIdent::new(return_type);
+    return_type.check_generic()?;
+    let computed_id = compute_id(&signature);
+    if let Some(id) = explicit_id.filter(|x| check_ids && *x != computed_id) {
+        if !SPECIAL_IDS.contains(&(name, id)) {
+            bail!(
+                "explicit id {:08x} of `{}` differs from computed id {:08x}",
+                id,
+                name,
+                computed_id
+            );
+        }
+    }
+
+    Ok(Some(Declaration {
+        id: explicit_id.unwrap_or(computed_id) as i32,
+        name: TLIdent::new(name),
+        params,
+        return_type,
+    }))
+}
+
+/// CRC32 of the declaration without id, normalized the way the official schemas are
+fn compute_id(signature: &str) -> u32 {
+    lazy_static! {
+        static ref TRUE_FLAG_PATTERN: Regex = Regex::new(r" \w+:flags\d*\.\d+\?true").unwrap();
+    }
+    let signature = signature
+        .replace(":bytes ", ":string ")
+        .replace("?bytes ", "?string ")
+        .replace('<', " ")
+        .replace(|x| x == '>' || x == '{' || x == '}', "");
+    crc32(TRUE_FLAG_PATTERN.replace_all(&signature, "").as_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+int ? = Int;
+int128 4*[ int ] = Int128;
+vector {t:Type} # [ t ] = Vector t;
+
+// comment with ---functions--- inside
+resPQ#05162463 nonce:int128 server_nonce:int128 pq:bytes
+    server_public_key_fingerprints:Vector<long> = ResPQ;
+peerSettings flags:# report_spam:flags.0?true = PeerSettings; // trailing comment
+
+---functions---
+
+req_pq#60469778 nonce:int128 = ResPQ;
+invokeWithLayer {X:Type} layer:int query:!X = X;
+"#;
+
+    #[test]
+    fn test_parse() {
+        let schema = parse(SCHEMA, true).unwrap();
+        let constructors = schema
+            .constructors
+            .iter()
+            .map(|x| {
+                (
+                    x.id,
+                    x.predicate.as_str(),
+                    x.params.len(),
+                    x.return_type.as_str(),
+                )
+            })
+            .collect_vec();
+        assert_eq!(
+            constructors,
+            vec![
+                (0x1cb5_c415, "vector", 0, "Vector t"),
+                (0x0516_2463, "resPQ", 4, "ResPQ"),
+                (-0x7e7b_d933, "peerSettings", 2, "PeerSettings"),
+            ]
+        );
+        let params = &schema.constructors[1].params[3];
+        assert_eq!(params.name.as_str(), "server_public_key_fingerprints");
+        assert_eq!(params.kind.as_str(), "Vector<long>");
+        assert_eq!(
+            schema.constructors[2].params[1].kind.as_str(),
+            "flags.0?true"
+        );
+
+        let methods = schema
+            .methods
+            .iter()
+            .map(|x| {
+                (
+                    x.id,
+                    x.method.as_str(),
+                    x.params.len(),
+                    x.return_type.as_str(),
+                )
+            })
+            .collect_vec();
+        assert_eq!(
+            methods,
+            vec![
+                (0x6046_9778, "req_pq", 1, "ResPQ"),
+                (-0x2564_f2f3, "invokeWithLayer", 2, "X"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_ids() {
+        let text = "msg_container#73f1f8dc messages:vector<%Message> = MessageContainer;";
+        let schema = parse(text, true).unwrap();
+        assert_eq!(schema.constructors[0].id, 0x73f1_f8dc);
+        let text = "msg_container#73f1f8dd messages:vector<%Message> = MessageContainer;";
+        assert!(parse(text, true).is_err());
+        let schema = parse(text, false).unwrap();
+        assert_eq!(schema.constructors[0].id, 0x73f1_f8dd);
+    }
+
+    #[test]
+    fn test_check_proto_ids() {
+        // `vector` is built in, its signature lacks `{t:Type} # [ t ]`
+        let text = Schema::proto_schema()
+            .constructors
+            .iter()
+            .filter(|x| x.predicate.as_str() != "vector")
+            .map(|x| x.signature() + ";")
+            .join("\n");
+        parse(&text, true).unwrap();
+    }
+
+    #[test]
+    fn test_layer() {
+        let text = r#"
+// LAYER 105
+===104===
+resPQ#05162463 nonce:int128 server_nonce:int128 pq:bytes
+    server_public_key_fingerprints:Vector<long> = ResPQ;
+===105===
+peerSettings flags:# report_spam:flags.0?true = PeerSettings;
+---functions---
+req_pq#60469778 nonce:int128 = ResPQ;
+"#;
+        let schema = parse(text, true).unwrap();
+        assert_eq!(schema.layer, Some(105));
+        assert_eq!(schema.constructors[0].layer, Some(104));
+        assert_eq!(schema.constructors[1].layer, Some(105));
+        assert_eq!(schema.methods[0].layer, Some(105));
+
+        let schema = parse(&text.replace("// LAYER 105", ""), true).unwrap();
+        assert_eq!(schema.layer, Some(105));
+        assert_eq!(parse(SCHEMA, true).unwrap().layer, None);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("resPQ nonce:int128 = ResPQ", true).is_err());
+        assert!(parse("resPQ nonce:int128;", true).is_err());
+        assert!(parse("resPQ#xyz nonce:int128 = ResPQ;", true).is_err());
+        assert!(parse("---unknown---", true).is_err());
+        assert!(parse("just x:Maybe<int> = Just;", false).is_err());
+        assert!(parse("getMaybe = Maybe<int>;", false).is_err());
+        assert!(parse("nested x:flags.0?Vector<Vector<int>> = Nested;", false).is_err());
+        assert!(parse("nested flags:# x:flags.1?flags.2?int = Nested;", false).is_err());
+        assert!(parse("wide flags:# x:flags.40?int = Wide;", false).is_err());
+        assert!(parse("wide flags:# x:flags.99999999999?int = Wide;", false).is_err());
+        assert!(parse("last flags:# x:flags.31?int = Last;", false).is_ok());
+    }
+
+    #[test]
+    fn test_descriptions() {
+        let text = r#"
+// A plain comment
+// on two lines
+boolTrue#997275b5 = Bool;
+
+//@class InputPeer @description Peer to send to
+
+//@description Chat, by id @chat_id Identifier of the chat
+//-as seen by the user
+inputPeerChat#179be863 chat_id:int = InputPeer;
+
+// unrelated comment
+
+inputPeerEmpty#7f3b18ea = InputPeer;
+"#;
+        let schema = parse(text, true).unwrap();
+        assert_eq!(
+            schema.constructors[0].description.as_ref().unwrap(),
+            "A plain comment\non two lines"
+        );
+        assert_eq!(
+            schema.constructors[1].description.as_ref().unwrap(),
+            "Chat, by id"
+        );
+        assert_eq!(
+            schema.constructors[1].params[0]
+                .description
+                .as_ref()
+                .unwrap(),
+            "Identifier of the chat as seen by the user"
+        );
+        assert!(schema.constructors[2].description.is_none());
+    }
+}
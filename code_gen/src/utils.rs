@@ -1,6 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Group, Ident, Literal, Span, TokenStream, TokenTree};
+use quote::quote;
 use serde::{Deserialize, Deserializer};
 
 pub fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
@@ -17,6 +18,47 @@ pub fn ident(name: impl AsRef<str>) -> Ident {
     Ident::new(name.as_ref(), Span::call_site())
 }
 
+/// Paths of a module directly under the generated module, e.g. `super::prelude::*`, as seen from
+/// a module `depth` levels under it, e.g. `super::super::prelude::*` at depth 2
+pub fn reroot(tokens: TokenStream, depth: usize) -> TokenStream {
+    let mut result = TokenStream::new();
+    let mut path_sep = 0;
+    for token in tokens {
+        let token = match token {
+            TokenTree::Ident(ref x) if *x == "super" && path_sep < 2 => {
+                let up = (1..depth).map(|_| ident("super"));
+                result.extend(quote!(super #(:: #up)*));
+                path_sep = 0;
+                continue;
+            }
+            TokenTree::Group(x) => {
+                let mut group = Group::new(x.delimiter(), reroot(x.stream(), depth));
+                group.set_span(x.span());
+                TokenTree::Group(group)
+            }
+            x => x,
+        };
+        path_sep = match token {
+            TokenTree::Punct(ref x) if x.as_char() == ':' => path_sep + 1,
+            _ => 0,
+        };
+        result.extend(Some(token));
+    }
+    result
+}
+
+/// Generate `///` lines of `text`
+pub fn doc(text: &str) -> TokenStream {
+    let lines = text.lines().map(|x| {
+        if x.is_empty() {
+            Literal::string("")
+        } else {
+            Literal::string(&format!(" {}", x))
+        }
+    });
+    quote!(#(#[doc = #lines])*)
+}
+
 pub type MyResult<T> = std::result::Result<T, failure::Error>;
 
 pub fn i32_suffixed(num: i32) -> TokenStream {
@@ -29,3 +71,19 @@ pub fn i32_suffixed(num: i32) -> TokenStream {
     };
     text.parse().unwrap()
 }
+
+/// CRC32 (IEEE 802.3), which TL uses to compute constructor ids
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
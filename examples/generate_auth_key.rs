@@ -0,0 +1,12 @@
+use i_am_mt::{
+    protocol::auth_key::AuthKeyGenerator,
+    transport::tcp_client::{TcpClient, TransporterVersion},
+};
+
+fn main() -> Result<(), failure::Error> {
+    let mut stream: TcpClient =
+        TcpClient::connect("149.154.167.40:443".parse()?, TransporterVersion::Abridged)?;
+    let auth_key = AuthKeyGenerator::new(&mut stream).generate()?;
+    println!("{:#?}", auth_key);
+    Ok(())
+}
@@ -3,17 +3,23 @@ use std::io::Write;
 use byteorder::{LittleEndian, WriteBytesExt};
 
 use i_am_mt::{
-    transport::tcp_client::{TcpClient, TransporterVersion},
-    utils::MyResult,
+    protocol::message_id::MessageIdGenerator,
+    tl_types::TLType,
+    transport::{
+        tcp_client::{TcpClient, TransporterVersion},
+        Transport,
+    },
 };
 
-fn main() -> MyResult<()> {
+fn main() -> Result<(), failure::Error> {
+    let constructor: u32 = 0x60469778;
+    let nonce: [u8; 16] = rand::random();
     let request = PqReq {
         auth_key_id: 0, // Always 0
-        message_id: rand::random(),
-        message_length: 20,
-        constructor: 0x60469778,
-        nonce: rand::random(),
+        message_id: MessageIdGenerator::new().next_id() as u64,
+        message_length: (constructor.serialized_len() + nonce.serialized_len()) as u32,
+        constructor,
+        nonce,
     };
     println!("request: {:#?}", request);
 
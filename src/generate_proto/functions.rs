@@ -0,0 +1,604 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `req_pq#60469778 nonce:int128 = ResPQ`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReqPq {
+    pub nonce: [u8; 16],
+}
+impl TLType for ReqPq {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6046_9778i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "req_pq",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6046_9778i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ReqPq {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ReqPq {
+            nonce: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ReqPq {
+    type Return = super::tl::ResPq;
+}
+pub struct ReqPqBuilder {
+    nonce: [u8; 16],
+}
+impl ReqPqBuilder {
+    pub fn new(nonce: [u8; 16]) -> Self {
+        ReqPqBuilder { nonce }
+    }
+    pub fn build(self) -> ReqPq {
+        ReqPq { nonce: self.nonce }
+    }
+}
+/// `req_DH_params#d712e4be nonce:int128 server_nonce:int128 p:bytes q:bytes public_key_fingerprint:long encrypted_data:bytes = Server_DH_Params`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReqDhParams {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub p: TLBytes,
+    pub q: TLBytes,
+    pub public_key_fingerprint: i64,
+    pub encrypted_data: TLBytes,
+}
+impl TLType for ReqDhParams {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x28ed_1b42i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "req_DH_params",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x28ed_1b42i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ReqDhParams {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ReqDhParams {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            server_nonce: TLType::tl_read_with_context(input, context)?,
+            p: TLType::tl_read_with_context(input, context)?,
+            q: TLType::tl_read_with_context(input, context)?,
+            public_key_fingerprint: TLType::tl_read_with_context(input, context)?,
+            encrypted_data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.server_nonce.tl_write(output)?;
+        result += self.p.tl_write(output)?;
+        result += self.q.tl_write(output)?;
+        result += self.public_key_fingerprint.tl_write(output)?;
+        result += self.encrypted_data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.server_nonce.serialized_len();
+        result += self.p.serialized_len();
+        result += self.q.serialized_len();
+        result += self.public_key_fingerprint.serialized_len();
+        result += self.encrypted_data.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ReqDhParams {
+    type Return = super::tl::ServerDhParams;
+}
+pub struct ReqDhParamsBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    p: TLBytes,
+    q: TLBytes,
+    public_key_fingerprint: i64,
+    encrypted_data: TLBytes,
+}
+impl ReqDhParamsBuilder {
+    pub fn new(
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        p: TLBytes,
+        q: TLBytes,
+        public_key_fingerprint: i64,
+        encrypted_data: TLBytes,
+    ) -> Self {
+        ReqDhParamsBuilder {
+            nonce,
+            server_nonce,
+            p,
+            q,
+            public_key_fingerprint,
+            encrypted_data,
+        }
+    }
+    pub fn build(self) -> ReqDhParams {
+        ReqDhParams {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            p: self.p,
+            q: self.q,
+            public_key_fingerprint: self.public_key_fingerprint,
+            encrypted_data: self.encrypted_data,
+        }
+    }
+}
+/// `set_client_DH_params#f5045f1f nonce:int128 server_nonce:int128 encrypted_data:bytes = Set_client_DH_params_answer`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetClientDhParams {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub encrypted_data: TLBytes,
+}
+impl TLType for SetClientDhParams {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0afb_a0e1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "set_client_DH_params",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0afb_a0e1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for SetClientDhParams {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = SetClientDhParams {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            server_nonce: TLType::tl_read_with_context(input, context)?,
+            encrypted_data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.server_nonce.tl_write(output)?;
+        result += self.encrypted_data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.server_nonce.serialized_len();
+        result += self.encrypted_data.serialized_len();
+        result
+    }
+}
+impl RemoteCall for SetClientDhParams {
+    type Return = super::tl::SetClientDhParamsAnswer;
+}
+pub struct SetClientDhParamsBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    encrypted_data: TLBytes,
+}
+impl SetClientDhParamsBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], encrypted_data: TLBytes) -> Self {
+        SetClientDhParamsBuilder {
+            nonce,
+            server_nonce,
+            encrypted_data,
+        }
+    }
+    pub fn build(self) -> SetClientDhParams {
+        SetClientDhParams {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            encrypted_data: self.encrypted_data,
+        }
+    }
+}
+/// `rpc_drop_answer#58e4a740 req_msg_id:long = RpcDropAnswer`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcDropAnswer {
+    pub req_msg_id: i64,
+}
+impl TLType for RpcDropAnswer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x58e4_a740i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "rpc_drop_answer",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x58e4_a740i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for RpcDropAnswer {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = RpcDropAnswer {
+            req_msg_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.req_msg_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.req_msg_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for RpcDropAnswer {
+    type Return = super::tl::RpcDropAnswer;
+}
+pub struct RpcDropAnswerBuilder {
+    req_msg_id: i64,
+}
+impl RpcDropAnswerBuilder {
+    pub fn new(req_msg_id: i64) -> Self {
+        RpcDropAnswerBuilder { req_msg_id }
+    }
+    pub fn build(self) -> RpcDropAnswer {
+        RpcDropAnswer {
+            req_msg_id: self.req_msg_id,
+        }
+    }
+}
+/// `get_future_salts#b921bd04 num:int = FutureSalts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetFutureSalts {
+    pub num: i32,
+}
+impl TLType for GetFutureSalts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x46de_42fci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "get_future_salts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x46de_42fci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for GetFutureSalts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = GetFutureSalts {
+            num: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.num.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.num.serialized_len();
+        result
+    }
+}
+impl RemoteCall for GetFutureSalts {
+    type Return = super::tl::FutureSalts;
+}
+pub struct GetFutureSaltsBuilder {
+    num: i32,
+}
+impl GetFutureSaltsBuilder {
+    pub fn new(num: i32) -> Self {
+        GetFutureSaltsBuilder { num }
+    }
+    pub fn build(self) -> GetFutureSalts {
+        GetFutureSalts { num: self.num }
+    }
+}
+/// `ping#7abe77ec ping_id:long = Pong`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ping {
+    pub ping_id: i64,
+}
+impl TLType for Ping {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7abe_77eci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ping",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7abe_77eci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Ping {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Ping {
+            ping_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.ping_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.ping_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for Ping {
+    type Return = super::tl::Pong;
+}
+pub struct PingBuilder {
+    ping_id: i64,
+}
+impl PingBuilder {
+    pub fn new(ping_id: i64) -> Self {
+        PingBuilder { ping_id }
+    }
+    pub fn build(self) -> Ping {
+        Ping {
+            ping_id: self.ping_id,
+        }
+    }
+}
+/// `ping_delay_disconnect#f3427b8c ping_id:long disconnect_delay:int = Pong`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PingDelayDisconnect {
+    pub ping_id: i64,
+    pub disconnect_delay: i32,
+}
+impl TLType for PingDelayDisconnect {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0cbd_8474i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ping_delay_disconnect",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0cbd_8474i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PingDelayDisconnect {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PingDelayDisconnect {
+            ping_id: TLType::tl_read_with_context(input, context)?,
+            disconnect_delay: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.ping_id.tl_write(output)?;
+        result += self.disconnect_delay.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.ping_id.serialized_len();
+        result += self.disconnect_delay.serialized_len();
+        result
+    }
+}
+impl RemoteCall for PingDelayDisconnect {
+    type Return = super::tl::Pong;
+}
+pub struct PingDelayDisconnectBuilder {
+    ping_id: i64,
+    disconnect_delay: i32,
+}
+impl PingDelayDisconnectBuilder {
+    pub fn new(ping_id: i64, disconnect_delay: i32) -> Self {
+        PingDelayDisconnectBuilder {
+            ping_id,
+            disconnect_delay,
+        }
+    }
+    pub fn build(self) -> PingDelayDisconnect {
+        PingDelayDisconnect {
+            ping_id: self.ping_id,
+            disconnect_delay: self.disconnect_delay,
+        }
+    }
+}
+/// `destroy_session#e7512126 session_id:long = DestroySessionRes`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DestroySession {
+    pub session_id: i64,
+}
+impl TLType for DestroySession {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x18ae_dedai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "destroy_session",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x18ae_dedai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for DestroySession {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DestroySession {
+            session_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.session_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.session_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for DestroySession {
+    type Return = super::tl::DestroySessionRes;
+}
+pub struct DestroySessionBuilder {
+    session_id: i64,
+}
+impl DestroySessionBuilder {
+    pub fn new(session_id: i64) -> Self {
+        DestroySessionBuilder { session_id }
+    }
+    pub fn build(self) -> DestroySession {
+        DestroySession {
+            session_id: self.session_id,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+pub mod functions;
+pub mod object;
+pub mod prelude;
+pub mod registry;
+pub mod tl;
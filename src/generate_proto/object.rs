@@ -0,0 +1,357 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// Any boxed type of the schema, dispatched on its constructor id
+///
+/// `Vector` only holds boxed items, read a vector of bare ones as its declared type
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Object {
+    Bool(bool),
+    Vector(Vec<Object>),
+    BadMsgNotification(Box<super::tl::BadMsgNotification>),
+    BindAuthKeyInner(Box<super::tl::BindAuthKeyInner>),
+    ClientDhInnerData(Box<super::tl::ClientDhInnerData>),
+    DestroySessionRes(Box<super::tl::DestroySessionRes>),
+    FutureSalt(Box<super::tl::FutureSalt>),
+    FutureSalts(Box<super::tl::FutureSalts>),
+    GzipPacked(Box<super::tl::GzipPacked>),
+    Message(Box<super::tl::Message>),
+    MessageContainer(Box<super::tl::MessageContainer>),
+    MessageCopy(Box<super::tl::MessageCopy>),
+    MsgDetailedInfo(Box<super::tl::MsgDetailedInfo>),
+    MsgResendReq(Box<super::tl::MsgResendReq>),
+    MsgsAck(Box<super::tl::MsgsAck>),
+    MsgsAllInfo(Box<super::tl::MsgsAllInfo>),
+    MsgsStateInfo(Box<super::tl::MsgsStateInfo>),
+    MsgsStateReq(Box<super::tl::MsgsStateReq>),
+    NewSession(Box<super::tl::NewSession>),
+    PQInnerData(Box<super::tl::PQInnerData>),
+    Pong(Box<super::tl::Pong>),
+    ResPq(Box<super::tl::ResPq>),
+    RpcDropAnswer(Box<super::tl::RpcDropAnswer>),
+    RpcError(Box<super::tl::RpcError>),
+    RpcResult(Box<super::tl::RpcResult>),
+    ServerDhInnerData(Box<super::tl::ServerDhInnerData>),
+    ServerDhParams(Box<super::tl::ServerDhParams>),
+    SetClientDhParamsAnswer(Box<super::tl::SetClientDhParamsAnswer>),
+    Rpc(super::super::generate_rpc::object::Object),
+}
+impl TLType for Object {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let mut id = [0u8; 4];
+        input.read_exact(&mut id)?;
+        let input = &mut std::io::Read::chain(&id[..], input);
+        let id = i32::from_le_bytes(id);
+        Ok(match id {
+            -0x668d_8a4bi32 | -0x4386_68c9i32 => {
+                Object::Bool(TLType::tl_read_with_context(input, context)?)
+            }
+            -0x5810_07efi32 | -0x1254_bb85i32 => {
+                Object::BadMsgNotification(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x75a3_f765i32 => {
+                Object::BindAuthKeyInner(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x6643_b654i32 => {
+                Object::ClientDhInnerData(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1ddf_ba04i32 | 0x62d3_50c9i32 => {
+                Object::DestroySessionRes(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x0949_d9dci32 => {
+                Object::FutureSalt(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x51af_f76bi32 => {
+                Object::FutureSalts(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x3072_cfa1i32 => {
+                Object::GzipPacked(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x5bb8_e511i32 => {
+                Object::Message(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x73f1_f8dci32 => {
+                Object::MessageContainer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1f9f_b94ei32 => {
+                Object::MessageCopy(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x276d_3ec6i32 | -0x7f62_4921i32 => {
+                Object::MsgDetailedInfo(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7d86_1a08i32 => {
+                Object::MsgResendReq(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x62d6_b459i32 => {
+                Object::MsgsAck(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x733f_2ecfi32 => {
+                Object::MsgsAllInfo(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x04de_b57di32 => {
+                Object::MsgsStateInfo(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2596_04aei32 => {
+                Object::MsgsStateReq(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x613d_f6f8i32 => {
+                Object::NewSession(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7c36_a514i32 | 0x3c6a_84d4i32 => {
+                Object::PQInnerData(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x3477_73c5i32 => Object::Pong(Box::new(TLType::tl_read_with_context(input, context)?)),
+            0x0516_2463i32 => {
+                Object::ResPq(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x5e2a_d36ei32 | -0x3287_1a7ai32 | -0x5bc5_2749i32 => {
+                Object::RpcDropAnswer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x2144_ca19i32 => {
+                Object::RpcError(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0ca3_92ffi32 => {
+                Object::RpcResult(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x4a76_f246i32 => {
+                Object::ServerDhInnerData(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x79cb_045di32 | -0x2f17_f8a4i32 => {
+                Object::ServerDhParams(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x3bcb_f734i32 | 0x46dc_1fb9i32 | -0x5962_51fei32 => Object::SetClientDhParamsAnswer(
+                Box::new(TLType::tl_read_with_context(input, context)?),
+            ),
+            0x1cb5_c415i32 => {
+                context.enter()?;
+                let result = Object::Vector(TLType::tl_read_with_context(input, context)?);
+                context.leave();
+                result
+            }
+            _ => Object::Rpc(TLType::tl_read_with_context(input, context)?),
+        })
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            Object::Bool(x) => x.tl_write(output),
+            Object::Vector(x) => x.tl_write(output),
+            Object::BadMsgNotification(x) => x.tl_write(output),
+            Object::BindAuthKeyInner(x) => x.tl_write(output),
+            Object::ClientDhInnerData(x) => x.tl_write(output),
+            Object::DestroySessionRes(x) => x.tl_write(output),
+            Object::FutureSalt(x) => x.tl_write(output),
+            Object::FutureSalts(x) => x.tl_write(output),
+            Object::GzipPacked(x) => x.tl_write(output),
+            Object::Message(x) => x.tl_write(output),
+            Object::MessageContainer(x) => x.tl_write(output),
+            Object::MessageCopy(x) => x.tl_write(output),
+            Object::MsgDetailedInfo(x) => x.tl_write(output),
+            Object::MsgResendReq(x) => x.tl_write(output),
+            Object::MsgsAck(x) => x.tl_write(output),
+            Object::MsgsAllInfo(x) => x.tl_write(output),
+            Object::MsgsStateInfo(x) => x.tl_write(output),
+            Object::MsgsStateReq(x) => x.tl_write(output),
+            Object::NewSession(x) => x.tl_write(output),
+            Object::PQInnerData(x) => x.tl_write(output),
+            Object::Pong(x) => x.tl_write(output),
+            Object::ResPq(x) => x.tl_write(output),
+            Object::RpcDropAnswer(x) => x.tl_write(output),
+            Object::RpcError(x) => x.tl_write(output),
+            Object::RpcResult(x) => x.tl_write(output),
+            Object::ServerDhInnerData(x) => x.tl_write(output),
+            Object::ServerDhParams(x) => x.tl_write(output),
+            Object::SetClientDhParamsAnswer(x) => x.tl_write(output),
+            Object::Rpc(x) => x.tl_write(output),
+        }
+    }
+    fn serialized_len(&self) -> usize {
+        match self {
+            Object::Bool(x) => x.serialized_len(),
+            Object::Vector(x) => x.serialized_len(),
+            Object::BadMsgNotification(x) => x.serialized_len(),
+            Object::BindAuthKeyInner(x) => x.serialized_len(),
+            Object::ClientDhInnerData(x) => x.serialized_len(),
+            Object::DestroySessionRes(x) => x.serialized_len(),
+            Object::FutureSalt(x) => x.serialized_len(),
+            Object::FutureSalts(x) => x.serialized_len(),
+            Object::GzipPacked(x) => x.serialized_len(),
+            Object::Message(x) => x.serialized_len(),
+            Object::MessageContainer(x) => x.serialized_len(),
+            Object::MessageCopy(x) => x.serialized_len(),
+            Object::MsgDetailedInfo(x) => x.serialized_len(),
+            Object::MsgResendReq(x) => x.serialized_len(),
+            Object::MsgsAck(x) => x.serialized_len(),
+            Object::MsgsAllInfo(x) => x.serialized_len(),
+            Object::MsgsStateInfo(x) => x.serialized_len(),
+            Object::MsgsStateReq(x) => x.serialized_len(),
+            Object::NewSession(x) => x.serialized_len(),
+            Object::PQInnerData(x) => x.serialized_len(),
+            Object::Pong(x) => x.serialized_len(),
+            Object::ResPq(x) => x.serialized_len(),
+            Object::RpcDropAnswer(x) => x.serialized_len(),
+            Object::RpcError(x) => x.serialized_len(),
+            Object::RpcResult(x) => x.serialized_len(),
+            Object::ServerDhInnerData(x) => x.serialized_len(),
+            Object::ServerDhParams(x) => x.serialized_len(),
+            Object::SetClientDhParamsAnswer(x) => x.serialized_len(),
+            Object::Rpc(x) => x.serialized_len(),
+        }
+    }
+}
+/// Any boxed type of the schema, dispatched on its constructor id
+///
+/// `Vector` only holds boxed items, read a vector of bare ones as its declared type
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectRef<'a> {
+    Bool(bool),
+    Vector(#[cfg_attr(feature = "serde", serde(borrow))] Vec<ObjectRef<'a>>),
+    BadMsgNotification(Box<super::tl::BadMsgNotification>),
+    BindAuthKeyInner(Box<super::tl::BindAuthKeyInner>),
+    ClientDhInnerData(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ClientDhInnerDataRef<'a>>,
+    ),
+    DestroySessionRes(Box<super::tl::DestroySessionRes>),
+    FutureSalt(Box<super::tl::FutureSalt>),
+    FutureSalts(Box<super::tl::FutureSalts>),
+    GzipPacked(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::GzipPackedRef<'a>>),
+    Message(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageRef<'a>>),
+    MessageContainer(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageContainerRef<'a>>,
+    ),
+    MessageCopy(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageCopyRef<'a>>),
+    MsgDetailedInfo(Box<super::tl::MsgDetailedInfo>),
+    MsgResendReq(Box<super::tl::MsgResendReq>),
+    MsgsAck(Box<super::tl::MsgsAck>),
+    MsgsAllInfo(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MsgsAllInfoRef<'a>>),
+    MsgsStateInfo(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MsgsStateInfoRef<'a>>,
+    ),
+    MsgsStateReq(Box<super::tl::MsgsStateReq>),
+    NewSession(Box<super::tl::NewSession>),
+    PQInnerData(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::PQInnerDataRef<'a>>),
+    Pong(Box<super::tl::Pong>),
+    ResPq(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ResPqRef<'a>>),
+    RpcDropAnswer(Box<super::tl::RpcDropAnswer>),
+    RpcError(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::RpcErrorRef<'a>>),
+    RpcResult(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::RpcResultRef<'a>>),
+    ServerDhInnerData(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ServerDhInnerDataRef<'a>>,
+    ),
+    ServerDhParams(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ServerDhParamsRef<'a>>,
+    ),
+    SetClientDhParamsAnswer(Box<super::tl::SetClientDhParamsAnswer>),
+    Rpc(
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        super::super::generate_rpc::object::ObjectRef<'a>,
+    ),
+}
+impl<'a> TLDeserialize<'a> for ObjectRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        if input.len() < 4 {
+            return Err(MyError::Truncated);
+        }
+        let id = i32::from_le_bytes([input[0], input[1], input[2], input[3]]);
+        Ok(match id {
+            -0x668d_8a4bi32 | -0x4386_68c9i32 => {
+                ObjectRef::Bool(TLDeserialize::tl_deserialize_with_context(input, context)?)
+            }
+            -0x5810_07efi32 | -0x1254_bb85i32 => ObjectRef::BadMsgNotification(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x75a3_f765i32 => ObjectRef::BindAuthKeyInner(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x6643_b654i32 => ObjectRef::ClientDhInnerData(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1ddf_ba04i32 | 0x62d3_50c9i32 => ObjectRef::DestroySessionRes(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x0949_d9dci32 => ObjectRef::FutureSalt(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x51af_f76bi32 => ObjectRef::FutureSalts(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x3072_cfa1i32 => ObjectRef::GzipPacked(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x5bb8_e511i32 => ObjectRef::Message(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x73f1_f8dci32 => ObjectRef::MessageContainer(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1f9f_b94ei32 => ObjectRef::MessageCopy(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x276d_3ec6i32 | -0x7f62_4921i32 => ObjectRef::MsgDetailedInfo(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x7d86_1a08i32 => ObjectRef::MsgResendReq(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x62d6_b459i32 => ObjectRef::MsgsAck(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x733f_2ecfi32 => ObjectRef::MsgsAllInfo(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x04de_b57di32 => ObjectRef::MsgsStateInfo(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2596_04aei32 => ObjectRef::MsgsStateReq(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x613d_f6f8i32 => ObjectRef::NewSession(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7c36_a514i32 | 0x3c6a_84d4i32 => ObjectRef::PQInnerData(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x3477_73c5i32 => ObjectRef::Pong(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x0516_2463i32 => ObjectRef::ResPq(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x5e2a_d36ei32 | -0x3287_1a7ai32 | -0x5bc5_2749i32 => ObjectRef::RpcDropAnswer(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            0x2144_ca19i32 => ObjectRef::RpcError(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x0ca3_92ffi32 => ObjectRef::RpcResult(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x4a76_f246i32 => ObjectRef::ServerDhInnerData(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x79cb_045di32 | -0x2f17_f8a4i32 => ObjectRef::ServerDhParams(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x3bcb_f734i32 | 0x46dc_1fb9i32 | -0x5962_51fei32 => {
+                ObjectRef::SetClientDhParamsAnswer(Box::new(
+                    TLDeserialize::tl_deserialize_with_context(input, context)?,
+                ))
+            }
+            0x1cb5_c415i32 => {
+                context.enter()?;
+                let result =
+                    ObjectRef::Vector(TLDeserialize::tl_deserialize_with_context(input, context)?);
+                context.leave();
+                result
+            }
+            _ => ObjectRef::Rpc(TLDeserialize::tl_deserialize_with_context(input, context)?),
+        })
+    }
+}
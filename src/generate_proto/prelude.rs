@@ -0,0 +1,11 @@
+pub use crate::{
+    tl_types::{
+        constructor_info::{ConstructorInfo, ParamInfo},
+        decode_context::DecodeContext,
+        tl_bare::TLBare,
+        tl_bare_vector::TLBareVector,
+        tl_bytes::TLBytes,
+        RemoteCall, TLBareDeserialize, TLBareType, TLDeserialize, TLType,
+    },
+    utils::{MyError, MyResult},
+};
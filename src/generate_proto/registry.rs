@@ -0,0 +1,900 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// Every constructor and method of the schema, sorted by id
+pub static CONSTRUCTORS: &[ConstructorInfo] = &[
+    ConstructorInfo {
+        id: -0x7f62_4921i32,
+        name: "msg_new_detailed_info",
+        params: &[
+            ParamInfo {
+                name: "answer_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "int",
+            },
+        ],
+        return_type: "MsgDetailedInfo",
+        rust_path: Some("tl::MsgDetailedInfo::MsgNewDetailedInfo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7c36_a514i32,
+        name: "p_q_inner_data",
+        params: &[
+            ParamInfo {
+                name: "pq",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "p",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "q",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce",
+                kind: "int256",
+            },
+        ],
+        return_type: "P_Q_inner_data",
+        rust_path: Some("tl::PQInnerData::PQInnerData"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x733f_2ecfi32,
+        name: "msgs_all_info",
+        params: &[
+            ParamInfo {
+                name: "msg_ids",
+                kind: "Vector<long>",
+            },
+            ParamInfo {
+                name: "info",
+                kind: "bytes",
+            },
+        ],
+        return_type: "MsgsAllInfo",
+        rust_path: Some("tl::MsgsAllInfo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6d66_ca61i32,
+        name: "http_wait",
+        params: &[
+            ParamInfo {
+                name: "max_delay",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "wait_after",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_wait",
+                kind: "int",
+            },
+        ],
+        return_type: "HttpWait",
+        rust_path: None,
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x613d_f6f8i32,
+        name: "new_session_created",
+        params: &[
+            ParamInfo {
+                name: "first_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "unique_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "server_salt",
+                kind: "long",
+            },
+        ],
+        return_type: "NewSession",
+        rust_path: Some("tl::NewSession"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5bc5_2749i32,
+        name: "rpc_answer_dropped",
+        params: &[
+            ParamInfo {
+                name: "msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "seq_no",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "int",
+            },
+        ],
+        return_type: "RpcDropAnswer",
+        rust_path: Some("tl::RpcDropAnswer::RpcAnswerDropped"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5962_51fei32,
+        name: "dh_gen_fail",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce_hash3",
+                kind: "int128",
+            },
+        ],
+        return_type: "Set_client_DH_params_answer",
+        rust_path: Some("tl::SetClientDhParamsAnswer::DhGenFail"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5810_07efi32,
+        name: "bad_msg_notification",
+        params: &[
+            ParamInfo {
+                name: "bad_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "bad_msg_seqno",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "error_code",
+                kind: "int",
+            },
+        ],
+        return_type: "BadMsgNotification",
+        rust_path: Some("tl::BadMsgNotification::BadMsgNotification"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x51af_f76bi32,
+        name: "future_salts",
+        params: &[
+            ParamInfo {
+                name: "req_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "now",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "salts",
+                kind: "vector<future_salt>",
+            },
+        ],
+        return_type: "FutureSalts",
+        rust_path: Some("tl::FutureSalts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4a76_f246i32,
+        name: "server_DH_inner_data",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "g",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "dh_prime",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "g_a",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "server_time",
+                kind: "int",
+            },
+        ],
+        return_type: "Server_DH_inner_data",
+        rust_path: Some("tl::ServerDhInnerData"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x46de_42fci32,
+        name: "get_future_salts",
+        params: &[ParamInfo {
+            name: "num",
+            kind: "int",
+        }],
+        return_type: "FutureSalts",
+        rust_path: Some("functions::GetFutureSalts"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3287_1a7ai32,
+        name: "rpc_answer_dropped_running",
+        params: &[],
+        return_type: "RpcDropAnswer",
+        rust_path: Some("tl::RpcDropAnswer::RpcAnswerDroppedRunning"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2f17_f8a4i32,
+        name: "server_DH_params_ok",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "encrypted_answer",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Server_DH_Params",
+        rust_path: Some("tl::ServerDhParams::ServerDhParamsOk"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x28ed_1b42i32,
+        name: "req_DH_params",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "p",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "q",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "public_key_fingerprint",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "encrypted_data",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Server_DH_Params",
+        rust_path: Some("functions::ReqDhParams"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2596_04aei32,
+        name: "msgs_state_req",
+        params: &[ParamInfo {
+            name: "msg_ids",
+            kind: "Vector<long>",
+        }],
+        return_type: "MsgsStateReq",
+        rust_path: Some("tl::MsgsStateReq"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1f9f_b94ei32,
+        name: "msg_copy",
+        params: &[ParamInfo {
+            name: "orig_message",
+            kind: "Message",
+        }],
+        return_type: "MessageCopy",
+        rust_path: Some("tl::MessageCopy"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1ddf_ba04i32,
+        name: "destroy_session_ok",
+        params: &[ParamInfo {
+            name: "session_id",
+            kind: "long",
+        }],
+        return_type: "DestroySessionRes",
+        rust_path: Some("tl::DestroySessionRes::DestroySessionOk"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x18ae_dedai32,
+        name: "destroy_session",
+        params: &[ParamInfo {
+            name: "session_id",
+            kind: "long",
+        }],
+        return_type: "DestroySessionRes",
+        rust_path: Some("functions::DestroySession"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1254_bb85i32,
+        name: "bad_server_salt",
+        params: &[
+            ParamInfo {
+                name: "bad_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "bad_msg_seqno",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "error_code",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "new_server_salt",
+                kind: "long",
+            },
+        ],
+        return_type: "BadMsgNotification",
+        rust_path: Some("tl::BadMsgNotification::BadServerSalt"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0cbd_8474i32,
+        name: "ping_delay_disconnect",
+        params: &[
+            ParamInfo {
+                name: "ping_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "disconnect_delay",
+                kind: "int",
+            },
+        ],
+        return_type: "Pong",
+        rust_path: Some("functions::PingDelayDisconnect"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0ca3_92ffi32,
+        name: "rpc_result",
+        params: &[
+            ParamInfo {
+                name: "req_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "result",
+                kind: "Object",
+            },
+        ],
+        return_type: "RpcResult",
+        rust_path: Some("tl::RpcResult"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0afb_a0e1i32,
+        name: "set_client_DH_params",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "encrypted_data",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Set_client_DH_params_answer",
+        rust_path: Some("functions::SetClientDhParams"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x04de_b57di32,
+        name: "msgs_state_info",
+        params: &[
+            ParamInfo {
+                name: "req_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "info",
+                kind: "bytes",
+            },
+        ],
+        return_type: "MsgsStateInfo",
+        rust_path: Some("tl::MsgsStateInfo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0516_2463i32,
+        name: "resPQ",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "pq",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "server_public_key_fingerprints",
+                kind: "Vector<long>",
+            },
+        ],
+        return_type: "ResPQ",
+        rust_path: Some("tl::ResPq"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0949_d9dci32,
+        name: "future_salt",
+        params: &[
+            ParamInfo {
+                name: "valid_since",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "valid_until",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "salt",
+                kind: "long",
+            },
+        ],
+        return_type: "FutureSalt",
+        rust_path: Some("tl::FutureSalt"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1cb5_c415i32,
+        name: "vector",
+        params: &[],
+        return_type: "Vector t",
+        rust_path: None,
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2144_ca19i32,
+        name: "rpc_error",
+        params: &[
+            ParamInfo {
+                name: "error_code",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "error_message",
+                kind: "string",
+            },
+        ],
+        return_type: "RpcError",
+        rust_path: Some("tl::RpcError"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x276d_3ec6i32,
+        name: "msg_detailed_info",
+        params: &[
+            ParamInfo {
+                name: "msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "answer_msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "int",
+            },
+        ],
+        return_type: "MsgDetailedInfo",
+        rust_path: Some("tl::MsgDetailedInfo::MsgDetailedInfo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3072_cfa1i32,
+        name: "gzip_packed",
+        params: &[ParamInfo {
+            name: "packed_data",
+            kind: "bytes",
+        }],
+        return_type: "Object",
+        rust_path: Some("tl::GzipPacked"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3477_73c5i32,
+        name: "pong",
+        params: &[
+            ParamInfo {
+                name: "msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "ping_id",
+                kind: "long",
+            },
+        ],
+        return_type: "Pong",
+        rust_path: Some("tl::Pong"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3bcb_f734i32,
+        name: "dh_gen_ok",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce_hash1",
+                kind: "int128",
+            },
+        ],
+        return_type: "Set_client_DH_params_answer",
+        rust_path: Some("tl::SetClientDhParamsAnswer::DhGenOk"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3c6a_84d4i32,
+        name: "p_q_inner_data_temp",
+        params: &[
+            ParamInfo {
+                name: "pq",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "p",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "q",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce",
+                kind: "int256",
+            },
+            ParamInfo {
+                name: "expires_in",
+                kind: "int",
+            },
+        ],
+        return_type: "P_Q_inner_data",
+        rust_path: Some("tl::PQInnerData::PQInnerDataTemp"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x46dc_1fb9i32,
+        name: "dh_gen_retry",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce_hash2",
+                kind: "int128",
+            },
+        ],
+        return_type: "Set_client_DH_params_answer",
+        rust_path: Some("tl::SetClientDhParamsAnswer::DhGenRetry"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x58e4_a740i32,
+        name: "rpc_drop_answer",
+        params: &[ParamInfo {
+            name: "req_msg_id",
+            kind: "long",
+        }],
+        return_type: "RpcDropAnswer",
+        rust_path: Some("functions::RpcDropAnswer"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5bb8_e511i32,
+        name: "message",
+        params: &[
+            ParamInfo {
+                name: "msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "seqno",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "body",
+                kind: "Object",
+            },
+        ],
+        return_type: "Message",
+        rust_path: Some("tl::Message"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5e2a_d36ei32,
+        name: "rpc_answer_unknown",
+        params: &[],
+        return_type: "RpcDropAnswer",
+        rust_path: Some("tl::RpcDropAnswer::RpcAnswerUnknown"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6046_9778i32,
+        name: "req_pq",
+        params: &[ParamInfo {
+            name: "nonce",
+            kind: "int128",
+        }],
+        return_type: "ResPQ",
+        rust_path: Some("functions::ReqPq"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x62d3_50c9i32,
+        name: "destroy_session_none",
+        params: &[ParamInfo {
+            name: "session_id",
+            kind: "long",
+        }],
+        return_type: "DestroySessionRes",
+        rust_path: Some("tl::DestroySessionRes::DestroySessionNone"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x62d6_b459i32,
+        name: "msgs_ack",
+        params: &[ParamInfo {
+            name: "msg_ids",
+            kind: "Vector<long>",
+        }],
+        return_type: "MsgsAck",
+        rust_path: Some("tl::MsgsAck"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6643_b654i32,
+        name: "client_DH_inner_data",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "retry_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "g_b",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Client_DH_Inner_Data",
+        rust_path: Some("tl::ClientDhInnerData"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x73f1_f8dci32,
+        name: "msg_container",
+        params: &[ParamInfo {
+            name: "messages",
+            kind: "vector<%Message>",
+        }],
+        return_type: "MessageContainer",
+        rust_path: Some("tl::MessageContainer"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x75a3_f765i32,
+        name: "bind_auth_key_inner",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "temp_auth_key_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "perm_auth_key_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "temp_session_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "expires_at",
+                kind: "int",
+            },
+        ],
+        return_type: "BindAuthKeyInner",
+        rust_path: Some("tl::BindAuthKeyInner"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x79cb_045di32,
+        name: "server_DH_params_fail",
+        params: &[
+            ParamInfo {
+                name: "nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "server_nonce",
+                kind: "int128",
+            },
+            ParamInfo {
+                name: "new_nonce_hash",
+                kind: "int128",
+            },
+        ],
+        return_type: "Server_DH_Params",
+        rust_path: Some("tl::ServerDhParams::ServerDhParamsFail"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7abe_77eci32,
+        name: "ping",
+        params: &[ParamInfo {
+            name: "ping_id",
+            kind: "long",
+        }],
+        return_type: "Pong",
+        rust_path: Some("functions::Ping"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7d86_1a08i32,
+        name: "msg_resend_req",
+        params: &[ParamInfo {
+            name: "msg_ids",
+            kind: "Vector<long>",
+        }],
+        return_type: "MsgResendReq",
+        rust_path: Some("tl::MsgResendReq"),
+        is_method: false,
+        layer: None,
+    },
+];
+pub fn by_id(id: i32) -> Option<&'static ConstructorInfo> {
+    CONSTRUCTORS
+        .binary_search_by_key(&id, |x| x.id)
+        .ok()
+        .map(|x| &CONSTRUCTORS[x])
+}
+pub fn by_name(name: &str) -> Option<&'static ConstructorInfo> {
+    CONSTRUCTORS.iter().find(|x| x.name == name)
+}
+/// Layer which defined type `return_type` (e.g. `auth.SentCode`), the lowest of its constructors
+pub fn type_layer(return_type: &str) -> Option<u32> {
+    CONSTRUCTORS
+        .iter()
+        .filter(|x| !x.is_method && x.return_type == return_type)
+        .filter_map(|x| x.layer)
+        .min()
+}
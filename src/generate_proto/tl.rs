@@ -0,0 +1,3390 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BadMsgNotification {
+    /// `bad_msg_notification#a7eff811 bad_msg_id:long bad_msg_seqno:int error_code:int = BadMsgNotification`
+    BadMsgNotification {
+        bad_msg_id: i64,
+        bad_msg_seqno: i32,
+        error_code: i32,
+    },
+    /// `bad_server_salt#edab447b bad_msg_id:long bad_msg_seqno:int error_code:int new_server_salt:long = BadMsgNotification`
+    BadServerSalt {
+        bad_msg_id: i64,
+        bad_msg_seqno: i32,
+        error_code: i32,
+        new_server_salt: i64,
+    },
+}
+impl TLType for BadMsgNotification {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x5810_07efi32 => BadMsgNotification::BadMsgNotification {
+                bad_msg_id: TLType::tl_read_with_context(input, context)?,
+                bad_msg_seqno: TLType::tl_read_with_context(input, context)?,
+                error_code: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x1254_bb85i32 => BadMsgNotification::BadServerSalt {
+                bad_msg_id: TLType::tl_read_with_context(input, context)?,
+                bad_msg_seqno: TLType::tl_read_with_context(input, context)?,
+                error_code: TLType::tl_read_with_context(input, context)?,
+                new_server_salt: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "BadMsgNotification",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            BadMsgNotification::BadMsgNotification {
+                bad_msg_id,
+                bad_msg_seqno,
+                error_code,
+            } => {
+                (-0x5810_07efi32).tl_write(output)?;
+                result += bad_msg_id.tl_write(output)?;
+                result += bad_msg_seqno.tl_write(output)?;
+                result += error_code.tl_write(output)?;
+            }
+            BadMsgNotification::BadServerSalt {
+                bad_msg_id,
+                bad_msg_seqno,
+                error_code,
+                new_server_salt,
+            } => {
+                (-0x1254_bb85i32).tl_write(output)?;
+                result += bad_msg_id.tl_write(output)?;
+                result += bad_msg_seqno.tl_write(output)?;
+                result += error_code.tl_write(output)?;
+                result += new_server_salt.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            BadMsgNotification::BadMsgNotification {
+                bad_msg_id,
+                bad_msg_seqno,
+                error_code,
+            } => {
+                result += bad_msg_id.serialized_len();
+                result += bad_msg_seqno.serialized_len();
+                result += error_code.serialized_len();
+            }
+            BadMsgNotification::BadServerSalt {
+                bad_msg_id,
+                bad_msg_seqno,
+                error_code,
+                new_server_salt,
+            } => {
+                result += bad_msg_id.serialized_len();
+                result += bad_msg_seqno.serialized_len();
+                result += error_code.serialized_len();
+                result += new_server_salt.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for BadMsgNotification {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x5810_07efi32 => BadMsgNotification::BadMsgNotification {
+                bad_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bad_msg_seqno: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                error_code: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x1254_bb85i32 => BadMsgNotification::BadServerSalt {
+                bad_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bad_msg_seqno: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                error_code: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_server_salt: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "BadMsgNotification",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct BadMsgNotificationBadMsgNotificationBuilder {
+    bad_msg_id: i64,
+    bad_msg_seqno: i32,
+    error_code: i32,
+}
+impl BadMsgNotificationBadMsgNotificationBuilder {
+    pub fn new(bad_msg_id: i64, bad_msg_seqno: i32, error_code: i32) -> Self {
+        BadMsgNotificationBadMsgNotificationBuilder {
+            bad_msg_id,
+            bad_msg_seqno,
+            error_code,
+        }
+    }
+    pub fn build(self) -> BadMsgNotification {
+        BadMsgNotification::BadMsgNotification {
+            bad_msg_id: self.bad_msg_id,
+            bad_msg_seqno: self.bad_msg_seqno,
+            error_code: self.error_code,
+        }
+    }
+}
+pub struct BadMsgNotificationBadServerSaltBuilder {
+    bad_msg_id: i64,
+    bad_msg_seqno: i32,
+    error_code: i32,
+    new_server_salt: i64,
+}
+impl BadMsgNotificationBadServerSaltBuilder {
+    pub fn new(bad_msg_id: i64, bad_msg_seqno: i32, error_code: i32, new_server_salt: i64) -> Self {
+        BadMsgNotificationBadServerSaltBuilder {
+            bad_msg_id,
+            bad_msg_seqno,
+            error_code,
+            new_server_salt,
+        }
+    }
+    pub fn build(self) -> BadMsgNotification {
+        BadMsgNotification::BadServerSalt {
+            bad_msg_id: self.bad_msg_id,
+            bad_msg_seqno: self.bad_msg_seqno,
+            error_code: self.error_code,
+            new_server_salt: self.new_server_salt,
+        }
+    }
+}
+/// `bind_auth_key_inner#75a3f765 nonce:long temp_auth_key_id:long perm_auth_key_id:long temp_session_id:long expires_at:int = BindAuthKeyInner`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindAuthKeyInner {
+    pub nonce: i64,
+    pub temp_auth_key_id: i64,
+    pub perm_auth_key_id: i64,
+    pub temp_session_id: i64,
+    pub expires_at: i32,
+}
+impl TLType for BindAuthKeyInner {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x75a3_f765i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "BindAuthKeyInner",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x75a3_f765i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for BindAuthKeyInner {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = BindAuthKeyInner {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            temp_auth_key_id: TLType::tl_read_with_context(input, context)?,
+            perm_auth_key_id: TLType::tl_read_with_context(input, context)?,
+            temp_session_id: TLType::tl_read_with_context(input, context)?,
+            expires_at: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.temp_auth_key_id.tl_write(output)?;
+        result += self.perm_auth_key_id.tl_write(output)?;
+        result += self.temp_session_id.tl_write(output)?;
+        result += self.expires_at.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.temp_auth_key_id.serialized_len();
+        result += self.perm_auth_key_id.serialized_len();
+        result += self.temp_session_id.serialized_len();
+        result += self.expires_at.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for BindAuthKeyInner {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x75a3_f765i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "BindAuthKeyInner",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for BindAuthKeyInner {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = BindAuthKeyInner {
+            nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            temp_auth_key_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            perm_auth_key_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            temp_session_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            expires_at: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct BindAuthKeyInnerBuilder {
+    nonce: i64,
+    temp_auth_key_id: i64,
+    perm_auth_key_id: i64,
+    temp_session_id: i64,
+    expires_at: i32,
+}
+impl BindAuthKeyInnerBuilder {
+    pub fn new(
+        nonce: i64,
+        temp_auth_key_id: i64,
+        perm_auth_key_id: i64,
+        temp_session_id: i64,
+        expires_at: i32,
+    ) -> Self {
+        BindAuthKeyInnerBuilder {
+            nonce,
+            temp_auth_key_id,
+            perm_auth_key_id,
+            temp_session_id,
+            expires_at,
+        }
+    }
+    pub fn build(self) -> BindAuthKeyInner {
+        BindAuthKeyInner {
+            nonce: self.nonce,
+            temp_auth_key_id: self.temp_auth_key_id,
+            perm_auth_key_id: self.perm_auth_key_id,
+            temp_session_id: self.temp_session_id,
+            expires_at: self.expires_at,
+        }
+    }
+}
+/// `client_DH_inner_data#6643b654 nonce:int128 server_nonce:int128 retry_id:long g_b:bytes = Client_DH_Inner_Data`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClientDhInnerData {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub retry_id: i64,
+    pub g_b: TLBytes,
+}
+impl TLType for ClientDhInnerData {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6643_b654i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Client_DH_Inner_Data",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6643_b654i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ClientDhInnerData {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ClientDhInnerData {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            server_nonce: TLType::tl_read_with_context(input, context)?,
+            retry_id: TLType::tl_read_with_context(input, context)?,
+            g_b: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.server_nonce.tl_write(output)?;
+        result += self.retry_id.tl_write(output)?;
+        result += self.g_b.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.server_nonce.serialized_len();
+        result += self.retry_id.serialized_len();
+        result += self.g_b.serialized_len();
+        result
+    }
+}
+/// `client_DH_inner_data#6643b654 nonce:int128 server_nonce:int128 retry_id:long g_b:bytes = Client_DH_Inner_Data`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClientDhInnerDataRef<'a> {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub retry_id: i64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub g_b: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for ClientDhInnerDataRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x6643_b654i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Client_DH_Inner_Data",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ClientDhInnerDataRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ClientDhInnerDataRef {
+            nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            retry_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            g_b: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ClientDhInnerDataBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    retry_id: i64,
+    g_b: TLBytes,
+}
+impl ClientDhInnerDataBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], retry_id: i64, g_b: TLBytes) -> Self {
+        ClientDhInnerDataBuilder {
+            nonce,
+            server_nonce,
+            retry_id,
+            g_b,
+        }
+    }
+    pub fn build(self) -> ClientDhInnerData {
+        ClientDhInnerData {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            retry_id: self.retry_id,
+            g_b: self.g_b,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DestroySessionRes {
+    /// `destroy_session_ok#e22045fc session_id:long = DestroySessionRes`
+    DestroySessionOk { session_id: i64 },
+    /// `destroy_session_none#62d350c9 session_id:long = DestroySessionRes`
+    DestroySessionNone { session_id: i64 },
+}
+impl TLType for DestroySessionRes {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x1ddf_ba04i32 => DestroySessionRes::DestroySessionOk {
+                session_id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x62d3_50c9i32 => DestroySessionRes::DestroySessionNone {
+                session_id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "DestroySessionRes",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            DestroySessionRes::DestroySessionOk { session_id } => {
+                (-0x1ddf_ba04i32).tl_write(output)?;
+                result += session_id.tl_write(output)?;
+            }
+            DestroySessionRes::DestroySessionNone { session_id } => {
+                (0x62d3_50c9i32).tl_write(output)?;
+                result += session_id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            DestroySessionRes::DestroySessionOk { session_id } => {
+                result += session_id.serialized_len();
+            }
+            DestroySessionRes::DestroySessionNone { session_id } => {
+                result += session_id.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for DestroySessionRes {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x1ddf_ba04i32 => DestroySessionRes::DestroySessionOk {
+                session_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x62d3_50c9i32 => DestroySessionRes::DestroySessionNone {
+                session_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "DestroySessionRes",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DestroySessionResDestroySessionOkBuilder {
+    session_id: i64,
+}
+impl DestroySessionResDestroySessionOkBuilder {
+    pub fn new(session_id: i64) -> Self {
+        DestroySessionResDestroySessionOkBuilder { session_id }
+    }
+    pub fn build(self) -> DestroySessionRes {
+        DestroySessionRes::DestroySessionOk {
+            session_id: self.session_id,
+        }
+    }
+}
+pub struct DestroySessionResDestroySessionNoneBuilder {
+    session_id: i64,
+}
+impl DestroySessionResDestroySessionNoneBuilder {
+    pub fn new(session_id: i64) -> Self {
+        DestroySessionResDestroySessionNoneBuilder { session_id }
+    }
+    pub fn build(self) -> DestroySessionRes {
+        DestroySessionRes::DestroySessionNone {
+            session_id: self.session_id,
+        }
+    }
+}
+/// `future_salt#0949d9dc valid_since:int valid_until:int salt:long = FutureSalt`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FutureSalt {
+    pub valid_since: i32,
+    pub valid_until: i32,
+    pub salt: i64,
+}
+impl TLType for FutureSalt {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0949_d9dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "FutureSalt",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0949_d9dci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for FutureSalt {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FutureSalt {
+            valid_since: TLType::tl_read_with_context(input, context)?,
+            valid_until: TLType::tl_read_with_context(input, context)?,
+            salt: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.valid_since.tl_write(output)?;
+        result += self.valid_until.tl_write(output)?;
+        result += self.salt.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.valid_since.serialized_len();
+        result += self.valid_until.serialized_len();
+        result += self.salt.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for FutureSalt {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x0949_d9dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "FutureSalt",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for FutureSalt {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FutureSalt {
+            valid_since: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            valid_until: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            salt: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct FutureSaltBuilder {
+    valid_since: i32,
+    valid_until: i32,
+    salt: i64,
+}
+impl FutureSaltBuilder {
+    pub fn new(valid_since: i32, valid_until: i32, salt: i64) -> Self {
+        FutureSaltBuilder {
+            valid_since,
+            valid_until,
+            salt,
+        }
+    }
+    pub fn build(self) -> FutureSalt {
+        FutureSalt {
+            valid_since: self.valid_since,
+            valid_until: self.valid_until,
+            salt: self.salt,
+        }
+    }
+}
+/// `future_salts#ae500895 req_msg_id:long now:int salts:vector<future_salt> = FutureSalts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FutureSalts {
+    pub req_msg_id: i64,
+    pub now: i32,
+    pub salts: TLBareVector<TLBare<super::tl::FutureSalt>>,
+}
+impl TLType for FutureSalts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x51af_f76bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "FutureSalts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x51af_f76bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for FutureSalts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FutureSalts {
+            req_msg_id: TLType::tl_read_with_context(input, context)?,
+            now: TLType::tl_read_with_context(input, context)?,
+            salts: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.req_msg_id.tl_write(output)?;
+        result += self.now.tl_write(output)?;
+        result += self.salts.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.req_msg_id.serialized_len();
+        result += self.now.serialized_len();
+        result += self.salts.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for FutureSalts {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x51af_f76bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "FutureSalts",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for FutureSalts {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FutureSalts {
+            req_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            now: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            salts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct FutureSaltsBuilder {
+    req_msg_id: i64,
+    now: i32,
+    salts: TLBareVector<TLBare<super::tl::FutureSalt>>,
+}
+impl FutureSaltsBuilder {
+    pub fn new(
+        req_msg_id: i64,
+        now: i32,
+        salts: TLBareVector<TLBare<super::tl::FutureSalt>>,
+    ) -> Self {
+        FutureSaltsBuilder {
+            req_msg_id,
+            now,
+            salts,
+        }
+    }
+    pub fn build(self) -> FutureSalts {
+        FutureSalts {
+            req_msg_id: self.req_msg_id,
+            now: self.now,
+            salts: self.salts,
+        }
+    }
+}
+/// `gzip_packed#3072cfa1 packed_data:bytes = Object`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GzipPacked {
+    pub packed_data: TLBytes,
+}
+impl TLType for GzipPacked {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3072_cfa1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "GzipPacked",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3072_cfa1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for GzipPacked {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = GzipPacked {
+            packed_data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.packed_data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.packed_data.serialized_len();
+        result
+    }
+}
+/// `gzip_packed#3072cfa1 packed_data:bytes = Object`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GzipPackedRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub packed_data: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for GzipPackedRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x3072_cfa1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "GzipPacked",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for GzipPackedRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = GzipPackedRef {
+            packed_data: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct GzipPackedBuilder {
+    packed_data: TLBytes,
+}
+impl GzipPackedBuilder {
+    pub fn new(packed_data: TLBytes) -> Self {
+        GzipPackedBuilder { packed_data }
+    }
+    pub fn build(self) -> GzipPacked {
+        GzipPacked {
+            packed_data: self.packed_data,
+        }
+    }
+}
+/// `message#5bb8e511 msg_id:long seqno:int bytes:int body:Object = Message`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Message {
+    pub msg_id: i64,
+    pub seqno: i32,
+    pub bytes: i32,
+    pub body: super::object::Object,
+}
+impl TLType for Message {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x5bb8_e511i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Message",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x5bb8_e511i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Message {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Message {
+            msg_id: TLType::tl_read_with_context(input, context)?,
+            seqno: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+            body: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_id.tl_write(output)?;
+        result += self.seqno.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        result += self.body.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_id.serialized_len();
+        result += self.seqno.serialized_len();
+        result += self.bytes.serialized_len();
+        result += self.body.serialized_len();
+        result
+    }
+}
+/// `message#5bb8e511 msg_id:long seqno:int bytes:int body:Object = Message`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageRef<'a> {
+    pub msg_id: i64,
+    pub seqno: i32,
+    pub bytes: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub body: super::object::ObjectRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for MessageRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x5bb8_e511i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Message",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MessageRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessageRef {
+            msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            seqno: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            body: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageBuilder {
+    msg_id: i64,
+    seqno: i32,
+    bytes: i32,
+    body: super::object::Object,
+}
+impl MessageBuilder {
+    pub fn new(msg_id: i64, seqno: i32, bytes: i32, body: super::object::Object) -> Self {
+        MessageBuilder {
+            msg_id,
+            seqno,
+            bytes,
+            body,
+        }
+    }
+    pub fn build(self) -> Message {
+        Message {
+            msg_id: self.msg_id,
+            seqno: self.seqno,
+            bytes: self.bytes,
+            body: self.body,
+        }
+    }
+}
+/// `msg_container#73f1f8dc messages:vector<%Message> = MessageContainer`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageContainer {
+    pub messages: TLBareVector<TLBare<super::tl::Message>>,
+}
+impl TLType for MessageContainer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x73f1_f8dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MessageContainer",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x73f1_f8dci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessageContainer {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessageContainer {
+            messages: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.messages.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.messages.serialized_len();
+        result
+    }
+}
+/// `msg_container#73f1f8dc messages:vector<%Message> = MessageContainer`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageContainerRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub messages: TLBareVector<TLBare<super::tl::MessageRef<'a>>>,
+}
+impl<'a> TLDeserialize<'a> for MessageContainerRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x73f1_f8dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MessageContainer",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MessageContainerRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessageContainerRef {
+            messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageContainerBuilder {
+    messages: TLBareVector<TLBare<super::tl::Message>>,
+}
+impl MessageContainerBuilder {
+    pub fn new(messages: TLBareVector<TLBare<super::tl::Message>>) -> Self {
+        MessageContainerBuilder { messages }
+    }
+    pub fn build(self) -> MessageContainer {
+        MessageContainer {
+            messages: self.messages,
+        }
+    }
+}
+/// `msg_copy#e06046b2 orig_message:Message = MessageCopy`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageCopy {
+    pub orig_message: super::tl::Message,
+}
+impl TLType for MessageCopy {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1f9f_b94ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MessageCopy",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1f9f_b94ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessageCopy {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessageCopy {
+            orig_message: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.orig_message.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.orig_message.serialized_len();
+        result
+    }
+}
+/// `msg_copy#e06046b2 orig_message:Message = MessageCopy`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageCopyRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub orig_message: super::tl::MessageRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for MessageCopyRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x1f9f_b94ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MessageCopy",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MessageCopyRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessageCopyRef {
+            orig_message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageCopyBuilder {
+    orig_message: super::tl::Message,
+}
+impl MessageCopyBuilder {
+    pub fn new(orig_message: super::tl::Message) -> Self {
+        MessageCopyBuilder { orig_message }
+    }
+    pub fn build(self) -> MessageCopy {
+        MessageCopy {
+            orig_message: self.orig_message,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MsgDetailedInfo {
+    /// `msg_detailed_info#276d3ec6 msg_id:long answer_msg_id:long bytes:int status:int = MsgDetailedInfo`
+    MsgDetailedInfo {
+        msg_id: i64,
+        answer_msg_id: i64,
+        bytes: i32,
+        status: i32,
+    },
+    /// `msg_new_detailed_info#809db6df answer_msg_id:long bytes:int status:int = MsgDetailedInfo`
+    MsgNewDetailedInfo {
+        answer_msg_id: i64,
+        bytes: i32,
+        status: i32,
+    },
+}
+impl TLType for MsgDetailedInfo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x276d_3ec6i32 => MsgDetailedInfo::MsgDetailedInfo {
+                msg_id: TLType::tl_read_with_context(input, context)?,
+                answer_msg_id: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x7f62_4921i32 => MsgDetailedInfo::MsgNewDetailedInfo {
+                answer_msg_id: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MsgDetailedInfo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            MsgDetailedInfo::MsgDetailedInfo {
+                msg_id,
+                answer_msg_id,
+                bytes,
+                status,
+            } => {
+                (0x276d_3ec6i32).tl_write(output)?;
+                result += msg_id.tl_write(output)?;
+                result += answer_msg_id.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+            MsgDetailedInfo::MsgNewDetailedInfo {
+                answer_msg_id,
+                bytes,
+                status,
+            } => {
+                (-0x7f62_4921i32).tl_write(output)?;
+                result += answer_msg_id.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            MsgDetailedInfo::MsgDetailedInfo {
+                msg_id,
+                answer_msg_id,
+                bytes,
+                status,
+            } => {
+                result += msg_id.serialized_len();
+                result += answer_msg_id.serialized_len();
+                result += bytes.serialized_len();
+                result += status.serialized_len();
+            }
+            MsgDetailedInfo::MsgNewDetailedInfo {
+                answer_msg_id,
+                bytes,
+                status,
+            } => {
+                result += answer_msg_id.serialized_len();
+                result += bytes.serialized_len();
+                result += status.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for MsgDetailedInfo {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x276d_3ec6i32 => MsgDetailedInfo::MsgDetailedInfo {
+                msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                answer_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x7f62_4921i32 => MsgDetailedInfo::MsgNewDetailedInfo {
+                answer_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MsgDetailedInfo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgDetailedInfoMsgDetailedInfoBuilder {
+    msg_id: i64,
+    answer_msg_id: i64,
+    bytes: i32,
+    status: i32,
+}
+impl MsgDetailedInfoMsgDetailedInfoBuilder {
+    pub fn new(msg_id: i64, answer_msg_id: i64, bytes: i32, status: i32) -> Self {
+        MsgDetailedInfoMsgDetailedInfoBuilder {
+            msg_id,
+            answer_msg_id,
+            bytes,
+            status,
+        }
+    }
+    pub fn build(self) -> MsgDetailedInfo {
+        MsgDetailedInfo::MsgDetailedInfo {
+            msg_id: self.msg_id,
+            answer_msg_id: self.answer_msg_id,
+            bytes: self.bytes,
+            status: self.status,
+        }
+    }
+}
+pub struct MsgDetailedInfoMsgNewDetailedInfoBuilder {
+    answer_msg_id: i64,
+    bytes: i32,
+    status: i32,
+}
+impl MsgDetailedInfoMsgNewDetailedInfoBuilder {
+    pub fn new(answer_msg_id: i64, bytes: i32, status: i32) -> Self {
+        MsgDetailedInfoMsgNewDetailedInfoBuilder {
+            answer_msg_id,
+            bytes,
+            status,
+        }
+    }
+    pub fn build(self) -> MsgDetailedInfo {
+        MsgDetailedInfo::MsgNewDetailedInfo {
+            answer_msg_id: self.answer_msg_id,
+            bytes: self.bytes,
+            status: self.status,
+        }
+    }
+}
+/// `msg_resend_req#7d861a08 msg_ids:Vector<long> = MsgResendReq`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgResendReq {
+    pub msg_ids: Vec<i64>,
+}
+impl TLType for MsgResendReq {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7d86_1a08i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgResendReq",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7d86_1a08i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MsgResendReq {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgResendReq {
+            msg_ids: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_ids.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_ids.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for MsgResendReq {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x7d86_1a08i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgResendReq",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MsgResendReq {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgResendReq {
+            msg_ids: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgResendReqBuilder {
+    msg_ids: Vec<i64>,
+}
+impl MsgResendReqBuilder {
+    pub fn new(msg_ids: Vec<i64>) -> Self {
+        MsgResendReqBuilder { msg_ids }
+    }
+    pub fn build(self) -> MsgResendReq {
+        MsgResendReq {
+            msg_ids: self.msg_ids,
+        }
+    }
+}
+/// `msgs_ack#62d6b459 msg_ids:Vector<long> = MsgsAck`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsAck {
+    pub msg_ids: Vec<i64>,
+}
+impl TLType for MsgsAck {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x62d6_b459i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsAck",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x62d6_b459i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MsgsAck {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsAck {
+            msg_ids: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_ids.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_ids.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for MsgsAck {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x62d6_b459i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsAck",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MsgsAck {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsAck {
+            msg_ids: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgsAckBuilder {
+    msg_ids: Vec<i64>,
+}
+impl MsgsAckBuilder {
+    pub fn new(msg_ids: Vec<i64>) -> Self {
+        MsgsAckBuilder { msg_ids }
+    }
+    pub fn build(self) -> MsgsAck {
+        MsgsAck {
+            msg_ids: self.msg_ids,
+        }
+    }
+}
+/// `msgs_all_info#8cc0d131 msg_ids:Vector<long> info:bytes = MsgsAllInfo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsAllInfo {
+    pub msg_ids: Vec<i64>,
+    pub info: TLBytes,
+}
+impl TLType for MsgsAllInfo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x733f_2ecfi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsAllInfo",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x733f_2ecfi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MsgsAllInfo {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsAllInfo {
+            msg_ids: TLType::tl_read_with_context(input, context)?,
+            info: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_ids.tl_write(output)?;
+        result += self.info.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_ids.serialized_len();
+        result += self.info.serialized_len();
+        result
+    }
+}
+/// `msgs_all_info#8cc0d131 msg_ids:Vector<long> info:bytes = MsgsAllInfo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsAllInfoRef<'a> {
+    pub msg_ids: Vec<i64>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub info: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for MsgsAllInfoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x733f_2ecfi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsAllInfo",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MsgsAllInfoRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsAllInfoRef {
+            msg_ids: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            info: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgsAllInfoBuilder {
+    msg_ids: Vec<i64>,
+    info: TLBytes,
+}
+impl MsgsAllInfoBuilder {
+    pub fn new(msg_ids: Vec<i64>, info: TLBytes) -> Self {
+        MsgsAllInfoBuilder { msg_ids, info }
+    }
+    pub fn build(self) -> MsgsAllInfo {
+        MsgsAllInfo {
+            msg_ids: self.msg_ids,
+            info: self.info,
+        }
+    }
+}
+/// `msgs_state_info#04deb57d req_msg_id:long info:bytes = MsgsStateInfo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsStateInfo {
+    pub req_msg_id: i64,
+    pub info: TLBytes,
+}
+impl TLType for MsgsStateInfo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x04de_b57di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsStateInfo",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x04de_b57di32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MsgsStateInfo {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsStateInfo {
+            req_msg_id: TLType::tl_read_with_context(input, context)?,
+            info: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.req_msg_id.tl_write(output)?;
+        result += self.info.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.req_msg_id.serialized_len();
+        result += self.info.serialized_len();
+        result
+    }
+}
+/// `msgs_state_info#04deb57d req_msg_id:long info:bytes = MsgsStateInfo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsStateInfoRef<'a> {
+    pub req_msg_id: i64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub info: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for MsgsStateInfoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x04de_b57di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsStateInfo",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MsgsStateInfoRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsStateInfoRef {
+            req_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            info: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgsStateInfoBuilder {
+    req_msg_id: i64,
+    info: TLBytes,
+}
+impl MsgsStateInfoBuilder {
+    pub fn new(req_msg_id: i64, info: TLBytes) -> Self {
+        MsgsStateInfoBuilder { req_msg_id, info }
+    }
+    pub fn build(self) -> MsgsStateInfo {
+        MsgsStateInfo {
+            req_msg_id: self.req_msg_id,
+            info: self.info,
+        }
+    }
+}
+/// `msgs_state_req#da69fb52 msg_ids:Vector<long> = MsgsStateReq`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsgsStateReq {
+    pub msg_ids: Vec<i64>,
+}
+impl TLType for MsgsStateReq {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2596_04aei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsStateReq",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2596_04aei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MsgsStateReq {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsStateReq {
+            msg_ids: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_ids.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_ids.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for MsgsStateReq {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x2596_04aei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "MsgsStateReq",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for MsgsStateReq {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MsgsStateReq {
+            msg_ids: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MsgsStateReqBuilder {
+    msg_ids: Vec<i64>,
+}
+impl MsgsStateReqBuilder {
+    pub fn new(msg_ids: Vec<i64>) -> Self {
+        MsgsStateReqBuilder { msg_ids }
+    }
+    pub fn build(self) -> MsgsStateReq {
+        MsgsStateReq {
+            msg_ids: self.msg_ids,
+        }
+    }
+}
+/// `new_session_created#9ec20908 first_msg_id:long unique_id:long server_salt:long = NewSession`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewSession {
+    pub first_msg_id: i64,
+    pub unique_id: i64,
+    pub server_salt: i64,
+}
+impl TLType for NewSession {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x613d_f6f8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "NewSession",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x613d_f6f8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for NewSession {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = NewSession {
+            first_msg_id: TLType::tl_read_with_context(input, context)?,
+            unique_id: TLType::tl_read_with_context(input, context)?,
+            server_salt: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.first_msg_id.tl_write(output)?;
+        result += self.unique_id.tl_write(output)?;
+        result += self.server_salt.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.first_msg_id.serialized_len();
+        result += self.unique_id.serialized_len();
+        result += self.server_salt.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for NewSession {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x613d_f6f8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "NewSession",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for NewSession {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = NewSession {
+            first_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            unique_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_salt: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct NewSessionBuilder {
+    first_msg_id: i64,
+    unique_id: i64,
+    server_salt: i64,
+}
+impl NewSessionBuilder {
+    pub fn new(first_msg_id: i64, unique_id: i64, server_salt: i64) -> Self {
+        NewSessionBuilder {
+            first_msg_id,
+            unique_id,
+            server_salt,
+        }
+    }
+    pub fn build(self) -> NewSession {
+        NewSession {
+            first_msg_id: self.first_msg_id,
+            unique_id: self.unique_id,
+            server_salt: self.server_salt,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PQInnerData {
+    /// `p_q_inner_data#83c95aec pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 = P_Q_inner_data`
+    PQInnerData {
+        pq: TLBytes,
+        p: TLBytes,
+        q: TLBytes,
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+    },
+    /// `p_q_inner_data_temp#3c6a84d4 pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 expires_in:int = P_Q_inner_data`
+    PQInnerDataTemp {
+        pq: TLBytes,
+        p: TLBytes,
+        q: TLBytes,
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+        expires_in: i32,
+    },
+}
+impl TLType for PQInnerData {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x7c36_a514i32 => PQInnerData::PQInnerData {
+                pq: TLType::tl_read_with_context(input, context)?,
+                p: TLType::tl_read_with_context(input, context)?,
+                q: TLType::tl_read_with_context(input, context)?,
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce: TLType::tl_read_with_context(input, context)?,
+            },
+            0x3c6a_84d4i32 => PQInnerData::PQInnerDataTemp {
+                pq: TLType::tl_read_with_context(input, context)?,
+                p: TLType::tl_read_with_context(input, context)?,
+                q: TLType::tl_read_with_context(input, context)?,
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce: TLType::tl_read_with_context(input, context)?,
+                expires_in: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "P_Q_inner_data",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            PQInnerData::PQInnerData {
+                pq,
+                p,
+                q,
+                nonce,
+                server_nonce,
+                new_nonce,
+            } => {
+                (-0x7c36_a514i32).tl_write(output)?;
+                result += pq.tl_write(output)?;
+                result += p.tl_write(output)?;
+                result += q.tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce.tl_write(output)?;
+            }
+            PQInnerData::PQInnerDataTemp {
+                pq,
+                p,
+                q,
+                nonce,
+                server_nonce,
+                new_nonce,
+                expires_in,
+            } => {
+                (0x3c6a_84d4i32).tl_write(output)?;
+                result += pq.tl_write(output)?;
+                result += p.tl_write(output)?;
+                result += q.tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce.tl_write(output)?;
+                result += expires_in.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            PQInnerData::PQInnerData {
+                pq,
+                p,
+                q,
+                nonce,
+                server_nonce,
+                new_nonce,
+            } => {
+                result += pq.serialized_len();
+                result += p.serialized_len();
+                result += q.serialized_len();
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce.serialized_len();
+            }
+            PQInnerData::PQInnerDataTemp {
+                pq,
+                p,
+                q,
+                nonce,
+                server_nonce,
+                new_nonce,
+                expires_in,
+            } => {
+                result += pq.serialized_len();
+                result += p.serialized_len();
+                result += q.serialized_len();
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce.serialized_len();
+                result += expires_in.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PQInnerDataRef<'a> {
+    /// `p_q_inner_data#83c95aec pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 = P_Q_inner_data`
+    PQInnerData {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        pq: &'a [u8],
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        p: &'a [u8],
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        q: &'a [u8],
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+    },
+    /// `p_q_inner_data_temp#3c6a84d4 pq:bytes p:bytes q:bytes nonce:int128 server_nonce:int128 new_nonce:int256 expires_in:int = P_Q_inner_data`
+    PQInnerDataTemp {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        pq: &'a [u8],
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        p: &'a [u8],
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        q: &'a [u8],
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+        expires_in: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for PQInnerDataRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x7c36_a514i32 => PQInnerDataRef::PQInnerData {
+                pq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                p: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                q: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x3c6a_84d4i32 => PQInnerDataRef::PQInnerDataTemp {
+                pq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                p: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                q: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                expires_in: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "P_Q_inner_data",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PQInnerDataPQInnerDataBuilder {
+    pq: TLBytes,
+    p: TLBytes,
+    q: TLBytes,
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce: [u8; 32],
+}
+impl PQInnerDataPQInnerDataBuilder {
+    pub fn new(
+        pq: TLBytes,
+        p: TLBytes,
+        q: TLBytes,
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+    ) -> Self {
+        PQInnerDataPQInnerDataBuilder {
+            pq,
+            p,
+            q,
+            nonce,
+            server_nonce,
+            new_nonce,
+        }
+    }
+    pub fn build(self) -> PQInnerData {
+        PQInnerData::PQInnerData {
+            pq: self.pq,
+            p: self.p,
+            q: self.q,
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce: self.new_nonce,
+        }
+    }
+}
+pub struct PQInnerDataPQInnerDataTempBuilder {
+    pq: TLBytes,
+    p: TLBytes,
+    q: TLBytes,
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce: [u8; 32],
+    expires_in: i32,
+}
+impl PQInnerDataPQInnerDataTempBuilder {
+    pub fn new(
+        pq: TLBytes,
+        p: TLBytes,
+        q: TLBytes,
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce: [u8; 32],
+        expires_in: i32,
+    ) -> Self {
+        PQInnerDataPQInnerDataTempBuilder {
+            pq,
+            p,
+            q,
+            nonce,
+            server_nonce,
+            new_nonce,
+            expires_in,
+        }
+    }
+    pub fn build(self) -> PQInnerData {
+        PQInnerData::PQInnerDataTemp {
+            pq: self.pq,
+            p: self.p,
+            q: self.q,
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce: self.new_nonce,
+            expires_in: self.expires_in,
+        }
+    }
+}
+/// `pong#347773c5 msg_id:long ping_id:long = Pong`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pong {
+    pub msg_id: i64,
+    pub ping_id: i64,
+}
+impl TLType for Pong {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3477_73c5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Pong",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3477_73c5i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Pong {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Pong {
+            msg_id: TLType::tl_read_with_context(input, context)?,
+            ping_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_id.tl_write(output)?;
+        result += self.ping_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_id.serialized_len();
+        result += self.ping_id.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for Pong {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x3477_73c5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Pong",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for Pong {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Pong {
+            msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            ping_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PongBuilder {
+    msg_id: i64,
+    ping_id: i64,
+}
+impl PongBuilder {
+    pub fn new(msg_id: i64, ping_id: i64) -> Self {
+        PongBuilder { msg_id, ping_id }
+    }
+    pub fn build(self) -> Pong {
+        Pong {
+            msg_id: self.msg_id,
+            ping_id: self.ping_id,
+        }
+    }
+}
+/// `resPQ#05162463 nonce:int128 server_nonce:int128 pq:bytes server_public_key_fingerprints:Vector<long> = ResPQ`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResPq {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub pq: TLBytes,
+    pub server_public_key_fingerprints: Vec<i64>,
+}
+impl TLType for ResPq {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0516_2463i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ResPQ",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0516_2463i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ResPq {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ResPq {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            server_nonce: TLType::tl_read_with_context(input, context)?,
+            pq: TLType::tl_read_with_context(input, context)?,
+            server_public_key_fingerprints: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.server_nonce.tl_write(output)?;
+        result += self.pq.tl_write(output)?;
+        result += self.server_public_key_fingerprints.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.server_nonce.serialized_len();
+        result += self.pq.serialized_len();
+        result += self.server_public_key_fingerprints.serialized_len();
+        result
+    }
+}
+/// `resPQ#05162463 nonce:int128 server_nonce:int128 pq:bytes server_public_key_fingerprints:Vector<long> = ResPQ`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResPqRef<'a> {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pq: &'a [u8],
+    pub server_public_key_fingerprints: Vec<i64>,
+}
+impl<'a> TLDeserialize<'a> for ResPqRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x0516_2463i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ResPQ",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ResPqRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ResPqRef {
+            nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            pq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_public_key_fingerprints: TLDeserialize::tl_deserialize_with_context(
+                input, context,
+            )?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ResPqBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    pq: TLBytes,
+    server_public_key_fingerprints: Vec<i64>,
+}
+impl ResPqBuilder {
+    pub fn new(
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        pq: TLBytes,
+        server_public_key_fingerprints: Vec<i64>,
+    ) -> Self {
+        ResPqBuilder {
+            nonce,
+            server_nonce,
+            pq,
+            server_public_key_fingerprints,
+        }
+    }
+    pub fn build(self) -> ResPq {
+        ResPq {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            pq: self.pq,
+            server_public_key_fingerprints: self.server_public_key_fingerprints,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RpcDropAnswer {
+    /// `rpc_answer_unknown#5e2ad36e = RpcDropAnswer`
+    RpcAnswerUnknown,
+    /// `rpc_answer_dropped_running#cd78e586 = RpcDropAnswer`
+    RpcAnswerDroppedRunning,
+    /// `rpc_answer_dropped#a43ad8b7 msg_id:long seq_no:int bytes:int = RpcDropAnswer`
+    RpcAnswerDropped {
+        msg_id: i64,
+        seq_no: i32,
+        bytes: i32,
+    },
+}
+impl TLType for RpcDropAnswer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x5e2a_d36ei32 => RpcDropAnswer::RpcAnswerUnknown {},
+            -0x3287_1a7ai32 => RpcDropAnswer::RpcAnswerDroppedRunning {},
+            -0x5bc5_2749i32 => RpcDropAnswer::RpcAnswerDropped {
+                msg_id: TLType::tl_read_with_context(input, context)?,
+                seq_no: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "RpcDropAnswer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            RpcDropAnswer::RpcAnswerUnknown => {
+                (0x5e2a_d36ei32).tl_write(output)?;
+            }
+            RpcDropAnswer::RpcAnswerDroppedRunning => {
+                (-0x3287_1a7ai32).tl_write(output)?;
+            }
+            RpcDropAnswer::RpcAnswerDropped {
+                msg_id,
+                seq_no,
+                bytes,
+            } => {
+                (-0x5bc5_2749i32).tl_write(output)?;
+                result += msg_id.tl_write(output)?;
+                result += seq_no.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            RpcDropAnswer::RpcAnswerUnknown => {}
+            RpcDropAnswer::RpcAnswerDroppedRunning => {}
+            RpcDropAnswer::RpcAnswerDropped {
+                msg_id,
+                seq_no,
+                bytes,
+            } => {
+                result += msg_id.serialized_len();
+                result += seq_no.serialized_len();
+                result += bytes.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for RpcDropAnswer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x5e2a_d36ei32 => RpcDropAnswer::RpcAnswerUnknown {},
+            -0x3287_1a7ai32 => RpcDropAnswer::RpcAnswerDroppedRunning {},
+            -0x5bc5_2749i32 => RpcDropAnswer::RpcAnswerDropped {
+                msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq_no: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "RpcDropAnswer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct RpcDropAnswerRpcAnswerDroppedBuilder {
+    msg_id: i64,
+    seq_no: i32,
+    bytes: i32,
+}
+impl RpcDropAnswerRpcAnswerDroppedBuilder {
+    pub fn new(msg_id: i64, seq_no: i32, bytes: i32) -> Self {
+        RpcDropAnswerRpcAnswerDroppedBuilder {
+            msg_id,
+            seq_no,
+            bytes,
+        }
+    }
+    pub fn build(self) -> RpcDropAnswer {
+        RpcDropAnswer::RpcAnswerDropped {
+            msg_id: self.msg_id,
+            seq_no: self.seq_no,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `rpc_error#2144ca19 error_code:int error_message:string = RpcError`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcError {
+    pub error_code: i32,
+    pub error_message: String,
+}
+impl TLType for RpcError {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2144_ca19i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "RpcError",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2144_ca19i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for RpcError {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = RpcError {
+            error_code: TLType::tl_read_with_context(input, context)?,
+            error_message: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.error_code.tl_write(output)?;
+        result += self.error_message.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.error_code.serialized_len();
+        result += self.error_message.serialized_len();
+        result
+    }
+}
+/// `rpc_error#2144ca19 error_code:int error_message:string = RpcError`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcErrorRef<'a> {
+    pub error_code: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub error_message: &'a str,
+}
+impl<'a> TLDeserialize<'a> for RpcErrorRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x2144_ca19i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "RpcError",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for RpcErrorRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = RpcErrorRef {
+            error_code: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            error_message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct RpcErrorBuilder {
+    error_code: i32,
+    error_message: String,
+}
+impl RpcErrorBuilder {
+    pub fn new(error_code: i32, error_message: String) -> Self {
+        RpcErrorBuilder {
+            error_code,
+            error_message,
+        }
+    }
+    pub fn build(self) -> RpcError {
+        RpcError {
+            error_code: self.error_code,
+            error_message: self.error_message,
+        }
+    }
+}
+/// `rpc_result#f35c6d01 req_msg_id:long result:Object = RpcResult`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcResult {
+    pub req_msg_id: i64,
+    pub result: super::object::Object,
+}
+impl TLType for RpcResult {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0ca3_92ffi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "RpcResult",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0ca3_92ffi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for RpcResult {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = RpcResult {
+            req_msg_id: TLType::tl_read_with_context(input, context)?,
+            result: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.req_msg_id.tl_write(output)?;
+        result += self.result.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.req_msg_id.serialized_len();
+        result += self.result.serialized_len();
+        result
+    }
+}
+/// `rpc_result#f35c6d01 req_msg_id:long result:Object = RpcResult`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RpcResultRef<'a> {
+    pub req_msg_id: i64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub result: super::object::ObjectRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for RpcResultRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x0ca3_92ffi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "RpcResult",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for RpcResultRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = RpcResultRef {
+            req_msg_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            result: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct RpcResultBuilder {
+    req_msg_id: i64,
+    result: super::object::Object,
+}
+impl RpcResultBuilder {
+    pub fn new(req_msg_id: i64, result: super::object::Object) -> Self {
+        RpcResultBuilder { req_msg_id, result }
+    }
+    pub fn build(self) -> RpcResult {
+        RpcResult {
+            req_msg_id: self.req_msg_id,
+            result: self.result,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServerDhParams {
+    /// `server_DH_params_fail#79cb045d nonce:int128 server_nonce:int128 new_nonce_hash:int128 = Server_DH_Params`
+    ServerDhParamsFail {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce_hash: [u8; 16],
+    },
+    /// `server_DH_params_ok#d0e8075c nonce:int128 server_nonce:int128 encrypted_answer:bytes = Server_DH_Params`
+    ServerDhParamsOk {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        encrypted_answer: TLBytes,
+    },
+}
+impl TLType for ServerDhParams {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x79cb_045di32 => ServerDhParams::ServerDhParamsFail {
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x2f17_f8a4i32 => ServerDhParams::ServerDhParamsOk {
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                encrypted_answer: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Server_DH_Params",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            ServerDhParams::ServerDhParamsFail {
+                nonce,
+                server_nonce,
+                new_nonce_hash,
+            } => {
+                (0x79cb_045di32).tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce_hash.tl_write(output)?;
+            }
+            ServerDhParams::ServerDhParamsOk {
+                nonce,
+                server_nonce,
+                encrypted_answer,
+            } => {
+                (-0x2f17_f8a4i32).tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += encrypted_answer.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            ServerDhParams::ServerDhParamsFail {
+                nonce,
+                server_nonce,
+                new_nonce_hash,
+            } => {
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce_hash.serialized_len();
+            }
+            ServerDhParams::ServerDhParamsOk {
+                nonce,
+                server_nonce,
+                encrypted_answer,
+            } => {
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += encrypted_answer.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServerDhParamsRef<'a> {
+    /// `server_DH_params_fail#79cb045d nonce:int128 server_nonce:int128 new_nonce_hash:int128 = Server_DH_Params`
+    ServerDhParamsFail {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce_hash: [u8; 16],
+    },
+    /// `server_DH_params_ok#d0e8075c nonce:int128 server_nonce:int128 encrypted_answer:bytes = Server_DH_Params`
+    ServerDhParamsOk {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        encrypted_answer: &'a [u8],
+    },
+}
+impl<'a> TLDeserialize<'a> for ServerDhParamsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x79cb_045di32 => ServerDhParamsRef::ServerDhParamsFail {
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x2f17_f8a4i32 => ServerDhParamsRef::ServerDhParamsOk {
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                encrypted_answer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Server_DH_Params",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ServerDhParamsServerDhParamsFailBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce_hash: [u8; 16],
+}
+impl ServerDhParamsServerDhParamsFailBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], new_nonce_hash: [u8; 16]) -> Self {
+        ServerDhParamsServerDhParamsFailBuilder {
+            nonce,
+            server_nonce,
+            new_nonce_hash,
+        }
+    }
+    pub fn build(self) -> ServerDhParams {
+        ServerDhParams::ServerDhParamsFail {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce_hash: self.new_nonce_hash,
+        }
+    }
+}
+pub struct ServerDhParamsServerDhParamsOkBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    encrypted_answer: TLBytes,
+}
+impl ServerDhParamsServerDhParamsOkBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], encrypted_answer: TLBytes) -> Self {
+        ServerDhParamsServerDhParamsOkBuilder {
+            nonce,
+            server_nonce,
+            encrypted_answer,
+        }
+    }
+    pub fn build(self) -> ServerDhParams {
+        ServerDhParams::ServerDhParamsOk {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            encrypted_answer: self.encrypted_answer,
+        }
+    }
+}
+/// `server_DH_inner_data#b5890dba nonce:int128 server_nonce:int128 g:int dh_prime:bytes g_a:bytes server_time:int = Server_DH_inner_data`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServerDhInnerData {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub g: i32,
+    pub dh_prime: TLBytes,
+    pub g_a: TLBytes,
+    pub server_time: i32,
+}
+impl TLType for ServerDhInnerData {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x4a76_f246i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Server_DH_inner_data",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x4a76_f246i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ServerDhInnerData {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ServerDhInnerData {
+            nonce: TLType::tl_read_with_context(input, context)?,
+            server_nonce: TLType::tl_read_with_context(input, context)?,
+            g: TLType::tl_read_with_context(input, context)?,
+            dh_prime: TLType::tl_read_with_context(input, context)?,
+            g_a: TLType::tl_read_with_context(input, context)?,
+            server_time: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.nonce.tl_write(output)?;
+        result += self.server_nonce.tl_write(output)?;
+        result += self.g.tl_write(output)?;
+        result += self.dh_prime.tl_write(output)?;
+        result += self.g_a.tl_write(output)?;
+        result += self.server_time.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.nonce.serialized_len();
+        result += self.server_nonce.serialized_len();
+        result += self.g.serialized_len();
+        result += self.dh_prime.serialized_len();
+        result += self.g_a.serialized_len();
+        result += self.server_time.serialized_len();
+        result
+    }
+}
+/// `server_DH_inner_data#b5890dba nonce:int128 server_nonce:int128 g:int dh_prime:bytes g_a:bytes server_time:int = Server_DH_inner_data`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServerDhInnerDataRef<'a> {
+    pub nonce: [u8; 16],
+    pub server_nonce: [u8; 16],
+    pub g: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub dh_prime: &'a [u8],
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub g_a: &'a [u8],
+    pub server_time: i32,
+}
+impl<'a> TLDeserialize<'a> for ServerDhInnerDataRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x4a76_f246i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Server_DH_inner_data",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ServerDhInnerDataRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ServerDhInnerDataRef {
+            nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            g: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            dh_prime: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            g_a: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            server_time: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ServerDhInnerDataBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    g: i32,
+    dh_prime: TLBytes,
+    g_a: TLBytes,
+    server_time: i32,
+}
+impl ServerDhInnerDataBuilder {
+    pub fn new(
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        g: i32,
+        dh_prime: TLBytes,
+        g_a: TLBytes,
+        server_time: i32,
+    ) -> Self {
+        ServerDhInnerDataBuilder {
+            nonce,
+            server_nonce,
+            g,
+            dh_prime,
+            g_a,
+            server_time,
+        }
+    }
+    pub fn build(self) -> ServerDhInnerData {
+        ServerDhInnerData {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            g: self.g,
+            dh_prime: self.dh_prime,
+            g_a: self.g_a,
+            server_time: self.server_time,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetClientDhParamsAnswer {
+    /// `dh_gen_ok#3bcbf734 nonce:int128 server_nonce:int128 new_nonce_hash1:int128 = Set_client_DH_params_answer`
+    DhGenOk {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce_hash1: [u8; 16],
+    },
+    /// `dh_gen_retry#46dc1fb9 nonce:int128 server_nonce:int128 new_nonce_hash2:int128 = Set_client_DH_params_answer`
+    DhGenRetry {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce_hash2: [u8; 16],
+    },
+    /// `dh_gen_fail#a69dae02 nonce:int128 server_nonce:int128 new_nonce_hash3:int128 = Set_client_DH_params_answer`
+    DhGenFail {
+        nonce: [u8; 16],
+        server_nonce: [u8; 16],
+        new_nonce_hash3: [u8; 16],
+    },
+}
+impl TLType for SetClientDhParamsAnswer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x3bcb_f734i32 => SetClientDhParamsAnswer::DhGenOk {
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce_hash1: TLType::tl_read_with_context(input, context)?,
+            },
+            0x46dc_1fb9i32 => SetClientDhParamsAnswer::DhGenRetry {
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce_hash2: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x5962_51fei32 => SetClientDhParamsAnswer::DhGenFail {
+                nonce: TLType::tl_read_with_context(input, context)?,
+                server_nonce: TLType::tl_read_with_context(input, context)?,
+                new_nonce_hash3: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Set_client_DH_params_answer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            SetClientDhParamsAnswer::DhGenOk {
+                nonce,
+                server_nonce,
+                new_nonce_hash1,
+            } => {
+                (0x3bcb_f734i32).tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce_hash1.tl_write(output)?;
+            }
+            SetClientDhParamsAnswer::DhGenRetry {
+                nonce,
+                server_nonce,
+                new_nonce_hash2,
+            } => {
+                (0x46dc_1fb9i32).tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce_hash2.tl_write(output)?;
+            }
+            SetClientDhParamsAnswer::DhGenFail {
+                nonce,
+                server_nonce,
+                new_nonce_hash3,
+            } => {
+                (-0x5962_51fei32).tl_write(output)?;
+                result += nonce.tl_write(output)?;
+                result += server_nonce.tl_write(output)?;
+                result += new_nonce_hash3.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            SetClientDhParamsAnswer::DhGenOk {
+                nonce,
+                server_nonce,
+                new_nonce_hash1,
+            } => {
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce_hash1.serialized_len();
+            }
+            SetClientDhParamsAnswer::DhGenRetry {
+                nonce,
+                server_nonce,
+                new_nonce_hash2,
+            } => {
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce_hash2.serialized_len();
+            }
+            SetClientDhParamsAnswer::DhGenFail {
+                nonce,
+                server_nonce,
+                new_nonce_hash3,
+            } => {
+                result += nonce.serialized_len();
+                result += server_nonce.serialized_len();
+                result += new_nonce_hash3.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for SetClientDhParamsAnswer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x3bcb_f734i32 => SetClientDhParamsAnswer::DhGenOk {
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce_hash1: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x46dc_1fb9i32 => SetClientDhParamsAnswer::DhGenRetry {
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce_hash2: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x5962_51fei32 => SetClientDhParamsAnswer::DhGenFail {
+                nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                server_nonce: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_nonce_hash3: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Set_client_DH_params_answer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SetClientDhParamsAnswerDhGenOkBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce_hash1: [u8; 16],
+}
+impl SetClientDhParamsAnswerDhGenOkBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], new_nonce_hash1: [u8; 16]) -> Self {
+        SetClientDhParamsAnswerDhGenOkBuilder {
+            nonce,
+            server_nonce,
+            new_nonce_hash1,
+        }
+    }
+    pub fn build(self) -> SetClientDhParamsAnswer {
+        SetClientDhParamsAnswer::DhGenOk {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce_hash1: self.new_nonce_hash1,
+        }
+    }
+}
+pub struct SetClientDhParamsAnswerDhGenRetryBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce_hash2: [u8; 16],
+}
+impl SetClientDhParamsAnswerDhGenRetryBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], new_nonce_hash2: [u8; 16]) -> Self {
+        SetClientDhParamsAnswerDhGenRetryBuilder {
+            nonce,
+            server_nonce,
+            new_nonce_hash2,
+        }
+    }
+    pub fn build(self) -> SetClientDhParamsAnswer {
+        SetClientDhParamsAnswer::DhGenRetry {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce_hash2: self.new_nonce_hash2,
+        }
+    }
+}
+pub struct SetClientDhParamsAnswerDhGenFailBuilder {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce_hash3: [u8; 16],
+}
+impl SetClientDhParamsAnswerDhGenFailBuilder {
+    pub fn new(nonce: [u8; 16], server_nonce: [u8; 16], new_nonce_hash3: [u8; 16]) -> Self {
+        SetClientDhParamsAnswerDhGenFailBuilder {
+            nonce,
+            server_nonce,
+            new_nonce_hash3,
+        }
+    }
+    pub fn build(self) -> SetClientDhParamsAnswer {
+        SetClientDhParamsAnswer::DhGenFail {
+            nonce: self.nonce,
+            server_nonce: self.server_nonce,
+            new_nonce_hash3: self.new_nonce_hash3,
+        }
+    }
+}
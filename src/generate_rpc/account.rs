@@ -0,0 +1,220 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `account.privacyRules#554abb6f rules:Vector<PrivacyRule> users:Vector<User> = account.PrivacyRules`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivacyRules {
+    pub rules: Vec<super::tl::PrivacyRule>,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for PrivacyRules {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x554a_bb6fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.PrivacyRules",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x554a_bb6fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PrivacyRules {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PrivacyRules {
+            rules: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.rules.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.rules.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `account.privacyRules#554abb6f rules:Vector<PrivacyRule> users:Vector<User> = account.PrivacyRules`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivacyRulesRef<'a> {
+    pub rules: Vec<super::tl::PrivacyRule>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for PrivacyRulesRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x554a_bb6fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.PrivacyRules",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for PrivacyRulesRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PrivacyRulesRef {
+            rules: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PrivacyRulesBuilder {
+    rules: Vec<super::tl::PrivacyRule>,
+    users: Vec<super::tl::User>,
+}
+impl PrivacyRulesBuilder {
+    pub fn new(rules: Vec<super::tl::PrivacyRule>, users: Vec<super::tl::User>) -> Self {
+        PrivacyRulesBuilder { rules, users }
+    }
+    pub fn build(self) -> PrivacyRules {
+        PrivacyRules {
+            rules: self.rules,
+            users: self.users,
+        }
+    }
+}
+/// `account.sentChangePhoneCode#a4f58c4c phone_code_hash:string send_call_timeout:int = account.SentChangePhoneCode`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SentChangePhoneCode {
+    pub phone_code_hash: String,
+    pub send_call_timeout: i32,
+}
+impl TLType for SentChangePhoneCode {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5b0a_73b4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.SentChangePhoneCode",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5b0a_73b4i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for SentChangePhoneCode {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = SentChangePhoneCode {
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+            send_call_timeout: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_code_hash.tl_write(output)?;
+        result += self.send_call_timeout.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_code_hash.serialized_len();
+        result += self.send_call_timeout.serialized_len();
+        result
+    }
+}
+/// `account.sentChangePhoneCode#a4f58c4c phone_code_hash:string send_call_timeout:int = account.SentChangePhoneCode`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SentChangePhoneCodeRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub phone_code_hash: &'a str,
+    pub send_call_timeout: i32,
+}
+impl<'a> TLDeserialize<'a> for SentChangePhoneCodeRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x5b0a_73b4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.SentChangePhoneCode",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for SentChangePhoneCodeRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = SentChangePhoneCodeRef {
+            phone_code_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            send_call_timeout: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SentChangePhoneCodeBuilder {
+    phone_code_hash: String,
+    send_call_timeout: i32,
+}
+impl SentChangePhoneCodeBuilder {
+    pub fn new(phone_code_hash: String, send_call_timeout: i32) -> Self {
+        SentChangePhoneCodeBuilder {
+            phone_code_hash,
+            send_call_timeout,
+        }
+    }
+    pub fn build(self) -> SentChangePhoneCode {
+        SentChangePhoneCode {
+            phone_code_hash: self.phone_code_hash,
+            send_call_timeout: self.send_call_timeout,
+        }
+    }
+}
@@ -0,0 +1,535 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `auth.authorization#f6b673a4 expires:int user:User = auth.Authorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Authorization {
+    pub expires: i32,
+    pub user: super::tl::User,
+}
+impl TLType for Authorization {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0949_8c5ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.Authorization",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0949_8c5ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Authorization {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Authorization {
+            expires: TLType::tl_read_with_context(input, context)?,
+            user: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.expires.tl_write(output)?;
+        result += self.user.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.expires.serialized_len();
+        result += self.user.serialized_len();
+        result
+    }
+}
+/// `auth.authorization#f6b673a4 expires:int user:User = auth.Authorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthorizationRef<'a> {
+    pub expires: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub user: super::tl::UserRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for AuthorizationRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x0949_8c5ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.Authorization",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for AuthorizationRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthorizationRef {
+            expires: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            user: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AuthorizationBuilder {
+    expires: i32,
+    user: super::tl::User,
+}
+impl AuthorizationBuilder {
+    pub fn new(expires: i32, user: super::tl::User) -> Self {
+        AuthorizationBuilder { expires, user }
+    }
+    pub fn build(self) -> Authorization {
+        Authorization {
+            expires: self.expires,
+            user: self.user,
+        }
+    }
+}
+/// `auth.checkedPhone#e300cc3b phone_registered:Bool phone_invited:Bool = auth.CheckedPhone`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckedPhone {
+    pub phone_registered: bool,
+    pub phone_invited: bool,
+}
+impl TLType for CheckedPhone {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1cff_33c5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.CheckedPhone",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1cff_33c5i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for CheckedPhone {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = CheckedPhone {
+            phone_registered: TLType::tl_read_with_context(input, context)?,
+            phone_invited: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_registered.tl_write(output)?;
+        result += self.phone_invited.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_registered.serialized_len();
+        result += self.phone_invited.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for CheckedPhone {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x1cff_33c5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.CheckedPhone",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for CheckedPhone {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = CheckedPhone {
+            phone_registered: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            phone_invited: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct CheckedPhoneBuilder {
+    phone_registered: bool,
+    phone_invited: bool,
+}
+impl CheckedPhoneBuilder {
+    pub fn new(phone_registered: bool, phone_invited: bool) -> Self {
+        CheckedPhoneBuilder {
+            phone_registered,
+            phone_invited,
+        }
+    }
+    pub fn build(self) -> CheckedPhone {
+        CheckedPhone {
+            phone_registered: self.phone_registered,
+            phone_invited: self.phone_invited,
+        }
+    }
+}
+/// `auth.exportedAuthorization#df969c2d id:int bytes:bytes = auth.ExportedAuthorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportedAuthorization {
+    pub id: i32,
+    pub bytes: TLBytes,
+}
+impl TLType for ExportedAuthorization {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2069_63d3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.ExportedAuthorization",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2069_63d3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ExportedAuthorization {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ExportedAuthorization {
+            id: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result += self.bytes.serialized_len();
+        result
+    }
+}
+/// `auth.exportedAuthorization#df969c2d id:int bytes:bytes = auth.ExportedAuthorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportedAuthorizationRef<'a> {
+    pub id: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bytes: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for ExportedAuthorizationRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x2069_63d3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.ExportedAuthorization",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ExportedAuthorizationRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ExportedAuthorizationRef {
+            id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ExportedAuthorizationBuilder {
+    id: i32,
+    bytes: TLBytes,
+}
+impl ExportedAuthorizationBuilder {
+    pub fn new(id: i32, bytes: TLBytes) -> Self {
+        ExportedAuthorizationBuilder { id, bytes }
+    }
+    pub fn build(self) -> ExportedAuthorization {
+        ExportedAuthorization {
+            id: self.id,
+            bytes: self.bytes,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentCode {
+    /// `auth.sentCode#efed51d9 phone_registered:Bool phone_code_hash:string send_call_timeout:int is_password:Bool = auth.SentCode`
+    SentCode {
+        phone_registered: bool,
+        phone_code_hash: String,
+        send_call_timeout: i32,
+        is_password: bool,
+    },
+    /// `auth.sentAppCode#e325edcf phone_registered:Bool phone_code_hash:string send_call_timeout:int is_password:Bool = auth.SentCode`
+    SentAppCode {
+        phone_registered: bool,
+        phone_code_hash: String,
+        send_call_timeout: i32,
+        is_password: bool,
+    },
+}
+impl TLType for SentCode {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x1012_ae27i32 => SentCode::SentCode {
+                phone_registered: TLType::tl_read_with_context(input, context)?,
+                phone_code_hash: TLType::tl_read_with_context(input, context)?,
+                send_call_timeout: TLType::tl_read_with_context(input, context)?,
+                is_password: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x1cda_1231i32 => SentCode::SentAppCode {
+                phone_registered: TLType::tl_read_with_context(input, context)?,
+                phone_code_hash: TLType::tl_read_with_context(input, context)?,
+                send_call_timeout: TLType::tl_read_with_context(input, context)?,
+                is_password: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "auth.SentCode",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            SentCode::SentCode {
+                phone_registered,
+                phone_code_hash,
+                send_call_timeout,
+                is_password,
+            } => {
+                (-0x1012_ae27i32).tl_write(output)?;
+                result += phone_registered.tl_write(output)?;
+                result += phone_code_hash.tl_write(output)?;
+                result += send_call_timeout.tl_write(output)?;
+                result += is_password.tl_write(output)?;
+            }
+            SentCode::SentAppCode {
+                phone_registered,
+                phone_code_hash,
+                send_call_timeout,
+                is_password,
+            } => {
+                (-0x1cda_1231i32).tl_write(output)?;
+                result += phone_registered.tl_write(output)?;
+                result += phone_code_hash.tl_write(output)?;
+                result += send_call_timeout.tl_write(output)?;
+                result += is_password.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            SentCode::SentCode {
+                phone_registered,
+                phone_code_hash,
+                send_call_timeout,
+                is_password,
+            } => {
+                result += phone_registered.serialized_len();
+                result += phone_code_hash.serialized_len();
+                result += send_call_timeout.serialized_len();
+                result += is_password.serialized_len();
+            }
+            SentCode::SentAppCode {
+                phone_registered,
+                phone_code_hash,
+                send_call_timeout,
+                is_password,
+            } => {
+                result += phone_registered.serialized_len();
+                result += phone_code_hash.serialized_len();
+                result += send_call_timeout.serialized_len();
+                result += is_password.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentCodeRef<'a> {
+    /// `auth.sentCode#efed51d9 phone_registered:Bool phone_code_hash:string send_call_timeout:int is_password:Bool = auth.SentCode`
+    SentCode {
+        phone_registered: bool,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone_code_hash: &'a str,
+        send_call_timeout: i32,
+        is_password: bool,
+    },
+    /// `auth.sentAppCode#e325edcf phone_registered:Bool phone_code_hash:string send_call_timeout:int is_password:Bool = auth.SentCode`
+    SentAppCode {
+        phone_registered: bool,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone_code_hash: &'a str,
+        send_call_timeout: i32,
+        is_password: bool,
+    },
+}
+impl<'a> TLDeserialize<'a> for SentCodeRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x1012_ae27i32 => SentCodeRef::SentCode {
+                phone_registered: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone_code_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                send_call_timeout: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                is_password: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x1cda_1231i32 => SentCodeRef::SentAppCode {
+                phone_registered: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone_code_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                send_call_timeout: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                is_password: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "auth.SentCode",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SentCodeSentCodeBuilder {
+    phone_registered: bool,
+    phone_code_hash: String,
+    send_call_timeout: i32,
+    is_password: bool,
+}
+impl SentCodeSentCodeBuilder {
+    pub fn new(
+        phone_registered: bool,
+        phone_code_hash: String,
+        send_call_timeout: i32,
+        is_password: bool,
+    ) -> Self {
+        SentCodeSentCodeBuilder {
+            phone_registered,
+            phone_code_hash,
+            send_call_timeout,
+            is_password,
+        }
+    }
+    pub fn build(self) -> SentCode {
+        SentCode::SentCode {
+            phone_registered: self.phone_registered,
+            phone_code_hash: self.phone_code_hash,
+            send_call_timeout: self.send_call_timeout,
+            is_password: self.is_password,
+        }
+    }
+}
+pub struct SentCodeSentAppCodeBuilder {
+    phone_registered: bool,
+    phone_code_hash: String,
+    send_call_timeout: i32,
+    is_password: bool,
+}
+impl SentCodeSentAppCodeBuilder {
+    pub fn new(
+        phone_registered: bool,
+        phone_code_hash: String,
+        send_call_timeout: i32,
+        is_password: bool,
+    ) -> Self {
+        SentCodeSentAppCodeBuilder {
+            phone_registered,
+            phone_code_hash,
+            send_call_timeout,
+            is_password,
+        }
+    }
+    pub fn build(self) -> SentCode {
+        SentCode::SentAppCode {
+            phone_registered: self.phone_registered,
+            phone_code_hash: self.phone_code_hash,
+            send_call_timeout: self.send_call_timeout,
+            is_password: self.is_password,
+        }
+    }
+}
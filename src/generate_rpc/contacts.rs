@@ -0,0 +1,831 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Blocked {
+    /// `contacts.blocked#1c138d15 blocked:Vector<ContactBlocked> users:Vector<User> = contacts.Blocked`
+    Blocked {
+        blocked: Vec<super::tl::ContactBlocked>,
+        users: Vec<super::tl::User>,
+    },
+    /// `contacts.blockedSlice#900802a1 count:int blocked:Vector<ContactBlocked> users:Vector<User> = contacts.Blocked`
+    BlockedSlice {
+        count: i32,
+        blocked: Vec<super::tl::ContactBlocked>,
+        users: Vec<super::tl::User>,
+    },
+}
+impl TLType for Blocked {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1c13_8d15i32 => Blocked::Blocked {
+                blocked: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x6ff7_fd5fi32 => Blocked::BlockedSlice {
+                count: TLType::tl_read_with_context(input, context)?,
+                blocked: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.Blocked",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Blocked::Blocked { blocked, users } => {
+                (0x1c13_8d15i32).tl_write(output)?;
+                result += blocked.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            Blocked::BlockedSlice {
+                count,
+                blocked,
+                users,
+            } => {
+                (-0x6ff7_fd5fi32).tl_write(output)?;
+                result += count.tl_write(output)?;
+                result += blocked.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Blocked::Blocked { blocked, users } => {
+                result += blocked.serialized_len();
+                result += users.serialized_len();
+            }
+            Blocked::BlockedSlice {
+                count,
+                blocked,
+                users,
+            } => {
+                result += count.serialized_len();
+                result += blocked.serialized_len();
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlockedRef<'a> {
+    /// `contacts.blocked#1c138d15 blocked:Vector<ContactBlocked> users:Vector<User> = contacts.Blocked`
+    Blocked {
+        blocked: Vec<super::tl::ContactBlocked>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+    /// `contacts.blockedSlice#900802a1 count:int blocked:Vector<ContactBlocked> users:Vector<User> = contacts.Blocked`
+    BlockedSlice {
+        count: i32,
+        blocked: Vec<super::tl::ContactBlocked>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for BlockedRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1c13_8d15i32 => BlockedRef::Blocked {
+                blocked: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x6ff7_fd5fi32 => BlockedRef::BlockedSlice {
+                count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                blocked: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.Blocked",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct BlockedBlockedBuilder {
+    blocked: Vec<super::tl::ContactBlocked>,
+    users: Vec<super::tl::User>,
+}
+impl BlockedBlockedBuilder {
+    pub fn new(blocked: Vec<super::tl::ContactBlocked>, users: Vec<super::tl::User>) -> Self {
+        BlockedBlockedBuilder { blocked, users }
+    }
+    pub fn build(self) -> Blocked {
+        Blocked::Blocked {
+            blocked: self.blocked,
+            users: self.users,
+        }
+    }
+}
+pub struct BlockedBlockedSliceBuilder {
+    count: i32,
+    blocked: Vec<super::tl::ContactBlocked>,
+    users: Vec<super::tl::User>,
+}
+impl BlockedBlockedSliceBuilder {
+    pub fn new(
+        count: i32,
+        blocked: Vec<super::tl::ContactBlocked>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        BlockedBlockedSliceBuilder {
+            count,
+            blocked,
+            users,
+        }
+    }
+    pub fn build(self) -> Blocked {
+        Blocked::BlockedSlice {
+            count: self.count,
+            blocked: self.blocked,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Contacts {
+    /// `contacts.contactsNotModified#b74ba9d2 = contacts.Contacts`
+    ContactsNotModified,
+    /// `contacts.contacts#6f8b8cb2 contacts:Vector<Contact> users:Vector<User> = contacts.Contacts`
+    Contacts {
+        contacts: Vec<super::tl::Contact>,
+        users: Vec<super::tl::User>,
+    },
+}
+impl TLType for Contacts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x48b4_562ei32 => Contacts::ContactsNotModified {},
+            0x6f8b_8cb2i32 => Contacts::Contacts {
+                contacts: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.Contacts",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Contacts::ContactsNotModified => {
+                (-0x48b4_562ei32).tl_write(output)?;
+            }
+            Contacts::Contacts { contacts, users } => {
+                (0x6f8b_8cb2i32).tl_write(output)?;
+                result += contacts.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Contacts::ContactsNotModified => {}
+            Contacts::Contacts { contacts, users } => {
+                result += contacts.serialized_len();
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContactsRef<'a> {
+    /// `contacts.contactsNotModified#b74ba9d2 = contacts.Contacts`
+    ContactsNotModified,
+    /// `contacts.contacts#6f8b8cb2 contacts:Vector<Contact> users:Vector<User> = contacts.Contacts`
+    Contacts {
+        contacts: Vec<super::tl::Contact>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for ContactsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x48b4_562ei32 => ContactsRef::ContactsNotModified {},
+            0x6f8b_8cb2i32 => ContactsRef::Contacts {
+                contacts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.Contacts",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ContactsContactsBuilder {
+    contacts: Vec<super::tl::Contact>,
+    users: Vec<super::tl::User>,
+}
+impl ContactsContactsBuilder {
+    pub fn new(contacts: Vec<super::tl::Contact>, users: Vec<super::tl::User>) -> Self {
+        ContactsContactsBuilder { contacts, users }
+    }
+    pub fn build(self) -> Contacts {
+        Contacts::Contacts {
+            contacts: self.contacts,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForeignLink {
+    /// `contacts.foreignLinkUnknown#133421f8 = contacts.ForeignLink`
+    ForeignLinkUnknown,
+    /// `contacts.foreignLinkRequested#a7801f47 has_phone:Bool = contacts.ForeignLink`
+    ForeignLinkRequested { has_phone: bool },
+    /// `contacts.foreignLinkMutual#1bea8ce1 = contacts.ForeignLink`
+    ForeignLinkMutual,
+}
+impl TLType for ForeignLink {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1334_21f8i32 => ForeignLink::ForeignLinkUnknown {},
+            -0x587f_e0b9i32 => ForeignLink::ForeignLinkRequested {
+                has_phone: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1bea_8ce1i32 => ForeignLink::ForeignLinkMutual {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.ForeignLink",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            ForeignLink::ForeignLinkUnknown => {
+                (0x1334_21f8i32).tl_write(output)?;
+            }
+            ForeignLink::ForeignLinkRequested { has_phone } => {
+                (-0x587f_e0b9i32).tl_write(output)?;
+                result += has_phone.tl_write(output)?;
+            }
+            ForeignLink::ForeignLinkMutual => {
+                (0x1bea_8ce1i32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            ForeignLink::ForeignLinkUnknown => {}
+            ForeignLink::ForeignLinkRequested { has_phone } => {
+                result += has_phone.serialized_len();
+            }
+            ForeignLink::ForeignLinkMutual => {}
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ForeignLink {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1334_21f8i32 => ForeignLink::ForeignLinkUnknown {},
+            -0x587f_e0b9i32 => ForeignLink::ForeignLinkRequested {
+                has_phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1bea_8ce1i32 => ForeignLink::ForeignLinkMutual {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.ForeignLink",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ForeignLinkForeignLinkRequestedBuilder {
+    has_phone: bool,
+}
+impl ForeignLinkForeignLinkRequestedBuilder {
+    pub fn new(has_phone: bool) -> Self {
+        ForeignLinkForeignLinkRequestedBuilder { has_phone }
+    }
+    pub fn build(self) -> ForeignLink {
+        ForeignLink::ForeignLinkRequested {
+            has_phone: self.has_phone,
+        }
+    }
+}
+/// `contacts.found#0566000e results:Vector<ContactFound> users:Vector<User> = contacts.Found`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Found {
+    pub results: Vec<super::tl::ContactFound>,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for Found {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0566_000ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.Found",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0566_000ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Found {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Found {
+            results: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.results.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.results.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `contacts.found#0566000e results:Vector<ContactFound> users:Vector<User> = contacts.Found`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FoundRef<'a> {
+    pub results: Vec<super::tl::ContactFound>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for FoundRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x0566_000ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.Found",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for FoundRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FoundRef {
+            results: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct FoundBuilder {
+    results: Vec<super::tl::ContactFound>,
+    users: Vec<super::tl::User>,
+}
+impl FoundBuilder {
+    pub fn new(results: Vec<super::tl::ContactFound>, users: Vec<super::tl::User>) -> Self {
+        FoundBuilder { results, users }
+    }
+    pub fn build(self) -> Found {
+        Found {
+            results: self.results,
+            users: self.users,
+        }
+    }
+}
+/// `contacts.importedContacts#ad524315 imported:Vector<ImportedContact> retry_contacts:Vector<long> users:Vector<User> = contacts.ImportedContacts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportedContacts {
+    pub imported: Vec<super::tl::ImportedContact>,
+    pub retry_contacts: Vec<i64>,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for ImportedContacts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x52ad_bcebi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.ImportedContacts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x52ad_bcebi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ImportedContacts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ImportedContacts {
+            imported: TLType::tl_read_with_context(input, context)?,
+            retry_contacts: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.imported.tl_write(output)?;
+        result += self.retry_contacts.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.imported.serialized_len();
+        result += self.retry_contacts.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `contacts.importedContacts#ad524315 imported:Vector<ImportedContact> retry_contacts:Vector<long> users:Vector<User> = contacts.ImportedContacts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportedContactsRef<'a> {
+    pub imported: Vec<super::tl::ImportedContact>,
+    pub retry_contacts: Vec<i64>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for ImportedContactsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x52ad_bcebi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.ImportedContacts",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ImportedContactsRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ImportedContactsRef {
+            imported: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            retry_contacts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ImportedContactsBuilder {
+    imported: Vec<super::tl::ImportedContact>,
+    retry_contacts: Vec<i64>,
+    users: Vec<super::tl::User>,
+}
+impl ImportedContactsBuilder {
+    pub fn new(
+        imported: Vec<super::tl::ImportedContact>,
+        retry_contacts: Vec<i64>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        ImportedContactsBuilder {
+            imported,
+            retry_contacts,
+            users,
+        }
+    }
+    pub fn build(self) -> ImportedContacts {
+        ImportedContacts {
+            imported: self.imported,
+            retry_contacts: self.retry_contacts,
+            users: self.users,
+        }
+    }
+}
+/// `contacts.link#eccea3f5 my_link:contacts.MyLink foreign_link:contacts.ForeignLink user:User = contacts.Link`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Link {
+    pub my_link: super::contacts::MyLink,
+    pub foreign_link: super::contacts::ForeignLink,
+    pub user: super::tl::User,
+}
+impl TLType for Link {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1331_5c0bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.Link",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1331_5c0bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Link {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Link {
+            my_link: TLType::tl_read_with_context(input, context)?,
+            foreign_link: TLType::tl_read_with_context(input, context)?,
+            user: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.my_link.tl_write(output)?;
+        result += self.foreign_link.tl_write(output)?;
+        result += self.user.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.my_link.serialized_len();
+        result += self.foreign_link.serialized_len();
+        result += self.user.serialized_len();
+        result
+    }
+}
+/// `contacts.link#eccea3f5 my_link:contacts.MyLink foreign_link:contacts.ForeignLink user:User = contacts.Link`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkRef<'a> {
+    pub my_link: super::contacts::MyLink,
+    pub foreign_link: super::contacts::ForeignLink,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub user: super::tl::UserRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for LinkRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x1331_5c0bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.Link",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for LinkRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = LinkRef {
+            my_link: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            foreign_link: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            user: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct LinkBuilder {
+    my_link: super::contacts::MyLink,
+    foreign_link: super::contacts::ForeignLink,
+    user: super::tl::User,
+}
+impl LinkBuilder {
+    pub fn new(
+        my_link: super::contacts::MyLink,
+        foreign_link: super::contacts::ForeignLink,
+        user: super::tl::User,
+    ) -> Self {
+        LinkBuilder {
+            my_link,
+            foreign_link,
+            user,
+        }
+    }
+    pub fn build(self) -> Link {
+        Link {
+            my_link: self.my_link,
+            foreign_link: self.foreign_link,
+            user: self.user,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MyLink {
+    /// `contacts.myLinkEmpty#d22a1c60 = contacts.MyLink`
+    MyLinkEmpty,
+    /// `contacts.myLinkRequested#6c69efee contact:Bool = contacts.MyLink`
+    MyLinkRequested { contact: bool },
+    /// `contacts.myLinkContact#c240ebd9 = contacts.MyLink`
+    MyLinkContact,
+}
+impl TLType for MyLink {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x2dd5_e3a0i32 => MyLink::MyLinkEmpty {},
+            0x6c69_efeei32 => MyLink::MyLinkRequested {
+                contact: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x3dbf_1427i32 => MyLink::MyLinkContact {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.MyLink",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            MyLink::MyLinkEmpty => {
+                (-0x2dd5_e3a0i32).tl_write(output)?;
+            }
+            MyLink::MyLinkRequested { contact } => {
+                (0x6c69_efeei32).tl_write(output)?;
+                result += contact.tl_write(output)?;
+            }
+            MyLink::MyLinkContact => {
+                (-0x3dbf_1427i32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            MyLink::MyLinkEmpty => {}
+            MyLink::MyLinkRequested { contact } => {
+                result += contact.serialized_len();
+            }
+            MyLink::MyLinkContact => {}
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for MyLink {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x2dd5_e3a0i32 => MyLink::MyLinkEmpty {},
+            0x6c69_efeei32 => MyLink::MyLinkRequested {
+                contact: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x3dbf_1427i32 => MyLink::MyLinkContact {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "contacts.MyLink",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MyLinkMyLinkRequestedBuilder {
+    contact: bool,
+}
+impl MyLinkMyLinkRequestedBuilder {
+    pub fn new(contact: bool) -> Self {
+        MyLinkMyLinkRequestedBuilder { contact }
+    }
+    pub fn build(self) -> MyLink {
+        MyLink::MyLinkRequested {
+            contact: self.contact,
+        }
+    }
+}
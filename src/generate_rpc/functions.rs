@@ -0,0 +1,7250 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `invokeAfterMsg#cb9f372d {X:Type} msg_id:long query:!X = X`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvokeAfterMsg<X: RemoteCall> {
+    pub msg_id: i64,
+    pub query: X,
+}
+impl<X: RemoteCall> TLType for InvokeAfterMsg<X> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3460_c8d3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "invokeAfterMsg",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3460_c8d3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl<X: RemoteCall> TLBareType for InvokeAfterMsg<X> {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InvokeAfterMsg {
+            msg_id: TLType::tl_read_with_context(input, context)?,
+            query: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_id.tl_write(output)?;
+        result += self.query.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_id.serialized_len();
+        result += self.query.serialized_len();
+        result
+    }
+}
+impl<X: RemoteCall> RemoteCall for InvokeAfterMsg<X> {
+    type Return = X::Return;
+}
+pub struct InvokeAfterMsgBuilder<X: RemoteCall> {
+    msg_id: i64,
+    query: X,
+}
+impl<X: RemoteCall> InvokeAfterMsgBuilder<X> {
+    pub fn new(msg_id: i64, query: X) -> Self {
+        InvokeAfterMsgBuilder { msg_id, query }
+    }
+    pub fn build(self) -> InvokeAfterMsg<X> {
+        InvokeAfterMsg {
+            msg_id: self.msg_id,
+            query: self.query,
+        }
+    }
+}
+/// `invokeAfterMsgs#3dc4b4f0 {X:Type} msg_ids:Vector<long> query:!X = X`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvokeAfterMsgs<X: RemoteCall> {
+    pub msg_ids: Vec<i64>,
+    pub query: X,
+}
+impl<X: RemoteCall> TLType for InvokeAfterMsgs<X> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3dc4_b4f0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "invokeAfterMsgs",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3dc4_b4f0i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl<X: RemoteCall> TLBareType for InvokeAfterMsgs<X> {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InvokeAfterMsgs {
+            msg_ids: TLType::tl_read_with_context(input, context)?,
+            query: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.msg_ids.tl_write(output)?;
+        result += self.query.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.msg_ids.serialized_len();
+        result += self.query.serialized_len();
+        result
+    }
+}
+impl<X: RemoteCall> RemoteCall for InvokeAfterMsgs<X> {
+    type Return = X::Return;
+}
+pub struct InvokeAfterMsgsBuilder<X: RemoteCall> {
+    msg_ids: Vec<i64>,
+    query: X,
+}
+impl<X: RemoteCall> InvokeAfterMsgsBuilder<X> {
+    pub fn new(msg_ids: Vec<i64>, query: X) -> Self {
+        InvokeAfterMsgsBuilder { msg_ids, query }
+    }
+    pub fn build(self) -> InvokeAfterMsgs<X> {
+        InvokeAfterMsgs {
+            msg_ids: self.msg_ids,
+            query: self.query,
+        }
+    }
+}
+/// `auth.checkPhone#6fe51dfb phone_number:string = auth.CheckedPhone`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthCheckPhone {
+    pub phone_number: String,
+}
+impl TLType for AuthCheckPhone {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6fe5_1dfbi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.checkPhone",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6fe5_1dfbi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthCheckPhone {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthCheckPhone {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthCheckPhone {
+    type Return = super::auth::CheckedPhone;
+}
+pub struct AuthCheckPhoneBuilder {
+    phone_number: String,
+}
+impl AuthCheckPhoneBuilder {
+    pub fn new(phone_number: String) -> Self {
+        AuthCheckPhoneBuilder { phone_number }
+    }
+    pub fn build(self) -> AuthCheckPhone {
+        AuthCheckPhone {
+            phone_number: self.phone_number,
+        }
+    }
+}
+/// `auth.sendCode#768d5f4d phone_number:string sms_type:int api_id:int api_hash:string lang_code:string = auth.SentCode`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthSendCode {
+    pub phone_number: String,
+    pub sms_type: i32,
+    pub api_id: i32,
+    pub api_hash: String,
+    pub lang_code: String,
+}
+impl TLType for AuthSendCode {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x768d_5f4di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.sendCode",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x768d_5f4di32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthSendCode {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthSendCode {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            sms_type: TLType::tl_read_with_context(input, context)?,
+            api_id: TLType::tl_read_with_context(input, context)?,
+            api_hash: TLType::tl_read_with_context(input, context)?,
+            lang_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.sms_type.tl_write(output)?;
+        result += self.api_id.tl_write(output)?;
+        result += self.api_hash.tl_write(output)?;
+        result += self.lang_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.sms_type.serialized_len();
+        result += self.api_id.serialized_len();
+        result += self.api_hash.serialized_len();
+        result += self.lang_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthSendCode {
+    type Return = super::auth::SentCode;
+}
+pub struct AuthSendCodeBuilder {
+    phone_number: String,
+    sms_type: i32,
+    api_id: i32,
+    api_hash: String,
+    lang_code: String,
+}
+impl AuthSendCodeBuilder {
+    pub fn new(
+        phone_number: String,
+        sms_type: i32,
+        api_id: i32,
+        api_hash: String,
+        lang_code: String,
+    ) -> Self {
+        AuthSendCodeBuilder {
+            phone_number,
+            sms_type,
+            api_id,
+            api_hash,
+            lang_code,
+        }
+    }
+    pub fn build(self) -> AuthSendCode {
+        AuthSendCode {
+            phone_number: self.phone_number,
+            sms_type: self.sms_type,
+            api_id: self.api_id,
+            api_hash: self.api_hash,
+            lang_code: self.lang_code,
+        }
+    }
+}
+/// `auth.sendCall#03c51564 phone_number:string phone_code_hash:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthSendCall {
+    pub phone_number: String,
+    pub phone_code_hash: String,
+}
+impl TLType for AuthSendCall {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x03c5_1564i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.sendCall",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x03c5_1564i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthSendCall {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthSendCall {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.phone_code_hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.phone_code_hash.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthSendCall {
+    type Return = bool;
+}
+pub struct AuthSendCallBuilder {
+    phone_number: String,
+    phone_code_hash: String,
+}
+impl AuthSendCallBuilder {
+    pub fn new(phone_number: String, phone_code_hash: String) -> Self {
+        AuthSendCallBuilder {
+            phone_number,
+            phone_code_hash,
+        }
+    }
+    pub fn build(self) -> AuthSendCall {
+        AuthSendCall {
+            phone_number: self.phone_number,
+            phone_code_hash: self.phone_code_hash,
+        }
+    }
+}
+/// `auth.signUp#1b067634 phone_number:string phone_code_hash:string phone_code:string first_name:string last_name:string = auth.Authorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthSignUp {
+    pub phone_number: String,
+    pub phone_code_hash: String,
+    pub phone_code: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+impl TLType for AuthSignUp {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x1b06_7634i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.signUp",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x1b06_7634i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthSignUp {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthSignUp {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+            phone_code: TLType::tl_read_with_context(input, context)?,
+            first_name: TLType::tl_read_with_context(input, context)?,
+            last_name: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.phone_code_hash.tl_write(output)?;
+        result += self.phone_code.tl_write(output)?;
+        result += self.first_name.tl_write(output)?;
+        result += self.last_name.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.phone_code_hash.serialized_len();
+        result += self.phone_code.serialized_len();
+        result += self.first_name.serialized_len();
+        result += self.last_name.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthSignUp {
+    type Return = super::auth::Authorization;
+}
+pub struct AuthSignUpBuilder {
+    phone_number: String,
+    phone_code_hash: String,
+    phone_code: String,
+    first_name: String,
+    last_name: String,
+}
+impl AuthSignUpBuilder {
+    pub fn new(
+        phone_number: String,
+        phone_code_hash: String,
+        phone_code: String,
+        first_name: String,
+        last_name: String,
+    ) -> Self {
+        AuthSignUpBuilder {
+            phone_number,
+            phone_code_hash,
+            phone_code,
+            first_name,
+            last_name,
+        }
+    }
+    pub fn build(self) -> AuthSignUp {
+        AuthSignUp {
+            phone_number: self.phone_number,
+            phone_code_hash: self.phone_code_hash,
+            phone_code: self.phone_code,
+            first_name: self.first_name,
+            last_name: self.last_name,
+        }
+    }
+}
+/// `auth.signIn#bcd51581 phone_number:string phone_code_hash:string phone_code:string = auth.Authorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthSignIn {
+    pub phone_number: String,
+    pub phone_code_hash: String,
+    pub phone_code: String,
+}
+impl TLType for AuthSignIn {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x432a_ea7fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.signIn",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x432a_ea7fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthSignIn {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthSignIn {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+            phone_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.phone_code_hash.tl_write(output)?;
+        result += self.phone_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.phone_code_hash.serialized_len();
+        result += self.phone_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthSignIn {
+    type Return = super::auth::Authorization;
+}
+pub struct AuthSignInBuilder {
+    phone_number: String,
+    phone_code_hash: String,
+    phone_code: String,
+}
+impl AuthSignInBuilder {
+    pub fn new(phone_number: String, phone_code_hash: String, phone_code: String) -> Self {
+        AuthSignInBuilder {
+            phone_number,
+            phone_code_hash,
+            phone_code,
+        }
+    }
+    pub fn build(self) -> AuthSignIn {
+        AuthSignIn {
+            phone_number: self.phone_number,
+            phone_code_hash: self.phone_code_hash,
+            phone_code: self.phone_code,
+        }
+    }
+}
+/// `auth.logOut#5717da40 = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthLogOut;
+impl TLType for AuthLogOut {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x5717_da40i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.logOut",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x5717_da40i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthLogOut {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthLogOut {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for AuthLogOut {
+    type Return = bool;
+}
+/// `auth.resetAuthorizations#9fab0d1a = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthResetAuthorizations;
+impl TLType for AuthResetAuthorizations {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x6054_f2e6i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.resetAuthorizations",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x6054_f2e6i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthResetAuthorizations {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthResetAuthorizations {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for AuthResetAuthorizations {
+    type Return = bool;
+}
+/// `auth.exportAuthorization#e5bfffcd dc_id:int = auth.ExportedAuthorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthExportAuthorization {
+    pub dc_id: i32,
+}
+impl TLType for AuthExportAuthorization {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1a40_0033i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.exportAuthorization",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1a40_0033i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthExportAuthorization {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthExportAuthorization {
+            dc_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.dc_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.dc_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthExportAuthorization {
+    type Return = super::auth::ExportedAuthorization;
+}
+pub struct AuthExportAuthorizationBuilder {
+    dc_id: i32,
+}
+impl AuthExportAuthorizationBuilder {
+    pub fn new(dc_id: i32) -> Self {
+        AuthExportAuthorizationBuilder { dc_id }
+    }
+    pub fn build(self) -> AuthExportAuthorization {
+        AuthExportAuthorization { dc_id: self.dc_id }
+    }
+}
+/// `auth.importAuthorization#e3ef9613 id:int bytes:bytes = auth.Authorization`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthImportAuthorization {
+    pub id: i32,
+    pub bytes: TLBytes,
+}
+impl TLType for AuthImportAuthorization {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1c10_69edi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.importAuthorization",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1c10_69edi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthImportAuthorization {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthImportAuthorization {
+            id: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result += self.bytes.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthImportAuthorization {
+    type Return = super::auth::Authorization;
+}
+pub struct AuthImportAuthorizationBuilder {
+    id: i32,
+    bytes: TLBytes,
+}
+impl AuthImportAuthorizationBuilder {
+    pub fn new(id: i32, bytes: TLBytes) -> Self {
+        AuthImportAuthorizationBuilder { id, bytes }
+    }
+    pub fn build(self) -> AuthImportAuthorization {
+        AuthImportAuthorization {
+            id: self.id,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `auth.bindTempAuthKey#cdd42a05 perm_auth_key_id:long nonce:long expires_at:int encrypted_message:bytes = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthBindTempAuthKey {
+    pub perm_auth_key_id: i64,
+    pub nonce: i64,
+    pub expires_at: i32,
+    pub encrypted_message: TLBytes,
+}
+impl TLType for AuthBindTempAuthKey {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x322b_d5fbi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.bindTempAuthKey",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x322b_d5fbi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthBindTempAuthKey {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthBindTempAuthKey {
+            perm_auth_key_id: TLType::tl_read_with_context(input, context)?,
+            nonce: TLType::tl_read_with_context(input, context)?,
+            expires_at: TLType::tl_read_with_context(input, context)?,
+            encrypted_message: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.perm_auth_key_id.tl_write(output)?;
+        result += self.nonce.tl_write(output)?;
+        result += self.expires_at.tl_write(output)?;
+        result += self.encrypted_message.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.perm_auth_key_id.serialized_len();
+        result += self.nonce.serialized_len();
+        result += self.expires_at.serialized_len();
+        result += self.encrypted_message.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthBindTempAuthKey {
+    type Return = bool;
+}
+pub struct AuthBindTempAuthKeyBuilder {
+    perm_auth_key_id: i64,
+    nonce: i64,
+    expires_at: i32,
+    encrypted_message: TLBytes,
+}
+impl AuthBindTempAuthKeyBuilder {
+    pub fn new(
+        perm_auth_key_id: i64,
+        nonce: i64,
+        expires_at: i32,
+        encrypted_message: TLBytes,
+    ) -> Self {
+        AuthBindTempAuthKeyBuilder {
+            perm_auth_key_id,
+            nonce,
+            expires_at,
+            encrypted_message,
+        }
+    }
+    pub fn build(self) -> AuthBindTempAuthKey {
+        AuthBindTempAuthKey {
+            perm_auth_key_id: self.perm_auth_key_id,
+            nonce: self.nonce,
+            expires_at: self.expires_at,
+            encrypted_message: self.encrypted_message,
+        }
+    }
+}
+/// `account.registerDevice#446c712c token_type:int token:string device_model:string system_version:string app_version:string app_sandbox:Bool lang_code:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountRegisterDevice {
+    pub token_type: i32,
+    pub token: String,
+    pub device_model: String,
+    pub system_version: String,
+    pub app_version: String,
+    pub app_sandbox: bool,
+    pub lang_code: String,
+}
+impl TLType for AccountRegisterDevice {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x446c_712ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.registerDevice",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x446c_712ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountRegisterDevice {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountRegisterDevice {
+            token_type: TLType::tl_read_with_context(input, context)?,
+            token: TLType::tl_read_with_context(input, context)?,
+            device_model: TLType::tl_read_with_context(input, context)?,
+            system_version: TLType::tl_read_with_context(input, context)?,
+            app_version: TLType::tl_read_with_context(input, context)?,
+            app_sandbox: TLType::tl_read_with_context(input, context)?,
+            lang_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.token_type.tl_write(output)?;
+        result += self.token.tl_write(output)?;
+        result += self.device_model.tl_write(output)?;
+        result += self.system_version.tl_write(output)?;
+        result += self.app_version.tl_write(output)?;
+        result += self.app_sandbox.tl_write(output)?;
+        result += self.lang_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.token_type.serialized_len();
+        result += self.token.serialized_len();
+        result += self.device_model.serialized_len();
+        result += self.system_version.serialized_len();
+        result += self.app_version.serialized_len();
+        result += self.app_sandbox.serialized_len();
+        result += self.lang_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountRegisterDevice {
+    type Return = bool;
+}
+pub struct AccountRegisterDeviceBuilder {
+    token_type: i32,
+    token: String,
+    device_model: String,
+    system_version: String,
+    app_version: String,
+    app_sandbox: bool,
+    lang_code: String,
+}
+impl AccountRegisterDeviceBuilder {
+    pub fn new(
+        token_type: i32,
+        token: String,
+        device_model: String,
+        system_version: String,
+        app_version: String,
+        app_sandbox: bool,
+        lang_code: String,
+    ) -> Self {
+        AccountRegisterDeviceBuilder {
+            token_type,
+            token,
+            device_model,
+            system_version,
+            app_version,
+            app_sandbox,
+            lang_code,
+        }
+    }
+    pub fn build(self) -> AccountRegisterDevice {
+        AccountRegisterDevice {
+            token_type: self.token_type,
+            token: self.token,
+            device_model: self.device_model,
+            system_version: self.system_version,
+            app_version: self.app_version,
+            app_sandbox: self.app_sandbox,
+            lang_code: self.lang_code,
+        }
+    }
+}
+/// `account.unregisterDevice#65c55b40 token_type:int token:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUnregisterDevice {
+    pub token_type: i32,
+    pub token: String,
+}
+impl TLType for AccountUnregisterDevice {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x65c5_5b40i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.unregisterDevice",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x65c5_5b40i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUnregisterDevice {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUnregisterDevice {
+            token_type: TLType::tl_read_with_context(input, context)?,
+            token: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.token_type.tl_write(output)?;
+        result += self.token.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.token_type.serialized_len();
+        result += self.token.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUnregisterDevice {
+    type Return = bool;
+}
+pub struct AccountUnregisterDeviceBuilder {
+    token_type: i32,
+    token: String,
+}
+impl AccountUnregisterDeviceBuilder {
+    pub fn new(token_type: i32, token: String) -> Self {
+        AccountUnregisterDeviceBuilder { token_type, token }
+    }
+    pub fn build(self) -> AccountUnregisterDevice {
+        AccountUnregisterDevice {
+            token_type: self.token_type,
+            token: self.token,
+        }
+    }
+}
+/// `account.updateNotifySettings#84be5b93 peer:InputNotifyPeer settings:InputPeerNotifySettings = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUpdateNotifySettings {
+    pub peer: super::tl::InputNotifyPeer,
+    pub settings: super::tl::InputPeerNotifySettings,
+}
+impl TLType for AccountUpdateNotifySettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x7b41_a46di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.updateNotifySettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x7b41_a46di32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUpdateNotifySettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUpdateNotifySettings {
+            peer: TLType::tl_read_with_context(input, context)?,
+            settings: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.settings.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.settings.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUpdateNotifySettings {
+    type Return = bool;
+}
+pub struct AccountUpdateNotifySettingsBuilder {
+    peer: super::tl::InputNotifyPeer,
+    settings: super::tl::InputPeerNotifySettings,
+}
+impl AccountUpdateNotifySettingsBuilder {
+    pub fn new(
+        peer: super::tl::InputNotifyPeer,
+        settings: super::tl::InputPeerNotifySettings,
+    ) -> Self {
+        AccountUpdateNotifySettingsBuilder { peer, settings }
+    }
+    pub fn build(self) -> AccountUpdateNotifySettings {
+        AccountUpdateNotifySettings {
+            peer: self.peer,
+            settings: self.settings,
+        }
+    }
+}
+/// `account.getNotifySettings#12b3ad31 peer:InputNotifyPeer = PeerNotifySettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountGetNotifySettings {
+    pub peer: super::tl::InputNotifyPeer,
+}
+impl TLType for AccountGetNotifySettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x12b3_ad31i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.getNotifySettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x12b3_ad31i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountGetNotifySettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountGetNotifySettings {
+            peer: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountGetNotifySettings {
+    type Return = super::tl::PeerNotifySettings;
+}
+pub struct AccountGetNotifySettingsBuilder {
+    peer: super::tl::InputNotifyPeer,
+}
+impl AccountGetNotifySettingsBuilder {
+    pub fn new(peer: super::tl::InputNotifyPeer) -> Self {
+        AccountGetNotifySettingsBuilder { peer }
+    }
+    pub fn build(self) -> AccountGetNotifySettings {
+        AccountGetNotifySettings { peer: self.peer }
+    }
+}
+/// `account.resetNotifySettings#db7e1747 = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountResetNotifySettings;
+impl TLType for AccountResetNotifySettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2481_e8b9i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.resetNotifySettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2481_e8b9i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountResetNotifySettings {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountResetNotifySettings {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for AccountResetNotifySettings {
+    type Return = bool;
+}
+/// `account.updateProfile#f0888d68 first_name:string last_name:string = User`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUpdateProfile {
+    pub first_name: String,
+    pub last_name: String,
+}
+impl TLType for AccountUpdateProfile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0f77_7298i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.updateProfile",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0f77_7298i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUpdateProfile {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUpdateProfile {
+            first_name: TLType::tl_read_with_context(input, context)?,
+            last_name: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.first_name.tl_write(output)?;
+        result += self.last_name.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.first_name.serialized_len();
+        result += self.last_name.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUpdateProfile {
+    type Return = super::tl::User;
+}
+pub struct AccountUpdateProfileBuilder {
+    first_name: String,
+    last_name: String,
+}
+impl AccountUpdateProfileBuilder {
+    pub fn new(first_name: String, last_name: String) -> Self {
+        AccountUpdateProfileBuilder {
+            first_name,
+            last_name,
+        }
+    }
+    pub fn build(self) -> AccountUpdateProfile {
+        AccountUpdateProfile {
+            first_name: self.first_name,
+            last_name: self.last_name,
+        }
+    }
+}
+/// `account.updateStatus#6628562c offline:Bool = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUpdateStatus {
+    pub offline: bool,
+}
+impl TLType for AccountUpdateStatus {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6628_562ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.updateStatus",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6628_562ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUpdateStatus {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUpdateStatus {
+            offline: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.offline.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.offline.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUpdateStatus {
+    type Return = bool;
+}
+pub struct AccountUpdateStatusBuilder {
+    offline: bool,
+}
+impl AccountUpdateStatusBuilder {
+    pub fn new(offline: bool) -> Self {
+        AccountUpdateStatusBuilder { offline }
+    }
+    pub fn build(self) -> AccountUpdateStatus {
+        AccountUpdateStatus {
+            offline: self.offline,
+        }
+    }
+}
+/// `account.getWallPapers#c04cfac2 = Vector<WallPaper>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountGetWallPapers;
+impl TLType for AccountGetWallPapers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3fb3_053ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.getWallPapers",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3fb3_053ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountGetWallPapers {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountGetWallPapers {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for AccountGetWallPapers {
+    type Return = Vec<super::tl::WallPaper>;
+}
+/// `account.reportPeer#ae189d5f peer:InputPeer reason:ReportReason = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountReportPeer {
+    pub peer: super::tl::InputPeer,
+    pub reason: super::tl::ReportReason,
+}
+impl TLType for AccountReportPeer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x51e7_62a1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.reportPeer",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x51e7_62a1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountReportPeer {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountReportPeer {
+            peer: TLType::tl_read_with_context(input, context)?,
+            reason: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.reason.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.reason.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountReportPeer {
+    type Return = bool;
+}
+pub struct AccountReportPeerBuilder {
+    peer: super::tl::InputPeer,
+    reason: super::tl::ReportReason,
+}
+impl AccountReportPeerBuilder {
+    pub fn new(peer: super::tl::InputPeer, reason: super::tl::ReportReason) -> Self {
+        AccountReportPeerBuilder { peer, reason }
+    }
+    pub fn build(self) -> AccountReportPeer {
+        AccountReportPeer {
+            peer: self.peer,
+            reason: self.reason,
+        }
+    }
+}
+/// `users.getUsers#0d91a548 id:Vector<InputUser> = Vector<User>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsersGetUsers {
+    pub id: Vec<super::tl::InputUser>,
+}
+impl TLType for UsersGetUsers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0d91_a548i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "users.getUsers",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0d91_a548i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UsersGetUsers {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UsersGetUsers {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UsersGetUsers {
+    type Return = Vec<super::tl::User>;
+}
+pub struct UsersGetUsersBuilder {
+    id: Vec<super::tl::InputUser>,
+}
+impl UsersGetUsersBuilder {
+    pub fn new(id: Vec<super::tl::InputUser>) -> Self {
+        UsersGetUsersBuilder { id }
+    }
+    pub fn build(self) -> UsersGetUsers {
+        UsersGetUsers { id: self.id }
+    }
+}
+/// `users.getFullUser#ca30a5b1 id:InputUser = UserFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UsersGetFullUser {
+    pub id: super::tl::InputUser,
+}
+impl TLType for UsersGetFullUser {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x35cf_5a4fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "users.getFullUser",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x35cf_5a4fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UsersGetFullUser {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UsersGetFullUser {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UsersGetFullUser {
+    type Return = super::tl::UserFull;
+}
+pub struct UsersGetFullUserBuilder {
+    id: super::tl::InputUser,
+}
+impl UsersGetFullUserBuilder {
+    pub fn new(id: super::tl::InputUser) -> Self {
+        UsersGetFullUserBuilder { id }
+    }
+    pub fn build(self) -> UsersGetFullUser {
+        UsersGetFullUser { id: self.id }
+    }
+}
+/// `contacts.getStatuses#c4a353ee = Vector<ContactStatus>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsGetStatuses;
+impl TLType for ContactsGetStatuses {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3b5c_ac12i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.getStatuses",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3b5c_ac12i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsGetStatuses {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsGetStatuses {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for ContactsGetStatuses {
+    type Return = Vec<super::tl::ContactStatus>;
+}
+/// `contacts.getContacts#22c6aa08 hash:string = contacts.Contacts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsGetContacts {
+    pub hash: String,
+}
+impl TLType for ContactsGetContacts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x22c6_aa08i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.getContacts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x22c6_aa08i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsGetContacts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsGetContacts {
+            hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.hash.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsGetContacts {
+    type Return = super::contacts::Contacts;
+}
+pub struct ContactsGetContactsBuilder {
+    hash: String,
+}
+impl ContactsGetContactsBuilder {
+    pub fn new(hash: String) -> Self {
+        ContactsGetContactsBuilder { hash }
+    }
+    pub fn build(self) -> ContactsGetContacts {
+        ContactsGetContacts { hash: self.hash }
+    }
+}
+/// `contacts.importContacts#da30b32d contacts:Vector<InputContact> replace:Bool = contacts.ImportedContacts`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsImportContacts {
+    pub contacts: Vec<super::tl::InputContact>,
+    pub replace: bool,
+}
+impl TLType for ContactsImportContacts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x25cf_4cd3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.importContacts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x25cf_4cd3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsImportContacts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsImportContacts {
+            contacts: TLType::tl_read_with_context(input, context)?,
+            replace: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.contacts.tl_write(output)?;
+        result += self.replace.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.contacts.serialized_len();
+        result += self.replace.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsImportContacts {
+    type Return = super::contacts::ImportedContacts;
+}
+pub struct ContactsImportContactsBuilder {
+    contacts: Vec<super::tl::InputContact>,
+    replace: bool,
+}
+impl ContactsImportContactsBuilder {
+    pub fn new(contacts: Vec<super::tl::InputContact>, replace: bool) -> Self {
+        ContactsImportContactsBuilder { contacts, replace }
+    }
+    pub fn build(self) -> ContactsImportContacts {
+        ContactsImportContacts {
+            contacts: self.contacts,
+            replace: self.replace,
+        }
+    }
+}
+/// `contacts.deleteContact#8e953744 id:InputUser = contacts.Link`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsDeleteContact {
+    pub id: super::tl::InputUser,
+}
+impl TLType for ContactsDeleteContact {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x716a_c8bci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.deleteContact",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x716a_c8bci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsDeleteContact {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsDeleteContact {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsDeleteContact {
+    type Return = super::contacts::Link;
+}
+pub struct ContactsDeleteContactBuilder {
+    id: super::tl::InputUser,
+}
+impl ContactsDeleteContactBuilder {
+    pub fn new(id: super::tl::InputUser) -> Self {
+        ContactsDeleteContactBuilder { id }
+    }
+    pub fn build(self) -> ContactsDeleteContact {
+        ContactsDeleteContact { id: self.id }
+    }
+}
+/// `contacts.deleteContacts#59ab389e id:Vector<InputUser> = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsDeleteContacts {
+    pub id: Vec<super::tl::InputUser>,
+}
+impl TLType for ContactsDeleteContacts {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x59ab_389ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.deleteContacts",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x59ab_389ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsDeleteContacts {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsDeleteContacts {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsDeleteContacts {
+    type Return = bool;
+}
+pub struct ContactsDeleteContactsBuilder {
+    id: Vec<super::tl::InputUser>,
+}
+impl ContactsDeleteContactsBuilder {
+    pub fn new(id: Vec<super::tl::InputUser>) -> Self {
+        ContactsDeleteContactsBuilder { id }
+    }
+    pub fn build(self) -> ContactsDeleteContacts {
+        ContactsDeleteContacts { id: self.id }
+    }
+}
+/// `contacts.block#332b49fc id:InputUser = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsBlock {
+    pub id: super::tl::InputUser,
+}
+impl TLType for ContactsBlock {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x332b_49fci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.block",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x332b_49fci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsBlock {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsBlock {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsBlock {
+    type Return = bool;
+}
+pub struct ContactsBlockBuilder {
+    id: super::tl::InputUser,
+}
+impl ContactsBlockBuilder {
+    pub fn new(id: super::tl::InputUser) -> Self {
+        ContactsBlockBuilder { id }
+    }
+    pub fn build(self) -> ContactsBlock {
+        ContactsBlock { id: self.id }
+    }
+}
+/// `contacts.unblock#e54100bd id:InputUser = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsUnblock {
+    pub id: super::tl::InputUser,
+}
+impl TLType for ContactsUnblock {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1abe_ff43i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.unblock",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1abe_ff43i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsUnblock {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsUnblock {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsUnblock {
+    type Return = bool;
+}
+pub struct ContactsUnblockBuilder {
+    id: super::tl::InputUser,
+}
+impl ContactsUnblockBuilder {
+    pub fn new(id: super::tl::InputUser) -> Self {
+        ContactsUnblockBuilder { id }
+    }
+    pub fn build(self) -> ContactsUnblock {
+        ContactsUnblock { id: self.id }
+    }
+}
+/// `contacts.getBlocked#f57c350f offset:int limit:int = contacts.Blocked`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsGetBlocked {
+    pub offset: i32,
+    pub limit: i32,
+}
+impl TLType for ContactsGetBlocked {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0a83_caf1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.getBlocked",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0a83_caf1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsGetBlocked {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsGetBlocked {
+            offset: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.offset.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.offset.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsGetBlocked {
+    type Return = super::contacts::Blocked;
+}
+pub struct ContactsGetBlockedBuilder {
+    offset: i32,
+    limit: i32,
+}
+impl ContactsGetBlockedBuilder {
+    pub fn new(offset: i32, limit: i32) -> Self {
+        ContactsGetBlockedBuilder { offset, limit }
+    }
+    pub fn build(self) -> ContactsGetBlocked {
+        ContactsGetBlocked {
+            offset: self.offset,
+            limit: self.limit,
+        }
+    }
+}
+/// `contacts.exportCard#84e53737 = Vector<int>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsExportCard;
+impl TLType for ContactsExportCard {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x7b1a_c8c9i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.exportCard",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x7b1a_c8c9i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsExportCard {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsExportCard {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for ContactsExportCard {
+    type Return = Vec<i32>;
+}
+/// `contacts.importCard#4fe196fe export_card:Vector<int> = User`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsImportCard {
+    pub export_card: Vec<i32>,
+}
+impl TLType for ContactsImportCard {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x4fe1_96fei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.importCard",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x4fe1_96fei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsImportCard {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsImportCard {
+            export_card: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.export_card.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.export_card.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsImportCard {
+    type Return = super::tl::User;
+}
+pub struct ContactsImportCardBuilder {
+    export_card: Vec<i32>,
+}
+impl ContactsImportCardBuilder {
+    pub fn new(export_card: Vec<i32>) -> Self {
+        ContactsImportCardBuilder { export_card }
+    }
+    pub fn build(self) -> ContactsImportCard {
+        ContactsImportCard {
+            export_card: self.export_card,
+        }
+    }
+}
+/// `messages.getMessages#4222fa74 id:Vector<int> = messages.Messages`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetMessages {
+    pub id: Vec<i32>,
+}
+impl TLType for MessagesGetMessages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x4222_fa74i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getMessages",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x4222_fa74i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetMessages {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetMessages {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetMessages {
+    type Return = super::messages::Messages;
+}
+pub struct MessagesGetMessagesBuilder {
+    id: Vec<i32>,
+}
+impl MessagesGetMessagesBuilder {
+    pub fn new(id: Vec<i32>) -> Self {
+        MessagesGetMessagesBuilder { id }
+    }
+    pub fn build(self) -> MessagesGetMessages {
+        MessagesGetMessages { id: self.id }
+    }
+}
+/// `messages.getDialogs#eccf1df6 offset:int max_id:int limit:int = messages.Dialogs`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetDialogs {
+    pub offset: i32,
+    pub max_id: i32,
+    pub limit: i32,
+}
+impl TLType for MessagesGetDialogs {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1330_e20ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getDialogs",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1330_e20ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetDialogs {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetDialogs {
+            offset: TLType::tl_read_with_context(input, context)?,
+            max_id: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.offset.tl_write(output)?;
+        result += self.max_id.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.offset.serialized_len();
+        result += self.max_id.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetDialogs {
+    type Return = super::messages::Dialogs;
+}
+pub struct MessagesGetDialogsBuilder {
+    offset: i32,
+    max_id: i32,
+    limit: i32,
+}
+impl MessagesGetDialogsBuilder {
+    pub fn new(offset: i32, max_id: i32, limit: i32) -> Self {
+        MessagesGetDialogsBuilder {
+            offset,
+            max_id,
+            limit,
+        }
+    }
+    pub fn build(self) -> MessagesGetDialogs {
+        MessagesGetDialogs {
+            offset: self.offset,
+            max_id: self.max_id,
+            limit: self.limit,
+        }
+    }
+}
+/// `messages.getHistory#92a1df2f peer:InputPeer offset:int max_id:int limit:int = messages.Messages`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetHistory {
+    pub peer: super::tl::InputPeer,
+    pub offset: i32,
+    pub max_id: i32,
+    pub limit: i32,
+}
+impl TLType for MessagesGetHistory {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x6d5e_20d1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getHistory",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x6d5e_20d1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetHistory {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetHistory {
+            peer: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+            max_id: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        result += self.max_id.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.offset.serialized_len();
+        result += self.max_id.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetHistory {
+    type Return = super::messages::Messages;
+}
+pub struct MessagesGetHistoryBuilder {
+    peer: super::tl::InputPeer,
+    offset: i32,
+    max_id: i32,
+    limit: i32,
+}
+impl MessagesGetHistoryBuilder {
+    pub fn new(peer: super::tl::InputPeer, offset: i32, max_id: i32, limit: i32) -> Self {
+        MessagesGetHistoryBuilder {
+            peer,
+            offset,
+            max_id,
+            limit,
+        }
+    }
+    pub fn build(self) -> MessagesGetHistory {
+        MessagesGetHistory {
+            peer: self.peer,
+            offset: self.offset,
+            max_id: self.max_id,
+            limit: self.limit,
+        }
+    }
+}
+/// `messages.search#07e9f2ab peer:InputPeer q:string filter:MessagesFilter min_date:int max_date:int offset:int max_id:int limit:int = messages.Messages`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSearch {
+    pub peer: super::tl::InputPeer,
+    pub q: String,
+    pub filter: super::tl::MessagesFilter,
+    pub min_date: i32,
+    pub max_date: i32,
+    pub offset: i32,
+    pub max_id: i32,
+    pub limit: i32,
+}
+impl TLType for MessagesSearch {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x07e9_f2abi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.search",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x07e9_f2abi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSearch {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSearch {
+            peer: TLType::tl_read_with_context(input, context)?,
+            q: TLType::tl_read_with_context(input, context)?,
+            filter: TLType::tl_read_with_context(input, context)?,
+            min_date: TLType::tl_read_with_context(input, context)?,
+            max_date: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+            max_id: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.q.tl_write(output)?;
+        result += self.filter.tl_write(output)?;
+        result += self.min_date.tl_write(output)?;
+        result += self.max_date.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        result += self.max_id.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.q.serialized_len();
+        result += self.filter.serialized_len();
+        result += self.min_date.serialized_len();
+        result += self.max_date.serialized_len();
+        result += self.offset.serialized_len();
+        result += self.max_id.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSearch {
+    type Return = super::messages::Messages;
+}
+pub struct MessagesSearchBuilder {
+    peer: super::tl::InputPeer,
+    q: String,
+    filter: super::tl::MessagesFilter,
+    min_date: i32,
+    max_date: i32,
+    offset: i32,
+    max_id: i32,
+    limit: i32,
+}
+impl MessagesSearchBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        peer: super::tl::InputPeer,
+        q: String,
+        filter: super::tl::MessagesFilter,
+        min_date: i32,
+        max_date: i32,
+        offset: i32,
+        max_id: i32,
+        limit: i32,
+    ) -> Self {
+        MessagesSearchBuilder {
+            peer,
+            q,
+            filter,
+            min_date,
+            max_date,
+            offset,
+            max_id,
+            limit,
+        }
+    }
+    pub fn build(self) -> MessagesSearch {
+        MessagesSearch {
+            peer: self.peer,
+            q: self.q,
+            filter: self.filter,
+            min_date: self.min_date,
+            max_date: self.max_date,
+            offset: self.offset,
+            max_id: self.max_id,
+            limit: self.limit,
+        }
+    }
+}
+/// `messages.readHistory#eed884c6 peer:InputPeer max_id:int offset:int read_contents:Bool = messages.AffectedHistory`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReadHistory {
+    pub peer: super::tl::InputPeer,
+    pub max_id: i32,
+    pub offset: i32,
+    pub read_contents: bool,
+}
+impl TLType for MessagesReadHistory {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1127_7b3ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.readHistory",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1127_7b3ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReadHistory {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReadHistory {
+            peer: TLType::tl_read_with_context(input, context)?,
+            max_id: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+            read_contents: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.max_id.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        result += self.read_contents.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.max_id.serialized_len();
+        result += self.offset.serialized_len();
+        result += self.read_contents.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReadHistory {
+    type Return = super::messages::AffectedHistory;
+}
+pub struct MessagesReadHistoryBuilder {
+    peer: super::tl::InputPeer,
+    max_id: i32,
+    offset: i32,
+    read_contents: bool,
+}
+impl MessagesReadHistoryBuilder {
+    pub fn new(peer: super::tl::InputPeer, max_id: i32, offset: i32, read_contents: bool) -> Self {
+        MessagesReadHistoryBuilder {
+            peer,
+            max_id,
+            offset,
+            read_contents,
+        }
+    }
+    pub fn build(self) -> MessagesReadHistory {
+        MessagesReadHistory {
+            peer: self.peer,
+            max_id: self.max_id,
+            offset: self.offset,
+            read_contents: self.read_contents,
+        }
+    }
+}
+/// `messages.deleteHistory#f4f8fb61 peer:InputPeer offset:int = messages.AffectedHistory`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesDeleteHistory {
+    pub peer: super::tl::InputPeer,
+    pub offset: i32,
+}
+impl TLType for MessagesDeleteHistory {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0b07_049fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.deleteHistory",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0b07_049fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesDeleteHistory {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesDeleteHistory {
+            peer: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.offset.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesDeleteHistory {
+    type Return = super::messages::AffectedHistory;
+}
+pub struct MessagesDeleteHistoryBuilder {
+    peer: super::tl::InputPeer,
+    offset: i32,
+}
+impl MessagesDeleteHistoryBuilder {
+    pub fn new(peer: super::tl::InputPeer, offset: i32) -> Self {
+        MessagesDeleteHistoryBuilder { peer, offset }
+    }
+    pub fn build(self) -> MessagesDeleteHistory {
+        MessagesDeleteHistory {
+            peer: self.peer,
+            offset: self.offset,
+        }
+    }
+}
+/// `messages.deleteMessages#14f2dd0a id:Vector<int> = Vector<int>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesDeleteMessages {
+    pub id: Vec<i32>,
+}
+impl TLType for MessagesDeleteMessages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x14f2_dd0ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.deleteMessages",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x14f2_dd0ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesDeleteMessages {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesDeleteMessages {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesDeleteMessages {
+    type Return = Vec<i32>;
+}
+pub struct MessagesDeleteMessagesBuilder {
+    id: Vec<i32>,
+}
+impl MessagesDeleteMessagesBuilder {
+    pub fn new(id: Vec<i32>) -> Self {
+        MessagesDeleteMessagesBuilder { id }
+    }
+    pub fn build(self) -> MessagesDeleteMessages {
+        MessagesDeleteMessages { id: self.id }
+    }
+}
+/// `messages.receivedMessages#28abcb68 max_id:int = Vector<int>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReceivedMessages {
+    pub max_id: i32,
+}
+impl TLType for MessagesReceivedMessages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x28ab_cb68i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.receivedMessages",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x28ab_cb68i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReceivedMessages {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReceivedMessages {
+            max_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.max_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.max_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReceivedMessages {
+    type Return = Vec<i32>;
+}
+pub struct MessagesReceivedMessagesBuilder {
+    max_id: i32,
+}
+impl MessagesReceivedMessagesBuilder {
+    pub fn new(max_id: i32) -> Self {
+        MessagesReceivedMessagesBuilder { max_id }
+    }
+    pub fn build(self) -> MessagesReceivedMessages {
+        MessagesReceivedMessages {
+            max_id: self.max_id,
+        }
+    }
+}
+/// `messages.setTyping#a3825e50 peer:InputPeer action:SendMessageAction = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSetTyping {
+    pub peer: super::tl::InputPeer,
+    pub action: super::tl::SendMessageAction,
+}
+impl TLType for MessagesSetTyping {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5c7d_a1b0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.setTyping",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5c7d_a1b0i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSetTyping {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSetTyping {
+            peer: TLType::tl_read_with_context(input, context)?,
+            action: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.action.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.action.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSetTyping {
+    type Return = bool;
+}
+pub struct MessagesSetTypingBuilder {
+    peer: super::tl::InputPeer,
+    action: super::tl::SendMessageAction,
+}
+impl MessagesSetTypingBuilder {
+    pub fn new(peer: super::tl::InputPeer, action: super::tl::SendMessageAction) -> Self {
+        MessagesSetTypingBuilder { peer, action }
+    }
+    pub fn build(self) -> MessagesSetTyping {
+        MessagesSetTyping {
+            peer: self.peer,
+            action: self.action,
+        }
+    }
+}
+/// `messages.sendMessage#4cde0aab peer:InputPeer message:string random_id:long = messages.SentMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSendMessage {
+    pub peer: super::tl::InputPeer,
+    pub message: String,
+    pub random_id: i64,
+}
+impl TLType for MessagesSendMessage {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x4cde_0aabi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.sendMessage",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x4cde_0aabi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSendMessage {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSendMessage {
+            peer: TLType::tl_read_with_context(input, context)?,
+            message: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.message.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.message.serialized_len();
+        result += self.random_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSendMessage {
+    type Return = super::messages::SentMessage;
+}
+pub struct MessagesSendMessageBuilder {
+    peer: super::tl::InputPeer,
+    message: String,
+    random_id: i64,
+}
+impl MessagesSendMessageBuilder {
+    pub fn new(peer: super::tl::InputPeer, message: String, random_id: i64) -> Self {
+        MessagesSendMessageBuilder {
+            peer,
+            message,
+            random_id,
+        }
+    }
+    pub fn build(self) -> MessagesSendMessage {
+        MessagesSendMessage {
+            peer: self.peer,
+            message: self.message,
+            random_id: self.random_id,
+        }
+    }
+}
+/// `messages.sendMedia#a3c85d76 peer:InputPeer media:InputMedia random_id:long = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSendMedia {
+    pub peer: super::tl::InputPeer,
+    pub media: super::tl::InputMedia,
+    pub random_id: i64,
+}
+impl TLType for MessagesSendMedia {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5c37_a28ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.sendMedia",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5c37_a28ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSendMedia {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSendMedia {
+            peer: TLType::tl_read_with_context(input, context)?,
+            media: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.media.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.media.serialized_len();
+        result += self.random_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSendMedia {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesSendMediaBuilder {
+    peer: super::tl::InputPeer,
+    media: super::tl::InputMedia,
+    random_id: i64,
+}
+impl MessagesSendMediaBuilder {
+    pub fn new(peer: super::tl::InputPeer, media: super::tl::InputMedia, random_id: i64) -> Self {
+        MessagesSendMediaBuilder {
+            peer,
+            media,
+            random_id,
+        }
+    }
+    pub fn build(self) -> MessagesSendMedia {
+        MessagesSendMedia {
+            peer: self.peer,
+            media: self.media,
+            random_id: self.random_id,
+        }
+    }
+}
+/// `messages.forwardMessages#514cd10f peer:InputPeer id:Vector<int> = messages.StatedMessages`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesForwardMessages {
+    pub peer: super::tl::InputPeer,
+    pub id: Vec<i32>,
+}
+impl TLType for MessagesForwardMessages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x514c_d10fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.forwardMessages",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x514c_d10fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesForwardMessages {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesForwardMessages {
+            peer: TLType::tl_read_with_context(input, context)?,
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesForwardMessages {
+    type Return = super::messages::StatedMessages;
+}
+pub struct MessagesForwardMessagesBuilder {
+    peer: super::tl::InputPeer,
+    id: Vec<i32>,
+}
+impl MessagesForwardMessagesBuilder {
+    pub fn new(peer: super::tl::InputPeer, id: Vec<i32>) -> Self {
+        MessagesForwardMessagesBuilder { peer, id }
+    }
+    pub fn build(self) -> MessagesForwardMessages {
+        MessagesForwardMessages {
+            peer: self.peer,
+            id: self.id,
+        }
+    }
+}
+/// `messages.reportSpam#cf1592db peer:InputPeer = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReportSpam {
+    pub peer: super::tl::InputPeer,
+}
+impl TLType for MessagesReportSpam {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x30ea_6d25i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.reportSpam",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x30ea_6d25i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReportSpam {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReportSpam {
+            peer: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReportSpam {
+    type Return = bool;
+}
+pub struct MessagesReportSpamBuilder {
+    peer: super::tl::InputPeer,
+}
+impl MessagesReportSpamBuilder {
+    pub fn new(peer: super::tl::InputPeer) -> Self {
+        MessagesReportSpamBuilder { peer }
+    }
+    pub fn build(self) -> MessagesReportSpam {
+        MessagesReportSpam { peer: self.peer }
+    }
+}
+/// `messages.hideReportSpam#a8f1709b peer:InputPeer = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesHideReportSpam {
+    pub peer: super::tl::InputPeer,
+}
+impl TLType for MessagesHideReportSpam {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x570e_8f65i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.hideReportSpam",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x570e_8f65i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesHideReportSpam {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesHideReportSpam {
+            peer: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesHideReportSpam {
+    type Return = bool;
+}
+pub struct MessagesHideReportSpamBuilder {
+    peer: super::tl::InputPeer,
+}
+impl MessagesHideReportSpamBuilder {
+    pub fn new(peer: super::tl::InputPeer) -> Self {
+        MessagesHideReportSpamBuilder { peer }
+    }
+    pub fn build(self) -> MessagesHideReportSpam {
+        MessagesHideReportSpam { peer: self.peer }
+    }
+}
+/// `messages.getPeerSettings#3672e09c peer:InputPeer = PeerSettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetPeerSettings {
+    pub peer: super::tl::InputPeer,
+}
+impl TLType for MessagesGetPeerSettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3672_e09ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getPeerSettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3672_e09ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetPeerSettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetPeerSettings {
+            peer: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetPeerSettings {
+    type Return = super::tl::PeerSettings;
+}
+pub struct MessagesGetPeerSettingsBuilder {
+    peer: super::tl::InputPeer,
+}
+impl MessagesGetPeerSettingsBuilder {
+    pub fn new(peer: super::tl::InputPeer) -> Self {
+        MessagesGetPeerSettingsBuilder { peer }
+    }
+    pub fn build(self) -> MessagesGetPeerSettings {
+        MessagesGetPeerSettings { peer: self.peer }
+    }
+}
+/// `messages.report#bd82b658 peer:InputPeer id:Vector<int> reason:ReportReason = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReport {
+    pub peer: super::tl::InputPeer,
+    pub id: Vec<i32>,
+    pub reason: super::tl::ReportReason,
+}
+impl TLType for MessagesReport {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x427d_49a8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.report",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x427d_49a8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReport {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReport {
+            peer: TLType::tl_read_with_context(input, context)?,
+            id: TLType::tl_read_with_context(input, context)?,
+            reason: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.id.tl_write(output)?;
+        result += self.reason.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.id.serialized_len();
+        result += self.reason.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReport {
+    type Return = bool;
+}
+pub struct MessagesReportBuilder {
+    peer: super::tl::InputPeer,
+    id: Vec<i32>,
+    reason: super::tl::ReportReason,
+}
+impl MessagesReportBuilder {
+    pub fn new(peer: super::tl::InputPeer, id: Vec<i32>, reason: super::tl::ReportReason) -> Self {
+        MessagesReportBuilder { peer, id, reason }
+    }
+    pub fn build(self) -> MessagesReport {
+        MessagesReport {
+            peer: self.peer,
+            id: self.id,
+            reason: self.reason,
+        }
+    }
+}
+/// `messages.getChats#3c6aa187 id:Vector<int> = messages.Chats`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetChats {
+    pub id: Vec<i32>,
+}
+impl TLType for MessagesGetChats {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3c6a_a187i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getChats",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3c6a_a187i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetChats {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetChats {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetChats {
+    type Return = super::messages::Chats;
+}
+pub struct MessagesGetChatsBuilder {
+    id: Vec<i32>,
+}
+impl MessagesGetChatsBuilder {
+    pub fn new(id: Vec<i32>) -> Self {
+        MessagesGetChatsBuilder { id }
+    }
+    pub fn build(self) -> MessagesGetChats {
+        MessagesGetChats { id: self.id }
+    }
+}
+/// `messages.getFullChat#3b831c66 chat_id:int = messages.ChatFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetFullChat {
+    pub chat_id: i32,
+}
+impl TLType for MessagesGetFullChat {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3b83_1c66i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getFullChat",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3b83_1c66i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetFullChat {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetFullChat {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetFullChat {
+    type Return = super::messages::ChatFull;
+}
+pub struct MessagesGetFullChatBuilder {
+    chat_id: i32,
+}
+impl MessagesGetFullChatBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        MessagesGetFullChatBuilder { chat_id }
+    }
+    pub fn build(self) -> MessagesGetFullChat {
+        MessagesGetFullChat {
+            chat_id: self.chat_id,
+        }
+    }
+}
+/// `messages.editChatTitle#b4bc68b5 chat_id:int title:string = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesEditChatTitle {
+    pub chat_id: i32,
+    pub title: String,
+}
+impl TLType for MessagesEditChatTitle {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x4b43_974bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.editChatTitle",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x4b43_974bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesEditChatTitle {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesEditChatTitle {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+            title: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        result += self.title.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result += self.title.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesEditChatTitle {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesEditChatTitleBuilder {
+    chat_id: i32,
+    title: String,
+}
+impl MessagesEditChatTitleBuilder {
+    pub fn new(chat_id: i32, title: String) -> Self {
+        MessagesEditChatTitleBuilder { chat_id, title }
+    }
+    pub fn build(self) -> MessagesEditChatTitle {
+        MessagesEditChatTitle {
+            chat_id: self.chat_id,
+            title: self.title,
+        }
+    }
+}
+/// `messages.editChatPhoto#d881821d chat_id:int photo:InputChatPhoto = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesEditChatPhoto {
+    pub chat_id: i32,
+    pub photo: super::tl::InputChatPhoto,
+}
+impl TLType for MessagesEditChatPhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x277e_7de3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.editChatPhoto",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x277e_7de3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesEditChatPhoto {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesEditChatPhoto {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+            photo: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        result += self.photo.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result += self.photo.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesEditChatPhoto {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesEditChatPhotoBuilder {
+    chat_id: i32,
+    photo: super::tl::InputChatPhoto,
+}
+impl MessagesEditChatPhotoBuilder {
+    pub fn new(chat_id: i32, photo: super::tl::InputChatPhoto) -> Self {
+        MessagesEditChatPhotoBuilder { chat_id, photo }
+    }
+    pub fn build(self) -> MessagesEditChatPhoto {
+        MessagesEditChatPhoto {
+            chat_id: self.chat_id,
+            photo: self.photo,
+        }
+    }
+}
+/// `messages.addChatUser#2ee9ee9e chat_id:int user_id:InputUser fwd_limit:int = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesAddChatUser {
+    pub chat_id: i32,
+    pub user_id: super::tl::InputUser,
+    pub fwd_limit: i32,
+}
+impl TLType for MessagesAddChatUser {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2ee9_ee9ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.addChatUser",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2ee9_ee9ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesAddChatUser {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesAddChatUser {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+            user_id: TLType::tl_read_with_context(input, context)?,
+            fwd_limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        result += self.user_id.tl_write(output)?;
+        result += self.fwd_limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result += self.user_id.serialized_len();
+        result += self.fwd_limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesAddChatUser {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesAddChatUserBuilder {
+    chat_id: i32,
+    user_id: super::tl::InputUser,
+    fwd_limit: i32,
+}
+impl MessagesAddChatUserBuilder {
+    pub fn new(chat_id: i32, user_id: super::tl::InputUser, fwd_limit: i32) -> Self {
+        MessagesAddChatUserBuilder {
+            chat_id,
+            user_id,
+            fwd_limit,
+        }
+    }
+    pub fn build(self) -> MessagesAddChatUser {
+        MessagesAddChatUser {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+            fwd_limit: self.fwd_limit,
+        }
+    }
+}
+/// `messages.deleteChatUser#c3c5cd23 chat_id:int user_id:InputUser = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesDeleteChatUser {
+    pub chat_id: i32,
+    pub user_id: super::tl::InputUser,
+}
+impl TLType for MessagesDeleteChatUser {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3c3a_32ddi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.deleteChatUser",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3c3a_32ddi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesDeleteChatUser {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesDeleteChatUser {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+            user_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        result += self.user_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result += self.user_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesDeleteChatUser {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesDeleteChatUserBuilder {
+    chat_id: i32,
+    user_id: super::tl::InputUser,
+}
+impl MessagesDeleteChatUserBuilder {
+    pub fn new(chat_id: i32, user_id: super::tl::InputUser) -> Self {
+        MessagesDeleteChatUserBuilder { chat_id, user_id }
+    }
+    pub fn build(self) -> MessagesDeleteChatUser {
+        MessagesDeleteChatUser {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+        }
+    }
+}
+/// `messages.createChat#419d9aee users:Vector<InputUser> title:string = messages.StatedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesCreateChat {
+    pub users: Vec<super::tl::InputUser>,
+    pub title: String,
+}
+impl TLType for MessagesCreateChat {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x419d_9aeei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.createChat",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x419d_9aeei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesCreateChat {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesCreateChat {
+            users: TLType::tl_read_with_context(input, context)?,
+            title: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.users.tl_write(output)?;
+        result += self.title.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.users.serialized_len();
+        result += self.title.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesCreateChat {
+    type Return = super::messages::StatedMessage;
+}
+pub struct MessagesCreateChatBuilder {
+    users: Vec<super::tl::InputUser>,
+    title: String,
+}
+impl MessagesCreateChatBuilder {
+    pub fn new(users: Vec<super::tl::InputUser>, title: String) -> Self {
+        MessagesCreateChatBuilder { users, title }
+    }
+    pub fn build(self) -> MessagesCreateChat {
+        MessagesCreateChat {
+            users: self.users,
+            title: self.title,
+        }
+    }
+}
+/// `updates.getState#edd4882a = updates.State`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdatesGetState;
+impl TLType for UpdatesGetState {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x122b_77d6i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "updates.getState",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x122b_77d6i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UpdatesGetState {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UpdatesGetState {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for UpdatesGetState {
+    type Return = super::updates::State;
+}
+/// `updates.getDifference#0a041495 pts:int date:int qts:int = updates.Difference`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdatesGetDifference {
+    pub pts: i32,
+    pub date: i32,
+    pub qts: i32,
+}
+impl TLType for UpdatesGetDifference {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0a04_1495i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "updates.getDifference",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0a04_1495i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UpdatesGetDifference {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UpdatesGetDifference {
+            pts: TLType::tl_read_with_context(input, context)?,
+            date: TLType::tl_read_with_context(input, context)?,
+            qts: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.pts.tl_write(output)?;
+        result += self.date.tl_write(output)?;
+        result += self.qts.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.pts.serialized_len();
+        result += self.date.serialized_len();
+        result += self.qts.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UpdatesGetDifference {
+    type Return = super::updates::Difference;
+}
+pub struct UpdatesGetDifferenceBuilder {
+    pts: i32,
+    date: i32,
+    qts: i32,
+}
+impl UpdatesGetDifferenceBuilder {
+    pub fn new(pts: i32, date: i32, qts: i32) -> Self {
+        UpdatesGetDifferenceBuilder { pts, date, qts }
+    }
+    pub fn build(self) -> UpdatesGetDifference {
+        UpdatesGetDifference {
+            pts: self.pts,
+            date: self.date,
+            qts: self.qts,
+        }
+    }
+}
+/// `photos.updateProfilePhoto#eef579a0 id:InputPhoto crop:InputPhotoCrop = UserProfilePhoto`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotosUpdateProfilePhoto {
+    pub id: super::tl::InputPhoto,
+    pub crop: super::tl::InputPhotoCrop,
+}
+impl TLType for PhotosUpdateProfilePhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x110a_8660i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.updateProfilePhoto",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x110a_8660i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PhotosUpdateProfilePhoto {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PhotosUpdateProfilePhoto {
+            id: TLType::tl_read_with_context(input, context)?,
+            crop: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        result += self.crop.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result += self.crop.serialized_len();
+        result
+    }
+}
+impl RemoteCall for PhotosUpdateProfilePhoto {
+    type Return = super::tl::UserProfilePhoto;
+}
+pub struct PhotosUpdateProfilePhotoBuilder {
+    id: super::tl::InputPhoto,
+    crop: super::tl::InputPhotoCrop,
+}
+impl PhotosUpdateProfilePhotoBuilder {
+    pub fn new(id: super::tl::InputPhoto, crop: super::tl::InputPhotoCrop) -> Self {
+        PhotosUpdateProfilePhotoBuilder { id, crop }
+    }
+    pub fn build(self) -> PhotosUpdateProfilePhoto {
+        PhotosUpdateProfilePhoto {
+            id: self.id,
+            crop: self.crop,
+        }
+    }
+}
+/// `photos.uploadProfilePhoto#d50f9c88 file:InputFile caption:string geo_point:InputGeoPoint crop:InputPhotoCrop = photos.Photo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotosUploadProfilePhoto {
+    pub file: super::tl::InputFile,
+    pub caption: String,
+    pub geo_point: super::tl::InputGeoPoint,
+    pub crop: super::tl::InputPhotoCrop,
+}
+impl TLType for PhotosUploadProfilePhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2af0_6378i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.uploadProfilePhoto",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2af0_6378i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PhotosUploadProfilePhoto {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PhotosUploadProfilePhoto {
+            file: TLType::tl_read_with_context(input, context)?,
+            caption: TLType::tl_read_with_context(input, context)?,
+            geo_point: TLType::tl_read_with_context(input, context)?,
+            crop: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.file.tl_write(output)?;
+        result += self.caption.tl_write(output)?;
+        result += self.geo_point.tl_write(output)?;
+        result += self.crop.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.file.serialized_len();
+        result += self.caption.serialized_len();
+        result += self.geo_point.serialized_len();
+        result += self.crop.serialized_len();
+        result
+    }
+}
+impl RemoteCall for PhotosUploadProfilePhoto {
+    type Return = super::photos::Photo;
+}
+pub struct PhotosUploadProfilePhotoBuilder {
+    file: super::tl::InputFile,
+    caption: String,
+    geo_point: super::tl::InputGeoPoint,
+    crop: super::tl::InputPhotoCrop,
+}
+impl PhotosUploadProfilePhotoBuilder {
+    pub fn new(
+        file: super::tl::InputFile,
+        caption: String,
+        geo_point: super::tl::InputGeoPoint,
+        crop: super::tl::InputPhotoCrop,
+    ) -> Self {
+        PhotosUploadProfilePhotoBuilder {
+            file,
+            caption,
+            geo_point,
+            crop,
+        }
+    }
+    pub fn build(self) -> PhotosUploadProfilePhoto {
+        PhotosUploadProfilePhoto {
+            file: self.file,
+            caption: self.caption,
+            geo_point: self.geo_point,
+            crop: self.crop,
+        }
+    }
+}
+/// `photos.deletePhotos#87cf7f2f id:Vector<InputPhoto> = Vector<long>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotosDeletePhotos {
+    pub id: Vec<super::tl::InputPhoto>,
+}
+impl TLType for PhotosDeletePhotos {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x7830_80d1i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.deletePhotos",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x7830_80d1i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PhotosDeletePhotos {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PhotosDeletePhotos {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for PhotosDeletePhotos {
+    type Return = Vec<i64>;
+}
+pub struct PhotosDeletePhotosBuilder {
+    id: Vec<super::tl::InputPhoto>,
+}
+impl PhotosDeletePhotosBuilder {
+    pub fn new(id: Vec<super::tl::InputPhoto>) -> Self {
+        PhotosDeletePhotosBuilder { id }
+    }
+    pub fn build(self) -> PhotosDeletePhotos {
+        PhotosDeletePhotos { id: self.id }
+    }
+}
+/// `upload.saveFilePart#b304a621 file_id:long file_part:int bytes:bytes = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UploadSaveFilePart {
+    pub file_id: i64,
+    pub file_part: i32,
+    pub bytes: TLBytes,
+}
+impl TLType for UploadSaveFilePart {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x4cfb_59dfi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "upload.saveFilePart",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x4cfb_59dfi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UploadSaveFilePart {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UploadSaveFilePart {
+            file_id: TLType::tl_read_with_context(input, context)?,
+            file_part: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.file_id.tl_write(output)?;
+        result += self.file_part.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.file_id.serialized_len();
+        result += self.file_part.serialized_len();
+        result += self.bytes.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UploadSaveFilePart {
+    type Return = bool;
+}
+pub struct UploadSaveFilePartBuilder {
+    file_id: i64,
+    file_part: i32,
+    bytes: TLBytes,
+}
+impl UploadSaveFilePartBuilder {
+    pub fn new(file_id: i64, file_part: i32, bytes: TLBytes) -> Self {
+        UploadSaveFilePartBuilder {
+            file_id,
+            file_part,
+            bytes,
+        }
+    }
+    pub fn build(self) -> UploadSaveFilePart {
+        UploadSaveFilePart {
+            file_id: self.file_id,
+            file_part: self.file_part,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `upload.getFile#e3a6cfb5 location:InputFileLocation offset:int limit:int = upload.File`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UploadGetFile {
+    pub location: super::tl::InputFileLocation,
+    pub offset: i32,
+    pub limit: i32,
+}
+impl TLType for UploadGetFile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1c59_304bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "upload.getFile",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1c59_304bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UploadGetFile {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UploadGetFile {
+            location: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.location.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.location.serialized_len();
+        result += self.offset.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UploadGetFile {
+    type Return = super::upload::File;
+}
+pub struct UploadGetFileBuilder {
+    location: super::tl::InputFileLocation,
+    offset: i32,
+    limit: i32,
+}
+impl UploadGetFileBuilder {
+    pub fn new(location: super::tl::InputFileLocation, offset: i32, limit: i32) -> Self {
+        UploadGetFileBuilder {
+            location,
+            offset,
+            limit,
+        }
+    }
+    pub fn build(self) -> UploadGetFile {
+        UploadGetFile {
+            location: self.location,
+            offset: self.offset,
+            limit: self.limit,
+        }
+    }
+}
+/// `help.getConfig#c4f9186b = Config`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpGetConfig;
+impl TLType for HelpGetConfig {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3b06_e795i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.getConfig",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3b06_e795i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpGetConfig {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpGetConfig {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for HelpGetConfig {
+    type Return = super::tl::Config;
+}
+/// `help.getNearestDc#1fb33026 = NearestDc`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpGetNearestDc;
+impl TLType for HelpGetNearestDc {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x1fb3_3026i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.getNearestDc",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x1fb3_3026i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpGetNearestDc {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpGetNearestDc {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for HelpGetNearestDc {
+    type Return = super::tl::NearestDc;
+}
+/// `help.getAppUpdate#c812ac7e device_model:string system_version:string app_version:string lang_code:string = help.AppUpdate`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpGetAppUpdate {
+    pub device_model: String,
+    pub system_version: String,
+    pub app_version: String,
+    pub lang_code: String,
+}
+impl TLType for HelpGetAppUpdate {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x37ed_5382i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.getAppUpdate",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x37ed_5382i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpGetAppUpdate {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpGetAppUpdate {
+            device_model: TLType::tl_read_with_context(input, context)?,
+            system_version: TLType::tl_read_with_context(input, context)?,
+            app_version: TLType::tl_read_with_context(input, context)?,
+            lang_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.device_model.tl_write(output)?;
+        result += self.system_version.tl_write(output)?;
+        result += self.app_version.tl_write(output)?;
+        result += self.lang_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.device_model.serialized_len();
+        result += self.system_version.serialized_len();
+        result += self.app_version.serialized_len();
+        result += self.lang_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for HelpGetAppUpdate {
+    type Return = super::help::AppUpdate;
+}
+pub struct HelpGetAppUpdateBuilder {
+    device_model: String,
+    system_version: String,
+    app_version: String,
+    lang_code: String,
+}
+impl HelpGetAppUpdateBuilder {
+    pub fn new(
+        device_model: String,
+        system_version: String,
+        app_version: String,
+        lang_code: String,
+    ) -> Self {
+        HelpGetAppUpdateBuilder {
+            device_model,
+            system_version,
+            app_version,
+            lang_code,
+        }
+    }
+    pub fn build(self) -> HelpGetAppUpdate {
+        HelpGetAppUpdate {
+            device_model: self.device_model,
+            system_version: self.system_version,
+            app_version: self.app_version,
+            lang_code: self.lang_code,
+        }
+    }
+}
+/// `help.saveAppLog#6f02f748 events:Vector<InputAppEvent> = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpSaveAppLog {
+    pub events: Vec<super::tl::InputAppEvent>,
+}
+impl TLType for HelpSaveAppLog {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6f02_f748i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.saveAppLog",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6f02_f748i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpSaveAppLog {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpSaveAppLog {
+            events: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.events.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.events.serialized_len();
+        result
+    }
+}
+impl RemoteCall for HelpSaveAppLog {
+    type Return = bool;
+}
+pub struct HelpSaveAppLogBuilder {
+    events: Vec<super::tl::InputAppEvent>,
+}
+impl HelpSaveAppLogBuilder {
+    pub fn new(events: Vec<super::tl::InputAppEvent>) -> Self {
+        HelpSaveAppLogBuilder { events }
+    }
+    pub fn build(self) -> HelpSaveAppLog {
+        HelpSaveAppLog {
+            events: self.events,
+        }
+    }
+}
+/// `help.getInviteText#a4a95186 lang_code:string = help.InviteText`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpGetInviteText {
+    pub lang_code: String,
+}
+impl TLType for HelpGetInviteText {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5b56_ae7ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.getInviteText",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5b56_ae7ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpGetInviteText {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpGetInviteText {
+            lang_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.lang_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.lang_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for HelpGetInviteText {
+    type Return = super::help::InviteText;
+}
+pub struct HelpGetInviteTextBuilder {
+    lang_code: String,
+}
+impl HelpGetInviteTextBuilder {
+    pub fn new(lang_code: String) -> Self {
+        HelpGetInviteTextBuilder { lang_code }
+    }
+    pub fn build(self) -> HelpGetInviteText {
+        HelpGetInviteText {
+            lang_code: self.lang_code,
+        }
+    }
+}
+/// `photos.getUserPhotos#b7ee553c user_id:InputUser offset:int max_id:int limit:int = photos.Photos`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotosGetUserPhotos {
+    pub user_id: super::tl::InputUser,
+    pub offset: i32,
+    pub max_id: i32,
+    pub limit: i32,
+}
+impl TLType for PhotosGetUserPhotos {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x4811_aac4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.getUserPhotos",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x4811_aac4i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PhotosGetUserPhotos {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PhotosGetUserPhotos {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+            max_id: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        result += self.max_id.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.offset.serialized_len();
+        result += self.max_id.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for PhotosGetUserPhotos {
+    type Return = super::photos::Photos;
+}
+pub struct PhotosGetUserPhotosBuilder {
+    user_id: super::tl::InputUser,
+    offset: i32,
+    max_id: i32,
+    limit: i32,
+}
+impl PhotosGetUserPhotosBuilder {
+    pub fn new(user_id: super::tl::InputUser, offset: i32, max_id: i32, limit: i32) -> Self {
+        PhotosGetUserPhotosBuilder {
+            user_id,
+            offset,
+            max_id,
+            limit,
+        }
+    }
+    pub fn build(self) -> PhotosGetUserPhotos {
+        PhotosGetUserPhotos {
+            user_id: self.user_id,
+            offset: self.offset,
+            max_id: self.max_id,
+            limit: self.limit,
+        }
+    }
+}
+/// `messages.getDhConfig#26cf8950 version:int random_length:int = messages.DhConfig`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetDhConfig {
+    pub version: i32,
+    pub random_length: i32,
+}
+impl TLType for MessagesGetDhConfig {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x26cf_8950i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getDhConfig",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x26cf_8950i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetDhConfig {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetDhConfig {
+            version: TLType::tl_read_with_context(input, context)?,
+            random_length: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.version.tl_write(output)?;
+        result += self.random_length.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.version.serialized_len();
+        result += self.random_length.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetDhConfig {
+    type Return = super::messages::DhConfig;
+}
+pub struct MessagesGetDhConfigBuilder {
+    version: i32,
+    random_length: i32,
+}
+impl MessagesGetDhConfigBuilder {
+    pub fn new(version: i32, random_length: i32) -> Self {
+        MessagesGetDhConfigBuilder {
+            version,
+            random_length,
+        }
+    }
+    pub fn build(self) -> MessagesGetDhConfig {
+        MessagesGetDhConfig {
+            version: self.version,
+            random_length: self.random_length,
+        }
+    }
+}
+/// `messages.requestEncryption#f64daf43 user_id:InputUser random_id:int g_a:bytes = EncryptedChat`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesRequestEncryption {
+    pub user_id: super::tl::InputUser,
+    pub random_id: i32,
+    pub g_a: TLBytes,
+}
+impl TLType for MessagesRequestEncryption {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x09b2_50bdi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.requestEncryption",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x09b2_50bdi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesRequestEncryption {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesRequestEncryption {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+            g_a: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        result += self.g_a.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.random_id.serialized_len();
+        result += self.g_a.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesRequestEncryption {
+    type Return = super::tl::EncryptedChat;
+}
+pub struct MessagesRequestEncryptionBuilder {
+    user_id: super::tl::InputUser,
+    random_id: i32,
+    g_a: TLBytes,
+}
+impl MessagesRequestEncryptionBuilder {
+    pub fn new(user_id: super::tl::InputUser, random_id: i32, g_a: TLBytes) -> Self {
+        MessagesRequestEncryptionBuilder {
+            user_id,
+            random_id,
+            g_a,
+        }
+    }
+    pub fn build(self) -> MessagesRequestEncryption {
+        MessagesRequestEncryption {
+            user_id: self.user_id,
+            random_id: self.random_id,
+            g_a: self.g_a,
+        }
+    }
+}
+/// `messages.acceptEncryption#3dbc0415 peer:InputEncryptedChat g_b:bytes key_fingerprint:long = EncryptedChat`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesAcceptEncryption {
+    pub peer: super::tl::InputEncryptedChat,
+    pub g_b: TLBytes,
+    pub key_fingerprint: i64,
+}
+impl TLType for MessagesAcceptEncryption {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3dbc_0415i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.acceptEncryption",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3dbc_0415i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesAcceptEncryption {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesAcceptEncryption {
+            peer: TLType::tl_read_with_context(input, context)?,
+            g_b: TLType::tl_read_with_context(input, context)?,
+            key_fingerprint: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.g_b.tl_write(output)?;
+        result += self.key_fingerprint.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.g_b.serialized_len();
+        result += self.key_fingerprint.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesAcceptEncryption {
+    type Return = super::tl::EncryptedChat;
+}
+pub struct MessagesAcceptEncryptionBuilder {
+    peer: super::tl::InputEncryptedChat,
+    g_b: TLBytes,
+    key_fingerprint: i64,
+}
+impl MessagesAcceptEncryptionBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat, g_b: TLBytes, key_fingerprint: i64) -> Self {
+        MessagesAcceptEncryptionBuilder {
+            peer,
+            g_b,
+            key_fingerprint,
+        }
+    }
+    pub fn build(self) -> MessagesAcceptEncryption {
+        MessagesAcceptEncryption {
+            peer: self.peer,
+            g_b: self.g_b,
+            key_fingerprint: self.key_fingerprint,
+        }
+    }
+}
+/// `messages.discardEncryption#edd923c5 chat_id:int = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesDiscardEncryption {
+    pub chat_id: i32,
+}
+impl TLType for MessagesDiscardEncryption {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1226_dc3bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.discardEncryption",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1226_dc3bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesDiscardEncryption {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesDiscardEncryption {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesDiscardEncryption {
+    type Return = bool;
+}
+pub struct MessagesDiscardEncryptionBuilder {
+    chat_id: i32,
+}
+impl MessagesDiscardEncryptionBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        MessagesDiscardEncryptionBuilder { chat_id }
+    }
+    pub fn build(self) -> MessagesDiscardEncryption {
+        MessagesDiscardEncryption {
+            chat_id: self.chat_id,
+        }
+    }
+}
+/// `messages.setEncryptedTyping#791451ed peer:InputEncryptedChat typing:Bool = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSetEncryptedTyping {
+    pub peer: super::tl::InputEncryptedChat,
+    pub typing: bool,
+}
+impl TLType for MessagesSetEncryptedTyping {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7914_51edi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.setEncryptedTyping",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7914_51edi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSetEncryptedTyping {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSetEncryptedTyping {
+            peer: TLType::tl_read_with_context(input, context)?,
+            typing: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.typing.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.typing.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSetEncryptedTyping {
+    type Return = bool;
+}
+pub struct MessagesSetEncryptedTypingBuilder {
+    peer: super::tl::InputEncryptedChat,
+    typing: bool,
+}
+impl MessagesSetEncryptedTypingBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat, typing: bool) -> Self {
+        MessagesSetEncryptedTypingBuilder { peer, typing }
+    }
+    pub fn build(self) -> MessagesSetEncryptedTyping {
+        MessagesSetEncryptedTyping {
+            peer: self.peer,
+            typing: self.typing,
+        }
+    }
+}
+/// `messages.readEncryptedHistory#7f4b690a peer:InputEncryptedChat max_date:int = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReadEncryptedHistory {
+    pub peer: super::tl::InputEncryptedChat,
+    pub max_date: i32,
+}
+impl TLType for MessagesReadEncryptedHistory {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7f4b_690ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.readEncryptedHistory",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7f4b_690ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReadEncryptedHistory {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReadEncryptedHistory {
+            peer: TLType::tl_read_with_context(input, context)?,
+            max_date: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.max_date.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.max_date.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReadEncryptedHistory {
+    type Return = bool;
+}
+pub struct MessagesReadEncryptedHistoryBuilder {
+    peer: super::tl::InputEncryptedChat,
+    max_date: i32,
+}
+impl MessagesReadEncryptedHistoryBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat, max_date: i32) -> Self {
+        MessagesReadEncryptedHistoryBuilder { peer, max_date }
+    }
+    pub fn build(self) -> MessagesReadEncryptedHistory {
+        MessagesReadEncryptedHistory {
+            peer: self.peer,
+            max_date: self.max_date,
+        }
+    }
+}
+/// `messages.sendEncrypted#a9776773 peer:InputEncryptedChat random_id:long data:bytes = messages.SentEncryptedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSendEncrypted {
+    pub peer: super::tl::InputEncryptedChat,
+    pub random_id: i64,
+    pub data: TLBytes,
+}
+impl TLType for MessagesSendEncrypted {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5688_988di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.sendEncrypted",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5688_988di32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSendEncrypted {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSendEncrypted {
+            peer: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+            data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        result += self.data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.random_id.serialized_len();
+        result += self.data.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSendEncrypted {
+    type Return = super::messages::SentEncryptedMessage;
+}
+pub struct MessagesSendEncryptedBuilder {
+    peer: super::tl::InputEncryptedChat,
+    random_id: i64,
+    data: TLBytes,
+}
+impl MessagesSendEncryptedBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat, random_id: i64, data: TLBytes) -> Self {
+        MessagesSendEncryptedBuilder {
+            peer,
+            random_id,
+            data,
+        }
+    }
+    pub fn build(self) -> MessagesSendEncrypted {
+        MessagesSendEncrypted {
+            peer: self.peer,
+            random_id: self.random_id,
+            data: self.data,
+        }
+    }
+}
+/// `messages.sendEncryptedFile#9a901b66 peer:InputEncryptedChat random_id:long data:bytes file:InputEncryptedFile = messages.SentEncryptedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSendEncryptedFile {
+    pub peer: super::tl::InputEncryptedChat,
+    pub random_id: i64,
+    pub data: TLBytes,
+    pub file: super::tl::InputEncryptedFile,
+}
+impl TLType for MessagesSendEncryptedFile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x656f_e49ai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.sendEncryptedFile",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x656f_e49ai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSendEncryptedFile {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSendEncryptedFile {
+            peer: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+            data: TLType::tl_read_with_context(input, context)?,
+            file: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        result += self.data.tl_write(output)?;
+        result += self.file.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.random_id.serialized_len();
+        result += self.data.serialized_len();
+        result += self.file.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSendEncryptedFile {
+    type Return = super::messages::SentEncryptedMessage;
+}
+pub struct MessagesSendEncryptedFileBuilder {
+    peer: super::tl::InputEncryptedChat,
+    random_id: i64,
+    data: TLBytes,
+    file: super::tl::InputEncryptedFile,
+}
+impl MessagesSendEncryptedFileBuilder {
+    pub fn new(
+        peer: super::tl::InputEncryptedChat,
+        random_id: i64,
+        data: TLBytes,
+        file: super::tl::InputEncryptedFile,
+    ) -> Self {
+        MessagesSendEncryptedFileBuilder {
+            peer,
+            random_id,
+            data,
+            file,
+        }
+    }
+    pub fn build(self) -> MessagesSendEncryptedFile {
+        MessagesSendEncryptedFile {
+            peer: self.peer,
+            random_id: self.random_id,
+            data: self.data,
+            file: self.file,
+        }
+    }
+}
+/// `messages.sendEncryptedService#32d439a4 peer:InputEncryptedChat random_id:long data:bytes = messages.SentEncryptedMessage`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesSendEncryptedService {
+    pub peer: super::tl::InputEncryptedChat,
+    pub random_id: i64,
+    pub data: TLBytes,
+}
+impl TLType for MessagesSendEncryptedService {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x32d4_39a4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.sendEncryptedService",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x32d4_39a4i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesSendEncryptedService {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesSendEncryptedService {
+            peer: TLType::tl_read_with_context(input, context)?,
+            random_id: TLType::tl_read_with_context(input, context)?,
+            data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.random_id.tl_write(output)?;
+        result += self.data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.random_id.serialized_len();
+        result += self.data.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesSendEncryptedService {
+    type Return = super::messages::SentEncryptedMessage;
+}
+pub struct MessagesSendEncryptedServiceBuilder {
+    peer: super::tl::InputEncryptedChat,
+    random_id: i64,
+    data: TLBytes,
+}
+impl MessagesSendEncryptedServiceBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat, random_id: i64, data: TLBytes) -> Self {
+        MessagesSendEncryptedServiceBuilder {
+            peer,
+            random_id,
+            data,
+        }
+    }
+    pub fn build(self) -> MessagesSendEncryptedService {
+        MessagesSendEncryptedService {
+            peer: self.peer,
+            random_id: self.random_id,
+            data: self.data,
+        }
+    }
+}
+/// `messages.receivedQueue#55a5bb66 max_qts:int = Vector<long>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReceivedQueue {
+    pub max_qts: i32,
+}
+impl TLType for MessagesReceivedQueue {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x55a5_bb66i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.receivedQueue",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x55a5_bb66i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReceivedQueue {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReceivedQueue {
+            max_qts: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.max_qts.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.max_qts.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReceivedQueue {
+    type Return = Vec<i64>;
+}
+pub struct MessagesReceivedQueueBuilder {
+    max_qts: i32,
+}
+impl MessagesReceivedQueueBuilder {
+    pub fn new(max_qts: i32) -> Self {
+        MessagesReceivedQueueBuilder { max_qts }
+    }
+    pub fn build(self) -> MessagesReceivedQueue {
+        MessagesReceivedQueue {
+            max_qts: self.max_qts,
+        }
+    }
+}
+/// `messages.reportEncryptedSpam#4b0c8c0f peer:InputEncryptedChat = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReportEncryptedSpam {
+    pub peer: super::tl::InputEncryptedChat,
+}
+impl TLType for MessagesReportEncryptedSpam {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x4b0c_8c0fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.reportEncryptedSpam",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x4b0c_8c0fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReportEncryptedSpam {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReportEncryptedSpam {
+            peer: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReportEncryptedSpam {
+    type Return = bool;
+}
+pub struct MessagesReportEncryptedSpamBuilder {
+    peer: super::tl::InputEncryptedChat,
+}
+impl MessagesReportEncryptedSpamBuilder {
+    pub fn new(peer: super::tl::InputEncryptedChat) -> Self {
+        MessagesReportEncryptedSpamBuilder { peer }
+    }
+    pub fn build(self) -> MessagesReportEncryptedSpam {
+        MessagesReportEncryptedSpam { peer: self.peer }
+    }
+}
+/// `upload.saveBigFilePart#de7b673d file_id:long file_part:int file_total_parts:int bytes:bytes = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UploadSaveBigFilePart {
+    pub file_id: i64,
+    pub file_part: i32,
+    pub file_total_parts: i32,
+    pub bytes: TLBytes,
+}
+impl TLType for UploadSaveBigFilePart {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2184_98c3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "upload.saveBigFilePart",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2184_98c3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UploadSaveBigFilePart {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UploadSaveBigFilePart {
+            file_id: TLType::tl_read_with_context(input, context)?,
+            file_part: TLType::tl_read_with_context(input, context)?,
+            file_total_parts: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.file_id.tl_write(output)?;
+        result += self.file_part.tl_write(output)?;
+        result += self.file_total_parts.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.file_id.serialized_len();
+        result += self.file_part.serialized_len();
+        result += self.file_total_parts.serialized_len();
+        result += self.bytes.serialized_len();
+        result
+    }
+}
+impl RemoteCall for UploadSaveBigFilePart {
+    type Return = bool;
+}
+pub struct UploadSaveBigFilePartBuilder {
+    file_id: i64,
+    file_part: i32,
+    file_total_parts: i32,
+    bytes: TLBytes,
+}
+impl UploadSaveBigFilePartBuilder {
+    pub fn new(file_id: i64, file_part: i32, file_total_parts: i32, bytes: TLBytes) -> Self {
+        UploadSaveBigFilePartBuilder {
+            file_id,
+            file_part,
+            file_total_parts,
+            bytes,
+        }
+    }
+    pub fn build(self) -> UploadSaveBigFilePart {
+        UploadSaveBigFilePart {
+            file_id: self.file_id,
+            file_part: self.file_part,
+            file_total_parts: self.file_total_parts,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `initConnection#69796de9 {X:Type} api_id:int device_model:string system_version:string app_version:string lang_code:string query:!X = X`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitConnection<X: RemoteCall> {
+    pub api_id: i32,
+    pub device_model: String,
+    pub system_version: String,
+    pub app_version: String,
+    pub lang_code: String,
+    pub query: X,
+}
+impl<X: RemoteCall> TLType for InitConnection<X> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x6979_6de9i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "initConnection",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x6979_6de9i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl<X: RemoteCall> TLBareType for InitConnection<X> {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InitConnection {
+            api_id: TLType::tl_read_with_context(input, context)?,
+            device_model: TLType::tl_read_with_context(input, context)?,
+            system_version: TLType::tl_read_with_context(input, context)?,
+            app_version: TLType::tl_read_with_context(input, context)?,
+            lang_code: TLType::tl_read_with_context(input, context)?,
+            query: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.api_id.tl_write(output)?;
+        result += self.device_model.tl_write(output)?;
+        result += self.system_version.tl_write(output)?;
+        result += self.app_version.tl_write(output)?;
+        result += self.lang_code.tl_write(output)?;
+        result += self.query.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.api_id.serialized_len();
+        result += self.device_model.serialized_len();
+        result += self.system_version.serialized_len();
+        result += self.app_version.serialized_len();
+        result += self.lang_code.serialized_len();
+        result += self.query.serialized_len();
+        result
+    }
+}
+impl<X: RemoteCall> RemoteCall for InitConnection<X> {
+    type Return = X::Return;
+}
+pub struct InitConnectionBuilder<X: RemoteCall> {
+    api_id: i32,
+    device_model: String,
+    system_version: String,
+    app_version: String,
+    lang_code: String,
+    query: X,
+}
+impl<X: RemoteCall> InitConnectionBuilder<X> {
+    pub fn new(
+        api_id: i32,
+        device_model: String,
+        system_version: String,
+        app_version: String,
+        lang_code: String,
+        query: X,
+    ) -> Self {
+        InitConnectionBuilder {
+            api_id,
+            device_model,
+            system_version,
+            app_version,
+            lang_code,
+            query,
+        }
+    }
+    pub fn build(self) -> InitConnection<X> {
+        InitConnection {
+            api_id: self.api_id,
+            device_model: self.device_model,
+            system_version: self.system_version,
+            app_version: self.app_version,
+            lang_code: self.lang_code,
+            query: self.query,
+        }
+    }
+}
+/// `help.getSupport#9cdf08cd = help.Support`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HelpGetSupport;
+impl TLType for HelpGetSupport {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x6320_f733i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.getSupport",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x6320_f733i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for HelpGetSupport {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = HelpGetSupport {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for HelpGetSupport {
+    type Return = super::help::Support;
+}
+/// `auth.sendSms#0da9f3e8 phone_number:string phone_code_hash:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthSendSms {
+    pub phone_number: String,
+    pub phone_code_hash: String,
+}
+impl TLType for AuthSendSms {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0da9_f3e8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "auth.sendSms",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0da9_f3e8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AuthSendSms {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AuthSendSms {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.phone_code_hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.phone_code_hash.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AuthSendSms {
+    type Return = bool;
+}
+pub struct AuthSendSmsBuilder {
+    phone_number: String,
+    phone_code_hash: String,
+}
+impl AuthSendSmsBuilder {
+    pub fn new(phone_number: String, phone_code_hash: String) -> Self {
+        AuthSendSmsBuilder {
+            phone_number,
+            phone_code_hash,
+        }
+    }
+    pub fn build(self) -> AuthSendSms {
+        AuthSendSms {
+            phone_number: self.phone_number,
+            phone_code_hash: self.phone_code_hash,
+        }
+    }
+}
+/// `messages.readMessageContents#354b5bc2 id:Vector<int> = Vector<int>`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesReadMessageContents {
+    pub id: Vec<i32>,
+}
+impl TLType for MessagesReadMessageContents {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x354b_5bc2i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.readMessageContents",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x354b_5bc2i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesReadMessageContents {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesReadMessageContents {
+            id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesReadMessageContents {
+    type Return = Vec<i32>;
+}
+pub struct MessagesReadMessageContentsBuilder {
+    id: Vec<i32>,
+}
+impl MessagesReadMessageContentsBuilder {
+    pub fn new(id: Vec<i32>) -> Self {
+        MessagesReadMessageContentsBuilder { id }
+    }
+    pub fn build(self) -> MessagesReadMessageContents {
+        MessagesReadMessageContents { id: self.id }
+    }
+}
+/// `account.checkUsername#2714d86c username:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountCheckUsername {
+    pub username: String,
+}
+impl TLType for AccountCheckUsername {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2714_d86ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.checkUsername",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2714_d86ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountCheckUsername {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountCheckUsername {
+            username: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.username.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.username.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountCheckUsername {
+    type Return = bool;
+}
+pub struct AccountCheckUsernameBuilder {
+    username: String,
+}
+impl AccountCheckUsernameBuilder {
+    pub fn new(username: String) -> Self {
+        AccountCheckUsernameBuilder { username }
+    }
+    pub fn build(self) -> AccountCheckUsername {
+        AccountCheckUsername {
+            username: self.username,
+        }
+    }
+}
+/// `account.updateUsername#3e0bdd7c username:string = User`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUpdateUsername {
+    pub username: String,
+}
+impl TLType for AccountUpdateUsername {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3e0b_dd7ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.updateUsername",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3e0b_dd7ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUpdateUsername {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUpdateUsername {
+            username: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.username.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.username.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUpdateUsername {
+    type Return = super::tl::User;
+}
+pub struct AccountUpdateUsernameBuilder {
+    username: String,
+}
+impl AccountUpdateUsernameBuilder {
+    pub fn new(username: String) -> Self {
+        AccountUpdateUsernameBuilder { username }
+    }
+    pub fn build(self) -> AccountUpdateUsername {
+        AccountUpdateUsername {
+            username: self.username,
+        }
+    }
+}
+/// `contacts.search#11f812d8 q:string limit:int = contacts.Found`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsSearch {
+    pub q: String,
+    pub limit: i32,
+}
+impl TLType for ContactsSearch {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x11f8_12d8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.search",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x11f8_12d8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsSearch {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsSearch {
+            q: TLType::tl_read_with_context(input, context)?,
+            limit: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.q.tl_write(output)?;
+        result += self.limit.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.q.serialized_len();
+        result += self.limit.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsSearch {
+    type Return = super::contacts::Found;
+}
+pub struct ContactsSearchBuilder {
+    q: String,
+    limit: i32,
+}
+impl ContactsSearchBuilder {
+    pub fn new(q: String, limit: i32) -> Self {
+        ContactsSearchBuilder { q, limit }
+    }
+    pub fn build(self) -> ContactsSearch {
+        ContactsSearch {
+            q: self.q,
+            limit: self.limit,
+        }
+    }
+}
+/// `account.getPrivacy#dadbc950 key:InputPrivacyKey = account.PrivacyRules`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountGetPrivacy {
+    pub key: super::tl::InputPrivacyKey,
+}
+impl TLType for AccountGetPrivacy {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2524_36b0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.getPrivacy",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2524_36b0i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountGetPrivacy {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountGetPrivacy {
+            key: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.key.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.key.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountGetPrivacy {
+    type Return = super::account::PrivacyRules;
+}
+pub struct AccountGetPrivacyBuilder {
+    key: super::tl::InputPrivacyKey,
+}
+impl AccountGetPrivacyBuilder {
+    pub fn new(key: super::tl::InputPrivacyKey) -> Self {
+        AccountGetPrivacyBuilder { key }
+    }
+    pub fn build(self) -> AccountGetPrivacy {
+        AccountGetPrivacy { key: self.key }
+    }
+}
+/// `account.setPrivacy#c9f81ce8 key:InputPrivacyKey rules:Vector<InputPrivacyRule> = account.PrivacyRules`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountSetPrivacy {
+    pub key: super::tl::InputPrivacyKey,
+    pub rules: Vec<super::tl::InputPrivacyRule>,
+}
+impl TLType for AccountSetPrivacy {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3607_e318i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.setPrivacy",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3607_e318i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountSetPrivacy {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountSetPrivacy {
+            key: TLType::tl_read_with_context(input, context)?,
+            rules: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.key.tl_write(output)?;
+        result += self.rules.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.key.serialized_len();
+        result += self.rules.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountSetPrivacy {
+    type Return = super::account::PrivacyRules;
+}
+pub struct AccountSetPrivacyBuilder {
+    key: super::tl::InputPrivacyKey,
+    rules: Vec<super::tl::InputPrivacyRule>,
+}
+impl AccountSetPrivacyBuilder {
+    pub fn new(key: super::tl::InputPrivacyKey, rules: Vec<super::tl::InputPrivacyRule>) -> Self {
+        AccountSetPrivacyBuilder { key, rules }
+    }
+    pub fn build(self) -> AccountSetPrivacy {
+        AccountSetPrivacy {
+            key: self.key,
+            rules: self.rules,
+        }
+    }
+}
+/// `account.deleteAccount#418d4e0b reason:string = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountDeleteAccount {
+    pub reason: String,
+}
+impl TLType for AccountDeleteAccount {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x418d_4e0bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.deleteAccount",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x418d_4e0bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountDeleteAccount {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountDeleteAccount {
+            reason: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.reason.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.reason.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountDeleteAccount {
+    type Return = bool;
+}
+pub struct AccountDeleteAccountBuilder {
+    reason: String,
+}
+impl AccountDeleteAccountBuilder {
+    pub fn new(reason: String) -> Self {
+        AccountDeleteAccountBuilder { reason }
+    }
+    pub fn build(self) -> AccountDeleteAccount {
+        AccountDeleteAccount {
+            reason: self.reason,
+        }
+    }
+}
+/// `account.getAccountTTL#08fc711d = AccountDaysTTL`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountGetAccountTtl;
+impl TLType for AccountGetAccountTtl {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x08fc_711di32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.getAccountTTL",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x08fc_711di32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountGetAccountTtl {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountGetAccountTtl {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl RemoteCall for AccountGetAccountTtl {
+    type Return = super::tl::AccountDaysTtl;
+}
+/// `account.setAccountTTL#2442485e ttl:AccountDaysTTL = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountSetAccountTtl {
+    pub ttl: super::tl::AccountDaysTtl,
+}
+impl TLType for AccountSetAccountTtl {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2442_485ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.setAccountTTL",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2442_485ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountSetAccountTtl {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountSetAccountTtl {
+            ttl: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.ttl.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.ttl.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountSetAccountTtl {
+    type Return = bool;
+}
+pub struct AccountSetAccountTtlBuilder {
+    ttl: super::tl::AccountDaysTtl,
+}
+impl AccountSetAccountTtlBuilder {
+    pub fn new(ttl: super::tl::AccountDaysTtl) -> Self {
+        AccountSetAccountTtlBuilder { ttl }
+    }
+    pub fn build(self) -> AccountSetAccountTtl {
+        AccountSetAccountTtl { ttl: self.ttl }
+    }
+}
+/// `invokeWithLayer#da9b0d0d {X:Type} layer:int query:!X = X`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvokeWithLayer<X: RemoteCall> {
+    pub layer: i32,
+    pub query: X,
+}
+impl<X: RemoteCall> TLType for InvokeWithLayer<X> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2564_f2f3i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "invokeWithLayer",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2564_f2f3i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl<X: RemoteCall> TLBareType for InvokeWithLayer<X> {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InvokeWithLayer {
+            layer: TLType::tl_read_with_context(input, context)?,
+            query: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.layer.tl_write(output)?;
+        result += self.query.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.layer.serialized_len();
+        result += self.query.serialized_len();
+        result
+    }
+}
+impl<X: RemoteCall> RemoteCall for InvokeWithLayer<X> {
+    type Return = X::Return;
+}
+pub struct InvokeWithLayerBuilder<X: RemoteCall> {
+    layer: i32,
+    query: X,
+}
+impl<X: RemoteCall> InvokeWithLayerBuilder<X> {
+    pub fn new(layer: i32, query: X) -> Self {
+        InvokeWithLayerBuilder { layer, query }
+    }
+    pub fn build(self) -> InvokeWithLayer<X> {
+        InvokeWithLayer {
+            layer: self.layer,
+            query: self.query,
+        }
+    }
+}
+/// `contacts.resolveUsername#0bf0131c username:string = User`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactsResolveUsername {
+    pub username: String,
+}
+impl TLType for ContactsResolveUsername {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x0bf0_131ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "contacts.resolveUsername",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x0bf0_131ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactsResolveUsername {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactsResolveUsername {
+            username: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.username.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.username.serialized_len();
+        result
+    }
+}
+impl RemoteCall for ContactsResolveUsername {
+    type Return = super::tl::User;
+}
+pub struct ContactsResolveUsernameBuilder {
+    username: String,
+}
+impl ContactsResolveUsernameBuilder {
+    pub fn new(username: String) -> Self {
+        ContactsResolveUsernameBuilder { username }
+    }
+    pub fn build(self) -> ContactsResolveUsername {
+        ContactsResolveUsername {
+            username: self.username,
+        }
+    }
+}
+/// `account.sendChangePhoneCode#a407a8f4 phone_number:string = account.SentChangePhoneCode`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountSendChangePhoneCode {
+    pub phone_number: String,
+}
+impl TLType for AccountSendChangePhoneCode {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5bf8_570ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.sendChangePhoneCode",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5bf8_570ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountSendChangePhoneCode {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountSendChangePhoneCode {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountSendChangePhoneCode {
+    type Return = super::account::SentChangePhoneCode;
+}
+pub struct AccountSendChangePhoneCodeBuilder {
+    phone_number: String,
+}
+impl AccountSendChangePhoneCodeBuilder {
+    pub fn new(phone_number: String) -> Self {
+        AccountSendChangePhoneCodeBuilder { phone_number }
+    }
+    pub fn build(self) -> AccountSendChangePhoneCode {
+        AccountSendChangePhoneCode {
+            phone_number: self.phone_number,
+        }
+    }
+}
+/// `account.changePhone#70c32edb phone_number:string phone_code_hash:string phone_code:string = User`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountChangePhone {
+    pub phone_number: String,
+    pub phone_code_hash: String,
+    pub phone_code: String,
+}
+impl TLType for AccountChangePhone {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x70c3_2edbi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.changePhone",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x70c3_2edbi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountChangePhone {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountChangePhone {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            phone_code_hash: TLType::tl_read_with_context(input, context)?,
+            phone_code: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.phone_code_hash.tl_write(output)?;
+        result += self.phone_code.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.phone_code_hash.serialized_len();
+        result += self.phone_code.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountChangePhone {
+    type Return = super::tl::User;
+}
+pub struct AccountChangePhoneBuilder {
+    phone_number: String,
+    phone_code_hash: String,
+    phone_code: String,
+}
+impl AccountChangePhoneBuilder {
+    pub fn new(phone_number: String, phone_code_hash: String, phone_code: String) -> Self {
+        AccountChangePhoneBuilder {
+            phone_number,
+            phone_code_hash,
+            phone_code,
+        }
+    }
+    pub fn build(self) -> AccountChangePhone {
+        AccountChangePhone {
+            phone_number: self.phone_number,
+            phone_code_hash: self.phone_code_hash,
+            phone_code: self.phone_code,
+        }
+    }
+}
+/// `messages.getStickers#ae22e045 emoticon:string hash:string = messages.Stickers`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetStickers {
+    pub emoticon: String,
+    pub hash: String,
+}
+impl TLType for MessagesGetStickers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x51dd_1fbbi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getStickers",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x51dd_1fbbi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetStickers {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetStickers {
+            emoticon: TLType::tl_read_with_context(input, context)?,
+            hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.emoticon.tl_write(output)?;
+        result += self.hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.emoticon.serialized_len();
+        result += self.hash.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetStickers {
+    type Return = super::messages::Stickers;
+}
+pub struct MessagesGetStickersBuilder {
+    emoticon: String,
+    hash: String,
+}
+impl MessagesGetStickersBuilder {
+    pub fn new(emoticon: String, hash: String) -> Self {
+        MessagesGetStickersBuilder { emoticon, hash }
+    }
+    pub fn build(self) -> MessagesGetStickers {
+        MessagesGetStickers {
+            emoticon: self.emoticon,
+            hash: self.hash,
+        }
+    }
+}
+/// `messages.getAllStickers#aa3bc868 hash:string = messages.AllStickers`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessagesGetAllStickers {
+    pub hash: String,
+}
+impl TLType for MessagesGetAllStickers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x55c4_3798i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.getAllStickers",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x55c4_3798i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for MessagesGetAllStickers {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = MessagesGetAllStickers {
+            hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.hash.serialized_len();
+        result
+    }
+}
+impl RemoteCall for MessagesGetAllStickers {
+    type Return = super::messages::AllStickers;
+}
+pub struct MessagesGetAllStickersBuilder {
+    hash: String,
+}
+impl MessagesGetAllStickersBuilder {
+    pub fn new(hash: String) -> Self {
+        MessagesGetAllStickersBuilder { hash }
+    }
+    pub fn build(self) -> MessagesGetAllStickers {
+        MessagesGetAllStickers { hash: self.hash }
+    }
+}
+/// `account.updateDeviceLocked#38df3532 period:int = Bool`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountUpdateDeviceLocked {
+    pub period: i32,
+}
+impl TLType for AccountUpdateDeviceLocked {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x38df_3532i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "account.updateDeviceLocked",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x38df_3532i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountUpdateDeviceLocked {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountUpdateDeviceLocked {
+            period: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.period.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.period.serialized_len();
+        result
+    }
+}
+impl RemoteCall for AccountUpdateDeviceLocked {
+    type Return = bool;
+}
+pub struct AccountUpdateDeviceLockedBuilder {
+    period: i32,
+}
+impl AccountUpdateDeviceLockedBuilder {
+    pub fn new(period: i32) -> Self {
+        AccountUpdateDeviceLockedBuilder { period }
+    }
+    pub fn build(self) -> AccountUpdateDeviceLocked {
+        AccountUpdateDeviceLocked {
+            period: self.period,
+        }
+    }
+}
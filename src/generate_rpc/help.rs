@@ -0,0 +1,353 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AppUpdate {
+    /// `help.appUpdate#8987f311 id:int critical:Bool url:string text:string = help.AppUpdate`
+    AppUpdate {
+        id: i32,
+        critical: bool,
+        url: String,
+        text: String,
+    },
+    /// `help.noAppUpdate#c45a6536 = help.AppUpdate`
+    NoAppUpdate,
+}
+impl TLType for AppUpdate {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x7678_0cefi32 => AppUpdate::AppUpdate {
+                id: TLType::tl_read_with_context(input, context)?,
+                critical: TLType::tl_read_with_context(input, context)?,
+                url: TLType::tl_read_with_context(input, context)?,
+                text: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x3ba5_9acai32 => AppUpdate::NoAppUpdate {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "help.AppUpdate",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            AppUpdate::AppUpdate {
+                id,
+                critical,
+                url,
+                text,
+            } => {
+                (-0x7678_0cefi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += critical.tl_write(output)?;
+                result += url.tl_write(output)?;
+                result += text.tl_write(output)?;
+            }
+            AppUpdate::NoAppUpdate => {
+                (-0x3ba5_9acai32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            AppUpdate::AppUpdate {
+                id,
+                critical,
+                url,
+                text,
+            } => {
+                result += id.serialized_len();
+                result += critical.serialized_len();
+                result += url.serialized_len();
+                result += text.serialized_len();
+            }
+            AppUpdate::NoAppUpdate => {}
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AppUpdateRef<'a> {
+    /// `help.appUpdate#8987f311 id:int critical:Bool url:string text:string = help.AppUpdate`
+    AppUpdate {
+        id: i32,
+        critical: bool,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        url: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        text: &'a str,
+    },
+    /// `help.noAppUpdate#c45a6536 = help.AppUpdate`
+    NoAppUpdate,
+}
+impl<'a> TLDeserialize<'a> for AppUpdateRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x7678_0cefi32 => AppUpdateRef::AppUpdate {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                critical: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                url: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                text: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x3ba5_9acai32 => AppUpdateRef::NoAppUpdate {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "help.AppUpdate",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AppUpdateAppUpdateBuilder {
+    id: i32,
+    critical: bool,
+    url: String,
+    text: String,
+}
+impl AppUpdateAppUpdateBuilder {
+    pub fn new(id: i32, critical: bool, url: String, text: String) -> Self {
+        AppUpdateAppUpdateBuilder {
+            id,
+            critical,
+            url,
+            text,
+        }
+    }
+    pub fn build(self) -> AppUpdate {
+        AppUpdate::AppUpdate {
+            id: self.id,
+            critical: self.critical,
+            url: self.url,
+            text: self.text,
+        }
+    }
+}
+/// `help.inviteText#18cb9f78 message:string = help.InviteText`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InviteText {
+    pub message: String,
+}
+impl TLType for InviteText {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x18cb_9f78i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.InviteText",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x18cb_9f78i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InviteText {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InviteText {
+            message: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.message.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.message.serialized_len();
+        result
+    }
+}
+/// `help.inviteText#18cb9f78 message:string = help.InviteText`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InviteTextRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: &'a str,
+}
+impl<'a> TLDeserialize<'a> for InviteTextRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x18cb_9f78i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.InviteText",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InviteTextRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InviteTextRef {
+            message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InviteTextBuilder {
+    message: String,
+}
+impl InviteTextBuilder {
+    pub fn new(message: String) -> Self {
+        InviteTextBuilder { message }
+    }
+    pub fn build(self) -> InviteText {
+        InviteText {
+            message: self.message,
+        }
+    }
+}
+/// `help.support#17c6b5f6 phone_number:string user:User = help.Support`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Support {
+    pub phone_number: String,
+    pub user: super::tl::User,
+}
+impl TLType for Support {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x17c6_b5f6i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.Support",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x17c6_b5f6i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Support {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Support {
+            phone_number: TLType::tl_read_with_context(input, context)?,
+            user: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.phone_number.tl_write(output)?;
+        result += self.user.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.phone_number.serialized_len();
+        result += self.user.serialized_len();
+        result
+    }
+}
+/// `help.support#17c6b5f6 phone_number:string user:User = help.Support`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupportRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub phone_number: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub user: super::tl::UserRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for SupportRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x17c6_b5f6i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "help.Support",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for SupportRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = SupportRef {
+            phone_number: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            user: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SupportBuilder {
+    phone_number: String,
+    user: super::tl::User,
+}
+impl SupportBuilder {
+    pub fn new(phone_number: String, user: super::tl::User) -> Self {
+        SupportBuilder { phone_number, user }
+    }
+    pub fn build(self) -> Support {
+        Support {
+            phone_number: self.phone_number,
+            user: self.user,
+        }
+    }
+}
@@ -0,0 +1,2031 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `messages.affectedHistory#b7de36f2 pts:int seq:int offset:int = messages.AffectedHistory`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AffectedHistory {
+    pub pts: i32,
+    pub seq: i32,
+    pub offset: i32,
+}
+impl TLType for AffectedHistory {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x4821_c90ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.AffectedHistory",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x4821_c90ei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AffectedHistory {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AffectedHistory {
+            pts: TLType::tl_read_with_context(input, context)?,
+            seq: TLType::tl_read_with_context(input, context)?,
+            offset: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.pts.tl_write(output)?;
+        result += self.seq.tl_write(output)?;
+        result += self.offset.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.pts.serialized_len();
+        result += self.seq.serialized_len();
+        result += self.offset.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for AffectedHistory {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x4821_c90ei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.AffectedHistory",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for AffectedHistory {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AffectedHistory {
+            pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            offset: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AffectedHistoryBuilder {
+    pts: i32,
+    seq: i32,
+    offset: i32,
+}
+impl AffectedHistoryBuilder {
+    pub fn new(pts: i32, seq: i32, offset: i32) -> Self {
+        AffectedHistoryBuilder { pts, seq, offset }
+    }
+    pub fn build(self) -> AffectedHistory {
+        AffectedHistory {
+            pts: self.pts,
+            seq: self.seq,
+            offset: self.offset,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AllStickers {
+    /// `messages.allStickersNotModified#e86602c3 = messages.AllStickers`
+    AllStickersNotModified,
+    /// `messages.allStickers#dcef3102 hash:string packs:Vector<StickerPack> documents:Vector<Document> = messages.AllStickers`
+    AllStickers {
+        hash: String,
+        packs: Vec<super::tl::StickerPack>,
+        documents: Vec<super::tl::Document>,
+    },
+}
+impl TLType for AllStickers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x1799_fd3di32 => AllStickers::AllStickersNotModified {},
+            -0x2310_cefei32 => AllStickers::AllStickers {
+                hash: TLType::tl_read_with_context(input, context)?,
+                packs: TLType::tl_read_with_context(input, context)?,
+                documents: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.AllStickers",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            AllStickers::AllStickersNotModified => {
+                (-0x1799_fd3di32).tl_write(output)?;
+            }
+            AllStickers::AllStickers {
+                hash,
+                packs,
+                documents,
+            } => {
+                (-0x2310_cefei32).tl_write(output)?;
+                result += hash.tl_write(output)?;
+                result += packs.tl_write(output)?;
+                result += documents.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            AllStickers::AllStickersNotModified => {}
+            AllStickers::AllStickers {
+                hash,
+                packs,
+                documents,
+            } => {
+                result += hash.serialized_len();
+                result += packs.serialized_len();
+                result += documents.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AllStickersRef<'a> {
+    /// `messages.allStickersNotModified#e86602c3 = messages.AllStickers`
+    AllStickersNotModified,
+    /// `messages.allStickers#dcef3102 hash:string packs:Vector<StickerPack> documents:Vector<Document> = messages.AllStickers`
+    AllStickers {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        hash: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        packs: Vec<super::tl::StickerPackRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        documents: Vec<super::tl::DocumentRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for AllStickersRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x1799_fd3di32 => AllStickersRef::AllStickersNotModified {},
+            -0x2310_cefei32 => AllStickersRef::AllStickers {
+                hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                packs: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                documents: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.AllStickers",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AllStickersAllStickersBuilder {
+    hash: String,
+    packs: Vec<super::tl::StickerPack>,
+    documents: Vec<super::tl::Document>,
+}
+impl AllStickersAllStickersBuilder {
+    pub fn new(
+        hash: String,
+        packs: Vec<super::tl::StickerPack>,
+        documents: Vec<super::tl::Document>,
+    ) -> Self {
+        AllStickersAllStickersBuilder {
+            hash,
+            packs,
+            documents,
+        }
+    }
+    pub fn build(self) -> AllStickers {
+        AllStickers::AllStickers {
+            hash: self.hash,
+            packs: self.packs,
+            documents: self.documents,
+        }
+    }
+}
+/// `messages.chatFull#e5d7d19c full_chat:ChatFull chats:Vector<Chat> users:Vector<User> = messages.ChatFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatFull {
+    pub full_chat: super::tl::ChatFull,
+    pub chats: Vec<super::tl::Chat>,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for ChatFull {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1a28_2e64i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.ChatFull",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1a28_2e64i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ChatFull {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatFull {
+            full_chat: TLType::tl_read_with_context(input, context)?,
+            chats: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.full_chat.tl_write(output)?;
+        result += self.chats.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.full_chat.serialized_len();
+        result += self.chats.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `messages.chatFull#e5d7d19c full_chat:ChatFull chats:Vector<Chat> users:Vector<User> = messages.ChatFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatFullRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub full_chat: super::tl::ChatFullRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub chats: Vec<super::tl::ChatRef<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for ChatFullRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x1a28_2e64i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.ChatFull",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ChatFullRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatFullRef {
+            full_chat: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatFullBuilder {
+    full_chat: super::tl::ChatFull,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl ChatFullBuilder {
+    pub fn new(
+        full_chat: super::tl::ChatFull,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        ChatFullBuilder {
+            full_chat,
+            chats,
+            users,
+        }
+    }
+    pub fn build(self) -> ChatFull {
+        ChatFull {
+            full_chat: self.full_chat,
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+/// `messages.chats#8150cbd8 chats:Vector<Chat> users:Vector<User> = messages.Chats`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chats {
+    pub chats: Vec<super::tl::Chat>,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for Chats {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x7eaf_3428i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.Chats",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x7eaf_3428i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Chats {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Chats {
+            chats: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chats.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chats.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `messages.chats#8150cbd8 chats:Vector<Chat> users:Vector<User> = messages.Chats`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatsRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub chats: Vec<super::tl::ChatRef<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for ChatsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x7eaf_3428i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "messages.Chats",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ChatsRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatsRef {
+            chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatsBuilder {
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl ChatsBuilder {
+    pub fn new(chats: Vec<super::tl::Chat>, users: Vec<super::tl::User>) -> Self {
+        ChatsBuilder { chats, users }
+    }
+    pub fn build(self) -> Chats {
+        Chats {
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DhConfig {
+    /// `messages.dhConfigNotModified#c0e24635 random:bytes = messages.DhConfig`
+    DhConfigNotModified { random: TLBytes },
+    /// `messages.dhConfig#2c221edd g:int p:bytes version:int random:bytes = messages.DhConfig`
+    DhConfig {
+        g: i32,
+        p: TLBytes,
+        version: i32,
+        random: TLBytes,
+    },
+}
+impl TLType for DhConfig {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x3f1d_b9cbi32 => DhConfig::DhConfigNotModified {
+                random: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2c22_1eddi32 => DhConfig::DhConfig {
+                g: TLType::tl_read_with_context(input, context)?,
+                p: TLType::tl_read_with_context(input, context)?,
+                version: TLType::tl_read_with_context(input, context)?,
+                random: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.DhConfig",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            DhConfig::DhConfigNotModified { random } => {
+                (-0x3f1d_b9cbi32).tl_write(output)?;
+                result += random.tl_write(output)?;
+            }
+            DhConfig::DhConfig {
+                g,
+                p,
+                version,
+                random,
+            } => {
+                (0x2c22_1eddi32).tl_write(output)?;
+                result += g.tl_write(output)?;
+                result += p.tl_write(output)?;
+                result += version.tl_write(output)?;
+                result += random.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            DhConfig::DhConfigNotModified { random } => {
+                result += random.serialized_len();
+            }
+            DhConfig::DhConfig {
+                g,
+                p,
+                version,
+                random,
+            } => {
+                result += g.serialized_len();
+                result += p.serialized_len();
+                result += version.serialized_len();
+                result += random.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DhConfigRef<'a> {
+    /// `messages.dhConfigNotModified#c0e24635 random:bytes = messages.DhConfig`
+    DhConfigNotModified {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        random: &'a [u8],
+    },
+    /// `messages.dhConfig#2c221edd g:int p:bytes version:int random:bytes = messages.DhConfig`
+    DhConfig {
+        g: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        p: &'a [u8],
+        version: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        random: &'a [u8],
+    },
+}
+impl<'a> TLDeserialize<'a> for DhConfigRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x3f1d_b9cbi32 => DhConfigRef::DhConfigNotModified {
+                random: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2c22_1eddi32 => DhConfigRef::DhConfig {
+                g: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                p: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                version: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                random: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.DhConfig",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DhConfigDhConfigNotModifiedBuilder {
+    random: TLBytes,
+}
+impl DhConfigDhConfigNotModifiedBuilder {
+    pub fn new(random: TLBytes) -> Self {
+        DhConfigDhConfigNotModifiedBuilder { random }
+    }
+    pub fn build(self) -> DhConfig {
+        DhConfig::DhConfigNotModified {
+            random: self.random,
+        }
+    }
+}
+pub struct DhConfigDhConfigBuilder {
+    g: i32,
+    p: TLBytes,
+    version: i32,
+    random: TLBytes,
+}
+impl DhConfigDhConfigBuilder {
+    pub fn new(g: i32, p: TLBytes, version: i32, random: TLBytes) -> Self {
+        DhConfigDhConfigBuilder {
+            g,
+            p,
+            version,
+            random,
+        }
+    }
+    pub fn build(self) -> DhConfig {
+        DhConfig::DhConfig {
+            g: self.g,
+            p: self.p,
+            version: self.version,
+            random: self.random,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dialogs {
+    /// `messages.dialogs#15ba6c40 dialogs:Vector<Dialog> messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Dialogs`
+    Dialogs {
+        dialogs: Vec<super::tl::Dialog>,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    },
+    /// `messages.dialogsSlice#71e094f3 count:int dialogs:Vector<Dialog> messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Dialogs`
+    DialogsSlice {
+        count: i32,
+        dialogs: Vec<super::tl::Dialog>,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    },
+}
+impl TLType for Dialogs {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x15ba_6c40i32 => Dialogs::Dialogs {
+                dialogs: TLType::tl_read_with_context(input, context)?,
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            0x71e0_94f3i32 => Dialogs::DialogsSlice {
+                count: TLType::tl_read_with_context(input, context)?,
+                dialogs: TLType::tl_read_with_context(input, context)?,
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Dialogs",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Dialogs::Dialogs {
+                dialogs,
+                messages,
+                chats,
+                users,
+            } => {
+                (0x15ba_6c40i32).tl_write(output)?;
+                result += dialogs.tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            Dialogs::DialogsSlice {
+                count,
+                dialogs,
+                messages,
+                chats,
+                users,
+            } => {
+                (0x71e0_94f3i32).tl_write(output)?;
+                result += count.tl_write(output)?;
+                result += dialogs.tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Dialogs::Dialogs {
+                dialogs,
+                messages,
+                chats,
+                users,
+            } => {
+                result += dialogs.serialized_len();
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+            }
+            Dialogs::DialogsSlice {
+                count,
+                dialogs,
+                messages,
+                chats,
+                users,
+            } => {
+                result += count.serialized_len();
+                result += dialogs.serialized_len();
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DialogsRef<'a> {
+    /// `messages.dialogs#15ba6c40 dialogs:Vector<Dialog> messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Dialogs`
+    Dialogs {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        dialogs: Vec<super::tl::DialogRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+    /// `messages.dialogsSlice#71e094f3 count:int dialogs:Vector<Dialog> messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Dialogs`
+    DialogsSlice {
+        count: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        dialogs: Vec<super::tl::DialogRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for DialogsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x15ba_6c40i32 => DialogsRef::Dialogs {
+                dialogs: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x71e0_94f3i32 => DialogsRef::DialogsSlice {
+                count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                dialogs: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Dialogs",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DialogsDialogsBuilder {
+    dialogs: Vec<super::tl::Dialog>,
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl DialogsDialogsBuilder {
+    pub fn new(
+        dialogs: Vec<super::tl::Dialog>,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        DialogsDialogsBuilder {
+            dialogs,
+            messages,
+            chats,
+            users,
+        }
+    }
+    pub fn build(self) -> Dialogs {
+        Dialogs::Dialogs {
+            dialogs: self.dialogs,
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+pub struct DialogsDialogsSliceBuilder {
+    count: i32,
+    dialogs: Vec<super::tl::Dialog>,
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl DialogsDialogsSliceBuilder {
+    pub fn new(
+        count: i32,
+        dialogs: Vec<super::tl::Dialog>,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        DialogsDialogsSliceBuilder {
+            count,
+            dialogs,
+            messages,
+            chats,
+            users,
+        }
+    }
+    pub fn build(self) -> Dialogs {
+        Dialogs::DialogsSlice {
+            count: self.count,
+            dialogs: self.dialogs,
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Messages {
+    /// `messages.messages#8c718e87 messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Messages`
+    Messages {
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    },
+    /// `messages.messagesSlice#0b446ae3 count:int messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Messages`
+    MessagesSlice {
+        count: i32,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    },
+}
+impl TLType for Messages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x738e_7179i32 => Messages::Messages {
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            0x0b44_6ae3i32 => Messages::MessagesSlice {
+                count: TLType::tl_read_with_context(input, context)?,
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Messages",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Messages::Messages {
+                messages,
+                chats,
+                users,
+            } => {
+                (-0x738e_7179i32).tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            Messages::MessagesSlice {
+                count,
+                messages,
+                chats,
+                users,
+            } => {
+                (0x0b44_6ae3i32).tl_write(output)?;
+                result += count.tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Messages::Messages {
+                messages,
+                chats,
+                users,
+            } => {
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+            }
+            Messages::MessagesSlice {
+                count,
+                messages,
+                chats,
+                users,
+            } => {
+                result += count.serialized_len();
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessagesRef<'a> {
+    /// `messages.messages#8c718e87 messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Messages`
+    Messages {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+    /// `messages.messagesSlice#0b446ae3 count:int messages:Vector<Message> chats:Vector<Chat> users:Vector<User> = messages.Messages`
+    MessagesSlice {
+        count: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for MessagesRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x738e_7179i32 => MessagesRef::Messages {
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x0b44_6ae3i32 => MessagesRef::MessagesSlice {
+                count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Messages",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessagesMessagesBuilder {
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl MessagesMessagesBuilder {
+    pub fn new(
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        MessagesMessagesBuilder {
+            messages,
+            chats,
+            users,
+        }
+    }
+    pub fn build(self) -> Messages {
+        Messages::Messages {
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+pub struct MessagesMessagesSliceBuilder {
+    count: i32,
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+}
+impl MessagesMessagesSliceBuilder {
+    pub fn new(
+        count: i32,
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+    ) -> Self {
+        MessagesMessagesSliceBuilder {
+            count,
+            messages,
+            chats,
+            users,
+        }
+    }
+    pub fn build(self) -> Messages {
+        Messages::MessagesSlice {
+            count: self.count,
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentEncryptedMessage {
+    /// `messages.sentEncryptedMessage#560f8935 date:int = messages.SentEncryptedMessage`
+    SentEncryptedMessage { date: i32 },
+    /// `messages.sentEncryptedFile#9493ff32 date:int file:EncryptedFile = messages.SentEncryptedMessage`
+    SentEncryptedFile {
+        date: i32,
+        file: super::tl::EncryptedFile,
+    },
+}
+impl TLType for SentEncryptedMessage {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x560f_8935i32 => SentEncryptedMessage::SentEncryptedMessage {
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x6b6c_00cei32 => SentEncryptedMessage::SentEncryptedFile {
+                date: TLType::tl_read_with_context(input, context)?,
+                file: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.SentEncryptedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            SentEncryptedMessage::SentEncryptedMessage { date } => {
+                (0x560f_8935i32).tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+            SentEncryptedMessage::SentEncryptedFile { date, file } => {
+                (-0x6b6c_00cei32).tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += file.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            SentEncryptedMessage::SentEncryptedMessage { date } => {
+                result += date.serialized_len();
+            }
+            SentEncryptedMessage::SentEncryptedFile { date, file } => {
+                result += date.serialized_len();
+                result += file.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for SentEncryptedMessage {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x560f_8935i32 => SentEncryptedMessage::SentEncryptedMessage {
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x6b6c_00cei32 => SentEncryptedMessage::SentEncryptedFile {
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.SentEncryptedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SentEncryptedMessageSentEncryptedMessageBuilder {
+    date: i32,
+}
+impl SentEncryptedMessageSentEncryptedMessageBuilder {
+    pub fn new(date: i32) -> Self {
+        SentEncryptedMessageSentEncryptedMessageBuilder { date }
+    }
+    pub fn build(self) -> SentEncryptedMessage {
+        SentEncryptedMessage::SentEncryptedMessage { date: self.date }
+    }
+}
+pub struct SentEncryptedMessageSentEncryptedFileBuilder {
+    date: i32,
+    file: super::tl::EncryptedFile,
+}
+impl SentEncryptedMessageSentEncryptedFileBuilder {
+    pub fn new(date: i32, file: super::tl::EncryptedFile) -> Self {
+        SentEncryptedMessageSentEncryptedFileBuilder { date, file }
+    }
+    pub fn build(self) -> SentEncryptedMessage {
+        SentEncryptedMessage::SentEncryptedFile {
+            date: self.date,
+            file: self.file,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentMessage {
+    /// `messages.sentMessage#d1f4d35c id:int date:int pts:int seq:int = messages.SentMessage`
+    SentMessage {
+        id: i32,
+        date: i32,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.sentMessageLink#e9db4a3f id:int date:int pts:int seq:int links:Vector<contacts.Link> = messages.SentMessage`
+    SentMessageLink {
+        id: i32,
+        date: i32,
+        pts: i32,
+        seq: i32,
+        links: Vec<super::contacts::Link>,
+    },
+}
+impl TLType for SentMessage {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x2e0b_2ca4i32 => SentMessage::SentMessage {
+                id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x1624_b5c1i32 => SentMessage::SentMessageLink {
+                id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+                links: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.SentMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            SentMessage::SentMessage { id, date, pts, seq } => {
+                (-0x2e0b_2ca4i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            SentMessage::SentMessageLink {
+                id,
+                date,
+                pts,
+                seq,
+                links,
+            } => {
+                (-0x1624_b5c1i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+                result += links.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            SentMessage::SentMessage { id, date, pts, seq } => {
+                result += id.serialized_len();
+                result += date.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+            }
+            SentMessage::SentMessageLink {
+                id,
+                date,
+                pts,
+                seq,
+                links,
+            } => {
+                result += id.serialized_len();
+                result += date.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+                result += links.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SentMessageRef<'a> {
+    /// `messages.sentMessage#d1f4d35c id:int date:int pts:int seq:int = messages.SentMessage`
+    SentMessage {
+        id: i32,
+        date: i32,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.sentMessageLink#e9db4a3f id:int date:int pts:int seq:int links:Vector<contacts.Link> = messages.SentMessage`
+    SentMessageLink {
+        id: i32,
+        date: i32,
+        pts: i32,
+        seq: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        links: Vec<super::contacts::LinkRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for SentMessageRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x2e0b_2ca4i32 => SentMessageRef::SentMessage {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x1624_b5c1i32 => SentMessageRef::SentMessageLink {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                links: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.SentMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct SentMessageSentMessageBuilder {
+    id: i32,
+    date: i32,
+    pts: i32,
+    seq: i32,
+}
+impl SentMessageSentMessageBuilder {
+    pub fn new(id: i32, date: i32, pts: i32, seq: i32) -> Self {
+        SentMessageSentMessageBuilder { id, date, pts, seq }
+    }
+    pub fn build(self) -> SentMessage {
+        SentMessage::SentMessage {
+            id: self.id,
+            date: self.date,
+            pts: self.pts,
+            seq: self.seq,
+        }
+    }
+}
+pub struct SentMessageSentMessageLinkBuilder {
+    id: i32,
+    date: i32,
+    pts: i32,
+    seq: i32,
+    links: Vec<super::contacts::Link>,
+}
+impl SentMessageSentMessageLinkBuilder {
+    pub fn new(id: i32, date: i32, pts: i32, seq: i32, links: Vec<super::contacts::Link>) -> Self {
+        SentMessageSentMessageLinkBuilder {
+            id,
+            date,
+            pts,
+            seq,
+            links,
+        }
+    }
+    pub fn build(self) -> SentMessage {
+        SentMessage::SentMessageLink {
+            id: self.id,
+            date: self.date,
+            pts: self.pts,
+            seq: self.seq,
+            links: self.links,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatedMessage {
+    /// `messages.statedMessage#d07ae726 message:Message chats:Vector<Chat> users:Vector<User> pts:int seq:int = messages.StatedMessage`
+    StatedMessage {
+        message: super::tl::Message,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.statedMessageLink#a9af2881 message:Message chats:Vector<Chat> users:Vector<User> links:Vector<contacts.Link> pts:int seq:int = messages.StatedMessage`
+    StatedMessageLink {
+        message: super::tl::Message,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        links: Vec<super::contacts::Link>,
+        pts: i32,
+        seq: i32,
+    },
+}
+impl TLType for StatedMessage {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x2f85_18dai32 => StatedMessage::StatedMessage {
+                message: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x5650_d77fi32 => StatedMessage::StatedMessageLink {
+                message: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                links: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.StatedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            StatedMessage::StatedMessage {
+                message,
+                chats,
+                users,
+                pts,
+                seq,
+            } => {
+                (-0x2f85_18dai32).tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            StatedMessage::StatedMessageLink {
+                message,
+                chats,
+                users,
+                links,
+                pts,
+                seq,
+            } => {
+                (-0x5650_d77fi32).tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += links.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            StatedMessage::StatedMessage {
+                message,
+                chats,
+                users,
+                pts,
+                seq,
+            } => {
+                result += message.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+            }
+            StatedMessage::StatedMessageLink {
+                message,
+                chats,
+                users,
+                links,
+                pts,
+                seq,
+            } => {
+                result += message.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += links.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatedMessageRef<'a> {
+    /// `messages.statedMessage#d07ae726 message:Message chats:Vector<Chat> users:Vector<User> pts:int seq:int = messages.StatedMessage`
+    StatedMessage {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: super::tl::MessageRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.statedMessageLink#a9af2881 message:Message chats:Vector<Chat> users:Vector<User> links:Vector<contacts.Link> pts:int seq:int = messages.StatedMessage`
+    StatedMessageLink {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: super::tl::MessageRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        links: Vec<super::contacts::LinkRef<'a>>,
+        pts: i32,
+        seq: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for StatedMessageRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x2f85_18dai32 => StatedMessageRef::StatedMessage {
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x5650_d77fi32 => StatedMessageRef::StatedMessageLink {
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                links: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.StatedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct StatedMessageStatedMessageBuilder {
+    message: super::tl::Message,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    pts: i32,
+    seq: i32,
+}
+impl StatedMessageStatedMessageBuilder {
+    pub fn new(
+        message: super::tl::Message,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        pts: i32,
+        seq: i32,
+    ) -> Self {
+        StatedMessageStatedMessageBuilder {
+            message,
+            chats,
+            users,
+            pts,
+            seq,
+        }
+    }
+    pub fn build(self) -> StatedMessage {
+        StatedMessage::StatedMessage {
+            message: self.message,
+            chats: self.chats,
+            users: self.users,
+            pts: self.pts,
+            seq: self.seq,
+        }
+    }
+}
+pub struct StatedMessageStatedMessageLinkBuilder {
+    message: super::tl::Message,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    links: Vec<super::contacts::Link>,
+    pts: i32,
+    seq: i32,
+}
+impl StatedMessageStatedMessageLinkBuilder {
+    pub fn new(
+        message: super::tl::Message,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        links: Vec<super::contacts::Link>,
+        pts: i32,
+        seq: i32,
+    ) -> Self {
+        StatedMessageStatedMessageLinkBuilder {
+            message,
+            chats,
+            users,
+            links,
+            pts,
+            seq,
+        }
+    }
+    pub fn build(self) -> StatedMessage {
+        StatedMessage::StatedMessageLink {
+            message: self.message,
+            chats: self.chats,
+            users: self.users,
+            links: self.links,
+            pts: self.pts,
+            seq: self.seq,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatedMessages {
+    /// `messages.statedMessages#969478bb messages:Vector<Message> chats:Vector<Chat> users:Vector<User> pts:int seq:int = messages.StatedMessages`
+    StatedMessages {
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.statedMessagesLinks#3e74f5c6 messages:Vector<Message> chats:Vector<Chat> users:Vector<User> links:Vector<contacts.Link> pts:int seq:int = messages.StatedMessages`
+    StatedMessagesLinks {
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        links: Vec<super::contacts::Link>,
+        pts: i32,
+        seq: i32,
+    },
+}
+impl TLType for StatedMessages {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x696b_8745i32 => StatedMessages::StatedMessages {
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            0x3e74_f5c6i32 => StatedMessages::StatedMessagesLinks {
+                messages: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                links: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.StatedMessages",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            StatedMessages::StatedMessages {
+                messages,
+                chats,
+                users,
+                pts,
+                seq,
+            } => {
+                (-0x696b_8745i32).tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            StatedMessages::StatedMessagesLinks {
+                messages,
+                chats,
+                users,
+                links,
+                pts,
+                seq,
+            } => {
+                (0x3e74_f5c6i32).tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += links.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            StatedMessages::StatedMessages {
+                messages,
+                chats,
+                users,
+                pts,
+                seq,
+            } => {
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+            }
+            StatedMessages::StatedMessagesLinks {
+                messages,
+                chats,
+                users,
+                links,
+                pts,
+                seq,
+            } => {
+                result += messages.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += links.serialized_len();
+                result += pts.serialized_len();
+                result += seq.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatedMessagesRef<'a> {
+    /// `messages.statedMessages#969478bb messages:Vector<Message> chats:Vector<Chat> users:Vector<User> pts:int seq:int = messages.StatedMessages`
+    StatedMessages {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        pts: i32,
+        seq: i32,
+    },
+    /// `messages.statedMessagesLinks#3e74f5c6 messages:Vector<Message> chats:Vector<Chat> users:Vector<User> links:Vector<contacts.Link> pts:int seq:int = messages.StatedMessages`
+    StatedMessagesLinks {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        links: Vec<super::contacts::LinkRef<'a>>,
+        pts: i32,
+        seq: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for StatedMessagesRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x696b_8745i32 => StatedMessagesRef::StatedMessages {
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x3e74_f5c6i32 => StatedMessagesRef::StatedMessagesLinks {
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                links: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.StatedMessages",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct StatedMessagesStatedMessagesBuilder {
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    pts: i32,
+    seq: i32,
+}
+impl StatedMessagesStatedMessagesBuilder {
+    pub fn new(
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        pts: i32,
+        seq: i32,
+    ) -> Self {
+        StatedMessagesStatedMessagesBuilder {
+            messages,
+            chats,
+            users,
+            pts,
+            seq,
+        }
+    }
+    pub fn build(self) -> StatedMessages {
+        StatedMessages::StatedMessages {
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+            pts: self.pts,
+            seq: self.seq,
+        }
+    }
+}
+pub struct StatedMessagesStatedMessagesLinksBuilder {
+    messages: Vec<super::tl::Message>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    links: Vec<super::contacts::Link>,
+    pts: i32,
+    seq: i32,
+}
+impl StatedMessagesStatedMessagesLinksBuilder {
+    pub fn new(
+        messages: Vec<super::tl::Message>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        links: Vec<super::contacts::Link>,
+        pts: i32,
+        seq: i32,
+    ) -> Self {
+        StatedMessagesStatedMessagesLinksBuilder {
+            messages,
+            chats,
+            users,
+            links,
+            pts,
+            seq,
+        }
+    }
+    pub fn build(self) -> StatedMessages {
+        StatedMessages::StatedMessagesLinks {
+            messages: self.messages,
+            chats: self.chats,
+            users: self.users,
+            links: self.links,
+            pts: self.pts,
+            seq: self.seq,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stickers {
+    /// `messages.stickersNotModified#f1749a22 = messages.Stickers`
+    StickersNotModified,
+    /// `messages.stickers#8a8ecd32 hash:string stickers:Vector<Document> = messages.Stickers`
+    Stickers {
+        hash: String,
+        stickers: Vec<super::tl::Document>,
+    },
+}
+impl TLType for Stickers {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x0e8b_65dei32 => Stickers::StickersNotModified {},
+            -0x7571_32cei32 => Stickers::Stickers {
+                hash: TLType::tl_read_with_context(input, context)?,
+                stickers: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Stickers",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Stickers::StickersNotModified => {
+                (-0x0e8b_65dei32).tl_write(output)?;
+            }
+            Stickers::Stickers { hash, stickers } => {
+                (-0x7571_32cei32).tl_write(output)?;
+                result += hash.tl_write(output)?;
+                result += stickers.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Stickers::StickersNotModified => {}
+            Stickers::Stickers { hash, stickers } => {
+                result += hash.serialized_len();
+                result += stickers.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StickersRef<'a> {
+    /// `messages.stickersNotModified#f1749a22 = messages.Stickers`
+    StickersNotModified,
+    /// `messages.stickers#8a8ecd32 hash:string stickers:Vector<Document> = messages.Stickers`
+    Stickers {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        hash: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        stickers: Vec<super::tl::DocumentRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for StickersRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x0e8b_65dei32 => StickersRef::StickersNotModified {},
+            -0x7571_32cei32 => StickersRef::Stickers {
+                hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                stickers: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "messages.Stickers",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct StickersStickersBuilder {
+    hash: String,
+    stickers: Vec<super::tl::Document>,
+}
+impl StickersStickersBuilder {
+    pub fn new(hash: String, stickers: Vec<super::tl::Document>) -> Self {
+        StickersStickersBuilder { hash, stickers }
+    }
+    pub fn build(self) -> Stickers {
+        Stickers::Stickers {
+            hash: self.hash,
+            stickers: self.stickers,
+        }
+    }
+}
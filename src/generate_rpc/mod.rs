@@ -0,0 +1,16 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+pub mod account;
+pub mod auth;
+pub mod contacts;
+pub mod functions;
+pub mod help;
+pub mod messages;
+pub mod object;
+pub mod photos;
+pub mod prelude;
+pub mod registry;
+pub mod storage;
+pub mod tl;
+pub mod updates;
+pub mod upload;
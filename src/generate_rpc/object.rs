@@ -0,0 +1,1269 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// Any boxed type of the schema, dispatched on its constructor id
+///
+/// `Vector` only holds boxed items, read a vector of bare ones as its declared type
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Object {
+    Bool(bool),
+    Vector(Vec<Object>),
+    AccountDaysTtl(Box<super::tl::AccountDaysTtl>),
+    AccountPrivacyRules(Box<super::account::PrivacyRules>),
+    AccountSentChangePhoneCode(Box<super::account::SentChangePhoneCode>),
+    Audio(Box<super::tl::Audio>),
+    AuthAuthorization(Box<super::auth::Authorization>),
+    AuthCheckedPhone(Box<super::auth::CheckedPhone>),
+    AuthExportedAuthorization(Box<super::auth::ExportedAuthorization>),
+    AuthSentCode(Box<super::auth::SentCode>),
+    Chat(Box<super::tl::Chat>),
+    ChatFull(Box<super::tl::ChatFull>),
+    ChatParticipant(Box<super::tl::ChatParticipant>),
+    ChatParticipants(Box<super::tl::ChatParticipants>),
+    ChatPhoto(Box<super::tl::ChatPhoto>),
+    Config(Box<super::tl::Config>),
+    Contact(Box<super::tl::Contact>),
+    ContactBlocked(Box<super::tl::ContactBlocked>),
+    ContactFound(Box<super::tl::ContactFound>),
+    ContactStatus(Box<super::tl::ContactStatus>),
+    ContactsBlocked(Box<super::contacts::Blocked>),
+    ContactsContacts(Box<super::contacts::Contacts>),
+    ContactsForeignLink(Box<super::contacts::ForeignLink>),
+    ContactsFound(Box<super::contacts::Found>),
+    ContactsImportedContacts(Box<super::contacts::ImportedContacts>),
+    ContactsLink(Box<super::contacts::Link>),
+    ContactsMyLink(Box<super::contacts::MyLink>),
+    DcOption(Box<super::tl::DcOption>),
+    Dialog(Box<super::tl::Dialog>),
+    DisabledFeature(Box<super::tl::DisabledFeature>),
+    Document(Box<super::tl::Document>),
+    DocumentAttribute(Box<super::tl::DocumentAttribute>),
+    EncryptedChat(Box<super::tl::EncryptedChat>),
+    EncryptedFile(Box<super::tl::EncryptedFile>),
+    EncryptedMessage(Box<super::tl::EncryptedMessage>),
+    Error(Box<super::tl::Error>),
+    FileLocation(Box<super::tl::FileLocation>),
+    GeoPoint(Box<super::tl::GeoPoint>),
+    HelpAppUpdate(Box<super::help::AppUpdate>),
+    HelpInviteText(Box<super::help::InviteText>),
+    HelpSupport(Box<super::help::Support>),
+    ImportedContact(Box<super::tl::ImportedContact>),
+    InputAppEvent(Box<super::tl::InputAppEvent>),
+    InputAudio(Box<super::tl::InputAudio>),
+    InputChatPhoto(Box<super::tl::InputChatPhoto>),
+    InputContact(Box<super::tl::InputContact>),
+    InputDocument(Box<super::tl::InputDocument>),
+    InputEncryptedChat(Box<super::tl::InputEncryptedChat>),
+    InputEncryptedFile(Box<super::tl::InputEncryptedFile>),
+    InputFile(Box<super::tl::InputFile>),
+    InputFileLocation(Box<super::tl::InputFileLocation>),
+    InputGeoPoint(Box<super::tl::InputGeoPoint>),
+    InputMedia(Box<super::tl::InputMedia>),
+    InputNotifyPeer(Box<super::tl::InputNotifyPeer>),
+    InputPeer(Box<super::tl::InputPeer>),
+    InputPeerNotifyEvents(Box<super::tl::InputPeerNotifyEvents>),
+    InputPeerNotifySettings(Box<super::tl::InputPeerNotifySettings>),
+    InputPhoto(Box<super::tl::InputPhoto>),
+    InputPhotoCrop(Box<super::tl::InputPhotoCrop>),
+    InputPrivacyKey(Box<super::tl::InputPrivacyKey>),
+    InputPrivacyRule(Box<super::tl::InputPrivacyRule>),
+    InputUser(Box<super::tl::InputUser>),
+    InputVideo(Box<super::tl::InputVideo>),
+    Message(Box<super::tl::Message>),
+    MessageAction(Box<super::tl::MessageAction>),
+    MessageMedia(Box<super::tl::MessageMedia>),
+    MessagesAffectedHistory(Box<super::messages::AffectedHistory>),
+    MessagesAllStickers(Box<super::messages::AllStickers>),
+    MessagesChatFull(Box<super::messages::ChatFull>),
+    MessagesChats(Box<super::messages::Chats>),
+    MessagesDhConfig(Box<super::messages::DhConfig>),
+    MessagesDialogs(Box<super::messages::Dialogs>),
+    MessagesFilter(Box<super::tl::MessagesFilter>),
+    MessagesMessages(Box<super::messages::Messages>),
+    MessagesSentEncryptedMessage(Box<super::messages::SentEncryptedMessage>),
+    MessagesSentMessage(Box<super::messages::SentMessage>),
+    MessagesStatedMessage(Box<super::messages::StatedMessage>),
+    MessagesStatedMessages(Box<super::messages::StatedMessages>),
+    MessagesStickers(Box<super::messages::Stickers>),
+    NearestDc(Box<super::tl::NearestDc>),
+    NotifyPeer(Box<super::tl::NotifyPeer>),
+    Null(Box<super::tl::Null>),
+    Peer(Box<super::tl::Peer>),
+    PeerNotifyEvents(Box<super::tl::PeerNotifyEvents>),
+    PeerNotifySettings(Box<super::tl::PeerNotifySettings>),
+    PeerSettings(Box<super::tl::PeerSettings>),
+    Photo(Box<super::tl::Photo>),
+    PhotoSize(Box<super::tl::PhotoSize>),
+    PhotosPhoto(Box<super::photos::Photo>),
+    PhotosPhotos(Box<super::photos::Photos>),
+    PrivacyKey(Box<super::tl::PrivacyKey>),
+    PrivacyRule(Box<super::tl::PrivacyRule>),
+    ReportReason(Box<super::tl::ReportReason>),
+    SendMessageAction(Box<super::tl::SendMessageAction>),
+    StickerPack(Box<super::tl::StickerPack>),
+    StorageFileType(Box<super::storage::FileType>),
+    True(Box<super::tl::True>),
+    Update(Box<super::tl::Update>),
+    Updates(Box<super::tl::Updates>),
+    UpdatesDifference(Box<super::updates::Difference>),
+    UpdatesState(Box<super::updates::State>),
+    UploadFile(Box<super::upload::File>),
+    User(Box<super::tl::User>),
+    UserFull(Box<super::tl::UserFull>),
+    UserProfilePhoto(Box<super::tl::UserProfilePhoto>),
+    UserStatus(Box<super::tl::UserStatus>),
+    Video(Box<super::tl::Video>),
+    WallPaper(Box<super::tl::WallPaper>),
+}
+impl TLType for Object {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let mut id = [0u8; 4];
+        input.read_exact(&mut id)?;
+        let input = &mut std::io::Read::chain(&id[..], input);
+        let id = i32::from_le_bytes(id);
+        Ok(match id {
+            -0x668d_8a4bi32 | -0x4386_68c9i32 => {
+                Object::Bool(TLType::tl_read_with_context(input, context)?)
+            }
+            -0x472f_5021i32 => {
+                Object::AccountDaysTtl(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x554a_bb6fi32 => {
+                Object::AccountPrivacyRules(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x5b0a_73b4i32 => Object::AccountSentChangePhoneCode(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            0x5869_88d8i32 | -0x3853_9b6ai32 => {
+                Object::Audio(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0949_8c5ci32 => {
+                Object::AuthAuthorization(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1cff_33c5i32 => {
+                Object::AuthCheckedPhone(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2069_63d3i32 => Object::AuthExportedAuthorization(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x1012_ae27i32 | -0x1cda_1231i32 => {
+                Object::AuthSentCode(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x645d_2800i32 | 0x6e9c_9bc7i32 | -0x04f3_33bfi32 => {
+                Object::Chat(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x630e_61bei32 => {
+                Object::ChatFull(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x3728_b6c2i32 => {
+                Object::ChatParticipant(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x0fd2_bb8ai32 | 0x7841_b415i32 => {
+                Object::ChatParticipants(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x37c1_011ci32 | 0x6153_276ai32 => {
+                Object::ChatPhoto(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7dae_33e0i32 => {
+                Object::Config(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x06ee_366ci32 => {
+                Object::Contact(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x561b_c879i32 => {
+                Object::ContactBlocked(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1578_606bi32 => {
+                Object::ContactFound(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2c97_f39fi32 => {
+                Object::ContactStatus(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1c13_8d15i32 | -0x6ff7_fd5fi32 => {
+                Object::ContactsBlocked(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x48b4_562ei32 | 0x6f8b_8cb2i32 => {
+                Object::ContactsContacts(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1334_21f8i32 | -0x587f_e0b9i32 | 0x1bea_8ce1i32 => {
+                Object::ContactsForeignLink(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x0566_000ei32 => {
+                Object::ContactsFound(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x52ad_bcebi32 => Object::ContactsImportedContacts(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x1331_5c0bi32 => {
+                Object::ContactsLink(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2dd5_e3a0i32 | 0x6c69_efeei32 | -0x3dbf_1427i32 => {
+                Object::ContactsMyLink(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x2ec2_a43ci32 => {
+                Object::DcOption(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x54c5_6654i32 => {
+                Object::Dialog(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x519c_90dci32 => {
+                Object::DisabledFeature(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x36f8_c871i32 | -0x065c_60b1i32 => {
+                Object::Document(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x6c37_c15ci32 | 0x11b5_8939i32 | -0x04f5_a8d9i32 | 0x5910_cccbi32 | 0x0514_48e5i32
+            | 0x1559_0068i32 => {
+                Object::DocumentAttribute(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x5481_3f60i32 | 0x3bf7_03dci32 | -0x3787_ad82i32 | -0x05a9_31cai32
+            | 0x13d6_dd27i32 => {
+                Object::EncryptedChat(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x3de0_b682i32 | 0x4a70_994ci32 => {
+                Object::EncryptedFile(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x12e7_3ee8i32 | 0x2373_4b06i32 => {
+                Object::EncryptedMessage(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x3b46_0645i32 => {
+                Object::Error(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7c59_6b46i32 | 0x53d6_9076i32 => {
+                Object::FileLocation(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1117_dd5fi32 | 0x2049_d70ci32 => {
+                Object::GeoPoint(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7678_0cefi32 | -0x3ba5_9acai32 => {
+                Object::HelpAppUpdate(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x18cb_9f78i32 => {
+                Object::HelpInviteText(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x17c6_b5f6i32 => {
+                Object::HelpSupport(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2ffd_7bc8i32 => {
+                Object::ImportedContact(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7706_56a8i32 => {
+                Object::InputAppEvent(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x26a5_237ci32 | 0x77d4_40ffi32 => {
+                Object::InputAudio(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1ca4_8f57i32 | -0x6bda_b8cei32 | -0x4d1e_40f8i32 => {
+                Object::InputChatPhoto(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0c6d_480ci32 => {
+                Object::InputContact(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x72f0_eaaei32 | 0x1879_8952i32 => {
+                Object::InputDocument(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0ebe_4a1fi32 => {
+                Object::InputEncryptedChat(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1837_c364i32 | 0x64bd_0306i32 | 0x5a17_b5e5i32 | 0x2dc1_73c8i32 => {
+                Object::InputEncryptedFile(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0ad0_0d81i32 | -0x05b0_f44bi32 => {
+                Object::InputFile(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1463_7196i32 | 0x3d03_64eci32 | -0x0adc_a2abi32 | 0x74dc_404di32 | 0x4e45_abe9i32 => {
+                Object::InputFileLocation(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1b3e_dc2ai32 | -0x0c48_5337i32 => {
+                Object::InputGeoPoint(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x699b_0a81i32 | 0x2dc5_3a7di32 | -0x70d5_4d14i32 | -0x063b_bebci32
+            | -0x591b_a679i32 | 0x133a_d6f6i32 | -0x66ed_2541i32 | 0x7f02_3ae6i32
+            | 0x4e49_8cabi32 | -0x766c_787fi32 | -0x0018_9488i32 | 0x4148_1486i32
+            | -0x2e7b_17bfi32 => {
+                Object::InputMedia(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x4743_a4f4i32 | 0x193b_4417i32 | 0x4a95_e84ei32 | -0x5bd6_477ai32 => {
+                Object::InputNotifyPeer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7f3b_18eai32 | 0x7da0_7ec9i32 | 0x1023_dbe8i32 | -0x64bb_8cdbi32 | 0x179b_e863i32 => {
+                Object::InputPeer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0fcf_9b28i32 | -0x1795_d38ci32 => Object::InputPeerNotifyEvents(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            0x46a2_ce98i32 => Object::InputPeerNotifySettings(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            0x1cd7_bf0di32 | -0x046a_393ci32 => {
+                Object::InputPhoto(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x5219_4ffci32 | -0x266e_acdbi32 => {
+                Object::InputPhotoCrop(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x4f96_cb18i32 => {
+                Object::InputPrivacyKey(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x0d09_e07bi32 | 0x184b_35cei32 | 0x131c_c67fi32 | 0x0ba5_2007i32 | -0x2994_9937i32
+            | -0x6fee_fb99i32 => {
+                Object::InputPrivacyRule(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x4677_7931i32 | -0x083e_4ec1i32 | -0x7916_b09bi32 | 0x655e_74ffi32 => {
+                Object::InputUser(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x5508_ec75i32 | -0x11a8_69aei32 => {
+                Object::InputVideo(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7c1a_21aci32 | 0x5676_99b3i32 | -0x5c98_18eai32 | 0x1d86_f70ei32 => {
+                Object::Message(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x4951_0850i32 | -0x599c_7466i32 | -0x4a5e_31a6i32 | 0x7fcb_13a8i32
+            | -0x6a1c_0411i32 | 0x5e3c_fc4bi32 | -0x4d51_64f4i32 => {
+                Object::MessageAction(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x3ded_6320i32 | -0x373b_a5d6i32 | -0x5d2d_bd70i32 | 0x56e0_d474i32
+            | 0x5e7d_2f39i32 | 0x2fda_2204i32 | -0x3949_7d00i32 => {
+                Object::MessageMedia(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x4821_c90ei32 => Object::MessagesAffectedHistory(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x1799_fd3di32 | -0x2310_cefei32 => {
+                Object::MessagesAllStickers(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1a28_2e64i32 => {
+                Object::MessagesChatFull(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7eaf_3428i32 => {
+                Object::MessagesChats(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x3f1d_b9cbi32 | 0x2c22_1eddi32 => {
+                Object::MessagesDhConfig(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x15ba_6c40i32 | 0x71e0_94f3i32 => {
+                Object::MessagesDialogs(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x57e2_f66ci32 | -0x69f6_5ae4i32 | -0x603f_f19bi32 | 0x56e9_f0e4i32
+            | -0x6122_0e78i32 | -0x3037_8adei32 | 0x5afb_f764i32 | 0x7ef0_dd87i32
+            | -0x0037_9a79i32 => {
+                Object::MessagesFilter(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x738e_7179i32 | 0x0b44_6ae3i32 => {
+                Object::MessagesMessages(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x560f_8935i32 | -0x6b6c_00cei32 => Object::MessagesSentEncryptedMessage(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x2e0b_2ca4i32 | -0x1624_b5c1i32 => {
+                Object::MessagesSentMessage(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x2f85_18dai32 | -0x5650_d77fi32 => Object::MessagesStatedMessage(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x696b_8745i32 | 0x3e74_f5c6i32 => Object::MessagesStatedMessages(Box::new(
+                TLType::tl_read_with_context(input, context)?,
+            )),
+            -0x0e8b_65dei32 | -0x7571_32cei32 => {
+                Object::MessagesStickers(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x71e5_e88bi32 => {
+                Object::NearestDc(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x602b_f428i32 | -0x4b37_c4b4i32 | -0x3ff8_313di32 | 0x74d0_7c60i32 => {
+                Object::NotifyPeer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x5673_0bcci32 => Object::Null(Box::new(TLType::tl_read_with_context(input, context)?)),
+            -0x624e_4393i32 | -0x452f_1a45i32 => {
+                Object::Peer(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x522a_c34di32 | 0x6d1d_ed88i32 => {
+                Object::PeerNotifyEvents(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x72a1_ee12i32 => {
+                Object::PeerNotifySettings(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7e7b_d933i32 => {
+                Object::PeerSettings(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x2331_b22di32 | 0x22b5_6751i32 => {
+                Object::Photo(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x0e17_e23ci32 | 0x77bf_b61bi32 | -0x1658_cb06i32 => {
+                Object::PhotoSize(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x2021_2ca8i32 => {
+                Object::PhotosPhoto(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x7235_955bi32 | 0x1505_1f54i32 => {
+                Object::PhotosPhotos(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x43d1_54d0i32 => {
+                Object::PrivacyKey(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x0001_e454i32 | 0x6542_7b82i32 | 0x4d5b_be0ci32 | -0x0777_05e6i32
+            | -0x748c_189di32 | 0x0c7f_49b7i32 => {
+                Object::PrivacyRule(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x58db_cab8i32 | 0x1e22_c78di32 | 0x2e59_d922i32 | -0x1e8b_92f6i32 => {
+                Object::ReportReason(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x16bf_744ei32 | -0x02a1_370bi32 | -0x5e78_2991i32 | -0x6dfb_d009i32
+            | -0x2ad0_8c09i32 | -0x1953_7591i32 | -0x66f5_c3e6i32 | -0x7051_1672i32
+            | 0x176f_8ba1i32 | 0x628c_bc6fi32 => {
+                Object::SendMessageAction(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x12b2_99d4i32 => {
+                Object::StickerPack(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x5569_c4fbi32 | 0x40bc_6f52i32 | 0x007e_fe0ei32 | -0x351e_5521i32
+            | 0x0a4f_63c0i32 | -0x51e1_af73i32 | 0x528a_0677i32 | 0x4b09_ebbci32
+            | -0x4c31_5f1ci32 | 0x1081_464ci32 => {
+                Object::StorageFileType(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x3fed_d339i32 => Object::True(Box::new(TLType::tl_read_with_context(input, context)?)),
+            0x013a_bdb3i32 | 0x4e90_bfd6i32 | -0x399b_61cfi32 | -0x56d4_01dai32
+            | 0x5c48_6927i32 | -0x659a_15e1i32 | 0x0776_1198i32 | 0x1bfb_d823i32
+            | -0x58cc_d48di32 | -0x6ace_c4f4i32 | 0x2575_bbb9i32 | 0x51a4_8a9ai32
+            | -0x70f9_ad66i32 | 0x12bc_bd9ai32 | 0x1710_f156i32 | -0x4b5d_1773i32
+            | 0x38fe_25b7i32 | 0x3a0e_eb22i32 | 0x6e5f_8c22i32 | -0x71a1_678di32
+            | -0x7f13_17e6i32 | -0x413d_9711i32 | 0x382d_d3e4i32 | -0x11c4_d8d6i32
+            | 0x12b9_417bi32 => {
+                Object::Update(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x1ce8_5082i32 | -0x2c0b_a87ci32 | 0x2b2f_bd4ei32 | 0x78d4_dec1i32
+            | 0x725b_04c3i32 | 0x74ae_4240i32 => {
+                Object::Updates(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x5d75_a138i32 | 0x00f4_9ca0i32 | -0x5704_e67fi32 => {
+                Object::UpdatesDifference(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x5a93_d5c2i32 => {
+                Object::UpdatesState(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x096a_18d5i32 => {
+                Object::UploadFile(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x2002_50bai32 | 0x7007_b451i32 | -0x354c_a1e8i32 | -0x2633_3b11i32
+            | 0x075c_f7a8i32 | -0x29fe_9286i32 => {
+                Object::User(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x7710_95dai32 => {
+                Object::UserFull(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x4f11_bae1i32 | -0x2aa6_2738i32 => {
+                Object::UserProfilePhoto(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x09d0_5049i32 | -0x1246_c6b7i32 | 0x008c_703fi32 | -0x1d90_bd0fi32
+            | 0x07bf_09fci32 | 0x77eb_c742i32 => {
+                Object::UserStatus(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x3ef9_a758i32 | 0x388f_a391i32 => {
+                Object::Video(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            -0x334f_c9a9i32 | 0x6311_7f24i32 => {
+                Object::WallPaper(Box::new(TLType::tl_read_with_context(input, context)?))
+            }
+            0x1cb5_c415i32 => {
+                context.enter()?;
+                let result = Object::Vector(TLType::tl_read_with_context(input, context)?);
+                context.leave();
+                result
+            }
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Object",
+                })
+            }
+        })
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            Object::Bool(x) => x.tl_write(output),
+            Object::Vector(x) => x.tl_write(output),
+            Object::AccountDaysTtl(x) => x.tl_write(output),
+            Object::AccountPrivacyRules(x) => x.tl_write(output),
+            Object::AccountSentChangePhoneCode(x) => x.tl_write(output),
+            Object::Audio(x) => x.tl_write(output),
+            Object::AuthAuthorization(x) => x.tl_write(output),
+            Object::AuthCheckedPhone(x) => x.tl_write(output),
+            Object::AuthExportedAuthorization(x) => x.tl_write(output),
+            Object::AuthSentCode(x) => x.tl_write(output),
+            Object::Chat(x) => x.tl_write(output),
+            Object::ChatFull(x) => x.tl_write(output),
+            Object::ChatParticipant(x) => x.tl_write(output),
+            Object::ChatParticipants(x) => x.tl_write(output),
+            Object::ChatPhoto(x) => x.tl_write(output),
+            Object::Config(x) => x.tl_write(output),
+            Object::Contact(x) => x.tl_write(output),
+            Object::ContactBlocked(x) => x.tl_write(output),
+            Object::ContactFound(x) => x.tl_write(output),
+            Object::ContactStatus(x) => x.tl_write(output),
+            Object::ContactsBlocked(x) => x.tl_write(output),
+            Object::ContactsContacts(x) => x.tl_write(output),
+            Object::ContactsForeignLink(x) => x.tl_write(output),
+            Object::ContactsFound(x) => x.tl_write(output),
+            Object::ContactsImportedContacts(x) => x.tl_write(output),
+            Object::ContactsLink(x) => x.tl_write(output),
+            Object::ContactsMyLink(x) => x.tl_write(output),
+            Object::DcOption(x) => x.tl_write(output),
+            Object::Dialog(x) => x.tl_write(output),
+            Object::DisabledFeature(x) => x.tl_write(output),
+            Object::Document(x) => x.tl_write(output),
+            Object::DocumentAttribute(x) => x.tl_write(output),
+            Object::EncryptedChat(x) => x.tl_write(output),
+            Object::EncryptedFile(x) => x.tl_write(output),
+            Object::EncryptedMessage(x) => x.tl_write(output),
+            Object::Error(x) => x.tl_write(output),
+            Object::FileLocation(x) => x.tl_write(output),
+            Object::GeoPoint(x) => x.tl_write(output),
+            Object::HelpAppUpdate(x) => x.tl_write(output),
+            Object::HelpInviteText(x) => x.tl_write(output),
+            Object::HelpSupport(x) => x.tl_write(output),
+            Object::ImportedContact(x) => x.tl_write(output),
+            Object::InputAppEvent(x) => x.tl_write(output),
+            Object::InputAudio(x) => x.tl_write(output),
+            Object::InputChatPhoto(x) => x.tl_write(output),
+            Object::InputContact(x) => x.tl_write(output),
+            Object::InputDocument(x) => x.tl_write(output),
+            Object::InputEncryptedChat(x) => x.tl_write(output),
+            Object::InputEncryptedFile(x) => x.tl_write(output),
+            Object::InputFile(x) => x.tl_write(output),
+            Object::InputFileLocation(x) => x.tl_write(output),
+            Object::InputGeoPoint(x) => x.tl_write(output),
+            Object::InputMedia(x) => x.tl_write(output),
+            Object::InputNotifyPeer(x) => x.tl_write(output),
+            Object::InputPeer(x) => x.tl_write(output),
+            Object::InputPeerNotifyEvents(x) => x.tl_write(output),
+            Object::InputPeerNotifySettings(x) => x.tl_write(output),
+            Object::InputPhoto(x) => x.tl_write(output),
+            Object::InputPhotoCrop(x) => x.tl_write(output),
+            Object::InputPrivacyKey(x) => x.tl_write(output),
+            Object::InputPrivacyRule(x) => x.tl_write(output),
+            Object::InputUser(x) => x.tl_write(output),
+            Object::InputVideo(x) => x.tl_write(output),
+            Object::Message(x) => x.tl_write(output),
+            Object::MessageAction(x) => x.tl_write(output),
+            Object::MessageMedia(x) => x.tl_write(output),
+            Object::MessagesAffectedHistory(x) => x.tl_write(output),
+            Object::MessagesAllStickers(x) => x.tl_write(output),
+            Object::MessagesChatFull(x) => x.tl_write(output),
+            Object::MessagesChats(x) => x.tl_write(output),
+            Object::MessagesDhConfig(x) => x.tl_write(output),
+            Object::MessagesDialogs(x) => x.tl_write(output),
+            Object::MessagesFilter(x) => x.tl_write(output),
+            Object::MessagesMessages(x) => x.tl_write(output),
+            Object::MessagesSentEncryptedMessage(x) => x.tl_write(output),
+            Object::MessagesSentMessage(x) => x.tl_write(output),
+            Object::MessagesStatedMessage(x) => x.tl_write(output),
+            Object::MessagesStatedMessages(x) => x.tl_write(output),
+            Object::MessagesStickers(x) => x.tl_write(output),
+            Object::NearestDc(x) => x.tl_write(output),
+            Object::NotifyPeer(x) => x.tl_write(output),
+            Object::Null(x) => x.tl_write(output),
+            Object::Peer(x) => x.tl_write(output),
+            Object::PeerNotifyEvents(x) => x.tl_write(output),
+            Object::PeerNotifySettings(x) => x.tl_write(output),
+            Object::PeerSettings(x) => x.tl_write(output),
+            Object::Photo(x) => x.tl_write(output),
+            Object::PhotoSize(x) => x.tl_write(output),
+            Object::PhotosPhoto(x) => x.tl_write(output),
+            Object::PhotosPhotos(x) => x.tl_write(output),
+            Object::PrivacyKey(x) => x.tl_write(output),
+            Object::PrivacyRule(x) => x.tl_write(output),
+            Object::ReportReason(x) => x.tl_write(output),
+            Object::SendMessageAction(x) => x.tl_write(output),
+            Object::StickerPack(x) => x.tl_write(output),
+            Object::StorageFileType(x) => x.tl_write(output),
+            Object::True(x) => x.tl_write(output),
+            Object::Update(x) => x.tl_write(output),
+            Object::Updates(x) => x.tl_write(output),
+            Object::UpdatesDifference(x) => x.tl_write(output),
+            Object::UpdatesState(x) => x.tl_write(output),
+            Object::UploadFile(x) => x.tl_write(output),
+            Object::User(x) => x.tl_write(output),
+            Object::UserFull(x) => x.tl_write(output),
+            Object::UserProfilePhoto(x) => x.tl_write(output),
+            Object::UserStatus(x) => x.tl_write(output),
+            Object::Video(x) => x.tl_write(output),
+            Object::WallPaper(x) => x.tl_write(output),
+        }
+    }
+    fn serialized_len(&self) -> usize {
+        match self {
+            Object::Bool(x) => x.serialized_len(),
+            Object::Vector(x) => x.serialized_len(),
+            Object::AccountDaysTtl(x) => x.serialized_len(),
+            Object::AccountPrivacyRules(x) => x.serialized_len(),
+            Object::AccountSentChangePhoneCode(x) => x.serialized_len(),
+            Object::Audio(x) => x.serialized_len(),
+            Object::AuthAuthorization(x) => x.serialized_len(),
+            Object::AuthCheckedPhone(x) => x.serialized_len(),
+            Object::AuthExportedAuthorization(x) => x.serialized_len(),
+            Object::AuthSentCode(x) => x.serialized_len(),
+            Object::Chat(x) => x.serialized_len(),
+            Object::ChatFull(x) => x.serialized_len(),
+            Object::ChatParticipant(x) => x.serialized_len(),
+            Object::ChatParticipants(x) => x.serialized_len(),
+            Object::ChatPhoto(x) => x.serialized_len(),
+            Object::Config(x) => x.serialized_len(),
+            Object::Contact(x) => x.serialized_len(),
+            Object::ContactBlocked(x) => x.serialized_len(),
+            Object::ContactFound(x) => x.serialized_len(),
+            Object::ContactStatus(x) => x.serialized_len(),
+            Object::ContactsBlocked(x) => x.serialized_len(),
+            Object::ContactsContacts(x) => x.serialized_len(),
+            Object::ContactsForeignLink(x) => x.serialized_len(),
+            Object::ContactsFound(x) => x.serialized_len(),
+            Object::ContactsImportedContacts(x) => x.serialized_len(),
+            Object::ContactsLink(x) => x.serialized_len(),
+            Object::ContactsMyLink(x) => x.serialized_len(),
+            Object::DcOption(x) => x.serialized_len(),
+            Object::Dialog(x) => x.serialized_len(),
+            Object::DisabledFeature(x) => x.serialized_len(),
+            Object::Document(x) => x.serialized_len(),
+            Object::DocumentAttribute(x) => x.serialized_len(),
+            Object::EncryptedChat(x) => x.serialized_len(),
+            Object::EncryptedFile(x) => x.serialized_len(),
+            Object::EncryptedMessage(x) => x.serialized_len(),
+            Object::Error(x) => x.serialized_len(),
+            Object::FileLocation(x) => x.serialized_len(),
+            Object::GeoPoint(x) => x.serialized_len(),
+            Object::HelpAppUpdate(x) => x.serialized_len(),
+            Object::HelpInviteText(x) => x.serialized_len(),
+            Object::HelpSupport(x) => x.serialized_len(),
+            Object::ImportedContact(x) => x.serialized_len(),
+            Object::InputAppEvent(x) => x.serialized_len(),
+            Object::InputAudio(x) => x.serialized_len(),
+            Object::InputChatPhoto(x) => x.serialized_len(),
+            Object::InputContact(x) => x.serialized_len(),
+            Object::InputDocument(x) => x.serialized_len(),
+            Object::InputEncryptedChat(x) => x.serialized_len(),
+            Object::InputEncryptedFile(x) => x.serialized_len(),
+            Object::InputFile(x) => x.serialized_len(),
+            Object::InputFileLocation(x) => x.serialized_len(),
+            Object::InputGeoPoint(x) => x.serialized_len(),
+            Object::InputMedia(x) => x.serialized_len(),
+            Object::InputNotifyPeer(x) => x.serialized_len(),
+            Object::InputPeer(x) => x.serialized_len(),
+            Object::InputPeerNotifyEvents(x) => x.serialized_len(),
+            Object::InputPeerNotifySettings(x) => x.serialized_len(),
+            Object::InputPhoto(x) => x.serialized_len(),
+            Object::InputPhotoCrop(x) => x.serialized_len(),
+            Object::InputPrivacyKey(x) => x.serialized_len(),
+            Object::InputPrivacyRule(x) => x.serialized_len(),
+            Object::InputUser(x) => x.serialized_len(),
+            Object::InputVideo(x) => x.serialized_len(),
+            Object::Message(x) => x.serialized_len(),
+            Object::MessageAction(x) => x.serialized_len(),
+            Object::MessageMedia(x) => x.serialized_len(),
+            Object::MessagesAffectedHistory(x) => x.serialized_len(),
+            Object::MessagesAllStickers(x) => x.serialized_len(),
+            Object::MessagesChatFull(x) => x.serialized_len(),
+            Object::MessagesChats(x) => x.serialized_len(),
+            Object::MessagesDhConfig(x) => x.serialized_len(),
+            Object::MessagesDialogs(x) => x.serialized_len(),
+            Object::MessagesFilter(x) => x.serialized_len(),
+            Object::MessagesMessages(x) => x.serialized_len(),
+            Object::MessagesSentEncryptedMessage(x) => x.serialized_len(),
+            Object::MessagesSentMessage(x) => x.serialized_len(),
+            Object::MessagesStatedMessage(x) => x.serialized_len(),
+            Object::MessagesStatedMessages(x) => x.serialized_len(),
+            Object::MessagesStickers(x) => x.serialized_len(),
+            Object::NearestDc(x) => x.serialized_len(),
+            Object::NotifyPeer(x) => x.serialized_len(),
+            Object::Null(x) => x.serialized_len(),
+            Object::Peer(x) => x.serialized_len(),
+            Object::PeerNotifyEvents(x) => x.serialized_len(),
+            Object::PeerNotifySettings(x) => x.serialized_len(),
+            Object::PeerSettings(x) => x.serialized_len(),
+            Object::Photo(x) => x.serialized_len(),
+            Object::PhotoSize(x) => x.serialized_len(),
+            Object::PhotosPhoto(x) => x.serialized_len(),
+            Object::PhotosPhotos(x) => x.serialized_len(),
+            Object::PrivacyKey(x) => x.serialized_len(),
+            Object::PrivacyRule(x) => x.serialized_len(),
+            Object::ReportReason(x) => x.serialized_len(),
+            Object::SendMessageAction(x) => x.serialized_len(),
+            Object::StickerPack(x) => x.serialized_len(),
+            Object::StorageFileType(x) => x.serialized_len(),
+            Object::True(x) => x.serialized_len(),
+            Object::Update(x) => x.serialized_len(),
+            Object::Updates(x) => x.serialized_len(),
+            Object::UpdatesDifference(x) => x.serialized_len(),
+            Object::UpdatesState(x) => x.serialized_len(),
+            Object::UploadFile(x) => x.serialized_len(),
+            Object::User(x) => x.serialized_len(),
+            Object::UserFull(x) => x.serialized_len(),
+            Object::UserProfilePhoto(x) => x.serialized_len(),
+            Object::UserStatus(x) => x.serialized_len(),
+            Object::Video(x) => x.serialized_len(),
+            Object::WallPaper(x) => x.serialized_len(),
+        }
+    }
+}
+/// Any boxed type of the schema, dispatched on its constructor id
+///
+/// `Vector` only holds boxed items, read a vector of bare ones as its declared type
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectRef<'a> {
+    Bool(bool),
+    Vector(#[cfg_attr(feature = "serde", serde(borrow))] Vec<ObjectRef<'a>>),
+    AccountDaysTtl(Box<super::tl::AccountDaysTtl>),
+    AccountPrivacyRules(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::account::PrivacyRulesRef<'a>>,
+    ),
+    AccountSentChangePhoneCode(
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        Box<super::account::SentChangePhoneCodeRef<'a>>,
+    ),
+    Audio(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::AudioRef<'a>>),
+    AuthAuthorization(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::auth::AuthorizationRef<'a>>,
+    ),
+    AuthCheckedPhone(Box<super::auth::CheckedPhone>),
+    AuthExportedAuthorization(
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        Box<super::auth::ExportedAuthorizationRef<'a>>,
+    ),
+    AuthSentCode(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::auth::SentCodeRef<'a>>),
+    Chat(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ChatRef<'a>>),
+    ChatFull(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ChatFullRef<'a>>),
+    ChatParticipant(Box<super::tl::ChatParticipant>),
+    ChatParticipants(Box<super::tl::ChatParticipants>),
+    ChatPhoto(Box<super::tl::ChatPhoto>),
+    Config(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ConfigRef<'a>>),
+    Contact(Box<super::tl::Contact>),
+    ContactBlocked(Box<super::tl::ContactBlocked>),
+    ContactFound(Box<super::tl::ContactFound>),
+    ContactStatus(Box<super::tl::ContactStatus>),
+    ContactsBlocked(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::contacts::BlockedRef<'a>>,
+    ),
+    ContactsContacts(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::contacts::ContactsRef<'a>>,
+    ),
+    ContactsForeignLink(Box<super::contacts::ForeignLink>),
+    ContactsFound(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::contacts::FoundRef<'a>>),
+    ContactsImportedContacts(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::contacts::ImportedContactsRef<'a>>,
+    ),
+    ContactsLink(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::contacts::LinkRef<'a>>),
+    ContactsMyLink(Box<super::contacts::MyLink>),
+    DcOption(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::DcOptionRef<'a>>),
+    Dialog(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::DialogRef<'a>>),
+    DisabledFeature(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::DisabledFeatureRef<'a>>,
+    ),
+    Document(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::DocumentRef<'a>>),
+    DocumentAttribute(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::DocumentAttributeRef<'a>>,
+    ),
+    EncryptedChat(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::EncryptedChatRef<'a>>,
+    ),
+    EncryptedFile(Box<super::tl::EncryptedFile>),
+    EncryptedMessage(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::EncryptedMessageRef<'a>>,
+    ),
+    Error(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ErrorRef<'a>>),
+    FileLocation(Box<super::tl::FileLocation>),
+    GeoPoint(Box<super::tl::GeoPoint>),
+    HelpAppUpdate(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::help::AppUpdateRef<'a>>),
+    HelpInviteText(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::help::InviteTextRef<'a>>,
+    ),
+    HelpSupport(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::help::SupportRef<'a>>),
+    ImportedContact(Box<super::tl::ImportedContact>),
+    InputAppEvent(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputAppEventRef<'a>>,
+    ),
+    InputAudio(Box<super::tl::InputAudio>),
+    InputChatPhoto(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputChatPhotoRef<'a>>,
+    ),
+    InputContact(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputContactRef<'a>>),
+    InputDocument(Box<super::tl::InputDocument>),
+    InputEncryptedChat(Box<super::tl::InputEncryptedChat>),
+    InputEncryptedFile(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputEncryptedFileRef<'a>>,
+    ),
+    InputFile(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputFileRef<'a>>),
+    InputFileLocation(Box<super::tl::InputFileLocation>),
+    InputGeoPoint(Box<super::tl::InputGeoPoint>),
+    InputMedia(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::InputMediaRef<'a>>),
+    InputNotifyPeer(Box<super::tl::InputNotifyPeer>),
+    InputPeer(Box<super::tl::InputPeer>),
+    InputPeerNotifyEvents(Box<super::tl::InputPeerNotifyEvents>),
+    InputPeerNotifySettings(
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        Box<super::tl::InputPeerNotifySettingsRef<'a>>,
+    ),
+    InputPhoto(Box<super::tl::InputPhoto>),
+    InputPhotoCrop(Box<super::tl::InputPhotoCrop>),
+    InputPrivacyKey(Box<super::tl::InputPrivacyKey>),
+    InputPrivacyRule(Box<super::tl::InputPrivacyRule>),
+    InputUser(Box<super::tl::InputUser>),
+    InputVideo(Box<super::tl::InputVideo>),
+    Message(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageRef<'a>>),
+    MessageAction(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageActionRef<'a>>,
+    ),
+    MessageMedia(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::MessageMediaRef<'a>>),
+    MessagesAffectedHistory(Box<super::messages::AffectedHistory>),
+    MessagesAllStickers(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::AllStickersRef<'a>>,
+    ),
+    MessagesChatFull(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::ChatFullRef<'a>>,
+    ),
+    MessagesChats(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::ChatsRef<'a>>),
+    MessagesDhConfig(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::DhConfigRef<'a>>,
+    ),
+    MessagesDialogs(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::DialogsRef<'a>>,
+    ),
+    MessagesFilter(Box<super::tl::MessagesFilter>),
+    MessagesMessages(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::MessagesRef<'a>>,
+    ),
+    MessagesSentEncryptedMessage(Box<super::messages::SentEncryptedMessage>),
+    MessagesSentMessage(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::SentMessageRef<'a>>,
+    ),
+    MessagesStatedMessage(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::StatedMessageRef<'a>>,
+    ),
+    MessagesStatedMessages(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::StatedMessagesRef<'a>>,
+    ),
+    MessagesStickers(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::messages::StickersRef<'a>>,
+    ),
+    NearestDc(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::NearestDcRef<'a>>),
+    NotifyPeer(Box<super::tl::NotifyPeer>),
+    Null(Box<super::tl::Null>),
+    Peer(Box<super::tl::Peer>),
+    PeerNotifyEvents(Box<super::tl::PeerNotifyEvents>),
+    PeerNotifySettings(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::PeerNotifySettingsRef<'a>>,
+    ),
+    PeerSettings(Box<super::tl::PeerSettings>),
+    Photo(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::PhotoRef<'a>>),
+    PhotoSize(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::PhotoSizeRef<'a>>),
+    PhotosPhoto(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::photos::PhotoRef<'a>>),
+    PhotosPhotos(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::photos::PhotosRef<'a>>),
+    PrivacyKey(Box<super::tl::PrivacyKey>),
+    PrivacyRule(Box<super::tl::PrivacyRule>),
+    ReportReason(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::ReportReasonRef<'a>>),
+    SendMessageAction(Box<super::tl::SendMessageAction>),
+    StickerPack(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::StickerPackRef<'a>>),
+    StorageFileType(Box<super::storage::FileType>),
+    True(Box<super::tl::True>),
+    Update(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::UpdateRef<'a>>),
+    Updates(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::UpdatesRef<'a>>),
+    UpdatesDifference(
+        #[cfg_attr(feature = "serde", serde(borrow))] Box<super::updates::DifferenceRef<'a>>,
+    ),
+    UpdatesState(Box<super::updates::State>),
+    UploadFile(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::upload::FileRef<'a>>),
+    User(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::UserRef<'a>>),
+    UserFull(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::UserFullRef<'a>>),
+    UserProfilePhoto(Box<super::tl::UserProfilePhoto>),
+    UserStatus(Box<super::tl::UserStatus>),
+    Video(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::VideoRef<'a>>),
+    WallPaper(#[cfg_attr(feature = "serde", serde(borrow))] Box<super::tl::WallPaperRef<'a>>),
+}
+impl<'a> TLDeserialize<'a> for ObjectRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        if input.len() < 4 {
+            return Err(MyError::Truncated);
+        }
+        let id = i32::from_le_bytes([input[0], input[1], input[2], input[3]]);
+        Ok(match id {
+            -0x668d_8a4bi32 | -0x4386_68c9i32 => {
+                ObjectRef::Bool(TLDeserialize::tl_deserialize_with_context(input, context)?)
+            }
+            -0x472f_5021i32 => ObjectRef::AccountDaysTtl(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x554a_bb6fi32 => ObjectRef::AccountPrivacyRules(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x5b0a_73b4i32 => ObjectRef::AccountSentChangePhoneCode(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x5869_88d8i32 | -0x3853_9b6ai32 => ObjectRef::Audio(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x0949_8c5ci32 => ObjectRef::AuthAuthorization(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1cff_33c5i32 => ObjectRef::AuthCheckedPhone(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2069_63d3i32 => ObjectRef::AuthExportedAuthorization(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1012_ae27i32 | -0x1cda_1231i32 => ObjectRef::AuthSentCode(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x645d_2800i32 | 0x6e9c_9bc7i32 | -0x04f3_33bfi32 => ObjectRef::Chat(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x630e_61bei32 => ObjectRef::ChatFull(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x3728_b6c2i32 => ObjectRef::ChatParticipant(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x0fd2_bb8ai32 | 0x7841_b415i32 => ObjectRef::ChatParticipants(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x37c1_011ci32 | 0x6153_276ai32 => ObjectRef::ChatPhoto(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x7dae_33e0i32 => ObjectRef::Config(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x06ee_366ci32 => ObjectRef::Contact(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x561b_c879i32 => ObjectRef::ContactBlocked(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1578_606bi32 => ObjectRef::ContactFound(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2c97_f39fi32 => ObjectRef::ContactStatus(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1c13_8d15i32 | -0x6ff7_fd5fi32 => ObjectRef::ContactsBlocked(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x48b4_562ei32 | 0x6f8b_8cb2i32 => ObjectRef::ContactsContacts(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1334_21f8i32 | -0x587f_e0b9i32 | 0x1bea_8ce1i32 => ObjectRef::ContactsForeignLink(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            0x0566_000ei32 => ObjectRef::ContactsFound(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x52ad_bcebi32 => ObjectRef::ContactsImportedContacts(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1331_5c0bi32 => ObjectRef::ContactsLink(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2dd5_e3a0i32 | 0x6c69_efeei32 | -0x3dbf_1427i32 => ObjectRef::ContactsMyLink(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            0x2ec2_a43ci32 => ObjectRef::DcOption(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x54c5_6654i32 => ObjectRef::Dialog(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x519c_90dci32 => ObjectRef::DisabledFeature(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x36f8_c871i32 | -0x065c_60b1i32 => ObjectRef::Document(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x6c37_c15ci32 | 0x11b5_8939i32 | -0x04f5_a8d9i32 | 0x5910_cccbi32 | 0x0514_48e5i32
+            | 0x1559_0068i32 => ObjectRef::DocumentAttribute(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x5481_3f60i32 | 0x3bf7_03dci32 | -0x3787_ad82i32 | -0x05a9_31cai32
+            | 0x13d6_dd27i32 => ObjectRef::EncryptedChat(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x3de0_b682i32 | 0x4a70_994ci32 => ObjectRef::EncryptedFile(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x12e7_3ee8i32 | 0x2373_4b06i32 => ObjectRef::EncryptedMessage(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x3b46_0645i32 => ObjectRef::Error(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x7c59_6b46i32 | 0x53d6_9076i32 => ObjectRef::FileLocation(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1117_dd5fi32 | 0x2049_d70ci32 => ObjectRef::GeoPoint(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7678_0cefi32 | -0x3ba5_9acai32 => ObjectRef::HelpAppUpdate(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x18cb_9f78i32 => ObjectRef::HelpInviteText(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x17c6_b5f6i32 => ObjectRef::HelpSupport(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2ffd_7bc8i32 => ObjectRef::ImportedContact(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x7706_56a8i32 => ObjectRef::InputAppEvent(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x26a5_237ci32 | 0x77d4_40ffi32 => ObjectRef::InputAudio(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1ca4_8f57i32 | -0x6bda_b8cei32 | -0x4d1e_40f8i32 => ObjectRef::InputChatPhoto(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            -0x0c6d_480ci32 => ObjectRef::InputContact(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x72f0_eaaei32 | 0x1879_8952i32 => ObjectRef::InputDocument(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x0ebe_4a1fi32 => ObjectRef::InputEncryptedChat(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1837_c364i32 | 0x64bd_0306i32 | 0x5a17_b5e5i32 | 0x2dc1_73c8i32 => {
+                ObjectRef::InputEncryptedFile(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            -0x0ad0_0d81i32 | -0x05b0_f44bi32 => ObjectRef::InputFile(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1463_7196i32 | 0x3d03_64eci32 | -0x0adc_a2abi32 | 0x74dc_404di32 | 0x4e45_abe9i32 => {
+                ObjectRef::InputFileLocation(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            -0x1b3e_dc2ai32 | -0x0c48_5337i32 => ObjectRef::InputGeoPoint(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x699b_0a81i32 | 0x2dc5_3a7di32 | -0x70d5_4d14i32 | -0x063b_bebci32
+            | -0x591b_a679i32 | 0x133a_d6f6i32 | -0x66ed_2541i32 | 0x7f02_3ae6i32
+            | 0x4e49_8cabi32 | -0x766c_787fi32 | -0x0018_9488i32 | 0x4148_1486i32
+            | -0x2e7b_17bfi32 => ObjectRef::InputMedia(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x4743_a4f4i32 | 0x193b_4417i32 | 0x4a95_e84ei32 | -0x5bd6_477ai32 => {
+                ObjectRef::InputNotifyPeer(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            0x7f3b_18eai32 | 0x7da0_7ec9i32 | 0x1023_dbe8i32 | -0x64bb_8cdbi32 | 0x179b_e863i32 => {
+                ObjectRef::InputPeer(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            -0x0fcf_9b28i32 | -0x1795_d38ci32 => ObjectRef::InputPeerNotifyEvents(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x46a2_ce98i32 => ObjectRef::InputPeerNotifySettings(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1cd7_bf0di32 | -0x046a_393ci32 => ObjectRef::InputPhoto(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x5219_4ffci32 | -0x266e_acdbi32 => ObjectRef::InputPhotoCrop(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x4f96_cb18i32 => ObjectRef::InputPrivacyKey(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x0d09_e07bi32 | 0x184b_35cei32 | 0x131c_c67fi32 | 0x0ba5_2007i32 | -0x2994_9937i32
+            | -0x6fee_fb99i32 => ObjectRef::InputPrivacyRule(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x4677_7931i32 | -0x083e_4ec1i32 | -0x7916_b09bi32 | 0x655e_74ffi32 => {
+                ObjectRef::InputUser(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            0x5508_ec75i32 | -0x11a8_69aei32 => ObjectRef::InputVideo(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7c1a_21aci32 | 0x5676_99b3i32 | -0x5c98_18eai32 | 0x1d86_f70ei32 => {
+                ObjectRef::Message(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            -0x4951_0850i32 | -0x599c_7466i32 | -0x4a5e_31a6i32 | 0x7fcb_13a8i32
+            | -0x6a1c_0411i32 | 0x5e3c_fc4bi32 | -0x4d51_64f4i32 => ObjectRef::MessageAction(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            0x3ded_6320i32 | -0x373b_a5d6i32 | -0x5d2d_bd70i32 | 0x56e0_d474i32
+            | 0x5e7d_2f39i32 | 0x2fda_2204i32 | -0x3949_7d00i32 => ObjectRef::MessageMedia(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            -0x4821_c90ei32 => ObjectRef::MessagesAffectedHistory(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1799_fd3di32 | -0x2310_cefei32 => ObjectRef::MessagesAllStickers(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1a28_2e64i32 => ObjectRef::MessagesChatFull(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7eaf_3428i32 => ObjectRef::MessagesChats(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x3f1d_b9cbi32 | 0x2c22_1eddi32 => ObjectRef::MessagesDhConfig(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x15ba_6c40i32 | 0x71e0_94f3i32 => ObjectRef::MessagesDialogs(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x57e2_f66ci32 | -0x69f6_5ae4i32 | -0x603f_f19bi32 | 0x56e9_f0e4i32
+            | -0x6122_0e78i32 | -0x3037_8adei32 | 0x5afb_f764i32 | 0x7ef0_dd87i32
+            | -0x0037_9a79i32 => ObjectRef::MessagesFilter(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x738e_7179i32 | 0x0b44_6ae3i32 => ObjectRef::MessagesMessages(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x560f_8935i32 | -0x6b6c_00cei32 => ObjectRef::MessagesSentEncryptedMessage(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2e0b_2ca4i32 | -0x1624_b5c1i32 => ObjectRef::MessagesSentMessage(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x2f85_18dai32 | -0x5650_d77fi32 => ObjectRef::MessagesStatedMessage(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x696b_8745i32 | 0x3e74_f5c6i32 => ObjectRef::MessagesStatedMessages(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x0e8b_65dei32 | -0x7571_32cei32 => ObjectRef::MessagesStickers(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x71e5_e88bi32 => ObjectRef::NearestDc(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x602b_f428i32 | -0x4b37_c4b4i32 | -0x3ff8_313di32 | 0x74d0_7c60i32 => {
+                ObjectRef::NotifyPeer(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            0x5673_0bcci32 => ObjectRef::Null(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x624e_4393i32 | -0x452f_1a45i32 => ObjectRef::Peer(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x522a_c34di32 | 0x6d1d_ed88i32 => ObjectRef::PeerNotifyEvents(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x72a1_ee12i32 => ObjectRef::PeerNotifySettings(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7e7b_d933i32 => ObjectRef::PeerSettings(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x2331_b22di32 | 0x22b5_6751i32 => ObjectRef::Photo(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x0e17_e23ci32 | 0x77bf_b61bi32 | -0x1658_cb06i32 => ObjectRef::PhotoSize(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x2021_2ca8i32 => ObjectRef::PhotosPhoto(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x7235_955bi32 | 0x1505_1f54i32 => ObjectRef::PhotosPhotos(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x43d1_54d0i32 => ObjectRef::PrivacyKey(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x0001_e454i32 | 0x6542_7b82i32 | 0x4d5b_be0ci32 | -0x0777_05e6i32
+            | -0x748c_189di32 | 0x0c7f_49b7i32 => ObjectRef::PrivacyRule(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x58db_cab8i32 | 0x1e22_c78di32 | 0x2e59_d922i32 | -0x1e8b_92f6i32 => {
+                ObjectRef::ReportReason(Box::new(TLDeserialize::tl_deserialize_with_context(
+                    input, context,
+                )?))
+            }
+            0x16bf_744ei32 | -0x02a1_370bi32 | -0x5e78_2991i32 | -0x6dfb_d009i32
+            | -0x2ad0_8c09i32 | -0x1953_7591i32 | -0x66f5_c3e6i32 | -0x7051_1672i32
+            | 0x176f_8ba1i32 | 0x628c_bc6fi32 => ObjectRef::SendMessageAction(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x12b2_99d4i32 => ObjectRef::StickerPack(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x5569_c4fbi32 | 0x40bc_6f52i32 | 0x007e_fe0ei32 | -0x351e_5521i32
+            | 0x0a4f_63c0i32 | -0x51e1_af73i32 | 0x528a_0677i32 | 0x4b09_ebbci32
+            | -0x4c31_5f1ci32 | 0x1081_464ci32 => ObjectRef::StorageFileType(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x3fed_d339i32 => ObjectRef::True(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x013a_bdb3i32 | 0x4e90_bfd6i32 | -0x399b_61cfi32 | -0x56d4_01dai32
+            | 0x5c48_6927i32 | -0x659a_15e1i32 | 0x0776_1198i32 | 0x1bfb_d823i32
+            | -0x58cc_d48di32 | -0x6ace_c4f4i32 | 0x2575_bbb9i32 | 0x51a4_8a9ai32
+            | -0x70f9_ad66i32 | 0x12bc_bd9ai32 | 0x1710_f156i32 | -0x4b5d_1773i32
+            | 0x38fe_25b7i32 | 0x3a0e_eb22i32 | 0x6e5f_8c22i32 | -0x71a1_678di32
+            | -0x7f13_17e6i32 | -0x413d_9711i32 | 0x382d_d3e4i32 | -0x11c4_d8d6i32
+            | 0x12b9_417bi32 => ObjectRef::Update(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x1ce8_5082i32 | -0x2c0b_a87ci32 | 0x2b2f_bd4ei32 | 0x78d4_dec1i32
+            | 0x725b_04c3i32 | 0x74ae_4240i32 => ObjectRef::Updates(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x5d75_a138i32 | 0x00f4_9ca0i32 | -0x5704_e67fi32 => ObjectRef::UpdatesDifference(
+                Box::new(TLDeserialize::tl_deserialize_with_context(input, context)?),
+            ),
+            -0x5a93_d5c2i32 => ObjectRef::UpdatesState(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x096a_18d5i32 => ObjectRef::UploadFile(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x2002_50bai32 | 0x7007_b451i32 | -0x354c_a1e8i32 | -0x2633_3b11i32
+            | 0x075c_f7a8i32 | -0x29fe_9286i32 => ObjectRef::User(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x7710_95dai32 => ObjectRef::UserFull(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x4f11_bae1i32 | -0x2aa6_2738i32 => ObjectRef::UserProfilePhoto(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x09d0_5049i32 | -0x1246_c6b7i32 | 0x008c_703fi32 | -0x1d90_bd0fi32
+            | 0x07bf_09fci32 | 0x77eb_c742i32 => ObjectRef::UserStatus(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x3ef9_a758i32 | 0x388f_a391i32 => ObjectRef::Video(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            -0x334f_c9a9i32 | 0x6311_7f24i32 => ObjectRef::WallPaper(Box::new(
+                TLDeserialize::tl_deserialize_with_context(input, context)?,
+            )),
+            0x1cb5_c415i32 => {
+                context.enter()?;
+                let result =
+                    ObjectRef::Vector(TLDeserialize::tl_deserialize_with_context(input, context)?);
+                context.leave();
+                result
+            }
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Object",
+                })
+            }
+        })
+    }
+}
@@ -0,0 +1,277 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `photos.photo#20212ca8 photo:Photo users:Vector<User> = photos.Photo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Photo {
+    pub photo: super::tl::Photo,
+    pub users: Vec<super::tl::User>,
+}
+impl TLType for Photo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2021_2ca8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.Photo",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2021_2ca8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Photo {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Photo {
+            photo: TLType::tl_read_with_context(input, context)?,
+            users: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.photo.tl_write(output)?;
+        result += self.users.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.photo.serialized_len();
+        result += self.users.serialized_len();
+        result
+    }
+}
+/// `photos.photo#20212ca8 photo:Photo users:Vector<User> = photos.Photo`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhotoRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub photo: super::tl::PhotoRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub users: Vec<super::tl::UserRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for PhotoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x2021_2ca8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "photos.Photo",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for PhotoRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PhotoRef {
+            photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PhotoBuilder {
+    photo: super::tl::Photo,
+    users: Vec<super::tl::User>,
+}
+impl PhotoBuilder {
+    pub fn new(photo: super::tl::Photo, users: Vec<super::tl::User>) -> Self {
+        PhotoBuilder { photo, users }
+    }
+    pub fn build(self) -> Photo {
+        Photo {
+            photo: self.photo,
+            users: self.users,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Photos {
+    /// `photos.photos#8dca6aa5 photos:Vector<Photo> users:Vector<User> = photos.Photos`
+    Photos {
+        photos: Vec<super::tl::Photo>,
+        users: Vec<super::tl::User>,
+    },
+    /// `photos.photosSlice#15051f54 count:int photos:Vector<Photo> users:Vector<User> = photos.Photos`
+    PhotosSlice {
+        count: i32,
+        photos: Vec<super::tl::Photo>,
+        users: Vec<super::tl::User>,
+    },
+}
+impl TLType for Photos {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x7235_955bi32 => Photos::Photos {
+                photos: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1505_1f54i32 => Photos::PhotosSlice {
+                count: TLType::tl_read_with_context(input, context)?,
+                photos: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "photos.Photos",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Photos::Photos { photos, users } => {
+                (-0x7235_955bi32).tl_write(output)?;
+                result += photos.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            Photos::PhotosSlice {
+                count,
+                photos,
+                users,
+            } => {
+                (0x1505_1f54i32).tl_write(output)?;
+                result += count.tl_write(output)?;
+                result += photos.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Photos::Photos { photos, users } => {
+                result += photos.serialized_len();
+                result += users.serialized_len();
+            }
+            Photos::PhotosSlice {
+                count,
+                photos,
+                users,
+            } => {
+                result += count.serialized_len();
+                result += photos.serialized_len();
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhotosRef<'a> {
+    /// `photos.photos#8dca6aa5 photos:Vector<Photo> users:Vector<User> = photos.Photos`
+    Photos {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        photos: Vec<super::tl::PhotoRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+    /// `photos.photosSlice#15051f54 count:int photos:Vector<Photo> users:Vector<User> = photos.Photos`
+    PhotosSlice {
+        count: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        photos: Vec<super::tl::PhotoRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for PhotosRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x7235_955bi32 => PhotosRef::Photos {
+                photos: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1505_1f54i32 => PhotosRef::PhotosSlice {
+                count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photos: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "photos.Photos",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PhotosPhotosBuilder {
+    photos: Vec<super::tl::Photo>,
+    users: Vec<super::tl::User>,
+}
+impl PhotosPhotosBuilder {
+    pub fn new(photos: Vec<super::tl::Photo>, users: Vec<super::tl::User>) -> Self {
+        PhotosPhotosBuilder { photos, users }
+    }
+    pub fn build(self) -> Photos {
+        Photos::Photos {
+            photos: self.photos,
+            users: self.users,
+        }
+    }
+}
+pub struct PhotosPhotosSliceBuilder {
+    count: i32,
+    photos: Vec<super::tl::Photo>,
+    users: Vec<super::tl::User>,
+}
+impl PhotosPhotosSliceBuilder {
+    pub fn new(count: i32, photos: Vec<super::tl::Photo>, users: Vec<super::tl::User>) -> Self {
+        PhotosPhotosSliceBuilder {
+            count,
+            photos,
+            users,
+        }
+    }
+    pub fn build(self) -> Photos {
+        Photos::PhotosSlice {
+            count: self.count,
+            photos: self.photos,
+            users: self.users,
+        }
+    }
+}
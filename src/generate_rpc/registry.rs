@@ -0,0 +1,6792 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// Layer of the schema, e.g. for `invokeWithLayer`
+pub const LAYER: u32 = 23;
+/// Every constructor and method of the schema, sorted by id
+pub static CONSTRUCTORS: &[ConstructorInfo] = &[
+    ConstructorInfo {
+        id: -0x7f13_17e6i32,
+        name: "updateUserBlocked",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "blocked",
+                kind: "Bool",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserBlocked"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7eaf_3428i32,
+        name: "messages.chats",
+        params: &[
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.Chats",
+        rust_path: Some("messages::Chats"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7e7b_d933i32,
+        name: "peerSettings",
+        params: &[
+            ParamInfo {
+                name: "flags",
+                kind: "#",
+            },
+            ParamInfo {
+                name: "report_spam",
+                kind: "flags.0?true",
+            },
+        ],
+        return_type: "PeerSettings",
+        rust_path: Some("tl::PeerSettings"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7c1a_21aci32,
+        name: "messageEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "int",
+        }],
+        return_type: "Message",
+        rust_path: Some("tl::Message::MessageEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7b41_a46di32,
+        name: "account.updateNotifySettings",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputNotifyPeer",
+            },
+            ParamInfo {
+                name: "settings",
+                kind: "InputPeerNotifySettings",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountUpdateNotifySettings"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7b1a_c8c9i32,
+        name: "contacts.exportCard",
+        params: &[],
+        return_type: "Vector<int>",
+        rust_path: Some("functions::ContactsExportCard"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7916_b09bi32,
+        name: "inputUserContact",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "InputUser",
+        rust_path: Some("tl::InputUser::InputUserContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7830_80d1i32,
+        name: "photos.deletePhotos",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<InputPhoto>",
+        }],
+        return_type: "Vector<long>",
+        rust_path: Some("functions::PhotosDeletePhotos"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7678_0cefi32,
+        name: "help.appUpdate",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "critical",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "url",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "text",
+                kind: "string",
+            },
+        ],
+        return_type: "help.AppUpdate",
+        rust_path: Some("help::AppUpdate::AppUpdate"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x766c_787fi32,
+        name: "inputMediaAudio",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputAudio",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7571_32cei32,
+        name: "messages.stickers",
+        params: &[
+            ParamInfo {
+                name: "hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "stickers",
+                kind: "Vector<Document>",
+            },
+        ],
+        return_type: "messages.Stickers",
+        rust_path: Some("messages::Stickers::Stickers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x748c_189di32,
+        name: "privacyValueDisallowAll",
+        params: &[],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueDisallowAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x738e_7179i32,
+        name: "messages.messages",
+        params: &[
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.Messages",
+        rust_path: Some("messages::Messages::Messages"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x72a1_ee12i32,
+        name: "peerNotifySettings",
+        params: &[
+            ParamInfo {
+                name: "mute_until",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "sound",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "show_previews",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "events_mask",
+                kind: "int",
+            },
+        ],
+        return_type: "PeerNotifySettings",
+        rust_path: Some("tl::PeerNotifySettings"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7235_955bi32,
+        name: "photos.photos",
+        params: &[
+            ParamInfo {
+                name: "photos",
+                kind: "Vector<Photo>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "photos.Photos",
+        rust_path: Some("photos::Photos::Photos"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x71e5_e88bi32,
+        name: "nearestDc",
+        params: &[
+            ParamInfo {
+                name: "country",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "this_dc",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "nearest_dc",
+                kind: "int",
+            },
+        ],
+        return_type: "NearestDc",
+        rust_path: Some("tl::NearestDc"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x71a1_678di32,
+        name: "updateDcOptions",
+        params: &[ParamInfo {
+            name: "dc_options",
+            kind: "Vector<DcOption>",
+        }],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateDcOptions"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x716a_c8bci32,
+        name: "contacts.deleteContact",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputUser",
+        }],
+        return_type: "contacts.Link",
+        rust_path: Some("functions::ContactsDeleteContact"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x70f9_ad66i32,
+        name: "updateNewAuthorization",
+        params: &[
+            ParamInfo {
+                name: "auth_key_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "device",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "location",
+                kind: "string",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateNewAuthorization"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x70d5_4d14i32,
+        name: "inputMediaPhoto",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputPhoto",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x7051_1672i32,
+        name: "sendMessageUploadDocumentAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageUploadDocumentAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6ff7_fd5fi32,
+        name: "contacts.blockedSlice",
+        params: &[
+            ParamInfo {
+                name: "count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "blocked",
+                kind: "Vector<ContactBlocked>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "contacts.Blocked",
+        rust_path: Some("contacts::Blocked::BlockedSlice"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6fee_fb99i32,
+        name: "inputPrivacyValueDisallowUsers",
+        params: &[ParamInfo {
+            name: "users",
+            kind: "Vector<InputUser>",
+        }],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueDisallowUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6dfb_d009i32,
+        name: "sendMessageUploadVideoAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageUploadVideoAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6d5e_20d1i32,
+        name: "messages.getHistory",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.Messages",
+        rust_path: Some("functions::MessagesGetHistory"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6bda_b8cei32,
+        name: "inputChatUploadedPhoto",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "crop",
+                kind: "InputPhotoCrop",
+            },
+        ],
+        return_type: "InputChatPhoto",
+        rust_path: Some("tl::InputChatPhoto::InputChatUploadedPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6b6c_00cei32,
+        name: "messages.sentEncryptedFile",
+        params: &[
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "file",
+                kind: "EncryptedFile",
+            },
+        ],
+        return_type: "messages.SentEncryptedMessage",
+        rust_path: Some("messages::SentEncryptedMessage::SentEncryptedFile"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6ace_c4f4i32,
+        name: "updateUserPhoto",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "UserProfilePhoto",
+            },
+            ParamInfo {
+                name: "previous",
+                kind: "Bool",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6a1c_0411i32,
+        name: "messageActionChatDeletePhoto",
+        params: &[],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatDeletePhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x69f6_5ae4i32,
+        name: "inputMessagesFilterPhotos",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterPhotos"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x699b_0a81i32,
+        name: "inputMediaEmpty",
+        params: &[],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x696b_8745i32,
+        name: "messages.statedMessages",
+        params: &[
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.StatedMessages",
+        rust_path: Some("messages::StatedMessages::StatedMessages"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x66f5_c3e6i32,
+        name: "sendMessageUploadPhotoAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageUploadPhotoAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x66ed_2541i32,
+        name: "inputMediaUploadedThumbVideo",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "thumb",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedThumbVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x668d_8a4bi32,
+        name: "boolTrue",
+        params: &[],
+        return_type: "Bool",
+        rust_path: None,
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x659a_15e1i32,
+        name: "updateChatUserTyping",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "action",
+                kind: "SendMessageAction",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateChatUserTyping"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x656f_e49ai32,
+        name: "messages.sendEncryptedFile",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "data",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "file",
+                kind: "InputEncryptedFile",
+            },
+        ],
+        return_type: "messages.SentEncryptedMessage",
+        rust_path: Some("functions::MessagesSendEncryptedFile"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x64bb_8cdbi32,
+        name: "inputPeerForeign",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputPeer",
+        rust_path: Some("tl::InputPeer::InputPeerForeign"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x645d_2800i32,
+        name: "chatEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "int",
+        }],
+        return_type: "Chat",
+        rust_path: Some("tl::Chat::ChatEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6320_f733i32,
+        name: "help.getSupport",
+        params: &[],
+        return_type: "help.Support",
+        rust_path: Some("functions::HelpGetSupport"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x624e_4393i32,
+        name: "peerUser",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "Peer",
+        rust_path: Some("tl::Peer::PeerUser"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6122_0e78i32,
+        name: "inputMessagesFilterDocument",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x6054_f2e6i32,
+        name: "auth.resetAuthorizations",
+        params: &[],
+        return_type: "Bool",
+        rust_path: Some("functions::AuthResetAuthorizations"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x603f_f19bi32,
+        name: "inputMessagesFilterVideo",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x602b_f428i32,
+        name: "notifyPeer",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "Peer",
+        }],
+        return_type: "NotifyPeer",
+        rust_path: Some("tl::NotifyPeer::NotifyPeer"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5e78_2991i32,
+        name: "sendMessageRecordVideoAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageRecordVideoAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5d2d_bd70i32,
+        name: "messageMediaVideo",
+        params: &[ParamInfo {
+            name: "video",
+            kind: "Video",
+        }],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5c98_18eai32,
+        name: "messageForwarded",
+        params: &[
+            ParamInfo {
+                name: "flags",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "fwd_from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "fwd_date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "to_id",
+                kind: "Peer",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "media",
+                kind: "MessageMedia",
+            },
+        ],
+        return_type: "Message",
+        rust_path: Some("tl::Message::MessageForwarded"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5c7d_a1b0i32,
+        name: "messages.setTyping",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "action",
+                kind: "SendMessageAction",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesSetTyping"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5c37_a28ai32,
+        name: "messages.sendMedia",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "media",
+                kind: "InputMedia",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesSendMedia"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5bf8_570ci32,
+        name: "account.sendChangePhoneCode",
+        params: &[ParamInfo {
+            name: "phone_number",
+            kind: "string",
+        }],
+        return_type: "account.SentChangePhoneCode",
+        rust_path: Some("functions::AccountSendChangePhoneCode"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5bd6_477ai32,
+        name: "inputNotifyAll",
+        params: &[],
+        return_type: "InputNotifyPeer",
+        rust_path: Some("tl::InputNotifyPeer::InputNotifyAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5b56_ae7ai32,
+        name: "help.getInviteText",
+        params: &[ParamInfo {
+            name: "lang_code",
+            kind: "string",
+        }],
+        return_type: "help.InviteText",
+        rust_path: Some("functions::HelpGetInviteText"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5b0a_73b4i32,
+        name: "account.sentChangePhoneCode",
+        params: &[
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "send_call_timeout",
+                kind: "int",
+            },
+        ],
+        return_type: "account.SentChangePhoneCode",
+        rust_path: Some("account::SentChangePhoneCode"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5a93_d5c2i32,
+        name: "updates.state",
+        params: &[
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "qts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "unread_count",
+                kind: "int",
+            },
+        ],
+        return_type: "updates.State",
+        rust_path: Some("updates::State"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x599c_7466i32,
+        name: "messageActionChatCreate",
+        params: &[
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<int>",
+            },
+        ],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatCreate"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x591b_a679i32,
+        name: "inputMediaContact",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x58cc_d48di32,
+        name: "updateUserName",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserName"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x587f_e0b9i32,
+        name: "contacts.foreignLinkRequested",
+        params: &[ParamInfo {
+            name: "has_phone",
+            kind: "Bool",
+        }],
+        return_type: "contacts.ForeignLink",
+        rust_path: Some("contacts::ForeignLink::ForeignLinkRequested"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x570e_8f65i32,
+        name: "messages.hideReportSpam",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputPeer",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesHideReportSpam"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5704_e67fi32,
+        name: "updates.differenceSlice",
+        params: &[
+            ParamInfo {
+                name: "new_messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "new_encrypted_messages",
+                kind: "Vector<EncryptedMessage>",
+            },
+            ParamInfo {
+                name: "other_updates",
+                kind: "Vector<Update>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "intermediate_state",
+                kind: "updates.State",
+            },
+        ],
+        return_type: "updates.Difference",
+        rust_path: Some("updates::Difference::DifferenceSlice"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x56d4_01dai32,
+        name: "updateDeleteMessages",
+        params: &[
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<int>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateDeleteMessages"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5688_988di32,
+        name: "messages.sendEncrypted",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "data",
+                kind: "bytes",
+            },
+        ],
+        return_type: "messages.SentEncryptedMessage",
+        rust_path: Some("functions::MessagesSendEncrypted"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5650_d77fi32,
+        name: "messages.statedMessageLink",
+        params: &[
+            ParamInfo {
+                name: "message",
+                kind: "Message",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "links",
+                kind: "Vector<contacts.Link>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("messages::StatedMessage::StatedMessageLink"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x55c4_3798i32,
+        name: "messages.getAllStickers",
+        params: &[ParamInfo {
+            name: "hash",
+            kind: "string",
+        }],
+        return_type: "messages.AllStickers",
+        rust_path: Some("functions::MessagesGetAllStickers"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5569_c4fbi32,
+        name: "storage.fileUnknown",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileUnknown"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x54c5_6654i32,
+        name: "dialog",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "Peer",
+            },
+            ParamInfo {
+                name: "top_message",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "unread_count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "notify_settings",
+                kind: "PeerNotifySettings",
+            },
+        ],
+        return_type: "Dialog",
+        rust_path: Some("tl::Dialog"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5481_3f60i32,
+        name: "encryptedChatEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "int",
+        }],
+        return_type: "EncryptedChat",
+        rust_path: Some("tl::EncryptedChat::EncryptedChatEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x52ad_bcebi32,
+        name: "contacts.importedContacts",
+        params: &[
+            ParamInfo {
+                name: "imported",
+                kind: "Vector<ImportedContact>",
+            },
+            ParamInfo {
+                name: "retry_contacts",
+                kind: "Vector<long>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "contacts.ImportedContacts",
+        rust_path: Some("contacts::ImportedContacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x522a_c34di32,
+        name: "peerNotifyEventsEmpty",
+        params: &[],
+        return_type: "PeerNotifyEvents",
+        rust_path: Some("tl::PeerNotifyEvents::PeerNotifyEventsEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x5219_4ffci32,
+        name: "inputPhotoCropAuto",
+        params: &[],
+        return_type: "InputPhotoCrop",
+        rust_path: Some("tl::InputPhotoCrop::InputPhotoCropAuto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x51e7_62a1i32,
+        name: "account.reportPeer",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "reason",
+                kind: "ReportReason",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountReportPeer"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x51e1_af73i32,
+        name: "storage.filePdf",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FilePdf"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x51dd_1fbbi32,
+        name: "messages.getStickers",
+        params: &[
+            ParamInfo {
+                name: "emoticon",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "hash",
+                kind: "string",
+            },
+        ],
+        return_type: "messages.Stickers",
+        rust_path: Some("functions::MessagesGetStickers"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x519c_90dci32,
+        name: "disabledFeature",
+        params: &[
+            ParamInfo {
+                name: "feature",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "description",
+                kind: "string",
+            },
+        ],
+        return_type: "DisabledFeature",
+        rust_path: Some("tl::DisabledFeature"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4d51_64f4i32,
+        name: "messageActionChatDeleteUser",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatDeleteUser"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4d1e_40f8i32,
+        name: "inputChatPhoto",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "InputPhoto",
+            },
+            ParamInfo {
+                name: "crop",
+                kind: "InputPhotoCrop",
+            },
+        ],
+        return_type: "InputChatPhoto",
+        rust_path: Some("tl::InputChatPhoto::InputChatPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4cfb_59dfi32,
+        name: "upload.saveFilePart",
+        params: &[
+            ParamInfo {
+                name: "file_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "file_part",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::UploadSaveFilePart"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4c31_5f1ci32,
+        name: "storage.fileMp4",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileMp4"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4b5d_1773i32,
+        name: "updateEncryption",
+        params: &[
+            ParamInfo {
+                name: "chat",
+                kind: "EncryptedChat",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateEncryption"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4b43_974bi32,
+        name: "messages.editChatTitle",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesEditChatTitle"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4b37_c4b4i32,
+        name: "notifyUsers",
+        params: &[],
+        return_type: "NotifyPeer",
+        rust_path: Some("tl::NotifyPeer::NotifyUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4a5e_31a6i32,
+        name: "messageActionChatEditTitle",
+        params: &[ParamInfo {
+            name: "title",
+            kind: "string",
+        }],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatEditTitle"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4951_0850i32,
+        name: "messageActionEmpty",
+        params: &[],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x48b4_562ei32,
+        name: "contacts.contactsNotModified",
+        params: &[],
+        return_type: "contacts.Contacts",
+        rust_path: Some("contacts::Contacts::ContactsNotModified"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4821_c90ei32,
+        name: "messages.affectedHistory",
+        params: &[
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.AffectedHistory",
+        rust_path: Some("messages::AffectedHistory"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4811_aac4i32,
+        name: "photos.getUserPhotos",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "InputUser",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "photos.Photos",
+        rust_path: Some("functions::PhotosGetUserPhotos"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4743_a4f4i32,
+        name: "inputNotifyPeer",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputPeer",
+        }],
+        return_type: "InputNotifyPeer",
+        rust_path: Some("tl::InputNotifyPeer::InputNotifyPeer"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x472f_5021i32,
+        name: "accountDaysTTL",
+        params: &[ParamInfo {
+            name: "days",
+            kind: "int",
+        }],
+        return_type: "AccountDaysTTL",
+        rust_path: Some("tl::AccountDaysTtl"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4677_7931i32,
+        name: "inputUserEmpty",
+        params: &[],
+        return_type: "InputUser",
+        rust_path: Some("tl::InputUser::InputUserEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x452f_1a45i32,
+        name: "peerChat",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "Peer",
+        rust_path: Some("tl::Peer::PeerChat"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x43d1_54d0i32,
+        name: "privacyKeyStatusTimestamp",
+        params: &[],
+        return_type: "PrivacyKey",
+        rust_path: Some("tl::PrivacyKey"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x4386_68c9i32,
+        name: "boolFalse",
+        params: &[],
+        return_type: "Bool",
+        rust_path: None,
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x432a_ea7fi32,
+        name: "auth.signIn",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code",
+                kind: "string",
+            },
+        ],
+        return_type: "auth.Authorization",
+        rust_path: Some("functions::AuthSignIn"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x427d_49a8i32,
+        name: "messages.report",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "id",
+                kind: "Vector<int>",
+            },
+            ParamInfo {
+                name: "reason",
+                kind: "ReportReason",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesReport"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x413d_9711i32,
+        name: "updateNotifySettings",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "NotifyPeer",
+            },
+            ParamInfo {
+                name: "notify_settings",
+                kind: "PeerNotifySettings",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateNotifySettings"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3ff8_313di32,
+        name: "notifyChats",
+        params: &[],
+        return_type: "NotifyPeer",
+        rust_path: Some("tl::NotifyPeer::NotifyChats"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3fb3_053ei32,
+        name: "account.getWallPapers",
+        params: &[],
+        return_type: "Vector<WallPaper>",
+        rust_path: Some("functions::AccountGetWallPapers"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3f1d_b9cbi32,
+        name: "messages.dhConfigNotModified",
+        params: &[ParamInfo {
+            name: "random",
+            kind: "bytes",
+        }],
+        return_type: "messages.DhConfig",
+        rust_path: Some("messages::DhConfig::DhConfigNotModified"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3ef9_a758i32,
+        name: "videoEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "long",
+        }],
+        return_type: "Video",
+        rust_path: Some("tl::Video::VideoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3de0_b682i32,
+        name: "encryptedFileEmpty",
+        params: &[],
+        return_type: "EncryptedFile",
+        rust_path: Some("tl::EncryptedFile::EncryptedFileEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3dbf_1427i32,
+        name: "contacts.myLinkContact",
+        params: &[],
+        return_type: "contacts.MyLink",
+        rust_path: Some("contacts::MyLink::MyLinkContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3c3a_32ddi32,
+        name: "messages.deleteChatUser",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "InputUser",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesDeleteChatUser"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3ba5_9acai32,
+        name: "help.noAppUpdate",
+        params: &[],
+        return_type: "help.AppUpdate",
+        rust_path: Some("help::AppUpdate::NoAppUpdate"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3b5c_ac12i32,
+        name: "contacts.getStatuses",
+        params: &[],
+        return_type: "Vector<ContactStatus>",
+        rust_path: Some("functions::ContactsGetStatuses"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3b46_0645i32,
+        name: "error",
+        params: &[
+            ParamInfo {
+                name: "code",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "text",
+                kind: "string",
+            },
+        ],
+        return_type: "Error",
+        rust_path: Some("tl::Error"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3b06_e795i32,
+        name: "help.getConfig",
+        params: &[],
+        return_type: "Config",
+        rust_path: Some("functions::HelpGetConfig"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x399b_61cfi32,
+        name: "updateReadMessages",
+        params: &[
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<int>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateReadMessages"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3949_7d00i32,
+        name: "messageMediaAudio",
+        params: &[ParamInfo {
+            name: "audio",
+            kind: "Audio",
+        }],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3853_9b6ai32,
+        name: "audio",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "size",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "dc_id",
+                kind: "int",
+            },
+        ],
+        return_type: "Audio",
+        rust_path: Some("tl::Audio::Audio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x37ed_5382i32,
+        name: "help.getAppUpdate",
+        params: &[
+            ParamInfo {
+                name: "device_model",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "system_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "app_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "lang_code",
+                kind: "string",
+            },
+        ],
+        return_type: "help.AppUpdate",
+        rust_path: Some("functions::HelpGetAppUpdate"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3787_ad82i32,
+        name: "encryptedChatRequested",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "admin_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "participant_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "g_a",
+                kind: "bytes",
+            },
+        ],
+        return_type: "EncryptedChat",
+        rust_path: Some("tl::EncryptedChat::EncryptedChatRequested"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x373b_a5d6i32,
+        name: "messageMediaPhoto",
+        params: &[ParamInfo {
+            name: "photo",
+            kind: "Photo",
+        }],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3728_b6c2i32,
+        name: "chatParticipant",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "inviter_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "ChatParticipant",
+        rust_path: Some("tl::ChatParticipant"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3607_e318i32,
+        name: "account.setPrivacy",
+        params: &[
+            ParamInfo {
+                name: "key",
+                kind: "InputPrivacyKey",
+            },
+            ParamInfo {
+                name: "rules",
+                kind: "Vector<InputPrivacyRule>",
+            },
+        ],
+        return_type: "account.PrivacyRules",
+        rust_path: Some("functions::AccountSetPrivacy"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x35cf_5a4fi32,
+        name: "users.getFullUser",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputUser",
+        }],
+        return_type: "UserFull",
+        rust_path: Some("functions::UsersGetFullUser"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x354c_a1e8i32,
+        name: "userContact",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "phone",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "UserProfilePhoto",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("tl::User::UserContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x351e_5521i32,
+        name: "storage.fileGif",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileGif"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3460_c8d3i32,
+        name: "invokeAfterMsg",
+        params: &[
+            ParamInfo {
+                name: "msg_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "query",
+                kind: "!X",
+            },
+        ],
+        return_type: "X",
+        rust_path: Some("functions::InvokeAfterMsg"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x334f_c9a9i32,
+        name: "wallPaper",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "sizes",
+                kind: "Vector<PhotoSize>",
+            },
+            ParamInfo {
+                name: "color",
+                kind: "int",
+            },
+        ],
+        return_type: "WallPaper",
+        rust_path: Some("tl::WallPaper::WallPaper"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x322b_d5fbi32,
+        name: "auth.bindTempAuthKey",
+        params: &[
+            ParamInfo {
+                name: "perm_auth_key_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "nonce",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "expires_at",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "encrypted_message",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AuthBindTempAuthKey"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x30ea_6d25i32,
+        name: "messages.reportSpam",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputPeer",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesReportSpam"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x3037_8adei32,
+        name: "inputMessagesFilterAudio",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2ffd_7bc8i32,
+        name: "importedContact",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "client_id",
+                kind: "long",
+            },
+        ],
+        return_type: "ImportedContact",
+        rust_path: Some("tl::ImportedContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2f85_18dai32,
+        name: "messages.statedMessage",
+        params: &[
+            ParamInfo {
+                name: "message",
+                kind: "Message",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("messages::StatedMessage::StatedMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2e7b_17bfi32,
+        name: "inputMediaDocument",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputDocument",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2e0b_2ca4i32,
+        name: "messages.sentMessage",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.SentMessage",
+        rust_path: Some("messages::SentMessage::SentMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2dd5_e3a0i32,
+        name: "contacts.myLinkEmpty",
+        params: &[],
+        return_type: "contacts.MyLink",
+        rust_path: Some("contacts::MyLink::MyLinkEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2c97_f39fi32,
+        name: "contactStatus",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+        ],
+        return_type: "ContactStatus",
+        rust_path: Some("tl::ContactStatus"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2c0b_a87ci32,
+        name: "updateShortMessage",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::UpdateShortMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2af0_6378i32,
+        name: "photos.uploadProfilePhoto",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "caption",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "geo_point",
+                kind: "InputGeoPoint",
+            },
+            ParamInfo {
+                name: "crop",
+                kind: "InputPhotoCrop",
+            },
+        ],
+        return_type: "photos.Photo",
+        rust_path: Some("functions::PhotosUploadProfilePhoto"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2ad0_8c09i32,
+        name: "sendMessageRecordAudioAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageRecordAudioAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2aa6_2738i32,
+        name: "userProfilePhoto",
+        params: &[
+            ParamInfo {
+                name: "photo_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "photo_small",
+                kind: "FileLocation",
+            },
+            ParamInfo {
+                name: "photo_big",
+                kind: "FileLocation",
+            },
+        ],
+        return_type: "UserProfilePhoto",
+        rust_path: Some("tl::UserProfilePhoto::UserProfilePhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x29fe_9286i32,
+        name: "userDeleted",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("tl::User::UserDeleted"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2994_9937i32,
+        name: "inputPrivacyValueDisallowAll",
+        params: &[],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueDisallowAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x277e_7de3i32,
+        name: "messages.editChatPhoto",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "InputChatPhoto",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesEditChatPhoto"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x26a5_237ci32,
+        name: "inputAudioEmpty",
+        params: &[],
+        return_type: "InputAudio",
+        rust_path: Some("tl::InputAudio::InputAudioEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x266e_acdbi32,
+        name: "inputPhotoCrop",
+        params: &[
+            ParamInfo {
+                name: "crop_left",
+                kind: "double",
+            },
+            ParamInfo {
+                name: "crop_top",
+                kind: "double",
+            },
+            ParamInfo {
+                name: "crop_width",
+                kind: "double",
+            },
+        ],
+        return_type: "InputPhotoCrop",
+        rust_path: Some("tl::InputPhotoCrop::InputPhotoCrop"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2633_3b11i32,
+        name: "userRequest",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "phone",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "UserProfilePhoto",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("tl::User::UserRequest"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x25cf_4cd3i32,
+        name: "contacts.importContacts",
+        params: &[
+            ParamInfo {
+                name: "contacts",
+                kind: "Vector<InputContact>",
+            },
+            ParamInfo {
+                name: "replace",
+                kind: "Bool",
+            },
+        ],
+        return_type: "contacts.ImportedContacts",
+        rust_path: Some("functions::ContactsImportContacts"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2564_f2f3i32,
+        name: "invokeWithLayer",
+        params: &[
+            ParamInfo {
+                name: "layer",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "query",
+                kind: "!X",
+            },
+        ],
+        return_type: "X",
+        rust_path: Some("functions::InvokeWithLayer"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2524_36b0i32,
+        name: "account.getPrivacy",
+        params: &[ParamInfo {
+            name: "key",
+            kind: "InputPrivacyKey",
+        }],
+        return_type: "account.PrivacyRules",
+        rust_path: Some("functions::AccountGetPrivacy"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2481_e8b9i32,
+        name: "account.resetNotifySettings",
+        params: &[],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountResetNotifySettings"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2310_cefei32,
+        name: "messages.allStickers",
+        params: &[
+            ParamInfo {
+                name: "hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "packs",
+                kind: "Vector<StickerPack>",
+            },
+            ParamInfo {
+                name: "documents",
+                kind: "Vector<Document>",
+            },
+        ],
+        return_type: "messages.AllStickers",
+        rust_path: Some("messages::AllStickers::AllStickers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2184_98c3i32,
+        name: "upload.saveBigFilePart",
+        params: &[
+            ParamInfo {
+                name: "file_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "file_part",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "file_total_parts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::UploadSaveBigFilePart"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x2069_63d3i32,
+        name: "auth.exportedAuthorization",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "auth.ExportedAuthorization",
+        rust_path: Some("auth::ExportedAuthorization"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1e8b_92f6i32,
+        name: "inputReportReasonOther",
+        params: &[ParamInfo {
+            name: "text",
+            kind: "string",
+        }],
+        return_type: "ReportReason",
+        rust_path: Some("tl::ReportReason::InputReportReasonOther"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1d90_bd0fi32,
+        name: "userStatusRecently",
+        params: &[],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusRecently"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1cff_33c5i32,
+        name: "auth.checkedPhone",
+        params: &[
+            ParamInfo {
+                name: "phone_registered",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "phone_invited",
+                kind: "Bool",
+            },
+        ],
+        return_type: "auth.CheckedPhone",
+        rust_path: Some("auth::CheckedPhone"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1ce8_5082i32,
+        name: "updatesTooLong",
+        params: &[],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::UpdatesTooLong"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1cda_1231i32,
+        name: "auth.sentAppCode",
+        params: &[
+            ParamInfo {
+                name: "phone_registered",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "send_call_timeout",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "is_password",
+                kind: "Bool",
+            },
+        ],
+        return_type: "auth.SentCode",
+        rust_path: Some("auth::SentCode::SentAppCode"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1c59_304bi32,
+        name: "upload.getFile",
+        params: &[
+            ParamInfo {
+                name: "location",
+                kind: "InputFileLocation",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "upload.File",
+        rust_path: Some("functions::UploadGetFile"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1c10_69edi32,
+        name: "auth.importAuthorization",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "auth.Authorization",
+        rust_path: Some("functions::AuthImportAuthorization"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1b3e_dc2ai32,
+        name: "inputGeoPointEmpty",
+        params: &[],
+        return_type: "InputGeoPoint",
+        rust_path: Some("tl::InputGeoPoint::InputGeoPointEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1abe_ff43i32,
+        name: "contacts.unblock",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputUser",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::ContactsUnblock"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1a40_0033i32,
+        name: "auth.exportAuthorization",
+        params: &[ParamInfo {
+            name: "dc_id",
+            kind: "int",
+        }],
+        return_type: "auth.ExportedAuthorization",
+        rust_path: Some("functions::AuthExportAuthorization"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1a28_2e64i32,
+        name: "messages.chatFull",
+        params: &[
+            ParamInfo {
+                name: "full_chat",
+                kind: "ChatFull",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.ChatFull",
+        rust_path: Some("messages::ChatFull"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1953_7591i32,
+        name: "sendMessageUploadAudioAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageUploadAudioAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1799_fd3di32,
+        name: "messages.allStickersNotModified",
+        params: &[],
+        return_type: "messages.AllStickers",
+        rust_path: Some("messages::AllStickers::AllStickersNotModified"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1795_d38ci32,
+        name: "inputPeerNotifyEventsAll",
+        params: &[],
+        return_type: "InputPeerNotifyEvents",
+        rust_path: Some("tl::InputPeerNotifyEvents::InputPeerNotifyEventsAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1658_cb06i32,
+        name: "photoCachedSize",
+        params: &[
+            ParamInfo {
+                name: "type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "location",
+                kind: "FileLocation",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "PhotoSize",
+        rust_path: Some("tl::PhotoSize::PhotoCachedSize"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1624_b5c1i32,
+        name: "messages.sentMessageLink",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "links",
+                kind: "Vector<contacts.Link>",
+            },
+        ],
+        return_type: "messages.SentMessage",
+        rust_path: Some("messages::SentMessage::SentMessageLink"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1578_606bi32,
+        name: "contactFound",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "ContactFound",
+        rust_path: Some("tl::ContactFound"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1331_5c0bi32,
+        name: "contacts.link",
+        params: &[
+            ParamInfo {
+                name: "my_link",
+                kind: "contacts.MyLink",
+            },
+            ParamInfo {
+                name: "foreign_link",
+                kind: "contacts.ForeignLink",
+            },
+            ParamInfo {
+                name: "user",
+                kind: "User",
+            },
+        ],
+        return_type: "contacts.Link",
+        rust_path: Some("contacts::Link"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1330_e20ai32,
+        name: "messages.getDialogs",
+        params: &[
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.Dialogs",
+        rust_path: Some("functions::MessagesGetDialogs"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x12e7_3ee8i32,
+        name: "encryptedMessage",
+        params: &[
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "file",
+                kind: "EncryptedFile",
+            },
+        ],
+        return_type: "EncryptedMessage",
+        rust_path: Some("tl::EncryptedMessage::EncryptedMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1246_c6b7i32,
+        name: "userStatusOnline",
+        params: &[ParamInfo {
+            name: "expires",
+            kind: "int",
+        }],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusOnline"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x122b_77d6i32,
+        name: "updates.getState",
+        params: &[],
+        return_type: "updates.State",
+        rust_path: Some("functions::UpdatesGetState"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1226_dc3bi32,
+        name: "messages.discardEncryption",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesDiscardEncryption"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x11c4_d8d6i32,
+        name: "updatePrivacy",
+        params: &[
+            ParamInfo {
+                name: "key",
+                kind: "PrivacyKey",
+            },
+            ParamInfo {
+                name: "rules",
+                kind: "Vector<PrivacyRule>",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdatePrivacy"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x11a8_69aei32,
+        name: "inputVideo",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputVideo",
+        rust_path: Some("tl::InputVideo::InputVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1127_7b3ai32,
+        name: "messages.readHistory",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "max_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "read_contents",
+                kind: "Bool",
+            },
+        ],
+        return_type: "messages.AffectedHistory",
+        rust_path: Some("functions::MessagesReadHistory"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x110a_8660i32,
+        name: "photos.updateProfilePhoto",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "InputPhoto",
+            },
+            ParamInfo {
+                name: "crop",
+                kind: "InputPhotoCrop",
+            },
+        ],
+        return_type: "UserProfilePhoto",
+        rust_path: Some("functions::PhotosUpdateProfilePhoto"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x1012_ae27i32,
+        name: "auth.sentCode",
+        params: &[
+            ParamInfo {
+                name: "phone_registered",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "send_call_timeout",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "is_password",
+                kind: "Bool",
+            },
+        ],
+        return_type: "auth.SentCode",
+        rust_path: Some("auth::SentCode::SentCode"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0fcf_9b28i32,
+        name: "inputPeerNotifyEventsEmpty",
+        params: &[],
+        return_type: "InputPeerNotifyEvents",
+        rust_path: Some("tl::InputPeerNotifyEvents::InputPeerNotifyEventsEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0f77_7298i32,
+        name: "account.updateProfile",
+        params: &[
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("functions::AccountUpdateProfile"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0ebe_4a1fi32,
+        name: "inputEncryptedChat",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputEncryptedChat",
+        rust_path: Some("tl::InputEncryptedChat"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0e8b_65dei32,
+        name: "messages.stickersNotModified",
+        params: &[],
+        return_type: "messages.Stickers",
+        rust_path: Some("messages::Stickers::StickersNotModified"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0c6d_480ci32,
+        name: "inputPhoneContact",
+        params: &[
+            ParamInfo {
+                name: "client_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "phone",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+        ],
+        return_type: "InputContact",
+        rust_path: Some("tl::InputContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0c48_5337i32,
+        name: "inputGeoPoint",
+        params: &[
+            ParamInfo {
+                name: "lat",
+                kind: "double",
+            },
+            ParamInfo {
+                name: "long",
+                kind: "double",
+            },
+        ],
+        return_type: "InputGeoPoint",
+        rust_path: Some("tl::InputGeoPoint::InputGeoPoint"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0b07_049fi32,
+        name: "messages.deleteHistory",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.AffectedHistory",
+        rust_path: Some("functions::MessagesDeleteHistory"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0adc_a2abi32,
+        name: "inputEncryptedFileLocation",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputFileLocation",
+        rust_path: Some("tl::InputFileLocation::InputEncryptedFileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0ad0_0d81i32,
+        name: "inputFile",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "parts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "md5_checksum",
+                kind: "string",
+            },
+        ],
+        return_type: "InputFile",
+        rust_path: Some("tl::InputFile::InputFile"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0a83_caf1i32,
+        name: "contacts.getBlocked",
+        params: &[
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "contacts.Blocked",
+        rust_path: Some("functions::ContactsGetBlocked"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x09b2_50bdi32,
+        name: "messages.requestEncryption",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "InputUser",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "g_a",
+                kind: "bytes",
+            },
+        ],
+        return_type: "EncryptedChat",
+        rust_path: Some("functions::MessagesRequestEncryption"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0949_8c5ci32,
+        name: "auth.authorization",
+        params: &[
+            ParamInfo {
+                name: "expires",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user",
+                kind: "User",
+            },
+        ],
+        return_type: "auth.Authorization",
+        rust_path: Some("auth::Authorization"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x083e_4ec1i32,
+        name: "inputUserSelf",
+        params: &[],
+        return_type: "InputUser",
+        rust_path: Some("tl::InputUser::InputUserSelf"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0777_05e6i32,
+        name: "privacyValueDisallowContacts",
+        params: &[],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueDisallowContacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x06ee_366ci32,
+        name: "contact",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mutual",
+                kind: "Bool",
+            },
+        ],
+        return_type: "Contact",
+        rust_path: Some("tl::Contact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x065c_60b1i32,
+        name: "document",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "size",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "thumb",
+                kind: "PhotoSize",
+            },
+            ParamInfo {
+                name: "dc_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "attributes",
+                kind: "Vector<DocumentAttribute>",
+            },
+        ],
+        return_type: "Document",
+        rust_path: Some("tl::Document::Document"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x063b_bebci32,
+        name: "inputMediaGeoPoint",
+        params: &[ParamInfo {
+            name: "geo_point",
+            kind: "InputGeoPoint",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaGeoPoint"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x05b0_f44bi32,
+        name: "inputFileBig",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "parts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "name",
+                kind: "string",
+            },
+        ],
+        return_type: "InputFile",
+        rust_path: Some("tl::InputFile::InputFileBig"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x05a9_31cai32,
+        name: "encryptedChat",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "admin_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "participant_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "g_a_or_b",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "key_fingerprint",
+                kind: "long",
+            },
+        ],
+        return_type: "EncryptedChat",
+        rust_path: Some("tl::EncryptedChat::EncryptedChat"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x04f5_a8d9i32,
+        name: "documentAttributeSticker",
+        params: &[],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeSticker"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x04f3_33bfi32,
+        name: "chatForbidden",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "Chat",
+        rust_path: Some("tl::Chat::ChatForbidden"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x046a_393ci32,
+        name: "inputPhoto",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputPhoto",
+        rust_path: Some("tl::InputPhoto::InputPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x02a1_370bi32,
+        name: "sendMessageCancelAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageCancelAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0037_9a79i32,
+        name: "inputMessagesFilterGif",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterGif"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0018_9488i32,
+        name: "inputMediaUploadedDocument",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "attributes",
+                kind: "Vector<DocumentAttribute>",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: -0x0001_e454i32,
+        name: "privacyValueAllowContacts",
+        params: &[],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueAllowContacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x007e_fe0ei32,
+        name: "storage.fileJpeg",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileJpeg"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x008c_703fi32,
+        name: "userStatusOffline",
+        params: &[ParamInfo {
+            name: "was_online",
+            kind: "int",
+        }],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusOffline"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x00f4_9ca0i32,
+        name: "updates.difference",
+        params: &[
+            ParamInfo {
+                name: "new_messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "new_encrypted_messages",
+                kind: "Vector<EncryptedMessage>",
+            },
+            ParamInfo {
+                name: "other_updates",
+                kind: "Vector<Update>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "state",
+                kind: "updates.State",
+            },
+        ],
+        return_type: "updates.Difference",
+        rust_path: Some("updates::Difference::Difference"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x013a_bdb3i32,
+        name: "updateNewMessage",
+        params: &[
+            ParamInfo {
+                name: "message",
+                kind: "Message",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateNewMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x03c5_1564i32,
+        name: "auth.sendCall",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AuthSendCall"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0514_48e5i32,
+        name: "documentAttributeAudio",
+        params: &[ParamInfo {
+            name: "duration",
+            kind: "int",
+        }],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0566_000ei32,
+        name: "contacts.found",
+        params: &[
+            ParamInfo {
+                name: "results",
+                kind: "Vector<ContactFound>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "contacts.Found",
+        rust_path: Some("contacts::Found"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x075c_f7a8i32,
+        name: "userForeign",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "UserProfilePhoto",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("tl::User::UserForeign"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0776_1198i32,
+        name: "updateChatParticipants",
+        params: &[ParamInfo {
+            name: "participants",
+            kind: "ChatParticipants",
+        }],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateChatParticipants"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x07bf_09fci32,
+        name: "userStatusLastWeek",
+        params: &[],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusLastWeek"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x07e9_f2abi32,
+        name: "messages.search",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "q",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "filter",
+                kind: "MessagesFilter",
+            },
+            ParamInfo {
+                name: "min_date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "offset",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.Messages",
+        rust_path: Some("functions::MessagesSearch"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x08fc_711di32,
+        name: "account.getAccountTTL",
+        params: &[],
+        return_type: "AccountDaysTTL",
+        rust_path: Some("functions::AccountGetAccountTtl"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x096a_18d5i32,
+        name: "upload.file",
+        params: &[
+            ParamInfo {
+                name: "type",
+                kind: "storage.FileType",
+            },
+            ParamInfo {
+                name: "mtime",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "upload.File",
+        rust_path: Some("upload::File"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x09d0_5049i32,
+        name: "userStatusEmpty",
+        params: &[],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0a04_1495i32,
+        name: "updates.getDifference",
+        params: &[
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "qts",
+                kind: "int",
+            },
+        ],
+        return_type: "updates.Difference",
+        rust_path: Some("functions::UpdatesGetDifference"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0a4f_63c0i32,
+        name: "storage.filePng",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FilePng"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0b44_6ae3i32,
+        name: "messages.messagesSlice",
+        params: &[
+            ParamInfo {
+                name: "count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.Messages",
+        rust_path: Some("messages::Messages::MessagesSlice"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0ba5_2007i32,
+        name: "inputPrivacyValueDisallowContacts",
+        params: &[],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueDisallowContacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0bf0_131ci32,
+        name: "contacts.resolveUsername",
+        params: &[ParamInfo {
+            name: "username",
+            kind: "string",
+        }],
+        return_type: "User",
+        rust_path: Some("functions::ContactsResolveUsername"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0c7f_49b7i32,
+        name: "privacyValueDisallowUsers",
+        params: &[ParamInfo {
+            name: "users",
+            kind: "Vector<int>",
+        }],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueDisallowUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0d09_e07bi32,
+        name: "inputPrivacyValueAllowContacts",
+        params: &[],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueAllowContacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0d91_a548i32,
+        name: "users.getUsers",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<InputUser>",
+        }],
+        return_type: "Vector<User>",
+        rust_path: Some("functions::UsersGetUsers"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0da9_f3e8i32,
+        name: "auth.sendSms",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AuthSendSms"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0e17_e23ci32,
+        name: "photoSizeEmpty",
+        params: &[ParamInfo {
+            name: "type",
+            kind: "string",
+        }],
+        return_type: "PhotoSize",
+        rust_path: Some("tl::PhotoSize::PhotoSizeEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x0fd2_bb8ai32,
+        name: "chatParticipantsForbidden",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "ChatParticipants",
+        rust_path: Some("tl::ChatParticipants::ChatParticipantsForbidden"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1023_dbe8i32,
+        name: "inputPeerContact",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "InputPeer",
+        rust_path: Some("tl::InputPeer::InputPeerContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1081_464ci32,
+        name: "storage.fileWebp",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileWebp"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1117_dd5fi32,
+        name: "geoPointEmpty",
+        params: &[],
+        return_type: "GeoPoint",
+        rust_path: Some("tl::GeoPoint::GeoPointEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x11b5_8939i32,
+        name: "documentAttributeAnimated",
+        params: &[],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeAnimated"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x11f8_12d8i32,
+        name: "contacts.search",
+        params: &[
+            ParamInfo {
+                name: "q",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "limit",
+                kind: "int",
+            },
+        ],
+        return_type: "contacts.Found",
+        rust_path: Some("functions::ContactsSearch"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x12b2_99d4i32,
+        name: "stickerPack",
+        params: &[
+            ParamInfo {
+                name: "emoticon",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "documents",
+                kind: "Vector<long>",
+            },
+        ],
+        return_type: "StickerPack",
+        rust_path: Some("tl::StickerPack"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x12b3_ad31i32,
+        name: "account.getNotifySettings",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputNotifyPeer",
+        }],
+        return_type: "PeerNotifySettings",
+        rust_path: Some("functions::AccountGetNotifySettings"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x12b9_417bi32,
+        name: "updateUserPhone",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "phone",
+                kind: "string",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserPhone"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x12bc_bd9ai32,
+        name: "updateNewEncryptedMessage",
+        params: &[
+            ParamInfo {
+                name: "message",
+                kind: "EncryptedMessage",
+            },
+            ParamInfo {
+                name: "qts",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateNewEncryptedMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x131c_c67fi32,
+        name: "inputPrivacyValueAllowUsers",
+        params: &[ParamInfo {
+            name: "users",
+            kind: "Vector<InputUser>",
+        }],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueAllowUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1334_21f8i32,
+        name: "contacts.foreignLinkUnknown",
+        params: &[],
+        return_type: "contacts.ForeignLink",
+        rust_path: Some("contacts::ForeignLink::ForeignLinkUnknown"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x133a_d6f6i32,
+        name: "inputMediaUploadedVideo",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x13d6_dd27i32,
+        name: "encryptedChatDiscarded",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "int",
+        }],
+        return_type: "EncryptedChat",
+        rust_path: Some("tl::EncryptedChat::EncryptedChatDiscarded"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1463_7196i32,
+        name: "inputFileLocation",
+        params: &[
+            ParamInfo {
+                name: "volume_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "local_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "secret",
+                kind: "long",
+            },
+        ],
+        return_type: "InputFileLocation",
+        rust_path: Some("tl::InputFileLocation::InputFileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x14f2_dd0ai32,
+        name: "messages.deleteMessages",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<int>",
+        }],
+        return_type: "Vector<int>",
+        rust_path: Some("functions::MessagesDeleteMessages"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1505_1f54i32,
+        name: "photos.photosSlice",
+        params: &[
+            ParamInfo {
+                name: "count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "photos",
+                kind: "Vector<Photo>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "photos.Photos",
+        rust_path: Some("photos::Photos::PhotosSlice"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1559_0068i32,
+        name: "documentAttributeFilename",
+        params: &[ParamInfo {
+            name: "file_name",
+            kind: "string",
+        }],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeFilename"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x15ba_6c40i32,
+        name: "messages.dialogs",
+        params: &[
+            ParamInfo {
+                name: "dialogs",
+                kind: "Vector<Dialog>",
+            },
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.Dialogs",
+        rust_path: Some("messages::Dialogs::Dialogs"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x16bf_744ei32,
+        name: "sendMessageTypingAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageTypingAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1710_f156i32,
+        name: "updateEncryptedChatTyping",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateEncryptedChatTyping"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x176f_8ba1i32,
+        name: "sendMessageGeoLocationAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageGeoLocationAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x179b_e863i32,
+        name: "inputPeerChat",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "InputPeer",
+        rust_path: Some("tl::InputPeer::InputPeerChat"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x17c6_b5f6i32,
+        name: "help.support",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "user",
+                kind: "User",
+            },
+        ],
+        return_type: "help.Support",
+        rust_path: Some("help::Support"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1837_c364i32,
+        name: "inputEncryptedFileEmpty",
+        params: &[],
+        return_type: "InputEncryptedFile",
+        rust_path: Some("tl::InputEncryptedFile::InputEncryptedFileEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x184b_35cei32,
+        name: "inputPrivacyValueAllowAll",
+        params: &[],
+        return_type: "InputPrivacyRule",
+        rust_path: Some("tl::InputPrivacyRule::InputPrivacyValueAllowAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1879_8952i32,
+        name: "inputDocument",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputDocument",
+        rust_path: Some("tl::InputDocument::InputDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x18cb_9f78i32,
+        name: "help.inviteText",
+        params: &[ParamInfo {
+            name: "message",
+            kind: "string",
+        }],
+        return_type: "help.InviteText",
+        rust_path: Some("help::InviteText"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x193b_4417i32,
+        name: "inputNotifyUsers",
+        params: &[],
+        return_type: "InputNotifyPeer",
+        rust_path: Some("tl::InputNotifyPeer::InputNotifyUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1b06_7634i32,
+        name: "auth.signUp",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+        ],
+        return_type: "auth.Authorization",
+        rust_path: Some("functions::AuthSignUp"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1bea_8ce1i32,
+        name: "contacts.foreignLinkMutual",
+        params: &[],
+        return_type: "contacts.ForeignLink",
+        rust_path: Some("contacts::ForeignLink::ForeignLinkMutual"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1bfb_d823i32,
+        name: "updateUserStatus",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserStatus"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1c13_8d15i32,
+        name: "contacts.blocked",
+        params: &[
+            ParamInfo {
+                name: "blocked",
+                kind: "Vector<ContactBlocked>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "contacts.Blocked",
+        rust_path: Some("contacts::Blocked::Blocked"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1ca4_8f57i32,
+        name: "inputChatPhotoEmpty",
+        params: &[],
+        return_type: "InputChatPhoto",
+        rust_path: Some("tl::InputChatPhoto::InputChatPhotoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1cb5_c415i32,
+        name: "vector",
+        params: &[],
+        return_type: "Vector t",
+        rust_path: None,
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1cd7_bf0di32,
+        name: "inputPhotoEmpty",
+        params: &[],
+        return_type: "InputPhoto",
+        rust_path: Some("tl::InputPhoto::InputPhotoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1d86_f70ei32,
+        name: "messageService",
+        params: &[
+            ParamInfo {
+                name: "flags",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "to_id",
+                kind: "Peer",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "action",
+                kind: "MessageAction",
+            },
+        ],
+        return_type: "Message",
+        rust_path: Some("tl::Message::MessageService"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1e22_c78di32,
+        name: "inputReportReasonViolence",
+        params: &[],
+        return_type: "ReportReason",
+        rust_path: Some("tl::ReportReason::InputReportReasonViolence"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x1fb3_3026i32,
+        name: "help.getNearestDc",
+        params: &[],
+        return_type: "NearestDc",
+        rust_path: Some("functions::HelpGetNearestDc"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2002_50bai32,
+        name: "userEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "int",
+        }],
+        return_type: "User",
+        rust_path: Some("tl::User::UserEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2021_2ca8i32,
+        name: "photos.photo",
+        params: &[
+            ParamInfo {
+                name: "photo",
+                kind: "Photo",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "photos.Photo",
+        rust_path: Some("photos::Photo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2049_d70ci32,
+        name: "geoPoint",
+        params: &[
+            ParamInfo {
+                name: "long",
+                kind: "double",
+            },
+            ParamInfo {
+                name: "lat",
+                kind: "double",
+            },
+        ],
+        return_type: "GeoPoint",
+        rust_path: Some("tl::GeoPoint::GeoPoint"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x22b5_6751i32,
+        name: "photo",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "caption",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "geo",
+                kind: "GeoPoint",
+            },
+            ParamInfo {
+                name: "sizes",
+                kind: "Vector<PhotoSize>",
+            },
+        ],
+        return_type: "Photo",
+        rust_path: Some("tl::Photo::Photo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x22c6_aa08i32,
+        name: "contacts.getContacts",
+        params: &[ParamInfo {
+            name: "hash",
+            kind: "string",
+        }],
+        return_type: "contacts.Contacts",
+        rust_path: Some("functions::ContactsGetContacts"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2331_b22di32,
+        name: "photoEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "long",
+        }],
+        return_type: "Photo",
+        rust_path: Some("tl::Photo::PhotoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2373_4b06i32,
+        name: "encryptedMessageService",
+        params: &[
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "bytes",
+                kind: "bytes",
+            },
+        ],
+        return_type: "EncryptedMessage",
+        rust_path: Some("tl::EncryptedMessage::EncryptedMessageService"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2442_485ei32,
+        name: "account.setAccountTTL",
+        params: &[ParamInfo {
+            name: "ttl",
+            kind: "AccountDaysTTL",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountSetAccountTtl"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2575_bbb9i32,
+        name: "updateContactRegistered",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateContactRegistered"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x26cf_8950i32,
+        name: "messages.getDhConfig",
+        params: &[
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "random_length",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.DhConfig",
+        rust_path: Some("functions::MessagesGetDhConfig"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2714_d86ci32,
+        name: "account.checkUsername",
+        params: &[ParamInfo {
+            name: "username",
+            kind: "string",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountCheckUsername"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x28ab_cb68i32,
+        name: "messages.receivedMessages",
+        params: &[ParamInfo {
+            name: "max_id",
+            kind: "int",
+        }],
+        return_type: "Vector<int>",
+        rust_path: Some("functions::MessagesReceivedMessages"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2b2f_bd4ei32,
+        name: "updateShortChatMessage",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::UpdateShortChatMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2c22_1eddi32,
+        name: "messages.dhConfig",
+        params: &[
+            ParamInfo {
+                name: "g",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "p",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "random",
+                kind: "bytes",
+            },
+        ],
+        return_type: "messages.DhConfig",
+        rust_path: Some("messages::DhConfig::DhConfig"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2dc1_73c8i32,
+        name: "inputEncryptedFileBigUploaded",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "parts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "key_fingerprint",
+                kind: "int",
+            },
+        ],
+        return_type: "InputEncryptedFile",
+        rust_path: Some("tl::InputEncryptedFile::InputEncryptedFileBigUploaded"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2dc5_3a7di32,
+        name: "inputMediaUploadedPhoto",
+        params: &[ParamInfo {
+            name: "file",
+            kind: "InputFile",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2e59_d922i32,
+        name: "inputReportReasonPornography",
+        params: &[],
+        return_type: "ReportReason",
+        rust_path: Some("tl::ReportReason::InputReportReasonPornography"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2ec2_a43ci32,
+        name: "dcOption",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "hostname",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "ip_address",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "port",
+                kind: "int",
+            },
+        ],
+        return_type: "DcOption",
+        rust_path: Some("tl::DcOption"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2ee9_ee9ei32,
+        name: "messages.addChatUser",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "InputUser",
+            },
+            ParamInfo {
+                name: "fwd_limit",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesAddChatUser"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x2fda_2204i32,
+        name: "messageMediaDocument",
+        params: &[ParamInfo {
+            name: "document",
+            kind: "Document",
+        }],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x32d4_39a4i32,
+        name: "messages.sendEncryptedService",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "data",
+                kind: "bytes",
+            },
+        ],
+        return_type: "messages.SentEncryptedMessage",
+        rust_path: Some("functions::MessagesSendEncryptedService"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x332b_49fci32,
+        name: "contacts.block",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputUser",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::ContactsBlock"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x354b_5bc2i32,
+        name: "messages.readMessageContents",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<int>",
+        }],
+        return_type: "Vector<int>",
+        rust_path: Some("functions::MessagesReadMessageContents"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3672_e09ci32,
+        name: "messages.getPeerSettings",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputPeer",
+        }],
+        return_type: "PeerSettings",
+        rust_path: Some("functions::MessagesGetPeerSettings"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x36f8_c871i32,
+        name: "documentEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "long",
+        }],
+        return_type: "Document",
+        rust_path: Some("tl::Document::DocumentEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x37c1_011ci32,
+        name: "chatPhotoEmpty",
+        params: &[],
+        return_type: "ChatPhoto",
+        rust_path: Some("tl::ChatPhoto::ChatPhotoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x382d_d3e4i32,
+        name: "updateServiceNotification",
+        params: &[
+            ParamInfo {
+                name: "type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "media",
+                kind: "MessageMedia",
+            },
+            ParamInfo {
+                name: "popup",
+                kind: "Bool",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateServiceNotification"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x388f_a391i32,
+        name: "video",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "caption",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "size",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "thumb",
+                kind: "PhotoSize",
+            },
+            ParamInfo {
+                name: "dc_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+        ],
+        return_type: "Video",
+        rust_path: Some("tl::Video::Video"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x38df_3532i32,
+        name: "account.updateDeviceLocked",
+        params: &[ParamInfo {
+            name: "period",
+            kind: "int",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountUpdateDeviceLocked"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x38fe_25b7i32,
+        name: "updateEncryptedMessagesRead",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "max_date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateEncryptedMessagesRead"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3a0e_eb22i32,
+        name: "updateChatParticipantAdd",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "inviter_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateChatParticipantAdd"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3b83_1c66i32,
+        name: "messages.getFullChat",
+        params: &[ParamInfo {
+            name: "chat_id",
+            kind: "int",
+        }],
+        return_type: "messages.ChatFull",
+        rust_path: Some("functions::MessagesGetFullChat"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3bf7_03dci32,
+        name: "encryptedChatWaiting",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "admin_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "participant_id",
+                kind: "int",
+            },
+        ],
+        return_type: "EncryptedChat",
+        rust_path: Some("tl::EncryptedChat::EncryptedChatWaiting"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3c6a_a187i32,
+        name: "messages.getChats",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<int>",
+        }],
+        return_type: "messages.Chats",
+        rust_path: Some("functions::MessagesGetChats"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3d03_64eci32,
+        name: "inputVideoFileLocation",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputFileLocation",
+        rust_path: Some("tl::InputFileLocation::InputVideoFileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3dbc_0415i32,
+        name: "messages.acceptEncryption",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "g_b",
+                kind: "bytes",
+            },
+            ParamInfo {
+                name: "key_fingerprint",
+                kind: "long",
+            },
+        ],
+        return_type: "EncryptedChat",
+        rust_path: Some("functions::MessagesAcceptEncryption"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3dc4_b4f0i32,
+        name: "invokeAfterMsgs",
+        params: &[
+            ParamInfo {
+                name: "msg_ids",
+                kind: "Vector<long>",
+            },
+            ParamInfo {
+                name: "query",
+                kind: "!X",
+            },
+        ],
+        return_type: "X",
+        rust_path: Some("functions::InvokeAfterMsgs"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3ded_6320i32,
+        name: "messageMediaEmpty",
+        params: &[],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3e0b_dd7ci32,
+        name: "account.updateUsername",
+        params: &[ParamInfo {
+            name: "username",
+            kind: "string",
+        }],
+        return_type: "User",
+        rust_path: Some("functions::AccountUpdateUsername"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3e74_f5c6i32,
+        name: "messages.statedMessagesLinks",
+        params: &[
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "links",
+                kind: "Vector<contacts.Link>",
+            },
+            ParamInfo {
+                name: "pts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "messages.StatedMessages",
+        rust_path: Some("messages::StatedMessages::StatedMessagesLinks"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x3fed_d339i32,
+        name: "true",
+        params: &[],
+        return_type: "True",
+        rust_path: Some("tl::True"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x40bc_6f52i32,
+        name: "storage.filePartial",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FilePartial"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4148_1486i32,
+        name: "inputMediaUploadedThumbDocument",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "thumb",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "attributes",
+                kind: "Vector<DocumentAttribute>",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedThumbDocument"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x418d_4e0bi32,
+        name: "account.deleteAccount",
+        params: &[ParamInfo {
+            name: "reason",
+            kind: "string",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountDeleteAccount"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x419d_9aeei32,
+        name: "messages.createChat",
+        params: &[
+            ParamInfo {
+                name: "users",
+                kind: "Vector<InputUser>",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+        ],
+        return_type: "messages.StatedMessage",
+        rust_path: Some("functions::MessagesCreateChat"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4222_fa74i32,
+        name: "messages.getMessages",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<int>",
+        }],
+        return_type: "messages.Messages",
+        rust_path: Some("functions::MessagesGetMessages"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x446c_712ci32,
+        name: "account.registerDevice",
+        params: &[
+            ParamInfo {
+                name: "token_type",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "token",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "device_model",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "system_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "app_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "app_sandbox",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "lang_code",
+                kind: "string",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountRegisterDevice"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x46a2_ce98i32,
+        name: "inputPeerNotifySettings",
+        params: &[
+            ParamInfo {
+                name: "mute_until",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "sound",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "show_previews",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "events_mask",
+                kind: "int",
+            },
+        ],
+        return_type: "InputPeerNotifySettings",
+        rust_path: Some("tl::InputPeerNotifySettings"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4a70_994ci32,
+        name: "encryptedFile",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "size",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "dc_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "key_fingerprint",
+                kind: "int",
+            },
+        ],
+        return_type: "EncryptedFile",
+        rust_path: Some("tl::EncryptedFile::EncryptedFile"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4a95_e84ei32,
+        name: "inputNotifyChats",
+        params: &[],
+        return_type: "InputNotifyPeer",
+        rust_path: Some("tl::InputNotifyPeer::InputNotifyChats"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4b09_ebbci32,
+        name: "storage.fileMov",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileMov"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4b0c_8c0fi32,
+        name: "messages.reportEncryptedSpam",
+        params: &[ParamInfo {
+            name: "peer",
+            kind: "InputEncryptedChat",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesReportEncryptedSpam"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4cde_0aabi32,
+        name: "messages.sendMessage",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+        ],
+        return_type: "messages.SentMessage",
+        rust_path: Some("functions::MessagesSendMessage"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4d5b_be0ci32,
+        name: "privacyValueAllowUsers",
+        params: &[ParamInfo {
+            name: "users",
+            kind: "Vector<int>",
+        }],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueAllowUsers"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4e45_abe9i32,
+        name: "inputDocumentFileLocation",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputFileLocation",
+        rust_path: Some("tl::InputFileLocation::InputDocumentFileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4e49_8cabi32,
+        name: "inputMediaUploadedAudio",
+        params: &[
+            ParamInfo {
+                name: "file",
+                kind: "InputFile",
+            },
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "mime_type",
+                kind: "string",
+            },
+        ],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaUploadedAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4e90_bfd6i32,
+        name: "updateMessageID",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "random_id",
+                kind: "long",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateMessageId"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4f11_bae1i32,
+        name: "userProfilePhotoEmpty",
+        params: &[],
+        return_type: "UserProfilePhoto",
+        rust_path: Some("tl::UserProfilePhoto::UserProfilePhotoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4f96_cb18i32,
+        name: "inputPrivacyKeyStatusTimestamp",
+        params: &[],
+        return_type: "InputPrivacyKey",
+        rust_path: Some("tl::InputPrivacyKey"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x4fe1_96fei32,
+        name: "contacts.importCard",
+        params: &[ParamInfo {
+            name: "export_card",
+            kind: "Vector<int>",
+        }],
+        return_type: "User",
+        rust_path: Some("functions::ContactsImportCard"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x514c_d10fi32,
+        name: "messages.forwardMessages",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputPeer",
+            },
+            ParamInfo {
+                name: "id",
+                kind: "Vector<int>",
+            },
+        ],
+        return_type: "messages.StatedMessages",
+        rust_path: Some("functions::MessagesForwardMessages"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x51a4_8a9ai32,
+        name: "updateContactLink",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "my_link",
+                kind: "contacts.MyLink",
+            },
+            ParamInfo {
+                name: "foreign_link",
+                kind: "contacts.ForeignLink",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateContactLink"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x528a_0677i32,
+        name: "storage.fileMp3",
+        params: &[],
+        return_type: "storage.FileType",
+        rust_path: Some("storage::FileType::FileMp3"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x53d6_9076i32,
+        name: "fileLocation",
+        params: &[
+            ParamInfo {
+                name: "dc_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "volume_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "local_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "secret",
+                kind: "long",
+            },
+        ],
+        return_type: "FileLocation",
+        rust_path: Some("tl::FileLocation::FileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5508_ec75i32,
+        name: "inputVideoEmpty",
+        params: &[],
+        return_type: "InputVideo",
+        rust_path: Some("tl::InputVideo::InputVideoEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x554a_bb6fi32,
+        name: "account.privacyRules",
+        params: &[
+            ParamInfo {
+                name: "rules",
+                kind: "Vector<PrivacyRule>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "account.PrivacyRules",
+        rust_path: Some("account::PrivacyRules"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x55a5_bb66i32,
+        name: "messages.receivedQueue",
+        params: &[ParamInfo {
+            name: "max_qts",
+            kind: "int",
+        }],
+        return_type: "Vector<long>",
+        rust_path: Some("functions::MessagesReceivedQueue"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x560f_8935i32,
+        name: "messages.sentEncryptedMessage",
+        params: &[ParamInfo {
+            name: "date",
+            kind: "int",
+        }],
+        return_type: "messages.SentEncryptedMessage",
+        rust_path: Some("messages::SentEncryptedMessage::SentEncryptedMessage"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x561b_c879i32,
+        name: "contactBlocked",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "ContactBlocked",
+        rust_path: Some("tl::ContactBlocked"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5673_0bcci32,
+        name: "null",
+        params: &[],
+        return_type: "Null",
+        rust_path: Some("tl::Null"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5676_99b3i32,
+        name: "message",
+        params: &[
+            ParamInfo {
+                name: "flags",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "from_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "to_id",
+                kind: "Peer",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "message",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "media",
+                kind: "MessageMedia",
+            },
+        ],
+        return_type: "Message",
+        rust_path: Some("tl::Message::Message"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x56e0_d474i32,
+        name: "messageMediaGeo",
+        params: &[ParamInfo {
+            name: "geo",
+            kind: "GeoPoint",
+        }],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaGeo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x56e9_f0e4i32,
+        name: "inputMessagesFilterPhotoVideo",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterPhotoVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5717_da40i32,
+        name: "auth.logOut",
+        params: &[],
+        return_type: "Bool",
+        rust_path: Some("functions::AuthLogOut"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x57e2_f66ci32,
+        name: "inputMessagesFilterEmpty",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5869_88d8i32,
+        name: "audioEmpty",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "long",
+        }],
+        return_type: "Audio",
+        rust_path: Some("tl::Audio::AudioEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x58db_cab8i32,
+        name: "inputReportReasonSpam",
+        params: &[],
+        return_type: "ReportReason",
+        rust_path: Some("tl::ReportReason::InputReportReasonSpam"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5910_cccbi32,
+        name: "documentAttributeVideo",
+        params: &[
+            ParamInfo {
+                name: "duration",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+        ],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x59ab_389ei32,
+        name: "contacts.deleteContacts",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "Vector<InputUser>",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::ContactsDeleteContacts"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5a17_b5e5i32,
+        name: "inputEncryptedFile",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputEncryptedFile",
+        rust_path: Some("tl::InputEncryptedFile::InputEncryptedFile"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5afb_f764i32,
+        name: "inputMessagesFilterAudioDocuments",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterAudioDocuments"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5c48_6927i32,
+        name: "updateUserTyping",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "action",
+                kind: "SendMessageAction",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateUserTyping"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5d75_a138i32,
+        name: "updates.differenceEmpty",
+        params: &[
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "updates.Difference",
+        rust_path: Some("updates::Difference::DifferenceEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5e3c_fc4bi32,
+        name: "messageActionChatAddUser",
+        params: &[ParamInfo {
+            name: "user_id",
+            kind: "int",
+        }],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatAddUser"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x5e7d_2f39i32,
+        name: "messageMediaContact",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+        ],
+        return_type: "MessageMedia",
+        rust_path: Some("tl::MessageMedia::MessageMediaContact"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6153_276ai32,
+        name: "chatPhoto",
+        params: &[
+            ParamInfo {
+                name: "photo_small",
+                kind: "FileLocation",
+            },
+            ParamInfo {
+                name: "photo_big",
+                kind: "FileLocation",
+            },
+        ],
+        return_type: "ChatPhoto",
+        rust_path: Some("tl::ChatPhoto::ChatPhoto"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x628c_bc6fi32,
+        name: "sendMessageChooseContactAction",
+        params: &[],
+        return_type: "SendMessageAction",
+        rust_path: Some("tl::SendMessageAction::SendMessageChooseContactAction"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x630e_61bei32,
+        name: "chatFull",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "participants",
+                kind: "ChatParticipants",
+            },
+            ParamInfo {
+                name: "chat_photo",
+                kind: "Photo",
+            },
+            ParamInfo {
+                name: "notify_settings",
+                kind: "PeerNotifySettings",
+            },
+        ],
+        return_type: "ChatFull",
+        rust_path: Some("tl::ChatFull"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6311_7f24i32,
+        name: "wallPaperSolid",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "bg_color",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "color",
+                kind: "int",
+            },
+        ],
+        return_type: "WallPaper",
+        rust_path: Some("tl::WallPaper::WallPaperSolid"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x64bd_0306i32,
+        name: "inputEncryptedFileUploaded",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "parts",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "md5_checksum",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "key_fingerprint",
+                kind: "int",
+            },
+        ],
+        return_type: "InputEncryptedFile",
+        rust_path: Some("tl::InputEncryptedFile::InputEncryptedFileUploaded"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6542_7b82i32,
+        name: "privacyValueAllowAll",
+        params: &[],
+        return_type: "PrivacyRule",
+        rust_path: Some("tl::PrivacyRule::PrivacyValueAllowAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x655e_74ffi32,
+        name: "inputUserForeign",
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputUser",
+        rust_path: Some("tl::InputUser::InputUserForeign"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x65c5_5b40i32,
+        name: "account.unregisterDevice",
+        params: &[
+            ParamInfo {
+                name: "token_type",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "token",
+                kind: "string",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountUnregisterDevice"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6628_562ci32,
+        name: "account.updateStatus",
+        params: &[ParamInfo {
+            name: "offline",
+            kind: "Bool",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::AccountUpdateStatus"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6979_6de9i32,
+        name: "initConnection",
+        params: &[
+            ParamInfo {
+                name: "api_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "device_model",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "system_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "app_version",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "lang_code",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "query",
+                kind: "!X",
+            },
+        ],
+        return_type: "X",
+        rust_path: Some("functions::InitConnection"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6c37_c15ci32,
+        name: "documentAttributeImageSize",
+        params: &[
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+        ],
+        return_type: "DocumentAttribute",
+        rust_path: Some("tl::DocumentAttribute::DocumentAttributeImageSize"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6c69_efeei32,
+        name: "contacts.myLinkRequested",
+        params: &[ParamInfo {
+            name: "contact",
+            kind: "Bool",
+        }],
+        return_type: "contacts.MyLink",
+        rust_path: Some("contacts::MyLink::MyLinkRequested"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6d1d_ed88i32,
+        name: "peerNotifyEventsAll",
+        params: &[],
+        return_type: "PeerNotifyEvents",
+        rust_path: Some("tl::PeerNotifyEvents::PeerNotifyEventsAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6e5f_8c22i32,
+        name: "updateChatParticipantDelete",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "user_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+        ],
+        return_type: "Update",
+        rust_path: Some("tl::Update::UpdateChatParticipantDelete"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6e9c_9bc7i32,
+        name: "chat",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "title",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "ChatPhoto",
+            },
+            ParamInfo {
+                name: "participants_count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "left",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+        ],
+        return_type: "Chat",
+        rust_path: Some("tl::Chat::Chat"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6f02_f748i32,
+        name: "help.saveAppLog",
+        params: &[ParamInfo {
+            name: "events",
+            kind: "Vector<InputAppEvent>",
+        }],
+        return_type: "Bool",
+        rust_path: Some("functions::HelpSaveAppLog"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6f8b_8cb2i32,
+        name: "contacts.contacts",
+        params: &[
+            ParamInfo {
+                name: "contacts",
+                kind: "Vector<Contact>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "contacts.Contacts",
+        rust_path: Some("contacts::Contacts::Contacts"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x6fe5_1dfbi32,
+        name: "auth.checkPhone",
+        params: &[ParamInfo {
+            name: "phone_number",
+            kind: "string",
+        }],
+        return_type: "auth.CheckedPhone",
+        rust_path: Some("functions::AuthCheckPhone"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7007_b451i32,
+        name: "userSelf",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "last_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "username",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "photo",
+                kind: "UserProfilePhoto",
+            },
+            ParamInfo {
+                name: "status",
+                kind: "UserStatus",
+            },
+            ParamInfo {
+                name: "inactive",
+                kind: "Bool",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("tl::User::UserSelf"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x70c3_2edbi32,
+        name: "account.changePhone",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "phone_code",
+                kind: "string",
+            },
+        ],
+        return_type: "User",
+        rust_path: Some("functions::AccountChangePhone"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x71e0_94f3i32,
+        name: "messages.dialogsSlice",
+        params: &[
+            ParamInfo {
+                name: "count",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "dialogs",
+                kind: "Vector<Dialog>",
+            },
+            ParamInfo {
+                name: "messages",
+                kind: "Vector<Message>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+        ],
+        return_type: "messages.Dialogs",
+        rust_path: Some("messages::Dialogs::DialogsSlice"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x725b_04c3i32,
+        name: "updatesCombined",
+        params: &[
+            ParamInfo {
+                name: "updates",
+                kind: "Vector<Update>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq_start",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::UpdatesCombined"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x72f0_eaaei32,
+        name: "inputDocumentEmpty",
+        params: &[],
+        return_type: "InputDocument",
+        rust_path: Some("tl::InputDocument::InputDocumentEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x74ae_4240i32,
+        name: "updates",
+        params: &[
+            ParamInfo {
+                name: "updates",
+                kind: "Vector<Update>",
+            },
+            ParamInfo {
+                name: "users",
+                kind: "Vector<User>",
+            },
+            ParamInfo {
+                name: "chats",
+                kind: "Vector<Chat>",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "seq",
+                kind: "int",
+            },
+        ],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::Updates"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x74d0_7c60i32,
+        name: "notifyAll",
+        params: &[],
+        return_type: "NotifyPeer",
+        rust_path: Some("tl::NotifyPeer::NotifyAll"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x74dc_404di32,
+        name: "inputAudioFileLocation",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputFileLocation",
+        rust_path: Some("tl::InputFileLocation::InputAudioFileLocation"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x768d_5f4di32,
+        name: "auth.sendCode",
+        params: &[
+            ParamInfo {
+                name: "phone_number",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "sms_type",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "api_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "api_hash",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "lang_code",
+                kind: "string",
+            },
+        ],
+        return_type: "auth.SentCode",
+        rust_path: Some("functions::AuthSendCode"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7706_56a8i32,
+        name: "inputAppEvent",
+        params: &[
+            ParamInfo {
+                name: "time",
+                kind: "double",
+            },
+            ParamInfo {
+                name: "type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "peer",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "data",
+                kind: "string",
+            },
+        ],
+        return_type: "InputAppEvent",
+        rust_path: Some("tl::InputAppEvent"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7710_95dai32,
+        name: "userFull",
+        params: &[
+            ParamInfo {
+                name: "user",
+                kind: "User",
+            },
+            ParamInfo {
+                name: "link",
+                kind: "contacts.Link",
+            },
+            ParamInfo {
+                name: "profile_photo",
+                kind: "Photo",
+            },
+            ParamInfo {
+                name: "notify_settings",
+                kind: "PeerNotifySettings",
+            },
+            ParamInfo {
+                name: "blocked",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "real_first_name",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "real_last_name",
+                kind: "string",
+            },
+        ],
+        return_type: "UserFull",
+        rust_path: Some("tl::UserFull"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x77bf_b61bi32,
+        name: "photoSize",
+        params: &[
+            ParamInfo {
+                name: "type",
+                kind: "string",
+            },
+            ParamInfo {
+                name: "location",
+                kind: "FileLocation",
+            },
+            ParamInfo {
+                name: "w",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "h",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "size",
+                kind: "int",
+            },
+        ],
+        return_type: "PhotoSize",
+        rust_path: Some("tl::PhotoSize::PhotoSize"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x77d4_40ffi32,
+        name: "inputAudio",
+        params: &[
+            ParamInfo {
+                name: "id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "access_hash",
+                kind: "long",
+            },
+        ],
+        return_type: "InputAudio",
+        rust_path: Some("tl::InputAudio::InputAudio"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x77eb_c742i32,
+        name: "userStatusLastMonth",
+        params: &[],
+        return_type: "UserStatus",
+        rust_path: Some("tl::UserStatus::UserStatusLastMonth"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7841_b415i32,
+        name: "chatParticipants",
+        params: &[
+            ParamInfo {
+                name: "chat_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "admin_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "participants",
+                kind: "Vector<ChatParticipant>",
+            },
+            ParamInfo {
+                name: "version",
+                kind: "int",
+            },
+        ],
+        return_type: "ChatParticipants",
+        rust_path: Some("tl::ChatParticipants::ChatParticipants"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x78d4_dec1i32,
+        name: "updateShort",
+        params: &[
+            ParamInfo {
+                name: "update",
+                kind: "Update",
+            },
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+        ],
+        return_type: "Updates",
+        rust_path: Some("tl::Updates::UpdateShort"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7914_51edi32,
+        name: "messages.setEncryptedTyping",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "typing",
+                kind: "Bool",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesSetEncryptedTyping"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7c59_6b46i32,
+        name: "fileLocationUnavailable",
+        params: &[
+            ParamInfo {
+                name: "volume_id",
+                kind: "long",
+            },
+            ParamInfo {
+                name: "local_id",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "secret",
+                kind: "long",
+            },
+        ],
+        return_type: "FileLocation",
+        rust_path: Some("tl::FileLocation::FileLocationUnavailable"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7da0_7ec9i32,
+        name: "inputPeerSelf",
+        params: &[],
+        return_type: "InputPeer",
+        rust_path: Some("tl::InputPeer::InputPeerSelf"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7dae_33e0i32,
+        name: "config",
+        params: &[
+            ParamInfo {
+                name: "date",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "expires",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "test_mode",
+                kind: "Bool",
+            },
+            ParamInfo {
+                name: "this_dc",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "dc_options",
+                kind: "Vector<DcOption>",
+            },
+            ParamInfo {
+                name: "chat_big_size",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "chat_size_max",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "broadcast_size_max",
+                kind: "int",
+            },
+            ParamInfo {
+                name: "disabled_features",
+                kind: "Vector<DisabledFeature>",
+            },
+        ],
+        return_type: "Config",
+        rust_path: Some("tl::Config"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7ef0_dd87i32,
+        name: "inputMessagesFilterUrl",
+        params: &[],
+        return_type: "MessagesFilter",
+        rust_path: Some("tl::MessagesFilter::InputMessagesFilterUrl"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7f02_3ae6i32,
+        name: "inputMediaVideo",
+        params: &[ParamInfo {
+            name: "id",
+            kind: "InputVideo",
+        }],
+        return_type: "InputMedia",
+        rust_path: Some("tl::InputMedia::InputMediaVideo"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7f3b_18eai32,
+        name: "inputPeerEmpty",
+        params: &[],
+        return_type: "InputPeer",
+        rust_path: Some("tl::InputPeer::InputPeerEmpty"),
+        is_method: false,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7f4b_690ai32,
+        name: "messages.readEncryptedHistory",
+        params: &[
+            ParamInfo {
+                name: "peer",
+                kind: "InputEncryptedChat",
+            },
+            ParamInfo {
+                name: "max_date",
+                kind: "int",
+            },
+        ],
+        return_type: "Bool",
+        rust_path: Some("functions::MessagesReadEncryptedHistory"),
+        is_method: true,
+        layer: None,
+    },
+    ConstructorInfo {
+        id: 0x7fcb_13a8i32,
+        name: "messageActionChatEditPhoto",
+        params: &[ParamInfo {
+            name: "photo",
+            kind: "Photo",
+        }],
+        return_type: "MessageAction",
+        rust_path: Some("tl::MessageAction::MessageActionChatEditPhoto"),
+        is_method: false,
+        layer: None,
+    },
+];
+pub fn by_id(id: i32) -> Option<&'static ConstructorInfo> {
+    CONSTRUCTORS
+        .binary_search_by_key(&id, |x| x.id)
+        .ok()
+        .map(|x| &CONSTRUCTORS[x])
+}
+pub fn by_name(name: &str) -> Option<&'static ConstructorInfo> {
+    CONSTRUCTORS.iter().find(|x| x.name == name)
+}
+/// Layer which defined type `return_type` (e.g. `auth.SentCode`), the lowest of its constructors
+pub fn type_layer(return_type: &str) -> Option<u32> {
+    CONSTRUCTORS
+        .iter()
+        .filter(|x| !x.is_method && x.return_type == return_type)
+        .filter_map(|x| x.layer)
+        .min()
+}
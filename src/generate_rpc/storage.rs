@@ -0,0 +1,123 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FileType {
+    /// `storage.fileUnknown#aa963b05 = storage.FileType`
+    FileUnknown,
+    /// `storage.filePartial#40bc6f52 = storage.FileType`
+    FilePartial,
+    /// `storage.fileJpeg#007efe0e = storage.FileType`
+    FileJpeg,
+    /// `storage.fileGif#cae1aadf = storage.FileType`
+    FileGif,
+    /// `storage.filePng#0a4f63c0 = storage.FileType`
+    FilePng,
+    /// `storage.filePdf#ae1e508d = storage.FileType`
+    FilePdf,
+    /// `storage.fileMp3#528a0677 = storage.FileType`
+    FileMp3,
+    /// `storage.fileMov#4b09ebbc = storage.FileType`
+    FileMov,
+    /// `storage.fileMp4#b3cea0e4 = storage.FileType`
+    FileMp4,
+    /// `storage.fileWebp#1081464c = storage.FileType`
+    FileWebp,
+}
+impl TLType for FileType {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x5569_c4fbi32 => FileType::FileUnknown {},
+            0x40bc_6f52i32 => FileType::FilePartial {},
+            0x007e_fe0ei32 => FileType::FileJpeg {},
+            -0x351e_5521i32 => FileType::FileGif {},
+            0x0a4f_63c0i32 => FileType::FilePng {},
+            -0x51e1_af73i32 => FileType::FilePdf {},
+            0x528a_0677i32 => FileType::FileMp3 {},
+            0x4b09_ebbci32 => FileType::FileMov {},
+            -0x4c31_5f1ci32 => FileType::FileMp4 {},
+            0x1081_464ci32 => FileType::FileWebp {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "storage.FileType",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            FileType::FileUnknown => {
+                (-0x5569_c4fbi32).tl_write(output)?;
+            }
+            FileType::FilePartial => {
+                (0x40bc_6f52i32).tl_write(output)?;
+            }
+            FileType::FileJpeg => {
+                (0x007e_fe0ei32).tl_write(output)?;
+            }
+            FileType::FileGif => {
+                (-0x351e_5521i32).tl_write(output)?;
+            }
+            FileType::FilePng => {
+                (0x0a4f_63c0i32).tl_write(output)?;
+            }
+            FileType::FilePdf => {
+                (-0x51e1_af73i32).tl_write(output)?;
+            }
+            FileType::FileMp3 => {
+                (0x528a_0677i32).tl_write(output)?;
+            }
+            FileType::FileMov => {
+                (0x4b09_ebbci32).tl_write(output)?;
+            }
+            FileType::FileMp4 => {
+                (-0x4c31_5f1ci32).tl_write(output)?;
+            }
+            FileType::FileWebp => {
+                (0x1081_464ci32).tl_write(output)?;
+            }
+        }
+        Ok(4usize)
+    }
+    fn serialized_len(&self) -> usize {
+        4usize
+    }
+}
+impl<'a> TLDeserialize<'a> for FileType {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x5569_c4fbi32 => FileType::FileUnknown {},
+            0x40bc_6f52i32 => FileType::FilePartial {},
+            0x007e_fe0ei32 => FileType::FileJpeg {},
+            -0x351e_5521i32 => FileType::FileGif {},
+            0x0a4f_63c0i32 => FileType::FilePng {},
+            -0x51e1_af73i32 => FileType::FilePdf {},
+            0x528a_0677i32 => FileType::FileMp3 {},
+            0x4b09_ebbci32 => FileType::FileMov {},
+            -0x4c31_5f1ci32 => FileType::FileMp4 {},
+            0x1081_464ci32 => FileType::FileWebp {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "storage.FileType",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
@@ -0,0 +1,13307 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `accountDaysTTL#b8d0afdf days:int = AccountDaysTTL`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountDaysTtl {
+    pub days: i32,
+}
+impl TLType for AccountDaysTtl {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x472f_5021i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "AccountDaysTTL",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x472f_5021i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for AccountDaysTtl {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountDaysTtl {
+            days: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.days.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.days.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for AccountDaysTtl {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x472f_5021i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "AccountDaysTTL",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for AccountDaysTtl {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = AccountDaysTtl {
+            days: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AccountDaysTtlBuilder {
+    days: i32,
+}
+impl AccountDaysTtlBuilder {
+    pub fn new(days: i32) -> Self {
+        AccountDaysTtlBuilder { days }
+    }
+    pub fn build(self) -> AccountDaysTtl {
+        AccountDaysTtl { days: self.days }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Audio {
+    /// `audioEmpty#586988d8 id:long = Audio`
+    AudioEmpty { id: i64 },
+    /// `audio#c7ac6496 id:long access_hash:long user_id:int date:int duration:int mime_type:string size:int dc_id:int = Audio`
+    Audio {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        duration: i32,
+        mime_type: String,
+        size: i32,
+        dc_id: i32,
+    },
+}
+impl TLType for Audio {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x5869_88d8i32 => Audio::AudioEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x3853_9b6ai32 => Audio::Audio {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                duration: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+                size: TLType::tl_read_with_context(input, context)?,
+                dc_id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Audio",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Audio::AudioEmpty { id } => {
+                (0x5869_88d8i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Audio::Audio {
+                id,
+                access_hash,
+                user_id,
+                date,
+                duration,
+                mime_type,
+                size,
+                dc_id,
+            } => {
+                (-0x3853_9b6ai32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+                result += size.tl_write(output)?;
+                result += dc_id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Audio::AudioEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Audio::Audio {
+                id,
+                access_hash,
+                user_id,
+                date,
+                duration,
+                mime_type,
+                size,
+                dc_id,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += user_id.serialized_len();
+                result += date.serialized_len();
+                result += duration.serialized_len();
+                result += mime_type.serialized_len();
+                result += size.serialized_len();
+                result += dc_id.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AudioRef<'a> {
+    /// `audioEmpty#586988d8 id:long = Audio`
+    AudioEmpty { id: i64 },
+    /// `audio#c7ac6496 id:long access_hash:long user_id:int date:int duration:int mime_type:string size:int dc_id:int = Audio`
+    Audio {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        duration: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+        size: i32,
+        dc_id: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for AudioRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x5869_88d8i32 => AudioRef::AudioEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x3853_9b6ai32 => AudioRef::Audio {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                dc_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Audio",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct AudioAudioEmptyBuilder {
+    id: i64,
+}
+impl AudioAudioEmptyBuilder {
+    pub fn new(id: i64) -> Self {
+        AudioAudioEmptyBuilder { id }
+    }
+    pub fn build(self) -> Audio {
+        Audio::AudioEmpty { id: self.id }
+    }
+}
+pub struct AudioAudioBuilder {
+    id: i64,
+    access_hash: i64,
+    user_id: i32,
+    date: i32,
+    duration: i32,
+    mime_type: String,
+    size: i32,
+    dc_id: i32,
+}
+impl AudioAudioBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        duration: i32,
+        mime_type: String,
+        size: i32,
+        dc_id: i32,
+    ) -> Self {
+        AudioAudioBuilder {
+            id,
+            access_hash,
+            user_id,
+            date,
+            duration,
+            mime_type,
+            size,
+            dc_id,
+        }
+    }
+    pub fn build(self) -> Audio {
+        Audio::Audio {
+            id: self.id,
+            access_hash: self.access_hash,
+            user_id: self.user_id,
+            date: self.date,
+            duration: self.duration,
+            mime_type: self.mime_type,
+            size: self.size,
+            dc_id: self.dc_id,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Chat {
+    /// `chatEmpty#9ba2d800 id:int = Chat`
+    ChatEmpty { id: i32 },
+    /// `chat#6e9c9bc7 id:int title:string photo:ChatPhoto participants_count:int date:int left:Bool version:int = Chat`
+    Chat {
+        id: i32,
+        title: String,
+        photo: super::tl::ChatPhoto,
+        participants_count: i32,
+        date: i32,
+        left: bool,
+        version: i32,
+    },
+    /// `chatForbidden#fb0ccc41 id:int title:string date:int = Chat`
+    ChatForbidden { id: i32, title: String, date: i32 },
+}
+impl TLType for Chat {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x645d_2800i32 => Chat::ChatEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x6e9c_9bc7i32 => Chat::Chat {
+                id: TLType::tl_read_with_context(input, context)?,
+                title: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                participants_count: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                left: TLType::tl_read_with_context(input, context)?,
+                version: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x04f3_33bfi32 => Chat::ChatForbidden {
+                id: TLType::tl_read_with_context(input, context)?,
+                title: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Chat",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Chat::ChatEmpty { id } => {
+                (-0x645d_2800i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Chat::Chat {
+                id,
+                title,
+                photo,
+                participants_count,
+                date,
+                left,
+                version,
+            } => {
+                (0x6e9c_9bc7i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += title.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += participants_count.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += left.tl_write(output)?;
+                result += version.tl_write(output)?;
+            }
+            Chat::ChatForbidden { id, title, date } => {
+                (-0x04f3_33bfi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += title.tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Chat::ChatEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Chat::Chat {
+                id,
+                title,
+                photo,
+                participants_count,
+                date,
+                left,
+                version,
+            } => {
+                result += id.serialized_len();
+                result += title.serialized_len();
+                result += photo.serialized_len();
+                result += participants_count.serialized_len();
+                result += date.serialized_len();
+                result += left.serialized_len();
+                result += version.serialized_len();
+            }
+            Chat::ChatForbidden { id, title, date } => {
+                result += id.serialized_len();
+                result += title.serialized_len();
+                result += date.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatRef<'a> {
+    /// `chatEmpty#9ba2d800 id:int = Chat`
+    ChatEmpty { id: i32 },
+    /// `chat#6e9c9bc7 id:int title:string photo:ChatPhoto participants_count:int date:int left:Bool version:int = Chat`
+    Chat {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+        photo: super::tl::ChatPhoto,
+        participants_count: i32,
+        date: i32,
+        left: bool,
+        version: i32,
+    },
+    /// `chatForbidden#fb0ccc41 id:int title:string date:int = Chat`
+    ChatForbidden {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+        date: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for ChatRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x645d_2800i32 => ChatRef::ChatEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x6e9c_9bc7i32 => ChatRef::Chat {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                participants_count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                left: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                version: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x04f3_33bfi32 => ChatRef::ChatForbidden {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Chat",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatChatEmptyBuilder {
+    id: i32,
+}
+impl ChatChatEmptyBuilder {
+    pub fn new(id: i32) -> Self {
+        ChatChatEmptyBuilder { id }
+    }
+    pub fn build(self) -> Chat {
+        Chat::ChatEmpty { id: self.id }
+    }
+}
+pub struct ChatChatBuilder {
+    id: i32,
+    title: String,
+    photo: super::tl::ChatPhoto,
+    participants_count: i32,
+    date: i32,
+    left: bool,
+    version: i32,
+}
+impl ChatChatBuilder {
+    pub fn new(
+        id: i32,
+        title: String,
+        photo: super::tl::ChatPhoto,
+        participants_count: i32,
+        date: i32,
+        left: bool,
+        version: i32,
+    ) -> Self {
+        ChatChatBuilder {
+            id,
+            title,
+            photo,
+            participants_count,
+            date,
+            left,
+            version,
+        }
+    }
+    pub fn build(self) -> Chat {
+        Chat::Chat {
+            id: self.id,
+            title: self.title,
+            photo: self.photo,
+            participants_count: self.participants_count,
+            date: self.date,
+            left: self.left,
+            version: self.version,
+        }
+    }
+}
+pub struct ChatChatForbiddenBuilder {
+    id: i32,
+    title: String,
+    date: i32,
+}
+impl ChatChatForbiddenBuilder {
+    pub fn new(id: i32, title: String, date: i32) -> Self {
+        ChatChatForbiddenBuilder { id, title, date }
+    }
+    pub fn build(self) -> Chat {
+        Chat::ChatForbidden {
+            id: self.id,
+            title: self.title,
+            date: self.date,
+        }
+    }
+}
+/// `chatFull#630e61be id:int participants:ChatParticipants chat_photo:Photo notify_settings:PeerNotifySettings = ChatFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatFull {
+    pub id: i32,
+    pub participants: super::tl::ChatParticipants,
+    pub chat_photo: super::tl::Photo,
+    pub notify_settings: super::tl::PeerNotifySettings,
+}
+impl TLType for ChatFull {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x630e_61bei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ChatFull",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x630e_61bei32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ChatFull {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatFull {
+            id: TLType::tl_read_with_context(input, context)?,
+            participants: TLType::tl_read_with_context(input, context)?,
+            chat_photo: TLType::tl_read_with_context(input, context)?,
+            notify_settings: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        result += self.participants.tl_write(output)?;
+        result += self.chat_photo.tl_write(output)?;
+        result += self.notify_settings.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result += self.participants.serialized_len();
+        result += self.chat_photo.serialized_len();
+        result += self.notify_settings.serialized_len();
+        result
+    }
+}
+/// `chatFull#630e61be id:int participants:ChatParticipants chat_photo:Photo notify_settings:PeerNotifySettings = ChatFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatFullRef<'a> {
+    pub id: i32,
+    pub participants: super::tl::ChatParticipants,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub chat_photo: super::tl::PhotoRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub notify_settings: super::tl::PeerNotifySettingsRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for ChatFullRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x630e_61bei32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ChatFull",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ChatFullRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatFullRef {
+            id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            participants: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            chat_photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            notify_settings: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatFullBuilder {
+    id: i32,
+    participants: super::tl::ChatParticipants,
+    chat_photo: super::tl::Photo,
+    notify_settings: super::tl::PeerNotifySettings,
+}
+impl ChatFullBuilder {
+    pub fn new(
+        id: i32,
+        participants: super::tl::ChatParticipants,
+        chat_photo: super::tl::Photo,
+        notify_settings: super::tl::PeerNotifySettings,
+    ) -> Self {
+        ChatFullBuilder {
+            id,
+            participants,
+            chat_photo,
+            notify_settings,
+        }
+    }
+    pub fn build(self) -> ChatFull {
+        ChatFull {
+            id: self.id,
+            participants: self.participants,
+            chat_photo: self.chat_photo,
+            notify_settings: self.notify_settings,
+        }
+    }
+}
+/// `chatParticipant#c8d7493e user_id:int inviter_id:int date:int = ChatParticipant`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatParticipant {
+    pub user_id: i32,
+    pub inviter_id: i32,
+    pub date: i32,
+}
+impl TLType for ChatParticipant {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3728_b6c2i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ChatParticipant",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3728_b6c2i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ChatParticipant {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatParticipant {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            inviter_id: TLType::tl_read_with_context(input, context)?,
+            date: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.inviter_id.tl_write(output)?;
+        result += self.date.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.inviter_id.serialized_len();
+        result += self.date.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ChatParticipant {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x3728_b6c2i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ChatParticipant",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ChatParticipant {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ChatParticipant {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            inviter_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatParticipantBuilder {
+    user_id: i32,
+    inviter_id: i32,
+    date: i32,
+}
+impl ChatParticipantBuilder {
+    pub fn new(user_id: i32, inviter_id: i32, date: i32) -> Self {
+        ChatParticipantBuilder {
+            user_id,
+            inviter_id,
+            date,
+        }
+    }
+    pub fn build(self) -> ChatParticipant {
+        ChatParticipant {
+            user_id: self.user_id,
+            inviter_id: self.inviter_id,
+            date: self.date,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatParticipants {
+    /// `chatParticipantsForbidden#0fd2bb8a chat_id:int = ChatParticipants`
+    ChatParticipantsForbidden { chat_id: i32 },
+    /// `chatParticipants#7841b415 chat_id:int admin_id:int participants:Vector<ChatParticipant> version:int = ChatParticipants`
+    ChatParticipants {
+        chat_id: i32,
+        admin_id: i32,
+        participants: Vec<super::tl::ChatParticipant>,
+        version: i32,
+    },
+}
+impl TLType for ChatParticipants {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x0fd2_bb8ai32 => ChatParticipants::ChatParticipantsForbidden {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x7841_b415i32 => ChatParticipants::ChatParticipants {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                admin_id: TLType::tl_read_with_context(input, context)?,
+                participants: TLType::tl_read_with_context(input, context)?,
+                version: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ChatParticipants",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            ChatParticipants::ChatParticipantsForbidden { chat_id } => {
+                (0x0fd2_bb8ai32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+            }
+            ChatParticipants::ChatParticipants {
+                chat_id,
+                admin_id,
+                participants,
+                version,
+            } => {
+                (0x7841_b415i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += admin_id.tl_write(output)?;
+                result += participants.tl_write(output)?;
+                result += version.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            ChatParticipants::ChatParticipantsForbidden { chat_id } => {
+                result += chat_id.serialized_len();
+            }
+            ChatParticipants::ChatParticipants {
+                chat_id,
+                admin_id,
+                participants,
+                version,
+            } => {
+                result += chat_id.serialized_len();
+                result += admin_id.serialized_len();
+                result += participants.serialized_len();
+                result += version.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ChatParticipants {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x0fd2_bb8ai32 => ChatParticipants::ChatParticipantsForbidden {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x7841_b415i32 => ChatParticipants::ChatParticipants {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                admin_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                participants: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                version: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ChatParticipants",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatParticipantsChatParticipantsForbiddenBuilder {
+    chat_id: i32,
+}
+impl ChatParticipantsChatParticipantsForbiddenBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        ChatParticipantsChatParticipantsForbiddenBuilder { chat_id }
+    }
+    pub fn build(self) -> ChatParticipants {
+        ChatParticipants::ChatParticipantsForbidden {
+            chat_id: self.chat_id,
+        }
+    }
+}
+pub struct ChatParticipantsChatParticipantsBuilder {
+    chat_id: i32,
+    admin_id: i32,
+    participants: Vec<super::tl::ChatParticipant>,
+    version: i32,
+}
+impl ChatParticipantsChatParticipantsBuilder {
+    pub fn new(
+        chat_id: i32,
+        admin_id: i32,
+        participants: Vec<super::tl::ChatParticipant>,
+        version: i32,
+    ) -> Self {
+        ChatParticipantsChatParticipantsBuilder {
+            chat_id,
+            admin_id,
+            participants,
+            version,
+        }
+    }
+    pub fn build(self) -> ChatParticipants {
+        ChatParticipants::ChatParticipants {
+            chat_id: self.chat_id,
+            admin_id: self.admin_id,
+            participants: self.participants,
+            version: self.version,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChatPhoto {
+    /// `chatPhotoEmpty#37c1011c = ChatPhoto`
+    ChatPhotoEmpty,
+    /// `chatPhoto#6153276a photo_small:FileLocation photo_big:FileLocation = ChatPhoto`
+    ChatPhoto {
+        photo_small: super::tl::FileLocation,
+        photo_big: super::tl::FileLocation,
+    },
+}
+impl TLType for ChatPhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x37c1_011ci32 => ChatPhoto::ChatPhotoEmpty {},
+            0x6153_276ai32 => ChatPhoto::ChatPhoto {
+                photo_small: TLType::tl_read_with_context(input, context)?,
+                photo_big: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ChatPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            ChatPhoto::ChatPhotoEmpty => {
+                (0x37c1_011ci32).tl_write(output)?;
+            }
+            ChatPhoto::ChatPhoto {
+                photo_small,
+                photo_big,
+            } => {
+                (0x6153_276ai32).tl_write(output)?;
+                result += photo_small.tl_write(output)?;
+                result += photo_big.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            ChatPhoto::ChatPhotoEmpty => {}
+            ChatPhoto::ChatPhoto {
+                photo_small,
+                photo_big,
+            } => {
+                result += photo_small.serialized_len();
+                result += photo_big.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ChatPhoto {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x37c1_011ci32 => ChatPhoto::ChatPhotoEmpty {},
+            0x6153_276ai32 => ChatPhoto::ChatPhoto {
+                photo_small: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo_big: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ChatPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ChatPhotoChatPhotoBuilder {
+    photo_small: super::tl::FileLocation,
+    photo_big: super::tl::FileLocation,
+}
+impl ChatPhotoChatPhotoBuilder {
+    pub fn new(photo_small: super::tl::FileLocation, photo_big: super::tl::FileLocation) -> Self {
+        ChatPhotoChatPhotoBuilder {
+            photo_small,
+            photo_big,
+        }
+    }
+    pub fn build(self) -> ChatPhoto {
+        ChatPhoto::ChatPhoto {
+            photo_small: self.photo_small,
+            photo_big: self.photo_big,
+        }
+    }
+}
+/// `config#7dae33e0 date:int expires:int test_mode:Bool this_dc:int dc_options:Vector<DcOption> chat_big_size:int chat_size_max:int broadcast_size_max:int disabled_features:Vector<DisabledFeature> = Config`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub date: i32,
+    pub expires: i32,
+    pub test_mode: bool,
+    pub this_dc: i32,
+    pub dc_options: Vec<super::tl::DcOption>,
+    pub chat_big_size: i32,
+    pub chat_size_max: i32,
+    pub broadcast_size_max: i32,
+    pub disabled_features: Vec<super::tl::DisabledFeature>,
+}
+impl TLType for Config {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7dae_33e0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Config",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7dae_33e0i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Config {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Config {
+            date: TLType::tl_read_with_context(input, context)?,
+            expires: TLType::tl_read_with_context(input, context)?,
+            test_mode: TLType::tl_read_with_context(input, context)?,
+            this_dc: TLType::tl_read_with_context(input, context)?,
+            dc_options: TLType::tl_read_with_context(input, context)?,
+            chat_big_size: TLType::tl_read_with_context(input, context)?,
+            chat_size_max: TLType::tl_read_with_context(input, context)?,
+            broadcast_size_max: TLType::tl_read_with_context(input, context)?,
+            disabled_features: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.date.tl_write(output)?;
+        result += self.expires.tl_write(output)?;
+        result += self.test_mode.tl_write(output)?;
+        result += self.this_dc.tl_write(output)?;
+        result += self.dc_options.tl_write(output)?;
+        result += self.chat_big_size.tl_write(output)?;
+        result += self.chat_size_max.tl_write(output)?;
+        result += self.broadcast_size_max.tl_write(output)?;
+        result += self.disabled_features.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.date.serialized_len();
+        result += self.expires.serialized_len();
+        result += self.test_mode.serialized_len();
+        result += self.this_dc.serialized_len();
+        result += self.dc_options.serialized_len();
+        result += self.chat_big_size.serialized_len();
+        result += self.chat_size_max.serialized_len();
+        result += self.broadcast_size_max.serialized_len();
+        result += self.disabled_features.serialized_len();
+        result
+    }
+}
+/// `config#7dae33e0 date:int expires:int test_mode:Bool this_dc:int dc_options:Vector<DcOption> chat_big_size:int chat_size_max:int broadcast_size_max:int disabled_features:Vector<DisabledFeature> = Config`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigRef<'a> {
+    pub date: i32,
+    pub expires: i32,
+    pub test_mode: bool,
+    pub this_dc: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub dc_options: Vec<super::tl::DcOptionRef<'a>>,
+    pub chat_big_size: i32,
+    pub chat_size_max: i32,
+    pub broadcast_size_max: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub disabled_features: Vec<super::tl::DisabledFeatureRef<'a>>,
+}
+impl<'a> TLDeserialize<'a> for ConfigRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x7dae_33e0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Config",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ConfigRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ConfigRef {
+            date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            expires: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            test_mode: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            this_dc: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            dc_options: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            chat_big_size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            chat_size_max: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            broadcast_size_max: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            disabled_features: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ConfigBuilder {
+    date: i32,
+    expires: i32,
+    test_mode: bool,
+    this_dc: i32,
+    dc_options: Vec<super::tl::DcOption>,
+    chat_big_size: i32,
+    chat_size_max: i32,
+    broadcast_size_max: i32,
+    disabled_features: Vec<super::tl::DisabledFeature>,
+}
+impl ConfigBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        date: i32,
+        expires: i32,
+        test_mode: bool,
+        this_dc: i32,
+        dc_options: Vec<super::tl::DcOption>,
+        chat_big_size: i32,
+        chat_size_max: i32,
+        broadcast_size_max: i32,
+        disabled_features: Vec<super::tl::DisabledFeature>,
+    ) -> Self {
+        ConfigBuilder {
+            date,
+            expires,
+            test_mode,
+            this_dc,
+            dc_options,
+            chat_big_size,
+            chat_size_max,
+            broadcast_size_max,
+            disabled_features,
+        }
+    }
+    pub fn build(self) -> Config {
+        Config {
+            date: self.date,
+            expires: self.expires,
+            test_mode: self.test_mode,
+            this_dc: self.this_dc,
+            dc_options: self.dc_options,
+            chat_big_size: self.chat_big_size,
+            chat_size_max: self.chat_size_max,
+            broadcast_size_max: self.broadcast_size_max,
+            disabled_features: self.disabled_features,
+        }
+    }
+}
+/// `contact#f911c994 user_id:int mutual:Bool = Contact`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contact {
+    pub user_id: i32,
+    pub mutual: bool,
+}
+impl TLType for Contact {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x06ee_366ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Contact",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x06ee_366ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Contact {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Contact {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            mutual: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.mutual.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.mutual.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for Contact {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x06ee_366ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Contact",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for Contact {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Contact {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            mutual: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ContactBuilder {
+    user_id: i32,
+    mutual: bool,
+}
+impl ContactBuilder {
+    pub fn new(user_id: i32, mutual: bool) -> Self {
+        ContactBuilder { user_id, mutual }
+    }
+    pub fn build(self) -> Contact {
+        Contact {
+            user_id: self.user_id,
+            mutual: self.mutual,
+        }
+    }
+}
+/// `contactBlocked#561bc879 user_id:int date:int = ContactBlocked`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactBlocked {
+    pub user_id: i32,
+    pub date: i32,
+}
+impl TLType for ContactBlocked {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x561b_c879i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactBlocked",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x561b_c879i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactBlocked {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactBlocked {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            date: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.date.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.date.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ContactBlocked {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x561b_c879i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactBlocked",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ContactBlocked {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactBlocked {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ContactBlockedBuilder {
+    user_id: i32,
+    date: i32,
+}
+impl ContactBlockedBuilder {
+    pub fn new(user_id: i32, date: i32) -> Self {
+        ContactBlockedBuilder { user_id, date }
+    }
+    pub fn build(self) -> ContactBlocked {
+        ContactBlocked {
+            user_id: self.user_id,
+            date: self.date,
+        }
+    }
+}
+/// `contactFound#ea879f95 user_id:int = ContactFound`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactFound {
+    pub user_id: i32,
+}
+impl TLType for ContactFound {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x1578_606bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactFound",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x1578_606bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactFound {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactFound {
+            user_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ContactFound {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x1578_606bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactFound",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ContactFound {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactFound {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ContactFoundBuilder {
+    user_id: i32,
+}
+impl ContactFoundBuilder {
+    pub fn new(user_id: i32) -> Self {
+        ContactFoundBuilder { user_id }
+    }
+    pub fn build(self) -> ContactFound {
+        ContactFound {
+            user_id: self.user_id,
+        }
+    }
+}
+/// `contactStatus#d3680c61 user_id:int status:UserStatus = ContactStatus`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContactStatus {
+    pub user_id: i32,
+    pub status: super::tl::UserStatus,
+}
+impl TLType for ContactStatus {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2c97_f39fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactStatus",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2c97_f39fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ContactStatus {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactStatus {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            status: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.status.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.status.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ContactStatus {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x2c97_f39fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ContactStatus",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ContactStatus {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ContactStatus {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ContactStatusBuilder {
+    user_id: i32,
+    status: super::tl::UserStatus,
+}
+impl ContactStatusBuilder {
+    pub fn new(user_id: i32, status: super::tl::UserStatus) -> Self {
+        ContactStatusBuilder { user_id, status }
+    }
+    pub fn build(self) -> ContactStatus {
+        ContactStatus {
+            user_id: self.user_id,
+            status: self.status,
+        }
+    }
+}
+/// `dcOption#2ec2a43c id:int hostname:string ip_address:string port:int = DcOption`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DcOption {
+    pub id: i32,
+    pub hostname: String,
+    pub ip_address: String,
+    pub port: i32,
+}
+impl TLType for DcOption {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x2ec2_a43ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "DcOption",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x2ec2_a43ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for DcOption {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DcOption {
+            id: TLType::tl_read_with_context(input, context)?,
+            hostname: TLType::tl_read_with_context(input, context)?,
+            ip_address: TLType::tl_read_with_context(input, context)?,
+            port: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.id.tl_write(output)?;
+        result += self.hostname.tl_write(output)?;
+        result += self.ip_address.tl_write(output)?;
+        result += self.port.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.id.serialized_len();
+        result += self.hostname.serialized_len();
+        result += self.ip_address.serialized_len();
+        result += self.port.serialized_len();
+        result
+    }
+}
+/// `dcOption#2ec2a43c id:int hostname:string ip_address:string port:int = DcOption`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DcOptionRef<'a> {
+    pub id: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hostname: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ip_address: &'a str,
+    pub port: i32,
+}
+impl<'a> TLDeserialize<'a> for DcOptionRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x2ec2_a43ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "DcOption",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for DcOptionRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DcOptionRef {
+            id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            hostname: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            ip_address: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            port: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DcOptionBuilder {
+    id: i32,
+    hostname: String,
+    ip_address: String,
+    port: i32,
+}
+impl DcOptionBuilder {
+    pub fn new(id: i32, hostname: String, ip_address: String, port: i32) -> Self {
+        DcOptionBuilder {
+            id,
+            hostname,
+            ip_address,
+            port,
+        }
+    }
+    pub fn build(self) -> DcOption {
+        DcOption {
+            id: self.id,
+            hostname: self.hostname,
+            ip_address: self.ip_address,
+            port: self.port,
+        }
+    }
+}
+/// `dialog#ab3a99ac peer:Peer top_message:int unread_count:int notify_settings:PeerNotifySettings = Dialog`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dialog {
+    pub peer: super::tl::Peer,
+    pub top_message: i32,
+    pub unread_count: i32,
+    pub notify_settings: super::tl::PeerNotifySettings,
+}
+impl TLType for Dialog {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x54c5_6654i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Dialog",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x54c5_6654i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Dialog {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Dialog {
+            peer: TLType::tl_read_with_context(input, context)?,
+            top_message: TLType::tl_read_with_context(input, context)?,
+            unread_count: TLType::tl_read_with_context(input, context)?,
+            notify_settings: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.peer.tl_write(output)?;
+        result += self.top_message.tl_write(output)?;
+        result += self.unread_count.tl_write(output)?;
+        result += self.notify_settings.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.peer.serialized_len();
+        result += self.top_message.serialized_len();
+        result += self.unread_count.serialized_len();
+        result += self.notify_settings.serialized_len();
+        result
+    }
+}
+/// `dialog#ab3a99ac peer:Peer top_message:int unread_count:int notify_settings:PeerNotifySettings = Dialog`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogRef<'a> {
+    pub peer: super::tl::Peer,
+    pub top_message: i32,
+    pub unread_count: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub notify_settings: super::tl::PeerNotifySettingsRef<'a>,
+}
+impl<'a> TLDeserialize<'a> for DialogRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x54c5_6654i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Dialog",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for DialogRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DialogRef {
+            peer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            top_message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            unread_count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            notify_settings: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DialogBuilder {
+    peer: super::tl::Peer,
+    top_message: i32,
+    unread_count: i32,
+    notify_settings: super::tl::PeerNotifySettings,
+}
+impl DialogBuilder {
+    pub fn new(
+        peer: super::tl::Peer,
+        top_message: i32,
+        unread_count: i32,
+        notify_settings: super::tl::PeerNotifySettings,
+    ) -> Self {
+        DialogBuilder {
+            peer,
+            top_message,
+            unread_count,
+            notify_settings,
+        }
+    }
+    pub fn build(self) -> Dialog {
+        Dialog {
+            peer: self.peer,
+            top_message: self.top_message,
+            unread_count: self.unread_count,
+            notify_settings: self.notify_settings,
+        }
+    }
+}
+/// `disabledFeature#ae636f24 feature:string description:string = DisabledFeature`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisabledFeature {
+    pub feature: String,
+    pub description: String,
+}
+impl TLType for DisabledFeature {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x519c_90dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "DisabledFeature",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x519c_90dci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for DisabledFeature {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DisabledFeature {
+            feature: TLType::tl_read_with_context(input, context)?,
+            description: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.feature.tl_write(output)?;
+        result += self.description.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.feature.serialized_len();
+        result += self.description.serialized_len();
+        result
+    }
+}
+/// `disabledFeature#ae636f24 feature:string description:string = DisabledFeature`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisabledFeatureRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub feature: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub description: &'a str,
+}
+impl<'a> TLDeserialize<'a> for DisabledFeatureRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x519c_90dci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "DisabledFeature",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for DisabledFeatureRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = DisabledFeatureRef {
+            feature: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            description: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DisabledFeatureBuilder {
+    feature: String,
+    description: String,
+}
+impl DisabledFeatureBuilder {
+    pub fn new(feature: String, description: String) -> Self {
+        DisabledFeatureBuilder {
+            feature,
+            description,
+        }
+    }
+    pub fn build(self) -> DisabledFeature {
+        DisabledFeature {
+            feature: self.feature,
+            description: self.description,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Document {
+    /// `documentEmpty#36f8c871 id:long = Document`
+    DocumentEmpty { id: i64 },
+    /// `document#f9a39f4f id:long access_hash:long date:int mime_type:string size:int thumb:PhotoSize dc_id:int attributes:Vector<DocumentAttribute> = Document`
+    Document {
+        id: i64,
+        access_hash: i64,
+        date: i32,
+        mime_type: String,
+        size: i32,
+        thumb: super::tl::PhotoSize,
+        dc_id: i32,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    },
+}
+impl TLType for Document {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x36f8_c871i32 => Document::DocumentEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x065c_60b1i32 => Document::Document {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+                size: TLType::tl_read_with_context(input, context)?,
+                thumb: TLType::tl_read_with_context(input, context)?,
+                dc_id: TLType::tl_read_with_context(input, context)?,
+                attributes: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Document",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Document::DocumentEmpty { id } => {
+                (0x36f8_c871i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Document::Document {
+                id,
+                access_hash,
+                date,
+                mime_type,
+                size,
+                thumb,
+                dc_id,
+                attributes,
+            } => {
+                (-0x065c_60b1i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+                result += size.tl_write(output)?;
+                result += thumb.tl_write(output)?;
+                result += dc_id.tl_write(output)?;
+                result += attributes.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Document::DocumentEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Document::Document {
+                id,
+                access_hash,
+                date,
+                mime_type,
+                size,
+                thumb,
+                dc_id,
+                attributes,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += date.serialized_len();
+                result += mime_type.serialized_len();
+                result += size.serialized_len();
+                result += thumb.serialized_len();
+                result += dc_id.serialized_len();
+                result += attributes.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentRef<'a> {
+    /// `documentEmpty#36f8c871 id:long = Document`
+    DocumentEmpty { id: i64 },
+    /// `document#f9a39f4f id:long access_hash:long date:int mime_type:string size:int thumb:PhotoSize dc_id:int attributes:Vector<DocumentAttribute> = Document`
+    Document {
+        id: i64,
+        access_hash: i64,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+        size: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        thumb: super::tl::PhotoSizeRef<'a>,
+        dc_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        attributes: Vec<super::tl::DocumentAttributeRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for DocumentRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x36f8_c871i32 => DocumentRef::DocumentEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x065c_60b1i32 => DocumentRef::Document {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                thumb: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                dc_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                attributes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Document",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DocumentDocumentEmptyBuilder {
+    id: i64,
+}
+impl DocumentDocumentEmptyBuilder {
+    pub fn new(id: i64) -> Self {
+        DocumentDocumentEmptyBuilder { id }
+    }
+    pub fn build(self) -> Document {
+        Document::DocumentEmpty { id: self.id }
+    }
+}
+pub struct DocumentDocumentBuilder {
+    id: i64,
+    access_hash: i64,
+    date: i32,
+    mime_type: String,
+    size: i32,
+    thumb: super::tl::PhotoSize,
+    dc_id: i32,
+    attributes: Vec<super::tl::DocumentAttribute>,
+}
+impl DocumentDocumentBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i64,
+        access_hash: i64,
+        date: i32,
+        mime_type: String,
+        size: i32,
+        thumb: super::tl::PhotoSize,
+        dc_id: i32,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    ) -> Self {
+        DocumentDocumentBuilder {
+            id,
+            access_hash,
+            date,
+            mime_type,
+            size,
+            thumb,
+            dc_id,
+            attributes,
+        }
+    }
+    pub fn build(self) -> Document {
+        Document::Document {
+            id: self.id,
+            access_hash: self.access_hash,
+            date: self.date,
+            mime_type: self.mime_type,
+            size: self.size,
+            thumb: self.thumb,
+            dc_id: self.dc_id,
+            attributes: self.attributes,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentAttribute {
+    /// `documentAttributeImageSize#6c37c15c w:int h:int = DocumentAttribute`
+    DocumentAttributeImageSize { w: i32, h: i32 },
+    /// `documentAttributeAnimated#11b58939 = DocumentAttribute`
+    DocumentAttributeAnimated,
+    /// `documentAttributeSticker#fb0a5727 = DocumentAttribute`
+    DocumentAttributeSticker,
+    /// `documentAttributeVideo#5910cccb duration:int w:int h:int = DocumentAttribute`
+    DocumentAttributeVideo { duration: i32, w: i32, h: i32 },
+    /// `documentAttributeAudio#051448e5 duration:int = DocumentAttribute`
+    DocumentAttributeAudio { duration: i32 },
+    /// `documentAttributeFilename#15590068 file_name:string = DocumentAttribute`
+    DocumentAttributeFilename { file_name: String },
+}
+impl TLType for DocumentAttribute {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x6c37_c15ci32 => DocumentAttribute::DocumentAttributeImageSize {
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+            },
+            0x11b5_8939i32 => DocumentAttribute::DocumentAttributeAnimated {},
+            -0x04f5_a8d9i32 => DocumentAttribute::DocumentAttributeSticker {},
+            0x5910_cccbi32 => DocumentAttribute::DocumentAttributeVideo {
+                duration: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+            },
+            0x0514_48e5i32 => DocumentAttribute::DocumentAttributeAudio {
+                duration: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1559_0068i32 => DocumentAttribute::DocumentAttributeFilename {
+                file_name: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "DocumentAttribute",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            DocumentAttribute::DocumentAttributeImageSize { w, h } => {
+                (0x6c37_c15ci32).tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+            }
+            DocumentAttribute::DocumentAttributeAnimated => {
+                (0x11b5_8939i32).tl_write(output)?;
+            }
+            DocumentAttribute::DocumentAttributeSticker => {
+                (-0x04f5_a8d9i32).tl_write(output)?;
+            }
+            DocumentAttribute::DocumentAttributeVideo { duration, w, h } => {
+                (0x5910_cccbi32).tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+            }
+            DocumentAttribute::DocumentAttributeAudio { duration } => {
+                (0x0514_48e5i32).tl_write(output)?;
+                result += duration.tl_write(output)?;
+            }
+            DocumentAttribute::DocumentAttributeFilename { file_name } => {
+                (0x1559_0068i32).tl_write(output)?;
+                result += file_name.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            DocumentAttribute::DocumentAttributeImageSize { w, h } => {
+                result += w.serialized_len();
+                result += h.serialized_len();
+            }
+            DocumentAttribute::DocumentAttributeAnimated => {}
+            DocumentAttribute::DocumentAttributeSticker => {}
+            DocumentAttribute::DocumentAttributeVideo { duration, w, h } => {
+                result += duration.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+            }
+            DocumentAttribute::DocumentAttributeAudio { duration } => {
+                result += duration.serialized_len();
+            }
+            DocumentAttribute::DocumentAttributeFilename { file_name } => {
+                result += file_name.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentAttributeRef<'a> {
+    /// `documentAttributeImageSize#6c37c15c w:int h:int = DocumentAttribute`
+    DocumentAttributeImageSize { w: i32, h: i32 },
+    /// `documentAttributeAnimated#11b58939 = DocumentAttribute`
+    DocumentAttributeAnimated,
+    /// `documentAttributeSticker#fb0a5727 = DocumentAttribute`
+    DocumentAttributeSticker,
+    /// `documentAttributeVideo#5910cccb duration:int w:int h:int = DocumentAttribute`
+    DocumentAttributeVideo { duration: i32, w: i32, h: i32 },
+    /// `documentAttributeAudio#051448e5 duration:int = DocumentAttribute`
+    DocumentAttributeAudio { duration: i32 },
+    /// `documentAttributeFilename#15590068 file_name:string = DocumentAttribute`
+    DocumentAttributeFilename {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file_name: &'a str,
+    },
+}
+impl<'a> TLDeserialize<'a> for DocumentAttributeRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x6c37_c15ci32 => DocumentAttributeRef::DocumentAttributeImageSize {
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x11b5_8939i32 => DocumentAttributeRef::DocumentAttributeAnimated {},
+            -0x04f5_a8d9i32 => DocumentAttributeRef::DocumentAttributeSticker {},
+            0x5910_cccbi32 => DocumentAttributeRef::DocumentAttributeVideo {
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x0514_48e5i32 => DocumentAttributeRef::DocumentAttributeAudio {
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1559_0068i32 => DocumentAttributeRef::DocumentAttributeFilename {
+                file_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "DocumentAttribute",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DocumentAttributeDocumentAttributeImageSizeBuilder {
+    w: i32,
+    h: i32,
+}
+impl DocumentAttributeDocumentAttributeImageSizeBuilder {
+    pub fn new(w: i32, h: i32) -> Self {
+        DocumentAttributeDocumentAttributeImageSizeBuilder { w, h }
+    }
+    pub fn build(self) -> DocumentAttribute {
+        DocumentAttribute::DocumentAttributeImageSize {
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
+pub struct DocumentAttributeDocumentAttributeVideoBuilder {
+    duration: i32,
+    w: i32,
+    h: i32,
+}
+impl DocumentAttributeDocumentAttributeVideoBuilder {
+    pub fn new(duration: i32, w: i32, h: i32) -> Self {
+        DocumentAttributeDocumentAttributeVideoBuilder { duration, w, h }
+    }
+    pub fn build(self) -> DocumentAttribute {
+        DocumentAttribute::DocumentAttributeVideo {
+            duration: self.duration,
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
+pub struct DocumentAttributeDocumentAttributeAudioBuilder {
+    duration: i32,
+}
+impl DocumentAttributeDocumentAttributeAudioBuilder {
+    pub fn new(duration: i32) -> Self {
+        DocumentAttributeDocumentAttributeAudioBuilder { duration }
+    }
+    pub fn build(self) -> DocumentAttribute {
+        DocumentAttribute::DocumentAttributeAudio {
+            duration: self.duration,
+        }
+    }
+}
+pub struct DocumentAttributeDocumentAttributeFilenameBuilder {
+    file_name: String,
+}
+impl DocumentAttributeDocumentAttributeFilenameBuilder {
+    pub fn new(file_name: String) -> Self {
+        DocumentAttributeDocumentAttributeFilenameBuilder { file_name }
+    }
+    pub fn build(self) -> DocumentAttribute {
+        DocumentAttribute::DocumentAttributeFilename {
+            file_name: self.file_name,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptedChat {
+    /// `encryptedChatEmpty#ab7ec0a0 id:int = EncryptedChat`
+    EncryptedChatEmpty { id: i32 },
+    /// `encryptedChatWaiting#3bf703dc id:int access_hash:long date:int admin_id:int participant_id:int = EncryptedChat`
+    EncryptedChatWaiting {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+    },
+    /// `encryptedChatRequested#c878527e id:int access_hash:long date:int admin_id:int participant_id:int g_a:bytes = EncryptedChat`
+    EncryptedChatRequested {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        g_a: TLBytes,
+    },
+    /// `encryptedChat#fa56ce36 id:int access_hash:long date:int admin_id:int participant_id:int g_a_or_b:bytes key_fingerprint:long = EncryptedChat`
+    EncryptedChat {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        g_a_or_b: TLBytes,
+        key_fingerprint: i64,
+    },
+    /// `encryptedChatDiscarded#13d6dd27 id:int = EncryptedChat`
+    EncryptedChatDiscarded { id: i32 },
+}
+impl TLType for EncryptedChat {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x5481_3f60i32 => EncryptedChat::EncryptedChatEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x3bf7_03dci32 => EncryptedChat::EncryptedChatWaiting {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                admin_id: TLType::tl_read_with_context(input, context)?,
+                participant_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x3787_ad82i32 => EncryptedChat::EncryptedChatRequested {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                admin_id: TLType::tl_read_with_context(input, context)?,
+                participant_id: TLType::tl_read_with_context(input, context)?,
+                g_a: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x05a9_31cai32 => EncryptedChat::EncryptedChat {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                admin_id: TLType::tl_read_with_context(input, context)?,
+                participant_id: TLType::tl_read_with_context(input, context)?,
+                g_a_or_b: TLType::tl_read_with_context(input, context)?,
+                key_fingerprint: TLType::tl_read_with_context(input, context)?,
+            },
+            0x13d6_dd27i32 => EncryptedChat::EncryptedChatDiscarded {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedChat",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            EncryptedChat::EncryptedChatEmpty { id } => {
+                (-0x5481_3f60i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            EncryptedChat::EncryptedChatWaiting {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+            } => {
+                (0x3bf7_03dci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += admin_id.tl_write(output)?;
+                result += participant_id.tl_write(output)?;
+            }
+            EncryptedChat::EncryptedChatRequested {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+                g_a,
+            } => {
+                (-0x3787_ad82i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += admin_id.tl_write(output)?;
+                result += participant_id.tl_write(output)?;
+                result += g_a.tl_write(output)?;
+            }
+            EncryptedChat::EncryptedChat {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+                g_a_or_b,
+                key_fingerprint,
+            } => {
+                (-0x05a9_31cai32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += admin_id.tl_write(output)?;
+                result += participant_id.tl_write(output)?;
+                result += g_a_or_b.tl_write(output)?;
+                result += key_fingerprint.tl_write(output)?;
+            }
+            EncryptedChat::EncryptedChatDiscarded { id } => {
+                (0x13d6_dd27i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            EncryptedChat::EncryptedChatEmpty { id } => {
+                result += id.serialized_len();
+            }
+            EncryptedChat::EncryptedChatWaiting {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += date.serialized_len();
+                result += admin_id.serialized_len();
+                result += participant_id.serialized_len();
+            }
+            EncryptedChat::EncryptedChatRequested {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+                g_a,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += date.serialized_len();
+                result += admin_id.serialized_len();
+                result += participant_id.serialized_len();
+                result += g_a.serialized_len();
+            }
+            EncryptedChat::EncryptedChat {
+                id,
+                access_hash,
+                date,
+                admin_id,
+                participant_id,
+                g_a_or_b,
+                key_fingerprint,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += date.serialized_len();
+                result += admin_id.serialized_len();
+                result += participant_id.serialized_len();
+                result += g_a_or_b.serialized_len();
+                result += key_fingerprint.serialized_len();
+            }
+            EncryptedChat::EncryptedChatDiscarded { id } => {
+                result += id.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptedChatRef<'a> {
+    /// `encryptedChatEmpty#ab7ec0a0 id:int = EncryptedChat`
+    EncryptedChatEmpty { id: i32 },
+    /// `encryptedChatWaiting#3bf703dc id:int access_hash:long date:int admin_id:int participant_id:int = EncryptedChat`
+    EncryptedChatWaiting {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+    },
+    /// `encryptedChatRequested#c878527e id:int access_hash:long date:int admin_id:int participant_id:int g_a:bytes = EncryptedChat`
+    EncryptedChatRequested {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        g_a: &'a [u8],
+    },
+    /// `encryptedChat#fa56ce36 id:int access_hash:long date:int admin_id:int participant_id:int g_a_or_b:bytes key_fingerprint:long = EncryptedChat`
+    EncryptedChat {
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        g_a_or_b: &'a [u8],
+        key_fingerprint: i64,
+    },
+    /// `encryptedChatDiscarded#13d6dd27 id:int = EncryptedChat`
+    EncryptedChatDiscarded { id: i32 },
+}
+impl<'a> TLDeserialize<'a> for EncryptedChatRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x5481_3f60i32 => EncryptedChatRef::EncryptedChatEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x3bf7_03dci32 => EncryptedChatRef::EncryptedChatWaiting {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                admin_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                participant_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x3787_ad82i32 => EncryptedChatRef::EncryptedChatRequested {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                admin_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                participant_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                g_a: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x05a9_31cai32 => EncryptedChatRef::EncryptedChat {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                admin_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                participant_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                g_a_or_b: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                key_fingerprint: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x13d6_dd27i32 => EncryptedChatRef::EncryptedChatDiscarded {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedChat",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct EncryptedChatEncryptedChatEmptyBuilder {
+    id: i32,
+}
+impl EncryptedChatEncryptedChatEmptyBuilder {
+    pub fn new(id: i32) -> Self {
+        EncryptedChatEncryptedChatEmptyBuilder { id }
+    }
+    pub fn build(self) -> EncryptedChat {
+        EncryptedChat::EncryptedChatEmpty { id: self.id }
+    }
+}
+pub struct EncryptedChatEncryptedChatWaitingBuilder {
+    id: i32,
+    access_hash: i64,
+    date: i32,
+    admin_id: i32,
+    participant_id: i32,
+}
+impl EncryptedChatEncryptedChatWaitingBuilder {
+    pub fn new(id: i32, access_hash: i64, date: i32, admin_id: i32, participant_id: i32) -> Self {
+        EncryptedChatEncryptedChatWaitingBuilder {
+            id,
+            access_hash,
+            date,
+            admin_id,
+            participant_id,
+        }
+    }
+    pub fn build(self) -> EncryptedChat {
+        EncryptedChat::EncryptedChatWaiting {
+            id: self.id,
+            access_hash: self.access_hash,
+            date: self.date,
+            admin_id: self.admin_id,
+            participant_id: self.participant_id,
+        }
+    }
+}
+pub struct EncryptedChatEncryptedChatRequestedBuilder {
+    id: i32,
+    access_hash: i64,
+    date: i32,
+    admin_id: i32,
+    participant_id: i32,
+    g_a: TLBytes,
+}
+impl EncryptedChatEncryptedChatRequestedBuilder {
+    pub fn new(
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        g_a: TLBytes,
+    ) -> Self {
+        EncryptedChatEncryptedChatRequestedBuilder {
+            id,
+            access_hash,
+            date,
+            admin_id,
+            participant_id,
+            g_a,
+        }
+    }
+    pub fn build(self) -> EncryptedChat {
+        EncryptedChat::EncryptedChatRequested {
+            id: self.id,
+            access_hash: self.access_hash,
+            date: self.date,
+            admin_id: self.admin_id,
+            participant_id: self.participant_id,
+            g_a: self.g_a,
+        }
+    }
+}
+pub struct EncryptedChatEncryptedChatBuilder {
+    id: i32,
+    access_hash: i64,
+    date: i32,
+    admin_id: i32,
+    participant_id: i32,
+    g_a_or_b: TLBytes,
+    key_fingerprint: i64,
+}
+impl EncryptedChatEncryptedChatBuilder {
+    pub fn new(
+        id: i32,
+        access_hash: i64,
+        date: i32,
+        admin_id: i32,
+        participant_id: i32,
+        g_a_or_b: TLBytes,
+        key_fingerprint: i64,
+    ) -> Self {
+        EncryptedChatEncryptedChatBuilder {
+            id,
+            access_hash,
+            date,
+            admin_id,
+            participant_id,
+            g_a_or_b,
+            key_fingerprint,
+        }
+    }
+    pub fn build(self) -> EncryptedChat {
+        EncryptedChat::EncryptedChat {
+            id: self.id,
+            access_hash: self.access_hash,
+            date: self.date,
+            admin_id: self.admin_id,
+            participant_id: self.participant_id,
+            g_a_or_b: self.g_a_or_b,
+            key_fingerprint: self.key_fingerprint,
+        }
+    }
+}
+pub struct EncryptedChatEncryptedChatDiscardedBuilder {
+    id: i32,
+}
+impl EncryptedChatEncryptedChatDiscardedBuilder {
+    pub fn new(id: i32) -> Self {
+        EncryptedChatEncryptedChatDiscardedBuilder { id }
+    }
+    pub fn build(self) -> EncryptedChat {
+        EncryptedChat::EncryptedChatDiscarded { id: self.id }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptedFile {
+    /// `encryptedFileEmpty#c21f497e = EncryptedFile`
+    EncryptedFileEmpty,
+    /// `encryptedFile#4a70994c id:long access_hash:long size:int dc_id:int key_fingerprint:int = EncryptedFile`
+    EncryptedFile {
+        id: i64,
+        access_hash: i64,
+        size: i32,
+        dc_id: i32,
+        key_fingerprint: i32,
+    },
+}
+impl TLType for EncryptedFile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x3de0_b682i32 => EncryptedFile::EncryptedFileEmpty {},
+            0x4a70_994ci32 => EncryptedFile::EncryptedFile {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                size: TLType::tl_read_with_context(input, context)?,
+                dc_id: TLType::tl_read_with_context(input, context)?,
+                key_fingerprint: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            EncryptedFile::EncryptedFileEmpty => {
+                (-0x3de0_b682i32).tl_write(output)?;
+            }
+            EncryptedFile::EncryptedFile {
+                id,
+                access_hash,
+                size,
+                dc_id,
+                key_fingerprint,
+            } => {
+                (0x4a70_994ci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += size.tl_write(output)?;
+                result += dc_id.tl_write(output)?;
+                result += key_fingerprint.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            EncryptedFile::EncryptedFileEmpty => {}
+            EncryptedFile::EncryptedFile {
+                id,
+                access_hash,
+                size,
+                dc_id,
+                key_fingerprint,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += size.serialized_len();
+                result += dc_id.serialized_len();
+                result += key_fingerprint.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for EncryptedFile {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x3de0_b682i32 => EncryptedFile::EncryptedFileEmpty {},
+            0x4a70_994ci32 => EncryptedFile::EncryptedFile {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                dc_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                key_fingerprint: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct EncryptedFileEncryptedFileBuilder {
+    id: i64,
+    access_hash: i64,
+    size: i32,
+    dc_id: i32,
+    key_fingerprint: i32,
+}
+impl EncryptedFileEncryptedFileBuilder {
+    pub fn new(id: i64, access_hash: i64, size: i32, dc_id: i32, key_fingerprint: i32) -> Self {
+        EncryptedFileEncryptedFileBuilder {
+            id,
+            access_hash,
+            size,
+            dc_id,
+            key_fingerprint,
+        }
+    }
+    pub fn build(self) -> EncryptedFile {
+        EncryptedFile::EncryptedFile {
+            id: self.id,
+            access_hash: self.access_hash,
+            size: self.size,
+            dc_id: self.dc_id,
+            key_fingerprint: self.key_fingerprint,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptedMessage {
+    /// `encryptedMessage#ed18c118 random_id:long chat_id:int date:int bytes:bytes file:EncryptedFile = EncryptedMessage`
+    EncryptedMessage {
+        random_id: i64,
+        chat_id: i32,
+        date: i32,
+        bytes: TLBytes,
+        file: super::tl::EncryptedFile,
+    },
+    /// `encryptedMessageService#23734b06 random_id:long chat_id:int date:int bytes:bytes = EncryptedMessage`
+    EncryptedMessageService {
+        random_id: i64,
+        chat_id: i32,
+        date: i32,
+        bytes: TLBytes,
+    },
+}
+impl TLType for EncryptedMessage {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x12e7_3ee8i32 => EncryptedMessage::EncryptedMessage {
+                random_id: TLType::tl_read_with_context(input, context)?,
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+                file: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2373_4b06i32 => EncryptedMessage::EncryptedMessageService {
+                random_id: TLType::tl_read_with_context(input, context)?,
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            EncryptedMessage::EncryptedMessage {
+                random_id,
+                chat_id,
+                date,
+                bytes,
+                file,
+            } => {
+                (-0x12e7_3ee8i32).tl_write(output)?;
+                result += random_id.tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+                result += file.tl_write(output)?;
+            }
+            EncryptedMessage::EncryptedMessageService {
+                random_id,
+                chat_id,
+                date,
+                bytes,
+            } => {
+                (0x2373_4b06i32).tl_write(output)?;
+                result += random_id.tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            EncryptedMessage::EncryptedMessage {
+                random_id,
+                chat_id,
+                date,
+                bytes,
+                file,
+            } => {
+                result += random_id.serialized_len();
+                result += chat_id.serialized_len();
+                result += date.serialized_len();
+                result += bytes.serialized_len();
+                result += file.serialized_len();
+            }
+            EncryptedMessage::EncryptedMessageService {
+                random_id,
+                chat_id,
+                date,
+                bytes,
+            } => {
+                result += random_id.serialized_len();
+                result += chat_id.serialized_len();
+                result += date.serialized_len();
+                result += bytes.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptedMessageRef<'a> {
+    /// `encryptedMessage#ed18c118 random_id:long chat_id:int date:int bytes:bytes file:EncryptedFile = EncryptedMessage`
+    EncryptedMessage {
+        random_id: i64,
+        chat_id: i32,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        bytes: &'a [u8],
+        file: super::tl::EncryptedFile,
+    },
+    /// `encryptedMessageService#23734b06 random_id:long chat_id:int date:int bytes:bytes = EncryptedMessage`
+    EncryptedMessageService {
+        random_id: i64,
+        chat_id: i32,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        bytes: &'a [u8],
+    },
+}
+impl<'a> TLDeserialize<'a> for EncryptedMessageRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x12e7_3ee8i32 => EncryptedMessageRef::EncryptedMessage {
+                random_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2373_4b06i32 => EncryptedMessageRef::EncryptedMessageService {
+                random_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "EncryptedMessage",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct EncryptedMessageEncryptedMessageBuilder {
+    random_id: i64,
+    chat_id: i32,
+    date: i32,
+    bytes: TLBytes,
+    file: super::tl::EncryptedFile,
+}
+impl EncryptedMessageEncryptedMessageBuilder {
+    pub fn new(
+        random_id: i64,
+        chat_id: i32,
+        date: i32,
+        bytes: TLBytes,
+        file: super::tl::EncryptedFile,
+    ) -> Self {
+        EncryptedMessageEncryptedMessageBuilder {
+            random_id,
+            chat_id,
+            date,
+            bytes,
+            file,
+        }
+    }
+    pub fn build(self) -> EncryptedMessage {
+        EncryptedMessage::EncryptedMessage {
+            random_id: self.random_id,
+            chat_id: self.chat_id,
+            date: self.date,
+            bytes: self.bytes,
+            file: self.file,
+        }
+    }
+}
+pub struct EncryptedMessageEncryptedMessageServiceBuilder {
+    random_id: i64,
+    chat_id: i32,
+    date: i32,
+    bytes: TLBytes,
+}
+impl EncryptedMessageEncryptedMessageServiceBuilder {
+    pub fn new(random_id: i64, chat_id: i32, date: i32, bytes: TLBytes) -> Self {
+        EncryptedMessageEncryptedMessageServiceBuilder {
+            random_id,
+            chat_id,
+            date,
+            bytes,
+        }
+    }
+    pub fn build(self) -> EncryptedMessage {
+        EncryptedMessage::EncryptedMessageService {
+            random_id: self.random_id,
+            chat_id: self.chat_id,
+            date: self.date,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `error#c4b9f9bb code:int text:string = Error`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Error {
+    pub code: i32,
+    pub text: String,
+}
+impl TLType for Error {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x3b46_0645i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Error",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x3b46_0645i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Error {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Error {
+            code: TLType::tl_read_with_context(input, context)?,
+            text: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.code.tl_write(output)?;
+        result += self.text.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.code.serialized_len();
+        result += self.text.serialized_len();
+        result
+    }
+}
+/// `error#c4b9f9bb code:int text:string = Error`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ErrorRef<'a> {
+    pub code: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub text: &'a str,
+}
+impl<'a> TLDeserialize<'a> for ErrorRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x3b46_0645i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Error",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ErrorRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ErrorRef {
+            code: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            text: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ErrorBuilder {
+    code: i32,
+    text: String,
+}
+impl ErrorBuilder {
+    pub fn new(code: i32, text: String) -> Self {
+        ErrorBuilder { code, text }
+    }
+    pub fn build(self) -> Error {
+        Error {
+            code: self.code,
+            text: self.text,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FileLocation {
+    /// `fileLocationUnavailable#7c596b46 volume_id:long local_id:int secret:long = FileLocation`
+    FileLocationUnavailable {
+        volume_id: i64,
+        local_id: i32,
+        secret: i64,
+    },
+    /// `fileLocation#53d69076 dc_id:int volume_id:long local_id:int secret:long = FileLocation`
+    FileLocation {
+        dc_id: i32,
+        volume_id: i64,
+        local_id: i32,
+        secret: i64,
+    },
+}
+impl TLType for FileLocation {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x7c59_6b46i32 => FileLocation::FileLocationUnavailable {
+                volume_id: TLType::tl_read_with_context(input, context)?,
+                local_id: TLType::tl_read_with_context(input, context)?,
+                secret: TLType::tl_read_with_context(input, context)?,
+            },
+            0x53d6_9076i32 => FileLocation::FileLocation {
+                dc_id: TLType::tl_read_with_context(input, context)?,
+                volume_id: TLType::tl_read_with_context(input, context)?,
+                local_id: TLType::tl_read_with_context(input, context)?,
+                secret: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "FileLocation",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            FileLocation::FileLocationUnavailable {
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                (0x7c59_6b46i32).tl_write(output)?;
+                result += volume_id.tl_write(output)?;
+                result += local_id.tl_write(output)?;
+                result += secret.tl_write(output)?;
+            }
+            FileLocation::FileLocation {
+                dc_id,
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                (0x53d6_9076i32).tl_write(output)?;
+                result += dc_id.tl_write(output)?;
+                result += volume_id.tl_write(output)?;
+                result += local_id.tl_write(output)?;
+                result += secret.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            FileLocation::FileLocationUnavailable {
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                result += volume_id.serialized_len();
+                result += local_id.serialized_len();
+                result += secret.serialized_len();
+            }
+            FileLocation::FileLocation {
+                dc_id,
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                result += dc_id.serialized_len();
+                result += volume_id.serialized_len();
+                result += local_id.serialized_len();
+                result += secret.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for FileLocation {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x7c59_6b46i32 => FileLocation::FileLocationUnavailable {
+                volume_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                local_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                secret: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x53d6_9076i32 => FileLocation::FileLocation {
+                dc_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                volume_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                local_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                secret: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "FileLocation",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct FileLocationFileLocationUnavailableBuilder {
+    volume_id: i64,
+    local_id: i32,
+    secret: i64,
+}
+impl FileLocationFileLocationUnavailableBuilder {
+    pub fn new(volume_id: i64, local_id: i32, secret: i64) -> Self {
+        FileLocationFileLocationUnavailableBuilder {
+            volume_id,
+            local_id,
+            secret,
+        }
+    }
+    pub fn build(self) -> FileLocation {
+        FileLocation::FileLocationUnavailable {
+            volume_id: self.volume_id,
+            local_id: self.local_id,
+            secret: self.secret,
+        }
+    }
+}
+pub struct FileLocationFileLocationBuilder {
+    dc_id: i32,
+    volume_id: i64,
+    local_id: i32,
+    secret: i64,
+}
+impl FileLocationFileLocationBuilder {
+    pub fn new(dc_id: i32, volume_id: i64, local_id: i32, secret: i64) -> Self {
+        FileLocationFileLocationBuilder {
+            dc_id,
+            volume_id,
+            local_id,
+            secret,
+        }
+    }
+    pub fn build(self) -> FileLocation {
+        FileLocation::FileLocation {
+            dc_id: self.dc_id,
+            volume_id: self.volume_id,
+            local_id: self.local_id,
+            secret: self.secret,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeoPoint {
+    /// `geoPointEmpty#1117dd5f = GeoPoint`
+    GeoPointEmpty,
+    /// `geoPoint#2049d70c long:double lat:double = GeoPoint`
+    GeoPoint { long: f64, lat: f64 },
+}
+impl TLType for GeoPoint {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1117_dd5fi32 => GeoPoint::GeoPointEmpty {},
+            0x2049_d70ci32 => GeoPoint::GeoPoint {
+                long: TLType::tl_read_with_context(input, context)?,
+                lat: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "GeoPoint",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            GeoPoint::GeoPointEmpty => {
+                (0x1117_dd5fi32).tl_write(output)?;
+            }
+            GeoPoint::GeoPoint { long, lat } => {
+                (0x2049_d70ci32).tl_write(output)?;
+                result += long.tl_write(output)?;
+                result += lat.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            GeoPoint::GeoPointEmpty => {}
+            GeoPoint::GeoPoint { long, lat } => {
+                result += long.serialized_len();
+                result += lat.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for GeoPoint {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1117_dd5fi32 => GeoPoint::GeoPointEmpty {},
+            0x2049_d70ci32 => GeoPoint::GeoPoint {
+                long: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                lat: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "GeoPoint",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct GeoPointGeoPointBuilder {
+    long: f64,
+    lat: f64,
+}
+impl GeoPointGeoPointBuilder {
+    pub fn new(long: f64, lat: f64) -> Self {
+        GeoPointGeoPointBuilder { long, lat }
+    }
+    pub fn build(self) -> GeoPoint {
+        GeoPoint::GeoPoint {
+            long: self.long,
+            lat: self.lat,
+        }
+    }
+}
+/// `importedContact#d0028438 user_id:int client_id:long = ImportedContact`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportedContact {
+    pub user_id: i32,
+    pub client_id: i64,
+}
+impl TLType for ImportedContact {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x2ffd_7bc8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ImportedContact",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x2ffd_7bc8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for ImportedContact {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ImportedContact {
+            user_id: TLType::tl_read_with_context(input, context)?,
+            client_id: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user_id.tl_write(output)?;
+        result += self.client_id.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user_id.serialized_len();
+        result += self.client_id.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for ImportedContact {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x2ffd_7bc8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "ImportedContact",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for ImportedContact {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = ImportedContact {
+            user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            client_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ImportedContactBuilder {
+    user_id: i32,
+    client_id: i64,
+}
+impl ImportedContactBuilder {
+    pub fn new(user_id: i32, client_id: i64) -> Self {
+        ImportedContactBuilder { user_id, client_id }
+    }
+    pub fn build(self) -> ImportedContact {
+        ImportedContact {
+            user_id: self.user_id,
+            client_id: self.client_id,
+        }
+    }
+}
+/// `inputAppEvent#770656a8 time:double type:string peer:long data:string = InputAppEvent`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputAppEvent {
+    pub time: f64,
+    pub ty: String,
+    pub peer: i64,
+    pub data: String,
+}
+impl TLType for InputAppEvent {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7706_56a8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputAppEvent",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7706_56a8i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InputAppEvent {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputAppEvent {
+            time: TLType::tl_read_with_context(input, context)?,
+            ty: TLType::tl_read_with_context(input, context)?,
+            peer: TLType::tl_read_with_context(input, context)?,
+            data: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.time.tl_write(output)?;
+        result += self.ty.tl_write(output)?;
+        result += self.peer.tl_write(output)?;
+        result += self.data.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.time.serialized_len();
+        result += self.ty.serialized_len();
+        result += self.peer.serialized_len();
+        result += self.data.serialized_len();
+        result
+    }
+}
+/// `inputAppEvent#770656a8 time:double type:string peer:long data:string = InputAppEvent`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputAppEventRef<'a> {
+    pub time: f64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ty: &'a str,
+    pub peer: i64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub data: &'a str,
+}
+impl<'a> TLDeserialize<'a> for InputAppEventRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x7706_56a8i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputAppEvent",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InputAppEventRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputAppEventRef {
+            time: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            peer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            data: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputAppEventBuilder {
+    time: f64,
+    ty: String,
+    peer: i64,
+    data: String,
+}
+impl InputAppEventBuilder {
+    pub fn new(time: f64, ty: String, peer: i64, data: String) -> Self {
+        InputAppEventBuilder {
+            time,
+            ty,
+            peer,
+            data,
+        }
+    }
+    pub fn build(self) -> InputAppEvent {
+        InputAppEvent {
+            time: self.time,
+            ty: self.ty,
+            peer: self.peer,
+            data: self.data,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputAudio {
+    /// `inputAudioEmpty#d95adc84 = InputAudio`
+    InputAudioEmpty,
+    /// `inputAudio#77d440ff id:long access_hash:long = InputAudio`
+    InputAudio { id: i64, access_hash: i64 },
+}
+impl TLType for InputAudio {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x26a5_237ci32 => InputAudio::InputAudioEmpty {},
+            0x77d4_40ffi32 => InputAudio::InputAudio {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputAudio",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputAudio::InputAudioEmpty => {
+                (-0x26a5_237ci32).tl_write(output)?;
+            }
+            InputAudio::InputAudio { id, access_hash } => {
+                (0x77d4_40ffi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputAudio::InputAudioEmpty => {}
+            InputAudio::InputAudio { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputAudio {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x26a5_237ci32 => InputAudio::InputAudioEmpty {},
+            0x77d4_40ffi32 => InputAudio::InputAudio {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputAudio",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputAudioInputAudioBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputAudioInputAudioBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputAudioInputAudioBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputAudio {
+        InputAudio::InputAudio {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputChatPhoto {
+    /// `inputChatPhotoEmpty#1ca48f57 = InputChatPhoto`
+    InputChatPhotoEmpty,
+    /// `inputChatUploadedPhoto#94254732 file:InputFile crop:InputPhotoCrop = InputChatPhoto`
+    InputChatUploadedPhoto {
+        file: super::tl::InputFile,
+        crop: super::tl::InputPhotoCrop,
+    },
+    /// `inputChatPhoto#b2e1bf08 id:InputPhoto crop:InputPhotoCrop = InputChatPhoto`
+    InputChatPhoto {
+        id: super::tl::InputPhoto,
+        crop: super::tl::InputPhotoCrop,
+    },
+}
+impl TLType for InputChatPhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1ca4_8f57i32 => InputChatPhoto::InputChatPhotoEmpty {},
+            -0x6bda_b8cei32 => InputChatPhoto::InputChatUploadedPhoto {
+                file: TLType::tl_read_with_context(input, context)?,
+                crop: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x4d1e_40f8i32 => InputChatPhoto::InputChatPhoto {
+                id: TLType::tl_read_with_context(input, context)?,
+                crop: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputChatPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputChatPhoto::InputChatPhotoEmpty => {
+                (0x1ca4_8f57i32).tl_write(output)?;
+            }
+            InputChatPhoto::InputChatUploadedPhoto { file, crop } => {
+                (-0x6bda_b8cei32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += crop.tl_write(output)?;
+            }
+            InputChatPhoto::InputChatPhoto { id, crop } => {
+                (-0x4d1e_40f8i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += crop.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputChatPhoto::InputChatPhotoEmpty => {}
+            InputChatPhoto::InputChatUploadedPhoto { file, crop } => {
+                result += file.serialized_len();
+                result += crop.serialized_len();
+            }
+            InputChatPhoto::InputChatPhoto { id, crop } => {
+                result += id.serialized_len();
+                result += crop.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputChatPhotoRef<'a> {
+    /// `inputChatPhotoEmpty#1ca48f57 = InputChatPhoto`
+    InputChatPhotoEmpty,
+    /// `inputChatUploadedPhoto#94254732 file:InputFile crop:InputPhotoCrop = InputChatPhoto`
+    InputChatUploadedPhoto {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        crop: super::tl::InputPhotoCrop,
+    },
+    /// `inputChatPhoto#b2e1bf08 id:InputPhoto crop:InputPhotoCrop = InputChatPhoto`
+    InputChatPhoto {
+        id: super::tl::InputPhoto,
+        crop: super::tl::InputPhotoCrop,
+    },
+}
+impl<'a> TLDeserialize<'a> for InputChatPhotoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1ca4_8f57i32 => InputChatPhotoRef::InputChatPhotoEmpty {},
+            -0x6bda_b8cei32 => InputChatPhotoRef::InputChatUploadedPhoto {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                crop: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x4d1e_40f8i32 => InputChatPhotoRef::InputChatPhoto {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                crop: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputChatPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputChatPhotoInputChatUploadedPhotoBuilder {
+    file: super::tl::InputFile,
+    crop: super::tl::InputPhotoCrop,
+}
+impl InputChatPhotoInputChatUploadedPhotoBuilder {
+    pub fn new(file: super::tl::InputFile, crop: super::tl::InputPhotoCrop) -> Self {
+        InputChatPhotoInputChatUploadedPhotoBuilder { file, crop }
+    }
+    pub fn build(self) -> InputChatPhoto {
+        InputChatPhoto::InputChatUploadedPhoto {
+            file: self.file,
+            crop: self.crop,
+        }
+    }
+}
+pub struct InputChatPhotoInputChatPhotoBuilder {
+    id: super::tl::InputPhoto,
+    crop: super::tl::InputPhotoCrop,
+}
+impl InputChatPhotoInputChatPhotoBuilder {
+    pub fn new(id: super::tl::InputPhoto, crop: super::tl::InputPhotoCrop) -> Self {
+        InputChatPhotoInputChatPhotoBuilder { id, crop }
+    }
+    pub fn build(self) -> InputChatPhoto {
+        InputChatPhoto::InputChatPhoto {
+            id: self.id,
+            crop: self.crop,
+        }
+    }
+}
+/// `inputPhoneContact#f392b7f4 client_id:long phone:string first_name:string last_name:string = InputContact`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputContact {
+    pub client_id: i64,
+    pub phone: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+impl TLType for InputContact {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0c6d_480ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputContact",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0c6d_480ci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InputContact {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputContact {
+            client_id: TLType::tl_read_with_context(input, context)?,
+            phone: TLType::tl_read_with_context(input, context)?,
+            first_name: TLType::tl_read_with_context(input, context)?,
+            last_name: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.client_id.tl_write(output)?;
+        result += self.phone.tl_write(output)?;
+        result += self.first_name.tl_write(output)?;
+        result += self.last_name.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.client_id.serialized_len();
+        result += self.phone.serialized_len();
+        result += self.first_name.serialized_len();
+        result += self.last_name.serialized_len();
+        result
+    }
+}
+/// `inputPhoneContact#f392b7f4 client_id:long phone:string first_name:string last_name:string = InputContact`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputContactRef<'a> {
+    pub client_id: i64,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub phone: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub first_name: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub last_name: &'a str,
+}
+impl<'a> TLDeserialize<'a> for InputContactRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x0c6d_480ci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputContact",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InputContactRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputContactRef {
+            client_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputContactBuilder {
+    client_id: i64,
+    phone: String,
+    first_name: String,
+    last_name: String,
+}
+impl InputContactBuilder {
+    pub fn new(client_id: i64, phone: String, first_name: String, last_name: String) -> Self {
+        InputContactBuilder {
+            client_id,
+            phone,
+            first_name,
+            last_name,
+        }
+    }
+    pub fn build(self) -> InputContact {
+        InputContact {
+            client_id: self.client_id,
+            phone: self.phone,
+            first_name: self.first_name,
+            last_name: self.last_name,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputDocument {
+    /// `inputDocumentEmpty#72f0eaae = InputDocument`
+    InputDocumentEmpty,
+    /// `inputDocument#18798952 id:long access_hash:long = InputDocument`
+    InputDocument { id: i64, access_hash: i64 },
+}
+impl TLType for InputDocument {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x72f0_eaaei32 => InputDocument::InputDocumentEmpty {},
+            0x1879_8952i32 => InputDocument::InputDocument {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputDocument",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputDocument::InputDocumentEmpty => {
+                (0x72f0_eaaei32).tl_write(output)?;
+            }
+            InputDocument::InputDocument { id, access_hash } => {
+                (0x1879_8952i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputDocument::InputDocumentEmpty => {}
+            InputDocument::InputDocument { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputDocument {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x72f0_eaaei32 => InputDocument::InputDocumentEmpty {},
+            0x1879_8952i32 => InputDocument::InputDocument {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputDocument",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputDocumentInputDocumentBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputDocumentInputDocumentBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputDocumentInputDocumentBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputDocument {
+        InputDocument::InputDocument {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+/// `inputEncryptedChat#f141b5e1 chat_id:int access_hash:long = InputEncryptedChat`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputEncryptedChat {
+    pub chat_id: i32,
+    pub access_hash: i64,
+}
+impl TLType for InputEncryptedChat {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x0ebe_4a1fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputEncryptedChat",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x0ebe_4a1fi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InputEncryptedChat {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputEncryptedChat {
+            chat_id: TLType::tl_read_with_context(input, context)?,
+            access_hash: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.chat_id.tl_write(output)?;
+        result += self.access_hash.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.chat_id.serialized_len();
+        result += self.access_hash.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputEncryptedChat {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x0ebe_4a1fi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputEncryptedChat",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InputEncryptedChat {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputEncryptedChat {
+            chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputEncryptedChatBuilder {
+    chat_id: i32,
+    access_hash: i64,
+}
+impl InputEncryptedChatBuilder {
+    pub fn new(chat_id: i32, access_hash: i64) -> Self {
+        InputEncryptedChatBuilder {
+            chat_id,
+            access_hash,
+        }
+    }
+    pub fn build(self) -> InputEncryptedChat {
+        InputEncryptedChat {
+            chat_id: self.chat_id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputEncryptedFile {
+    /// `inputEncryptedFileEmpty#1837c364 = InputEncryptedFile`
+    InputEncryptedFileEmpty,
+    /// `inputEncryptedFileUploaded#64bd0306 id:long parts:int md5_checksum:string key_fingerprint:int = InputEncryptedFile`
+    InputEncryptedFileUploaded {
+        id: i64,
+        parts: i32,
+        md5_checksum: String,
+        key_fingerprint: i32,
+    },
+    /// `inputEncryptedFile#5a17b5e5 id:long access_hash:long = InputEncryptedFile`
+    InputEncryptedFile { id: i64, access_hash: i64 },
+    /// `inputEncryptedFileBigUploaded#2dc173c8 id:long parts:int key_fingerprint:int = InputEncryptedFile`
+    InputEncryptedFileBigUploaded {
+        id: i64,
+        parts: i32,
+        key_fingerprint: i32,
+    },
+}
+impl TLType for InputEncryptedFile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1837_c364i32 => InputEncryptedFile::InputEncryptedFileEmpty {},
+            0x64bd_0306i32 => InputEncryptedFile::InputEncryptedFileUploaded {
+                id: TLType::tl_read_with_context(input, context)?,
+                parts: TLType::tl_read_with_context(input, context)?,
+                md5_checksum: TLType::tl_read_with_context(input, context)?,
+                key_fingerprint: TLType::tl_read_with_context(input, context)?,
+            },
+            0x5a17_b5e5i32 => InputEncryptedFile::InputEncryptedFile {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2dc1_73c8i32 => InputEncryptedFile::InputEncryptedFileBigUploaded {
+                id: TLType::tl_read_with_context(input, context)?,
+                parts: TLType::tl_read_with_context(input, context)?,
+                key_fingerprint: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputEncryptedFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputEncryptedFile::InputEncryptedFileEmpty => {
+                (0x1837_c364i32).tl_write(output)?;
+            }
+            InputEncryptedFile::InputEncryptedFileUploaded {
+                id,
+                parts,
+                md5_checksum,
+                key_fingerprint,
+            } => {
+                (0x64bd_0306i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += parts.tl_write(output)?;
+                result += md5_checksum.tl_write(output)?;
+                result += key_fingerprint.tl_write(output)?;
+            }
+            InputEncryptedFile::InputEncryptedFile { id, access_hash } => {
+                (0x5a17_b5e5i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+            InputEncryptedFile::InputEncryptedFileBigUploaded {
+                id,
+                parts,
+                key_fingerprint,
+            } => {
+                (0x2dc1_73c8i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += parts.tl_write(output)?;
+                result += key_fingerprint.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputEncryptedFile::InputEncryptedFileEmpty => {}
+            InputEncryptedFile::InputEncryptedFileUploaded {
+                id,
+                parts,
+                md5_checksum,
+                key_fingerprint,
+            } => {
+                result += id.serialized_len();
+                result += parts.serialized_len();
+                result += md5_checksum.serialized_len();
+                result += key_fingerprint.serialized_len();
+            }
+            InputEncryptedFile::InputEncryptedFile { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+            InputEncryptedFile::InputEncryptedFileBigUploaded {
+                id,
+                parts,
+                key_fingerprint,
+            } => {
+                result += id.serialized_len();
+                result += parts.serialized_len();
+                result += key_fingerprint.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputEncryptedFileRef<'a> {
+    /// `inputEncryptedFileEmpty#1837c364 = InputEncryptedFile`
+    InputEncryptedFileEmpty,
+    /// `inputEncryptedFileUploaded#64bd0306 id:long parts:int md5_checksum:string key_fingerprint:int = InputEncryptedFile`
+    InputEncryptedFileUploaded {
+        id: i64,
+        parts: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        md5_checksum: &'a str,
+        key_fingerprint: i32,
+    },
+    /// `inputEncryptedFile#5a17b5e5 id:long access_hash:long = InputEncryptedFile`
+    InputEncryptedFile { id: i64, access_hash: i64 },
+    /// `inputEncryptedFileBigUploaded#2dc173c8 id:long parts:int key_fingerprint:int = InputEncryptedFile`
+    InputEncryptedFileBigUploaded {
+        id: i64,
+        parts: i32,
+        key_fingerprint: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for InputEncryptedFileRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1837_c364i32 => InputEncryptedFileRef::InputEncryptedFileEmpty {},
+            0x64bd_0306i32 => InputEncryptedFileRef::InputEncryptedFileUploaded {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                parts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                md5_checksum: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                key_fingerprint: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x5a17_b5e5i32 => InputEncryptedFileRef::InputEncryptedFile {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2dc1_73c8i32 => InputEncryptedFileRef::InputEncryptedFileBigUploaded {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                parts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                key_fingerprint: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputEncryptedFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputEncryptedFileInputEncryptedFileUploadedBuilder {
+    id: i64,
+    parts: i32,
+    md5_checksum: String,
+    key_fingerprint: i32,
+}
+impl InputEncryptedFileInputEncryptedFileUploadedBuilder {
+    pub fn new(id: i64, parts: i32, md5_checksum: String, key_fingerprint: i32) -> Self {
+        InputEncryptedFileInputEncryptedFileUploadedBuilder {
+            id,
+            parts,
+            md5_checksum,
+            key_fingerprint,
+        }
+    }
+    pub fn build(self) -> InputEncryptedFile {
+        InputEncryptedFile::InputEncryptedFileUploaded {
+            id: self.id,
+            parts: self.parts,
+            md5_checksum: self.md5_checksum,
+            key_fingerprint: self.key_fingerprint,
+        }
+    }
+}
+pub struct InputEncryptedFileInputEncryptedFileBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputEncryptedFileInputEncryptedFileBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputEncryptedFileInputEncryptedFileBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputEncryptedFile {
+        InputEncryptedFile::InputEncryptedFile {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+pub struct InputEncryptedFileInputEncryptedFileBigUploadedBuilder {
+    id: i64,
+    parts: i32,
+    key_fingerprint: i32,
+}
+impl InputEncryptedFileInputEncryptedFileBigUploadedBuilder {
+    pub fn new(id: i64, parts: i32, key_fingerprint: i32) -> Self {
+        InputEncryptedFileInputEncryptedFileBigUploadedBuilder {
+            id,
+            parts,
+            key_fingerprint,
+        }
+    }
+    pub fn build(self) -> InputEncryptedFile {
+        InputEncryptedFile::InputEncryptedFileBigUploaded {
+            id: self.id,
+            parts: self.parts,
+            key_fingerprint: self.key_fingerprint,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputFile {
+    /// `inputFile#f52ff27f id:long parts:int name:string md5_checksum:string = InputFile`
+    InputFile {
+        id: i64,
+        parts: i32,
+        name: String,
+        md5_checksum: String,
+    },
+    /// `inputFileBig#fa4f0bb5 id:long parts:int name:string = InputFile`
+    InputFileBig { id: i64, parts: i32, name: String },
+}
+impl TLType for InputFile {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x0ad0_0d81i32 => InputFile::InputFile {
+                id: TLType::tl_read_with_context(input, context)?,
+                parts: TLType::tl_read_with_context(input, context)?,
+                name: TLType::tl_read_with_context(input, context)?,
+                md5_checksum: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x05b0_f44bi32 => InputFile::InputFileBig {
+                id: TLType::tl_read_with_context(input, context)?,
+                parts: TLType::tl_read_with_context(input, context)?,
+                name: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputFile::InputFile {
+                id,
+                parts,
+                name,
+                md5_checksum,
+            } => {
+                (-0x0ad0_0d81i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += parts.tl_write(output)?;
+                result += name.tl_write(output)?;
+                result += md5_checksum.tl_write(output)?;
+            }
+            InputFile::InputFileBig { id, parts, name } => {
+                (-0x05b0_f44bi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += parts.tl_write(output)?;
+                result += name.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputFile::InputFile {
+                id,
+                parts,
+                name,
+                md5_checksum,
+            } => {
+                result += id.serialized_len();
+                result += parts.serialized_len();
+                result += name.serialized_len();
+                result += md5_checksum.serialized_len();
+            }
+            InputFile::InputFileBig { id, parts, name } => {
+                result += id.serialized_len();
+                result += parts.serialized_len();
+                result += name.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputFileRef<'a> {
+    /// `inputFile#f52ff27f id:long parts:int name:string md5_checksum:string = InputFile`
+    InputFile {
+        id: i64,
+        parts: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        md5_checksum: &'a str,
+    },
+    /// `inputFileBig#fa4f0bb5 id:long parts:int name:string = InputFile`
+    InputFileBig {
+        id: i64,
+        parts: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        name: &'a str,
+    },
+}
+impl<'a> TLDeserialize<'a> for InputFileRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x0ad0_0d81i32 => InputFileRef::InputFile {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                parts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                md5_checksum: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x05b0_f44bi32 => InputFileRef::InputFileBig {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                parts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputFile",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputFileInputFileBuilder {
+    id: i64,
+    parts: i32,
+    name: String,
+    md5_checksum: String,
+}
+impl InputFileInputFileBuilder {
+    pub fn new(id: i64, parts: i32, name: String, md5_checksum: String) -> Self {
+        InputFileInputFileBuilder {
+            id,
+            parts,
+            name,
+            md5_checksum,
+        }
+    }
+    pub fn build(self) -> InputFile {
+        InputFile::InputFile {
+            id: self.id,
+            parts: self.parts,
+            name: self.name,
+            md5_checksum: self.md5_checksum,
+        }
+    }
+}
+pub struct InputFileInputFileBigBuilder {
+    id: i64,
+    parts: i32,
+    name: String,
+}
+impl InputFileInputFileBigBuilder {
+    pub fn new(id: i64, parts: i32, name: String) -> Self {
+        InputFileInputFileBigBuilder { id, parts, name }
+    }
+    pub fn build(self) -> InputFile {
+        InputFile::InputFileBig {
+            id: self.id,
+            parts: self.parts,
+            name: self.name,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputFileLocation {
+    /// `inputFileLocation#14637196 volume_id:long local_id:int secret:long = InputFileLocation`
+    InputFileLocation {
+        volume_id: i64,
+        local_id: i32,
+        secret: i64,
+    },
+    /// `inputVideoFileLocation#3d0364ec id:long access_hash:long = InputFileLocation`
+    InputVideoFileLocation { id: i64, access_hash: i64 },
+    /// `inputEncryptedFileLocation#f5235d55 id:long access_hash:long = InputFileLocation`
+    InputEncryptedFileLocation { id: i64, access_hash: i64 },
+    /// `inputAudioFileLocation#74dc404d id:long access_hash:long = InputFileLocation`
+    InputAudioFileLocation { id: i64, access_hash: i64 },
+    /// `inputDocumentFileLocation#4e45abe9 id:long access_hash:long = InputFileLocation`
+    InputDocumentFileLocation { id: i64, access_hash: i64 },
+}
+impl TLType for InputFileLocation {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1463_7196i32 => InputFileLocation::InputFileLocation {
+                volume_id: TLType::tl_read_with_context(input, context)?,
+                local_id: TLType::tl_read_with_context(input, context)?,
+                secret: TLType::tl_read_with_context(input, context)?,
+            },
+            0x3d03_64eci32 => InputFileLocation::InputVideoFileLocation {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x0adc_a2abi32 => InputFileLocation::InputEncryptedFileLocation {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            0x74dc_404di32 => InputFileLocation::InputAudioFileLocation {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            0x4e45_abe9i32 => InputFileLocation::InputDocumentFileLocation {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputFileLocation",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputFileLocation::InputFileLocation {
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                (0x1463_7196i32).tl_write(output)?;
+                result += volume_id.tl_write(output)?;
+                result += local_id.tl_write(output)?;
+                result += secret.tl_write(output)?;
+            }
+            InputFileLocation::InputVideoFileLocation { id, access_hash } => {
+                (0x3d03_64eci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+            InputFileLocation::InputEncryptedFileLocation { id, access_hash } => {
+                (-0x0adc_a2abi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+            InputFileLocation::InputAudioFileLocation { id, access_hash } => {
+                (0x74dc_404di32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+            InputFileLocation::InputDocumentFileLocation { id, access_hash } => {
+                (0x4e45_abe9i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputFileLocation::InputFileLocation {
+                volume_id,
+                local_id,
+                secret,
+            } => {
+                result += volume_id.serialized_len();
+                result += local_id.serialized_len();
+                result += secret.serialized_len();
+            }
+            InputFileLocation::InputVideoFileLocation { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+            InputFileLocation::InputEncryptedFileLocation { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+            InputFileLocation::InputAudioFileLocation { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+            InputFileLocation::InputDocumentFileLocation { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputFileLocation {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1463_7196i32 => InputFileLocation::InputFileLocation {
+                volume_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                local_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                secret: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x3d03_64eci32 => InputFileLocation::InputVideoFileLocation {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x0adc_a2abi32 => InputFileLocation::InputEncryptedFileLocation {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x74dc_404di32 => InputFileLocation::InputAudioFileLocation {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x4e45_abe9i32 => InputFileLocation::InputDocumentFileLocation {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputFileLocation",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputFileLocationInputFileLocationBuilder {
+    volume_id: i64,
+    local_id: i32,
+    secret: i64,
+}
+impl InputFileLocationInputFileLocationBuilder {
+    pub fn new(volume_id: i64, local_id: i32, secret: i64) -> Self {
+        InputFileLocationInputFileLocationBuilder {
+            volume_id,
+            local_id,
+            secret,
+        }
+    }
+    pub fn build(self) -> InputFileLocation {
+        InputFileLocation::InputFileLocation {
+            volume_id: self.volume_id,
+            local_id: self.local_id,
+            secret: self.secret,
+        }
+    }
+}
+pub struct InputFileLocationInputVideoFileLocationBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputFileLocationInputVideoFileLocationBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputFileLocationInputVideoFileLocationBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputFileLocation {
+        InputFileLocation::InputVideoFileLocation {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+pub struct InputFileLocationInputEncryptedFileLocationBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputFileLocationInputEncryptedFileLocationBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputFileLocationInputEncryptedFileLocationBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputFileLocation {
+        InputFileLocation::InputEncryptedFileLocation {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+pub struct InputFileLocationInputAudioFileLocationBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputFileLocationInputAudioFileLocationBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputFileLocationInputAudioFileLocationBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputFileLocation {
+        InputFileLocation::InputAudioFileLocation {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+pub struct InputFileLocationInputDocumentFileLocationBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputFileLocationInputDocumentFileLocationBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputFileLocationInputDocumentFileLocationBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputFileLocation {
+        InputFileLocation::InputDocumentFileLocation {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputGeoPoint {
+    /// `inputGeoPointEmpty#e4c123d6 = InputGeoPoint`
+    InputGeoPointEmpty,
+    /// `inputGeoPoint#f3b7acc9 lat:double long:double = InputGeoPoint`
+    InputGeoPoint { lat: f64, long: f64 },
+}
+impl TLType for InputGeoPoint {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x1b3e_dc2ai32 => InputGeoPoint::InputGeoPointEmpty {},
+            -0x0c48_5337i32 => InputGeoPoint::InputGeoPoint {
+                lat: TLType::tl_read_with_context(input, context)?,
+                long: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputGeoPoint",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputGeoPoint::InputGeoPointEmpty => {
+                (-0x1b3e_dc2ai32).tl_write(output)?;
+            }
+            InputGeoPoint::InputGeoPoint { lat, long } => {
+                (-0x0c48_5337i32).tl_write(output)?;
+                result += lat.tl_write(output)?;
+                result += long.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputGeoPoint::InputGeoPointEmpty => {}
+            InputGeoPoint::InputGeoPoint { lat, long } => {
+                result += lat.serialized_len();
+                result += long.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputGeoPoint {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x1b3e_dc2ai32 => InputGeoPoint::InputGeoPointEmpty {},
+            -0x0c48_5337i32 => InputGeoPoint::InputGeoPoint {
+                lat: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                long: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputGeoPoint",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputGeoPointInputGeoPointBuilder {
+    lat: f64,
+    long: f64,
+}
+impl InputGeoPointInputGeoPointBuilder {
+    pub fn new(lat: f64, long: f64) -> Self {
+        InputGeoPointInputGeoPointBuilder { lat, long }
+    }
+    pub fn build(self) -> InputGeoPoint {
+        InputGeoPoint::InputGeoPoint {
+            lat: self.lat,
+            long: self.long,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputMedia {
+    /// `inputMediaEmpty#9664f57f = InputMedia`
+    InputMediaEmpty,
+    /// `inputMediaUploadedPhoto#2dc53a7d file:InputFile = InputMedia`
+    InputMediaUploadedPhoto { file: super::tl::InputFile },
+    /// `inputMediaPhoto#8f2ab2ec id:InputPhoto = InputMedia`
+    InputMediaPhoto { id: super::tl::InputPhoto },
+    /// `inputMediaGeoPoint#f9c44144 geo_point:InputGeoPoint = InputMedia`
+    InputMediaGeoPoint { geo_point: super::tl::InputGeoPoint },
+    /// `inputMediaContact#a6e45987 phone_number:string first_name:string last_name:string = InputMedia`
+    InputMediaContact {
+        phone_number: String,
+        first_name: String,
+        last_name: String,
+    },
+    /// `inputMediaUploadedVideo#133ad6f6 file:InputFile duration:int w:int h:int mime_type:string = InputMedia`
+    InputMediaUploadedVideo {
+        file: super::tl::InputFile,
+        duration: i32,
+        w: i32,
+        h: i32,
+        mime_type: String,
+    },
+    /// `inputMediaUploadedThumbVideo#9912dabf file:InputFile thumb:InputFile duration:int w:int h:int mime_type:string = InputMedia`
+    InputMediaUploadedThumbVideo {
+        file: super::tl::InputFile,
+        thumb: super::tl::InputFile,
+        duration: i32,
+        w: i32,
+        h: i32,
+        mime_type: String,
+    },
+    /// `inputMediaVideo#7f023ae6 id:InputVideo = InputMedia`
+    InputMediaVideo { id: super::tl::InputVideo },
+    /// `inputMediaUploadedAudio#4e498cab file:InputFile duration:int mime_type:string = InputMedia`
+    InputMediaUploadedAudio {
+        file: super::tl::InputFile,
+        duration: i32,
+        mime_type: String,
+    },
+    /// `inputMediaAudio#89938781 id:InputAudio = InputMedia`
+    InputMediaAudio { id: super::tl::InputAudio },
+    /// `inputMediaUploadedDocument#ffe76b78 file:InputFile mime_type:string attributes:Vector<DocumentAttribute> = InputMedia`
+    InputMediaUploadedDocument {
+        file: super::tl::InputFile,
+        mime_type: String,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    },
+    /// `inputMediaUploadedThumbDocument#41481486 file:InputFile thumb:InputFile mime_type:string attributes:Vector<DocumentAttribute> = InputMedia`
+    InputMediaUploadedThumbDocument {
+        file: super::tl::InputFile,
+        thumb: super::tl::InputFile,
+        mime_type: String,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    },
+    /// `inputMediaDocument#d184e841 id:InputDocument = InputMedia`
+    InputMediaDocument { id: super::tl::InputDocument },
+}
+impl TLType for InputMedia {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x699b_0a81i32 => InputMedia::InputMediaEmpty {},
+            0x2dc5_3a7di32 => InputMedia::InputMediaUploadedPhoto {
+                file: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x70d5_4d14i32 => InputMedia::InputMediaPhoto {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x063b_bebci32 => InputMedia::InputMediaGeoPoint {
+                geo_point: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x591b_a679i32 => InputMedia::InputMediaContact {
+                phone_number: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+            },
+            0x133a_d6f6i32 => InputMedia::InputMediaUploadedVideo {
+                file: TLType::tl_read_with_context(input, context)?,
+                duration: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x66ed_2541i32 => InputMedia::InputMediaUploadedThumbVideo {
+                file: TLType::tl_read_with_context(input, context)?,
+                thumb: TLType::tl_read_with_context(input, context)?,
+                duration: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+            },
+            0x7f02_3ae6i32 => InputMedia::InputMediaVideo {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x4e49_8cabi32 => InputMedia::InputMediaUploadedAudio {
+                file: TLType::tl_read_with_context(input, context)?,
+                duration: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x766c_787fi32 => InputMedia::InputMediaAudio {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x0018_9488i32 => InputMedia::InputMediaUploadedDocument {
+                file: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+                attributes: TLType::tl_read_with_context(input, context)?,
+            },
+            0x4148_1486i32 => InputMedia::InputMediaUploadedThumbDocument {
+                file: TLType::tl_read_with_context(input, context)?,
+                thumb: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+                attributes: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x2e7b_17bfi32 => InputMedia::InputMediaDocument {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputMedia",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputMedia::InputMediaEmpty => {
+                (-0x699b_0a81i32).tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedPhoto { file } => {
+                (0x2dc5_3a7di32).tl_write(output)?;
+                result += file.tl_write(output)?;
+            }
+            InputMedia::InputMediaPhoto { id } => {
+                (-0x70d5_4d14i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            InputMedia::InputMediaGeoPoint { geo_point } => {
+                (-0x063b_bebci32).tl_write(output)?;
+                result += geo_point.tl_write(output)?;
+            }
+            InputMedia::InputMediaContact {
+                phone_number,
+                first_name,
+                last_name,
+            } => {
+                (-0x591b_a679i32).tl_write(output)?;
+                result += phone_number.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedVideo {
+                file,
+                duration,
+                w,
+                h,
+                mime_type,
+            } => {
+                (0x133a_d6f6i32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedThumbVideo {
+                file,
+                thumb,
+                duration,
+                w,
+                h,
+                mime_type,
+            } => {
+                (-0x66ed_2541i32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += thumb.tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+            }
+            InputMedia::InputMediaVideo { id } => {
+                (0x7f02_3ae6i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedAudio {
+                file,
+                duration,
+                mime_type,
+            } => {
+                (0x4e49_8cabi32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+            }
+            InputMedia::InputMediaAudio { id } => {
+                (-0x766c_787fi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedDocument {
+                file,
+                mime_type,
+                attributes,
+            } => {
+                (-0x0018_9488i32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+                result += attributes.tl_write(output)?;
+            }
+            InputMedia::InputMediaUploadedThumbDocument {
+                file,
+                thumb,
+                mime_type,
+                attributes,
+            } => {
+                (0x4148_1486i32).tl_write(output)?;
+                result += file.tl_write(output)?;
+                result += thumb.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+                result += attributes.tl_write(output)?;
+            }
+            InputMedia::InputMediaDocument { id } => {
+                (-0x2e7b_17bfi32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputMedia::InputMediaEmpty => {}
+            InputMedia::InputMediaUploadedPhoto { file } => {
+                result += file.serialized_len();
+            }
+            InputMedia::InputMediaPhoto { id } => {
+                result += id.serialized_len();
+            }
+            InputMedia::InputMediaGeoPoint { geo_point } => {
+                result += geo_point.serialized_len();
+            }
+            InputMedia::InputMediaContact {
+                phone_number,
+                first_name,
+                last_name,
+            } => {
+                result += phone_number.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+            }
+            InputMedia::InputMediaUploadedVideo {
+                file,
+                duration,
+                w,
+                h,
+                mime_type,
+            } => {
+                result += file.serialized_len();
+                result += duration.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+                result += mime_type.serialized_len();
+            }
+            InputMedia::InputMediaUploadedThumbVideo {
+                file,
+                thumb,
+                duration,
+                w,
+                h,
+                mime_type,
+            } => {
+                result += file.serialized_len();
+                result += thumb.serialized_len();
+                result += duration.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+                result += mime_type.serialized_len();
+            }
+            InputMedia::InputMediaVideo { id } => {
+                result += id.serialized_len();
+            }
+            InputMedia::InputMediaUploadedAudio {
+                file,
+                duration,
+                mime_type,
+            } => {
+                result += file.serialized_len();
+                result += duration.serialized_len();
+                result += mime_type.serialized_len();
+            }
+            InputMedia::InputMediaAudio { id } => {
+                result += id.serialized_len();
+            }
+            InputMedia::InputMediaUploadedDocument {
+                file,
+                mime_type,
+                attributes,
+            } => {
+                result += file.serialized_len();
+                result += mime_type.serialized_len();
+                result += attributes.serialized_len();
+            }
+            InputMedia::InputMediaUploadedThumbDocument {
+                file,
+                thumb,
+                mime_type,
+                attributes,
+            } => {
+                result += file.serialized_len();
+                result += thumb.serialized_len();
+                result += mime_type.serialized_len();
+                result += attributes.serialized_len();
+            }
+            InputMedia::InputMediaDocument { id } => {
+                result += id.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputMediaRef<'a> {
+    /// `inputMediaEmpty#9664f57f = InputMedia`
+    InputMediaEmpty,
+    /// `inputMediaUploadedPhoto#2dc53a7d file:InputFile = InputMedia`
+    InputMediaUploadedPhoto {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+    },
+    /// `inputMediaPhoto#8f2ab2ec id:InputPhoto = InputMedia`
+    InputMediaPhoto { id: super::tl::InputPhoto },
+    /// `inputMediaGeoPoint#f9c44144 geo_point:InputGeoPoint = InputMedia`
+    InputMediaGeoPoint { geo_point: super::tl::InputGeoPoint },
+    /// `inputMediaContact#a6e45987 phone_number:string first_name:string last_name:string = InputMedia`
+    InputMediaContact {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone_number: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+    },
+    /// `inputMediaUploadedVideo#133ad6f6 file:InputFile duration:int w:int h:int mime_type:string = InputMedia`
+    InputMediaUploadedVideo {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        duration: i32,
+        w: i32,
+        h: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+    },
+    /// `inputMediaUploadedThumbVideo#9912dabf file:InputFile thumb:InputFile duration:int w:int h:int mime_type:string = InputMedia`
+    InputMediaUploadedThumbVideo {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        thumb: super::tl::InputFileRef<'a>,
+        duration: i32,
+        w: i32,
+        h: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+    },
+    /// `inputMediaVideo#7f023ae6 id:InputVideo = InputMedia`
+    InputMediaVideo { id: super::tl::InputVideo },
+    /// `inputMediaUploadedAudio#4e498cab file:InputFile duration:int mime_type:string = InputMedia`
+    InputMediaUploadedAudio {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        duration: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+    },
+    /// `inputMediaAudio#89938781 id:InputAudio = InputMedia`
+    InputMediaAudio { id: super::tl::InputAudio },
+    /// `inputMediaUploadedDocument#ffe76b78 file:InputFile mime_type:string attributes:Vector<DocumentAttribute> = InputMedia`
+    InputMediaUploadedDocument {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        attributes: Vec<super::tl::DocumentAttributeRef<'a>>,
+    },
+    /// `inputMediaUploadedThumbDocument#41481486 file:InputFile thumb:InputFile mime_type:string attributes:Vector<DocumentAttribute> = InputMedia`
+    InputMediaUploadedThumbDocument {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        file: super::tl::InputFileRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        thumb: super::tl::InputFileRef<'a>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        attributes: Vec<super::tl::DocumentAttributeRef<'a>>,
+    },
+    /// `inputMediaDocument#d184e841 id:InputDocument = InputMedia`
+    InputMediaDocument { id: super::tl::InputDocument },
+}
+impl<'a> TLDeserialize<'a> for InputMediaRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x699b_0a81i32 => InputMediaRef::InputMediaEmpty {},
+            0x2dc5_3a7di32 => InputMediaRef::InputMediaUploadedPhoto {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x70d5_4d14i32 => InputMediaRef::InputMediaPhoto {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x063b_bebci32 => InputMediaRef::InputMediaGeoPoint {
+                geo_point: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x591b_a679i32 => InputMediaRef::InputMediaContact {
+                phone_number: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x133a_d6f6i32 => InputMediaRef::InputMediaUploadedVideo {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x66ed_2541i32 => InputMediaRef::InputMediaUploadedThumbVideo {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                thumb: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x7f02_3ae6i32 => InputMediaRef::InputMediaVideo {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x4e49_8cabi32 => InputMediaRef::InputMediaUploadedAudio {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x766c_787fi32 => InputMediaRef::InputMediaAudio {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x0018_9488i32 => InputMediaRef::InputMediaUploadedDocument {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                attributes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x4148_1486i32 => InputMediaRef::InputMediaUploadedThumbDocument {
+                file: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                thumb: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                attributes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x2e7b_17bfi32 => InputMediaRef::InputMediaDocument {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputMedia",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputMediaInputMediaUploadedPhotoBuilder {
+    file: super::tl::InputFile,
+}
+impl InputMediaInputMediaUploadedPhotoBuilder {
+    pub fn new(file: super::tl::InputFile) -> Self {
+        InputMediaInputMediaUploadedPhotoBuilder { file }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedPhoto { file: self.file }
+    }
+}
+pub struct InputMediaInputMediaPhotoBuilder {
+    id: super::tl::InputPhoto,
+}
+impl InputMediaInputMediaPhotoBuilder {
+    pub fn new(id: super::tl::InputPhoto) -> Self {
+        InputMediaInputMediaPhotoBuilder { id }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaPhoto { id: self.id }
+    }
+}
+pub struct InputMediaInputMediaGeoPointBuilder {
+    geo_point: super::tl::InputGeoPoint,
+}
+impl InputMediaInputMediaGeoPointBuilder {
+    pub fn new(geo_point: super::tl::InputGeoPoint) -> Self {
+        InputMediaInputMediaGeoPointBuilder { geo_point }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaGeoPoint {
+            geo_point: self.geo_point,
+        }
+    }
+}
+pub struct InputMediaInputMediaContactBuilder {
+    phone_number: String,
+    first_name: String,
+    last_name: String,
+}
+impl InputMediaInputMediaContactBuilder {
+    pub fn new(phone_number: String, first_name: String, last_name: String) -> Self {
+        InputMediaInputMediaContactBuilder {
+            phone_number,
+            first_name,
+            last_name,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaContact {
+            phone_number: self.phone_number,
+            first_name: self.first_name,
+            last_name: self.last_name,
+        }
+    }
+}
+pub struct InputMediaInputMediaUploadedVideoBuilder {
+    file: super::tl::InputFile,
+    duration: i32,
+    w: i32,
+    h: i32,
+    mime_type: String,
+}
+impl InputMediaInputMediaUploadedVideoBuilder {
+    pub fn new(
+        file: super::tl::InputFile,
+        duration: i32,
+        w: i32,
+        h: i32,
+        mime_type: String,
+    ) -> Self {
+        InputMediaInputMediaUploadedVideoBuilder {
+            file,
+            duration,
+            w,
+            h,
+            mime_type,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedVideo {
+            file: self.file,
+            duration: self.duration,
+            w: self.w,
+            h: self.h,
+            mime_type: self.mime_type,
+        }
+    }
+}
+pub struct InputMediaInputMediaUploadedThumbVideoBuilder {
+    file: super::tl::InputFile,
+    thumb: super::tl::InputFile,
+    duration: i32,
+    w: i32,
+    h: i32,
+    mime_type: String,
+}
+impl InputMediaInputMediaUploadedThumbVideoBuilder {
+    pub fn new(
+        file: super::tl::InputFile,
+        thumb: super::tl::InputFile,
+        duration: i32,
+        w: i32,
+        h: i32,
+        mime_type: String,
+    ) -> Self {
+        InputMediaInputMediaUploadedThumbVideoBuilder {
+            file,
+            thumb,
+            duration,
+            w,
+            h,
+            mime_type,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedThumbVideo {
+            file: self.file,
+            thumb: self.thumb,
+            duration: self.duration,
+            w: self.w,
+            h: self.h,
+            mime_type: self.mime_type,
+        }
+    }
+}
+pub struct InputMediaInputMediaVideoBuilder {
+    id: super::tl::InputVideo,
+}
+impl InputMediaInputMediaVideoBuilder {
+    pub fn new(id: super::tl::InputVideo) -> Self {
+        InputMediaInputMediaVideoBuilder { id }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaVideo { id: self.id }
+    }
+}
+pub struct InputMediaInputMediaUploadedAudioBuilder {
+    file: super::tl::InputFile,
+    duration: i32,
+    mime_type: String,
+}
+impl InputMediaInputMediaUploadedAudioBuilder {
+    pub fn new(file: super::tl::InputFile, duration: i32, mime_type: String) -> Self {
+        InputMediaInputMediaUploadedAudioBuilder {
+            file,
+            duration,
+            mime_type,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedAudio {
+            file: self.file,
+            duration: self.duration,
+            mime_type: self.mime_type,
+        }
+    }
+}
+pub struct InputMediaInputMediaAudioBuilder {
+    id: super::tl::InputAudio,
+}
+impl InputMediaInputMediaAudioBuilder {
+    pub fn new(id: super::tl::InputAudio) -> Self {
+        InputMediaInputMediaAudioBuilder { id }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaAudio { id: self.id }
+    }
+}
+pub struct InputMediaInputMediaUploadedDocumentBuilder {
+    file: super::tl::InputFile,
+    mime_type: String,
+    attributes: Vec<super::tl::DocumentAttribute>,
+}
+impl InputMediaInputMediaUploadedDocumentBuilder {
+    pub fn new(
+        file: super::tl::InputFile,
+        mime_type: String,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    ) -> Self {
+        InputMediaInputMediaUploadedDocumentBuilder {
+            file,
+            mime_type,
+            attributes,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedDocument {
+            file: self.file,
+            mime_type: self.mime_type,
+            attributes: self.attributes,
+        }
+    }
+}
+pub struct InputMediaInputMediaUploadedThumbDocumentBuilder {
+    file: super::tl::InputFile,
+    thumb: super::tl::InputFile,
+    mime_type: String,
+    attributes: Vec<super::tl::DocumentAttribute>,
+}
+impl InputMediaInputMediaUploadedThumbDocumentBuilder {
+    pub fn new(
+        file: super::tl::InputFile,
+        thumb: super::tl::InputFile,
+        mime_type: String,
+        attributes: Vec<super::tl::DocumentAttribute>,
+    ) -> Self {
+        InputMediaInputMediaUploadedThumbDocumentBuilder {
+            file,
+            thumb,
+            mime_type,
+            attributes,
+        }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaUploadedThumbDocument {
+            file: self.file,
+            thumb: self.thumb,
+            mime_type: self.mime_type,
+            attributes: self.attributes,
+        }
+    }
+}
+pub struct InputMediaInputMediaDocumentBuilder {
+    id: super::tl::InputDocument,
+}
+impl InputMediaInputMediaDocumentBuilder {
+    pub fn new(id: super::tl::InputDocument) -> Self {
+        InputMediaInputMediaDocumentBuilder { id }
+    }
+    pub fn build(self) -> InputMedia {
+        InputMedia::InputMediaDocument { id: self.id }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputNotifyPeer {
+    /// `inputNotifyPeer#b8bc5b0c peer:InputPeer = InputNotifyPeer`
+    InputNotifyPeer { peer: super::tl::InputPeer },
+    /// `inputNotifyUsers#193b4417 = InputNotifyPeer`
+    InputNotifyUsers,
+    /// `inputNotifyChats#4a95e84e = InputNotifyPeer`
+    InputNotifyChats,
+    /// `inputNotifyAll#a429b886 = InputNotifyPeer`
+    InputNotifyAll,
+}
+impl TLType for InputNotifyPeer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x4743_a4f4i32 => InputNotifyPeer::InputNotifyPeer {
+                peer: TLType::tl_read_with_context(input, context)?,
+            },
+            0x193b_4417i32 => InputNotifyPeer::InputNotifyUsers {},
+            0x4a95_e84ei32 => InputNotifyPeer::InputNotifyChats {},
+            -0x5bd6_477ai32 => InputNotifyPeer::InputNotifyAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputNotifyPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputNotifyPeer::InputNotifyPeer { peer } => {
+                (-0x4743_a4f4i32).tl_write(output)?;
+                result += peer.tl_write(output)?;
+            }
+            InputNotifyPeer::InputNotifyUsers => {
+                (0x193b_4417i32).tl_write(output)?;
+            }
+            InputNotifyPeer::InputNotifyChats => {
+                (0x4a95_e84ei32).tl_write(output)?;
+            }
+            InputNotifyPeer::InputNotifyAll => {
+                (-0x5bd6_477ai32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputNotifyPeer::InputNotifyPeer { peer } => {
+                result += peer.serialized_len();
+            }
+            InputNotifyPeer::InputNotifyUsers => {}
+            InputNotifyPeer::InputNotifyChats => {}
+            InputNotifyPeer::InputNotifyAll => {}
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputNotifyPeer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x4743_a4f4i32 => InputNotifyPeer::InputNotifyPeer {
+                peer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x193b_4417i32 => InputNotifyPeer::InputNotifyUsers {},
+            0x4a95_e84ei32 => InputNotifyPeer::InputNotifyChats {},
+            -0x5bd6_477ai32 => InputNotifyPeer::InputNotifyAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputNotifyPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputNotifyPeerInputNotifyPeerBuilder {
+    peer: super::tl::InputPeer,
+}
+impl InputNotifyPeerInputNotifyPeerBuilder {
+    pub fn new(peer: super::tl::InputPeer) -> Self {
+        InputNotifyPeerInputNotifyPeerBuilder { peer }
+    }
+    pub fn build(self) -> InputNotifyPeer {
+        InputNotifyPeer::InputNotifyPeer { peer: self.peer }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputPeer {
+    /// `inputPeerEmpty#7f3b18ea = InputPeer`
+    InputPeerEmpty,
+    /// `inputPeerSelf#7da07ec9 = InputPeer`
+    InputPeerSelf,
+    /// `inputPeerContact#1023dbe8 user_id:int = InputPeer`
+    InputPeerContact { user_id: i32 },
+    /// `inputPeerForeign#9b447325 user_id:int access_hash:long = InputPeer`
+    InputPeerForeign { user_id: i32, access_hash: i64 },
+    /// `inputPeerChat#179be863 chat_id:int = InputPeer`
+    InputPeerChat { chat_id: i32 },
+}
+impl TLType for InputPeer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x7f3b_18eai32 => InputPeer::InputPeerEmpty {},
+            0x7da0_7ec9i32 => InputPeer::InputPeerSelf {},
+            0x1023_dbe8i32 => InputPeer::InputPeerContact {
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x64bb_8cdbi32 => InputPeer::InputPeerForeign {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            0x179b_e863i32 => InputPeer::InputPeerChat {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputPeer::InputPeerEmpty => {
+                (0x7f3b_18eai32).tl_write(output)?;
+            }
+            InputPeer::InputPeerSelf => {
+                (0x7da0_7ec9i32).tl_write(output)?;
+            }
+            InputPeer::InputPeerContact { user_id } => {
+                (0x1023_dbe8i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+            InputPeer::InputPeerForeign {
+                user_id,
+                access_hash,
+            } => {
+                (-0x64bb_8cdbi32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+            InputPeer::InputPeerChat { chat_id } => {
+                (0x179b_e863i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputPeer::InputPeerEmpty => {}
+            InputPeer::InputPeerSelf => {}
+            InputPeer::InputPeerContact { user_id } => {
+                result += user_id.serialized_len();
+            }
+            InputPeer::InputPeerForeign {
+                user_id,
+                access_hash,
+            } => {
+                result += user_id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+            InputPeer::InputPeerChat { chat_id } => {
+                result += chat_id.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPeer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x7f3b_18eai32 => InputPeer::InputPeerEmpty {},
+            0x7da0_7ec9i32 => InputPeer::InputPeerSelf {},
+            0x1023_dbe8i32 => InputPeer::InputPeerContact {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x64bb_8cdbi32 => InputPeer::InputPeerForeign {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x179b_e863i32 => InputPeer::InputPeerChat {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputPeerInputPeerContactBuilder {
+    user_id: i32,
+}
+impl InputPeerInputPeerContactBuilder {
+    pub fn new(user_id: i32) -> Self {
+        InputPeerInputPeerContactBuilder { user_id }
+    }
+    pub fn build(self) -> InputPeer {
+        InputPeer::InputPeerContact {
+            user_id: self.user_id,
+        }
+    }
+}
+pub struct InputPeerInputPeerForeignBuilder {
+    user_id: i32,
+    access_hash: i64,
+}
+impl InputPeerInputPeerForeignBuilder {
+    pub fn new(user_id: i32, access_hash: i64) -> Self {
+        InputPeerInputPeerForeignBuilder {
+            user_id,
+            access_hash,
+        }
+    }
+    pub fn build(self) -> InputPeer {
+        InputPeer::InputPeerForeign {
+            user_id: self.user_id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+pub struct InputPeerInputPeerChatBuilder {
+    chat_id: i32,
+}
+impl InputPeerInputPeerChatBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        InputPeerInputPeerChatBuilder { chat_id }
+    }
+    pub fn build(self) -> InputPeer {
+        InputPeer::InputPeerChat {
+            chat_id: self.chat_id,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputPeerNotifyEvents {
+    /// `inputPeerNotifyEventsEmpty#f03064d8 = InputPeerNotifyEvents`
+    InputPeerNotifyEventsEmpty,
+    /// `inputPeerNotifyEventsAll#e86a2c74 = InputPeerNotifyEvents`
+    InputPeerNotifyEventsAll,
+}
+impl TLType for InputPeerNotifyEvents {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x0fcf_9b28i32 => InputPeerNotifyEvents::InputPeerNotifyEventsEmpty {},
+            -0x1795_d38ci32 => InputPeerNotifyEvents::InputPeerNotifyEventsAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPeerNotifyEvents",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            InputPeerNotifyEvents::InputPeerNotifyEventsEmpty => {
+                (-0x0fcf_9b28i32).tl_write(output)?;
+            }
+            InputPeerNotifyEvents::InputPeerNotifyEventsAll => {
+                (-0x1795_d38ci32).tl_write(output)?;
+            }
+        }
+        Ok(4usize)
+    }
+    fn serialized_len(&self) -> usize {
+        4usize
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPeerNotifyEvents {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x0fcf_9b28i32 => InputPeerNotifyEvents::InputPeerNotifyEventsEmpty {},
+            -0x1795_d38ci32 => InputPeerNotifyEvents::InputPeerNotifyEventsAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPeerNotifyEvents",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+/// `inputPeerNotifySettings#46a2ce98 mute_until:int sound:string show_previews:Bool events_mask:int = InputPeerNotifySettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputPeerNotifySettings {
+    pub mute_until: i32,
+    pub sound: String,
+    pub show_previews: bool,
+    pub events_mask: i32,
+}
+impl TLType for InputPeerNotifySettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x46a2_ce98i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputPeerNotifySettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x46a2_ce98i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InputPeerNotifySettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputPeerNotifySettings {
+            mute_until: TLType::tl_read_with_context(input, context)?,
+            sound: TLType::tl_read_with_context(input, context)?,
+            show_previews: TLType::tl_read_with_context(input, context)?,
+            events_mask: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.mute_until.tl_write(output)?;
+        result += self.sound.tl_write(output)?;
+        result += self.show_previews.tl_write(output)?;
+        result += self.events_mask.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.mute_until.serialized_len();
+        result += self.sound.serialized_len();
+        result += self.show_previews.serialized_len();
+        result += self.events_mask.serialized_len();
+        result
+    }
+}
+/// `inputPeerNotifySettings#46a2ce98 mute_until:int sound:string show_previews:Bool events_mask:int = InputPeerNotifySettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputPeerNotifySettingsRef<'a> {
+    pub mute_until: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub sound: &'a str,
+    pub show_previews: bool,
+    pub events_mask: i32,
+}
+impl<'a> TLDeserialize<'a> for InputPeerNotifySettingsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x46a2_ce98i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputPeerNotifySettings",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InputPeerNotifySettingsRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputPeerNotifySettingsRef {
+            mute_until: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            sound: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            show_previews: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            events_mask: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputPeerNotifySettingsBuilder {
+    mute_until: i32,
+    sound: String,
+    show_previews: bool,
+    events_mask: i32,
+}
+impl InputPeerNotifySettingsBuilder {
+    pub fn new(mute_until: i32, sound: String, show_previews: bool, events_mask: i32) -> Self {
+        InputPeerNotifySettingsBuilder {
+            mute_until,
+            sound,
+            show_previews,
+            events_mask,
+        }
+    }
+    pub fn build(self) -> InputPeerNotifySettings {
+        InputPeerNotifySettings {
+            mute_until: self.mute_until,
+            sound: self.sound,
+            show_previews: self.show_previews,
+            events_mask: self.events_mask,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputPhoto {
+    /// `inputPhotoEmpty#1cd7bf0d = InputPhoto`
+    InputPhotoEmpty,
+    /// `inputPhoto#fb95c6c4 id:long access_hash:long = InputPhoto`
+    InputPhoto { id: i64, access_hash: i64 },
+}
+impl TLType for InputPhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x1cd7_bf0di32 => InputPhoto::InputPhotoEmpty {},
+            -0x046a_393ci32 => InputPhoto::InputPhoto {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputPhoto::InputPhotoEmpty => {
+                (0x1cd7_bf0di32).tl_write(output)?;
+            }
+            InputPhoto::InputPhoto { id, access_hash } => {
+                (-0x046a_393ci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputPhoto::InputPhotoEmpty => {}
+            InputPhoto::InputPhoto { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPhoto {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x1cd7_bf0di32 => InputPhoto::InputPhotoEmpty {},
+            -0x046a_393ci32 => InputPhoto::InputPhoto {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputPhotoInputPhotoBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputPhotoInputPhotoBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputPhotoInputPhotoBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputPhoto {
+        InputPhoto::InputPhoto {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputPhotoCrop {
+    /// `inputPhotoCropAuto#ade6b004 = InputPhotoCrop`
+    InputPhotoCropAuto,
+    /// `inputPhotoCrop#d9915325 crop_left:double crop_top:double crop_width:double = InputPhotoCrop`
+    InputPhotoCrop {
+        crop_left: f64,
+        crop_top: f64,
+        crop_width: f64,
+    },
+}
+impl TLType for InputPhotoCrop {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x5219_4ffci32 => InputPhotoCrop::InputPhotoCropAuto {},
+            -0x266e_acdbi32 => InputPhotoCrop::InputPhotoCrop {
+                crop_left: TLType::tl_read_with_context(input, context)?,
+                crop_top: TLType::tl_read_with_context(input, context)?,
+                crop_width: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPhotoCrop",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputPhotoCrop::InputPhotoCropAuto => {
+                (-0x5219_4ffci32).tl_write(output)?;
+            }
+            InputPhotoCrop::InputPhotoCrop {
+                crop_left,
+                crop_top,
+                crop_width,
+            } => {
+                (-0x266e_acdbi32).tl_write(output)?;
+                result += crop_left.tl_write(output)?;
+                result += crop_top.tl_write(output)?;
+                result += crop_width.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputPhotoCrop::InputPhotoCropAuto => {}
+            InputPhotoCrop::InputPhotoCrop {
+                crop_left,
+                crop_top,
+                crop_width,
+            } => {
+                result += crop_left.serialized_len();
+                result += crop_top.serialized_len();
+                result += crop_width.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPhotoCrop {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x5219_4ffci32 => InputPhotoCrop::InputPhotoCropAuto {},
+            -0x266e_acdbi32 => InputPhotoCrop::InputPhotoCrop {
+                crop_left: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                crop_top: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                crop_width: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPhotoCrop",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputPhotoCropInputPhotoCropBuilder {
+    crop_left: f64,
+    crop_top: f64,
+    crop_width: f64,
+}
+impl InputPhotoCropInputPhotoCropBuilder {
+    pub fn new(crop_left: f64, crop_top: f64, crop_width: f64) -> Self {
+        InputPhotoCropInputPhotoCropBuilder {
+            crop_left,
+            crop_top,
+            crop_width,
+        }
+    }
+    pub fn build(self) -> InputPhotoCrop {
+        InputPhotoCrop::InputPhotoCrop {
+            crop_left: self.crop_left,
+            crop_top: self.crop_top,
+            crop_width: self.crop_width,
+        }
+    }
+}
+/// `inputPrivacyKeyStatusTimestamp#4f96cb18 = InputPrivacyKey`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputPrivacyKey;
+impl TLType for InputPrivacyKey {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x4f96_cb18i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputPrivacyKey",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x4f96_cb18i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for InputPrivacyKey {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputPrivacyKey {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPrivacyKey {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x4f96_cb18i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "InputPrivacyKey",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for InputPrivacyKey {
+    fn tl_deserialize_bare_with_context(
+        _input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = InputPrivacyKey {};
+        context.leave();
+        Ok(result)
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputPrivacyRule {
+    /// `inputPrivacyValueAllowContacts#0d09e07b = InputPrivacyRule`
+    InputPrivacyValueAllowContacts,
+    /// `inputPrivacyValueAllowAll#184b35ce = InputPrivacyRule`
+    InputPrivacyValueAllowAll,
+    /// `inputPrivacyValueAllowUsers#131cc67f users:Vector<InputUser> = InputPrivacyRule`
+    InputPrivacyValueAllowUsers { users: Vec<super::tl::InputUser> },
+    /// `inputPrivacyValueDisallowContacts#0ba52007 = InputPrivacyRule`
+    InputPrivacyValueDisallowContacts,
+    /// `inputPrivacyValueDisallowAll#d66b66c9 = InputPrivacyRule`
+    InputPrivacyValueDisallowAll,
+    /// `inputPrivacyValueDisallowUsers#90110467 users:Vector<InputUser> = InputPrivacyRule`
+    InputPrivacyValueDisallowUsers { users: Vec<super::tl::InputUser> },
+}
+impl TLType for InputPrivacyRule {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x0d09_e07bi32 => InputPrivacyRule::InputPrivacyValueAllowContacts {},
+            0x184b_35cei32 => InputPrivacyRule::InputPrivacyValueAllowAll {},
+            0x131c_c67fi32 => InputPrivacyRule::InputPrivacyValueAllowUsers {
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            0x0ba5_2007i32 => InputPrivacyRule::InputPrivacyValueDisallowContacts {},
+            -0x2994_9937i32 => InputPrivacyRule::InputPrivacyValueDisallowAll {},
+            -0x6fee_fb99i32 => InputPrivacyRule::InputPrivacyValueDisallowUsers {
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPrivacyRule",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputPrivacyRule::InputPrivacyValueAllowContacts => {
+                (0x0d09_e07bi32).tl_write(output)?;
+            }
+            InputPrivacyRule::InputPrivacyValueAllowAll => {
+                (0x184b_35cei32).tl_write(output)?;
+            }
+            InputPrivacyRule::InputPrivacyValueAllowUsers { users } => {
+                (0x131c_c67fi32).tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            InputPrivacyRule::InputPrivacyValueDisallowContacts => {
+                (0x0ba5_2007i32).tl_write(output)?;
+            }
+            InputPrivacyRule::InputPrivacyValueDisallowAll => {
+                (-0x2994_9937i32).tl_write(output)?;
+            }
+            InputPrivacyRule::InputPrivacyValueDisallowUsers { users } => {
+                (-0x6fee_fb99i32).tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputPrivacyRule::InputPrivacyValueAllowContacts => {}
+            InputPrivacyRule::InputPrivacyValueAllowAll => {}
+            InputPrivacyRule::InputPrivacyValueAllowUsers { users } => {
+                result += users.serialized_len();
+            }
+            InputPrivacyRule::InputPrivacyValueDisallowContacts => {}
+            InputPrivacyRule::InputPrivacyValueDisallowAll => {}
+            InputPrivacyRule::InputPrivacyValueDisallowUsers { users } => {
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputPrivacyRule {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x0d09_e07bi32 => InputPrivacyRule::InputPrivacyValueAllowContacts {},
+            0x184b_35cei32 => InputPrivacyRule::InputPrivacyValueAllowAll {},
+            0x131c_c67fi32 => InputPrivacyRule::InputPrivacyValueAllowUsers {
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x0ba5_2007i32 => InputPrivacyRule::InputPrivacyValueDisallowContacts {},
+            -0x2994_9937i32 => InputPrivacyRule::InputPrivacyValueDisallowAll {},
+            -0x6fee_fb99i32 => InputPrivacyRule::InputPrivacyValueDisallowUsers {
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputPrivacyRule",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputPrivacyRuleInputPrivacyValueAllowUsersBuilder {
+    users: Vec<super::tl::InputUser>,
+}
+impl InputPrivacyRuleInputPrivacyValueAllowUsersBuilder {
+    pub fn new(users: Vec<super::tl::InputUser>) -> Self {
+        InputPrivacyRuleInputPrivacyValueAllowUsersBuilder { users }
+    }
+    pub fn build(self) -> InputPrivacyRule {
+        InputPrivacyRule::InputPrivacyValueAllowUsers { users: self.users }
+    }
+}
+pub struct InputPrivacyRuleInputPrivacyValueDisallowUsersBuilder {
+    users: Vec<super::tl::InputUser>,
+}
+impl InputPrivacyRuleInputPrivacyValueDisallowUsersBuilder {
+    pub fn new(users: Vec<super::tl::InputUser>) -> Self {
+        InputPrivacyRuleInputPrivacyValueDisallowUsersBuilder { users }
+    }
+    pub fn build(self) -> InputPrivacyRule {
+        InputPrivacyRule::InputPrivacyValueDisallowUsers { users: self.users }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputUser {
+    /// `inputUserEmpty#b98886cf = InputUser`
+    InputUserEmpty,
+    /// `inputUserSelf#f7c1b13f = InputUser`
+    InputUserSelf,
+    /// `inputUserContact#86e94f65 user_id:int = InputUser`
+    InputUserContact { user_id: i32 },
+    /// `inputUserForeign#655e74ff user_id:int access_hash:long = InputUser`
+    InputUserForeign { user_id: i32, access_hash: i64 },
+}
+impl TLType for InputUser {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x4677_7931i32 => InputUser::InputUserEmpty {},
+            -0x083e_4ec1i32 => InputUser::InputUserSelf {},
+            -0x7916_b09bi32 => InputUser::InputUserContact {
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x655e_74ffi32 => InputUser::InputUserForeign {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputUser",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputUser::InputUserEmpty => {
+                (-0x4677_7931i32).tl_write(output)?;
+            }
+            InputUser::InputUserSelf => {
+                (-0x083e_4ec1i32).tl_write(output)?;
+            }
+            InputUser::InputUserContact { user_id } => {
+                (-0x7916_b09bi32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+            InputUser::InputUserForeign {
+                user_id,
+                access_hash,
+            } => {
+                (0x655e_74ffi32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputUser::InputUserEmpty => {}
+            InputUser::InputUserSelf => {}
+            InputUser::InputUserContact { user_id } => {
+                result += user_id.serialized_len();
+            }
+            InputUser::InputUserForeign {
+                user_id,
+                access_hash,
+            } => {
+                result += user_id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputUser {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x4677_7931i32 => InputUser::InputUserEmpty {},
+            -0x083e_4ec1i32 => InputUser::InputUserSelf {},
+            -0x7916_b09bi32 => InputUser::InputUserContact {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x655e_74ffi32 => InputUser::InputUserForeign {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputUser",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputUserInputUserContactBuilder {
+    user_id: i32,
+}
+impl InputUserInputUserContactBuilder {
+    pub fn new(user_id: i32) -> Self {
+        InputUserInputUserContactBuilder { user_id }
+    }
+    pub fn build(self) -> InputUser {
+        InputUser::InputUserContact {
+            user_id: self.user_id,
+        }
+    }
+}
+pub struct InputUserInputUserForeignBuilder {
+    user_id: i32,
+    access_hash: i64,
+}
+impl InputUserInputUserForeignBuilder {
+    pub fn new(user_id: i32, access_hash: i64) -> Self {
+        InputUserInputUserForeignBuilder {
+            user_id,
+            access_hash,
+        }
+    }
+    pub fn build(self) -> InputUser {
+        InputUser::InputUserForeign {
+            user_id: self.user_id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputVideo {
+    /// `inputVideoEmpty#5508ec75 = InputVideo`
+    InputVideoEmpty,
+    /// `inputVideo#ee579652 id:long access_hash:long = InputVideo`
+    InputVideo { id: i64, access_hash: i64 },
+}
+impl TLType for InputVideo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x5508_ec75i32 => InputVideo::InputVideoEmpty {},
+            -0x11a8_69aei32 => InputVideo::InputVideo {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputVideo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            InputVideo::InputVideoEmpty => {
+                (0x5508_ec75i32).tl_write(output)?;
+            }
+            InputVideo::InputVideo { id, access_hash } => {
+                (-0x11a8_69aei32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            InputVideo::InputVideoEmpty => {}
+            InputVideo::InputVideo { id, access_hash } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for InputVideo {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x5508_ec75i32 => InputVideo::InputVideoEmpty {},
+            -0x11a8_69aei32 => InputVideo::InputVideo {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "InputVideo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct InputVideoInputVideoBuilder {
+    id: i64,
+    access_hash: i64,
+}
+impl InputVideoInputVideoBuilder {
+    pub fn new(id: i64, access_hash: i64) -> Self {
+        InputVideoInputVideoBuilder { id, access_hash }
+    }
+    pub fn build(self) -> InputVideo {
+        InputVideo::InputVideo {
+            id: self.id,
+            access_hash: self.access_hash,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Message {
+    /// `messageEmpty#83e5de54 id:int = Message`
+    MessageEmpty { id: i32 },
+    /// `message#567699b3 flags:int id:int from_id:int to_id:Peer date:int message:string media:MessageMedia = Message`
+    Message {
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        message: String,
+        media: super::tl::MessageMedia,
+    },
+    /// `messageForwarded#a367e716 flags:int id:int fwd_from_id:int fwd_date:int from_id:int to_id:Peer date:int message:string media:MessageMedia = Message`
+    MessageForwarded {
+        flags: i32,
+        id: i32,
+        fwd_from_id: i32,
+        fwd_date: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        message: String,
+        media: super::tl::MessageMedia,
+    },
+    /// `messageService#1d86f70e flags:int id:int from_id:int to_id:Peer date:int action:MessageAction = Message`
+    MessageService {
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        action: super::tl::MessageAction,
+    },
+}
+impl TLType for Message {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x7c1a_21aci32 => Message::MessageEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x5676_99b3i32 => Message::Message {
+                flags: TLType::tl_read_with_context(input, context)?,
+                id: TLType::tl_read_with_context(input, context)?,
+                from_id: TLType::tl_read_with_context(input, context)?,
+                to_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                message: TLType::tl_read_with_context(input, context)?,
+                media: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x5c98_18eai32 => Message::MessageForwarded {
+                flags: TLType::tl_read_with_context(input, context)?,
+                id: TLType::tl_read_with_context(input, context)?,
+                fwd_from_id: TLType::tl_read_with_context(input, context)?,
+                fwd_date: TLType::tl_read_with_context(input, context)?,
+                from_id: TLType::tl_read_with_context(input, context)?,
+                to_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                message: TLType::tl_read_with_context(input, context)?,
+                media: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1d86_f70ei32 => Message::MessageService {
+                flags: TLType::tl_read_with_context(input, context)?,
+                id: TLType::tl_read_with_context(input, context)?,
+                from_id: TLType::tl_read_with_context(input, context)?,
+                to_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                action: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Message",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Message::MessageEmpty { id } => {
+                (-0x7c1a_21aci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Message::Message {
+                flags,
+                id,
+                from_id,
+                to_id,
+                date,
+                message,
+                media,
+            } => {
+                (0x5676_99b3i32).tl_write(output)?;
+                result += flags.tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += from_id.tl_write(output)?;
+                result += to_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += media.tl_write(output)?;
+            }
+            Message::MessageForwarded {
+                flags,
+                id,
+                fwd_from_id,
+                fwd_date,
+                from_id,
+                to_id,
+                date,
+                message,
+                media,
+            } => {
+                (-0x5c98_18eai32).tl_write(output)?;
+                result += flags.tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += fwd_from_id.tl_write(output)?;
+                result += fwd_date.tl_write(output)?;
+                result += from_id.tl_write(output)?;
+                result += to_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += media.tl_write(output)?;
+            }
+            Message::MessageService {
+                flags,
+                id,
+                from_id,
+                to_id,
+                date,
+                action,
+            } => {
+                (0x1d86_f70ei32).tl_write(output)?;
+                result += flags.tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += from_id.tl_write(output)?;
+                result += to_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += action.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Message::MessageEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Message::Message {
+                flags,
+                id,
+                from_id,
+                to_id,
+                date,
+                message,
+                media,
+            } => {
+                result += flags.serialized_len();
+                result += id.serialized_len();
+                result += from_id.serialized_len();
+                result += to_id.serialized_len();
+                result += date.serialized_len();
+                result += message.serialized_len();
+                result += media.serialized_len();
+            }
+            Message::MessageForwarded {
+                flags,
+                id,
+                fwd_from_id,
+                fwd_date,
+                from_id,
+                to_id,
+                date,
+                message,
+                media,
+            } => {
+                result += flags.serialized_len();
+                result += id.serialized_len();
+                result += fwd_from_id.serialized_len();
+                result += fwd_date.serialized_len();
+                result += from_id.serialized_len();
+                result += to_id.serialized_len();
+                result += date.serialized_len();
+                result += message.serialized_len();
+                result += media.serialized_len();
+            }
+            Message::MessageService {
+                flags,
+                id,
+                from_id,
+                to_id,
+                date,
+                action,
+            } => {
+                result += flags.serialized_len();
+                result += id.serialized_len();
+                result += from_id.serialized_len();
+                result += to_id.serialized_len();
+                result += date.serialized_len();
+                result += action.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageRef<'a> {
+    /// `messageEmpty#83e5de54 id:int = Message`
+    MessageEmpty { id: i32 },
+    /// `message#567699b3 flags:int id:int from_id:int to_id:Peer date:int message:string media:MessageMedia = Message`
+    Message {
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        media: super::tl::MessageMediaRef<'a>,
+    },
+    /// `messageForwarded#a367e716 flags:int id:int fwd_from_id:int fwd_date:int from_id:int to_id:Peer date:int message:string media:MessageMedia = Message`
+    MessageForwarded {
+        flags: i32,
+        id: i32,
+        fwd_from_id: i32,
+        fwd_date: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        media: super::tl::MessageMediaRef<'a>,
+    },
+    /// `messageService#1d86f70e flags:int id:int from_id:int to_id:Peer date:int action:MessageAction = Message`
+    MessageService {
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        action: super::tl::MessageActionRef<'a>,
+    },
+}
+impl<'a> TLDeserialize<'a> for MessageRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x7c1a_21aci32 => MessageRef::MessageEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x5676_99b3i32 => MessageRef::Message {
+                flags: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                to_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                media: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x5c98_18eai32 => MessageRef::MessageForwarded {
+                flags: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                fwd_from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                fwd_date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                to_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                media: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1d86_f70ei32 => MessageRef::MessageService {
+                flags: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                to_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                action: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Message",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageMessageEmptyBuilder {
+    id: i32,
+}
+impl MessageMessageEmptyBuilder {
+    pub fn new(id: i32) -> Self {
+        MessageMessageEmptyBuilder { id }
+    }
+    pub fn build(self) -> Message {
+        Message::MessageEmpty { id: self.id }
+    }
+}
+pub struct MessageMessageBuilder {
+    flags: i32,
+    id: i32,
+    from_id: i32,
+    to_id: super::tl::Peer,
+    date: i32,
+    message: String,
+    media: super::tl::MessageMedia,
+}
+impl MessageMessageBuilder {
+    pub fn new(
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        message: String,
+        media: super::tl::MessageMedia,
+    ) -> Self {
+        MessageMessageBuilder {
+            flags,
+            id,
+            from_id,
+            to_id,
+            date,
+            message,
+            media,
+        }
+    }
+    pub fn build(self) -> Message {
+        Message::Message {
+            flags: self.flags,
+            id: self.id,
+            from_id: self.from_id,
+            to_id: self.to_id,
+            date: self.date,
+            message: self.message,
+            media: self.media,
+        }
+    }
+}
+pub struct MessageMessageForwardedBuilder {
+    flags: i32,
+    id: i32,
+    fwd_from_id: i32,
+    fwd_date: i32,
+    from_id: i32,
+    to_id: super::tl::Peer,
+    date: i32,
+    message: String,
+    media: super::tl::MessageMedia,
+}
+impl MessageMessageForwardedBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        flags: i32,
+        id: i32,
+        fwd_from_id: i32,
+        fwd_date: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        message: String,
+        media: super::tl::MessageMedia,
+    ) -> Self {
+        MessageMessageForwardedBuilder {
+            flags,
+            id,
+            fwd_from_id,
+            fwd_date,
+            from_id,
+            to_id,
+            date,
+            message,
+            media,
+        }
+    }
+    pub fn build(self) -> Message {
+        Message::MessageForwarded {
+            flags: self.flags,
+            id: self.id,
+            fwd_from_id: self.fwd_from_id,
+            fwd_date: self.fwd_date,
+            from_id: self.from_id,
+            to_id: self.to_id,
+            date: self.date,
+            message: self.message,
+            media: self.media,
+        }
+    }
+}
+pub struct MessageMessageServiceBuilder {
+    flags: i32,
+    id: i32,
+    from_id: i32,
+    to_id: super::tl::Peer,
+    date: i32,
+    action: super::tl::MessageAction,
+}
+impl MessageMessageServiceBuilder {
+    pub fn new(
+        flags: i32,
+        id: i32,
+        from_id: i32,
+        to_id: super::tl::Peer,
+        date: i32,
+        action: super::tl::MessageAction,
+    ) -> Self {
+        MessageMessageServiceBuilder {
+            flags,
+            id,
+            from_id,
+            to_id,
+            date,
+            action,
+        }
+    }
+    pub fn build(self) -> Message {
+        Message::MessageService {
+            flags: self.flags,
+            id: self.id,
+            from_id: self.from_id,
+            to_id: self.to_id,
+            date: self.date,
+            action: self.action,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageAction {
+    /// `messageActionEmpty#b6aef7b0 = MessageAction`
+    MessageActionEmpty,
+    /// `messageActionChatCreate#a6638b9a title:string users:Vector<int> = MessageAction`
+    MessageActionChatCreate { title: String, users: Vec<i32> },
+    /// `messageActionChatEditTitle#b5a1ce5a title:string = MessageAction`
+    MessageActionChatEditTitle { title: String },
+    /// `messageActionChatEditPhoto#7fcb13a8 photo:Photo = MessageAction`
+    MessageActionChatEditPhoto { photo: super::tl::Photo },
+    /// `messageActionChatDeletePhoto#95e3fbef = MessageAction`
+    MessageActionChatDeletePhoto,
+    /// `messageActionChatAddUser#5e3cfc4b user_id:int = MessageAction`
+    MessageActionChatAddUser { user_id: i32 },
+    /// `messageActionChatDeleteUser#b2ae9b0c user_id:int = MessageAction`
+    MessageActionChatDeleteUser { user_id: i32 },
+}
+impl TLType for MessageAction {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x4951_0850i32 => MessageAction::MessageActionEmpty {},
+            -0x599c_7466i32 => MessageAction::MessageActionChatCreate {
+                title: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x4a5e_31a6i32 => MessageAction::MessageActionChatEditTitle {
+                title: TLType::tl_read_with_context(input, context)?,
+            },
+            0x7fcb_13a8i32 => MessageAction::MessageActionChatEditPhoto {
+                photo: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x6a1c_0411i32 => MessageAction::MessageActionChatDeletePhoto {},
+            0x5e3c_fc4bi32 => MessageAction::MessageActionChatAddUser {
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x4d51_64f4i32 => MessageAction::MessageActionChatDeleteUser {
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessageAction",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            MessageAction::MessageActionEmpty => {
+                (-0x4951_0850i32).tl_write(output)?;
+            }
+            MessageAction::MessageActionChatCreate { title, users } => {
+                (-0x599c_7466i32).tl_write(output)?;
+                result += title.tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            MessageAction::MessageActionChatEditTitle { title } => {
+                (-0x4a5e_31a6i32).tl_write(output)?;
+                result += title.tl_write(output)?;
+            }
+            MessageAction::MessageActionChatEditPhoto { photo } => {
+                (0x7fcb_13a8i32).tl_write(output)?;
+                result += photo.tl_write(output)?;
+            }
+            MessageAction::MessageActionChatDeletePhoto => {
+                (-0x6a1c_0411i32).tl_write(output)?;
+            }
+            MessageAction::MessageActionChatAddUser { user_id } => {
+                (0x5e3c_fc4bi32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+            MessageAction::MessageActionChatDeleteUser { user_id } => {
+                (-0x4d51_64f4i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            MessageAction::MessageActionEmpty => {}
+            MessageAction::MessageActionChatCreate { title, users } => {
+                result += title.serialized_len();
+                result += users.serialized_len();
+            }
+            MessageAction::MessageActionChatEditTitle { title } => {
+                result += title.serialized_len();
+            }
+            MessageAction::MessageActionChatEditPhoto { photo } => {
+                result += photo.serialized_len();
+            }
+            MessageAction::MessageActionChatDeletePhoto => {}
+            MessageAction::MessageActionChatAddUser { user_id } => {
+                result += user_id.serialized_len();
+            }
+            MessageAction::MessageActionChatDeleteUser { user_id } => {
+                result += user_id.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageActionRef<'a> {
+    /// `messageActionEmpty#b6aef7b0 = MessageAction`
+    MessageActionEmpty,
+    /// `messageActionChatCreate#a6638b9a title:string users:Vector<int> = MessageAction`
+    MessageActionChatCreate {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+        users: Vec<i32>,
+    },
+    /// `messageActionChatEditTitle#b5a1ce5a title:string = MessageAction`
+    MessageActionChatEditTitle {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+    },
+    /// `messageActionChatEditPhoto#7fcb13a8 photo:Photo = MessageAction`
+    MessageActionChatEditPhoto {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        photo: super::tl::PhotoRef<'a>,
+    },
+    /// `messageActionChatDeletePhoto#95e3fbef = MessageAction`
+    MessageActionChatDeletePhoto,
+    /// `messageActionChatAddUser#5e3cfc4b user_id:int = MessageAction`
+    MessageActionChatAddUser { user_id: i32 },
+    /// `messageActionChatDeleteUser#b2ae9b0c user_id:int = MessageAction`
+    MessageActionChatDeleteUser { user_id: i32 },
+}
+impl<'a> TLDeserialize<'a> for MessageActionRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x4951_0850i32 => MessageActionRef::MessageActionEmpty {},
+            -0x599c_7466i32 => MessageActionRef::MessageActionChatCreate {
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x4a5e_31a6i32 => MessageActionRef::MessageActionChatEditTitle {
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x7fcb_13a8i32 => MessageActionRef::MessageActionChatEditPhoto {
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x6a1c_0411i32 => MessageActionRef::MessageActionChatDeletePhoto {},
+            0x5e3c_fc4bi32 => MessageActionRef::MessageActionChatAddUser {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x4d51_64f4i32 => MessageActionRef::MessageActionChatDeleteUser {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessageAction",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageActionMessageActionChatCreateBuilder {
+    title: String,
+    users: Vec<i32>,
+}
+impl MessageActionMessageActionChatCreateBuilder {
+    pub fn new(title: String, users: Vec<i32>) -> Self {
+        MessageActionMessageActionChatCreateBuilder { title, users }
+    }
+    pub fn build(self) -> MessageAction {
+        MessageAction::MessageActionChatCreate {
+            title: self.title,
+            users: self.users,
+        }
+    }
+}
+pub struct MessageActionMessageActionChatEditTitleBuilder {
+    title: String,
+}
+impl MessageActionMessageActionChatEditTitleBuilder {
+    pub fn new(title: String) -> Self {
+        MessageActionMessageActionChatEditTitleBuilder { title }
+    }
+    pub fn build(self) -> MessageAction {
+        MessageAction::MessageActionChatEditTitle { title: self.title }
+    }
+}
+pub struct MessageActionMessageActionChatEditPhotoBuilder {
+    photo: super::tl::Photo,
+}
+impl MessageActionMessageActionChatEditPhotoBuilder {
+    pub fn new(photo: super::tl::Photo) -> Self {
+        MessageActionMessageActionChatEditPhotoBuilder { photo }
+    }
+    pub fn build(self) -> MessageAction {
+        MessageAction::MessageActionChatEditPhoto { photo: self.photo }
+    }
+}
+pub struct MessageActionMessageActionChatAddUserBuilder {
+    user_id: i32,
+}
+impl MessageActionMessageActionChatAddUserBuilder {
+    pub fn new(user_id: i32) -> Self {
+        MessageActionMessageActionChatAddUserBuilder { user_id }
+    }
+    pub fn build(self) -> MessageAction {
+        MessageAction::MessageActionChatAddUser {
+            user_id: self.user_id,
+        }
+    }
+}
+pub struct MessageActionMessageActionChatDeleteUserBuilder {
+    user_id: i32,
+}
+impl MessageActionMessageActionChatDeleteUserBuilder {
+    pub fn new(user_id: i32) -> Self {
+        MessageActionMessageActionChatDeleteUserBuilder { user_id }
+    }
+    pub fn build(self) -> MessageAction {
+        MessageAction::MessageActionChatDeleteUser {
+            user_id: self.user_id,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageMedia {
+    /// `messageMediaEmpty#3ded6320 = MessageMedia`
+    MessageMediaEmpty,
+    /// `messageMediaPhoto#c8c45a2a photo:Photo = MessageMedia`
+    MessageMediaPhoto { photo: super::tl::Photo },
+    /// `messageMediaVideo#a2d24290 video:Video = MessageMedia`
+    MessageMediaVideo { video: super::tl::Video },
+    /// `messageMediaGeo#56e0d474 geo:GeoPoint = MessageMedia`
+    MessageMediaGeo { geo: super::tl::GeoPoint },
+    /// `messageMediaContact#5e7d2f39 phone_number:string first_name:string last_name:string user_id:int = MessageMedia`
+    MessageMediaContact {
+        phone_number: String,
+        first_name: String,
+        last_name: String,
+        user_id: i32,
+    },
+    /// `messageMediaDocument#2fda2204 document:Document = MessageMedia`
+    MessageMediaDocument { document: super::tl::Document },
+    /// `messageMediaAudio#c6b68300 audio:Audio = MessageMedia`
+    MessageMediaAudio { audio: super::tl::Audio },
+}
+impl TLType for MessageMedia {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x3ded_6320i32 => MessageMedia::MessageMediaEmpty {},
+            -0x373b_a5d6i32 => MessageMedia::MessageMediaPhoto {
+                photo: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x5d2d_bd70i32 => MessageMedia::MessageMediaVideo {
+                video: TLType::tl_read_with_context(input, context)?,
+            },
+            0x56e0_d474i32 => MessageMedia::MessageMediaGeo {
+                geo: TLType::tl_read_with_context(input, context)?,
+            },
+            0x5e7d_2f39i32 => MessageMedia::MessageMediaContact {
+                phone_number: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2fda_2204i32 => MessageMedia::MessageMediaDocument {
+                document: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x3949_7d00i32 => MessageMedia::MessageMediaAudio {
+                audio: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessageMedia",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            MessageMedia::MessageMediaEmpty => {
+                (0x3ded_6320i32).tl_write(output)?;
+            }
+            MessageMedia::MessageMediaPhoto { photo } => {
+                (-0x373b_a5d6i32).tl_write(output)?;
+                result += photo.tl_write(output)?;
+            }
+            MessageMedia::MessageMediaVideo { video } => {
+                (-0x5d2d_bd70i32).tl_write(output)?;
+                result += video.tl_write(output)?;
+            }
+            MessageMedia::MessageMediaGeo { geo } => {
+                (0x56e0_d474i32).tl_write(output)?;
+                result += geo.tl_write(output)?;
+            }
+            MessageMedia::MessageMediaContact {
+                phone_number,
+                first_name,
+                last_name,
+                user_id,
+            } => {
+                (0x5e7d_2f39i32).tl_write(output)?;
+                result += phone_number.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+            MessageMedia::MessageMediaDocument { document } => {
+                (0x2fda_2204i32).tl_write(output)?;
+                result += document.tl_write(output)?;
+            }
+            MessageMedia::MessageMediaAudio { audio } => {
+                (-0x3949_7d00i32).tl_write(output)?;
+                result += audio.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            MessageMedia::MessageMediaEmpty => {}
+            MessageMedia::MessageMediaPhoto { photo } => {
+                result += photo.serialized_len();
+            }
+            MessageMedia::MessageMediaVideo { video } => {
+                result += video.serialized_len();
+            }
+            MessageMedia::MessageMediaGeo { geo } => {
+                result += geo.serialized_len();
+            }
+            MessageMedia::MessageMediaContact {
+                phone_number,
+                first_name,
+                last_name,
+                user_id,
+            } => {
+                result += phone_number.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += user_id.serialized_len();
+            }
+            MessageMedia::MessageMediaDocument { document } => {
+                result += document.serialized_len();
+            }
+            MessageMedia::MessageMediaAudio { audio } => {
+                result += audio.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageMediaRef<'a> {
+    /// `messageMediaEmpty#3ded6320 = MessageMedia`
+    MessageMediaEmpty,
+    /// `messageMediaPhoto#c8c45a2a photo:Photo = MessageMedia`
+    MessageMediaPhoto {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        photo: super::tl::PhotoRef<'a>,
+    },
+    /// `messageMediaVideo#a2d24290 video:Video = MessageMedia`
+    MessageMediaVideo {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        video: super::tl::VideoRef<'a>,
+    },
+    /// `messageMediaGeo#56e0d474 geo:GeoPoint = MessageMedia`
+    MessageMediaGeo { geo: super::tl::GeoPoint },
+    /// `messageMediaContact#5e7d2f39 phone_number:string first_name:string last_name:string user_id:int = MessageMedia`
+    MessageMediaContact {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone_number: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        user_id: i32,
+    },
+    /// `messageMediaDocument#2fda2204 document:Document = MessageMedia`
+    MessageMediaDocument {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        document: super::tl::DocumentRef<'a>,
+    },
+    /// `messageMediaAudio#c6b68300 audio:Audio = MessageMedia`
+    MessageMediaAudio {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        audio: super::tl::AudioRef<'a>,
+    },
+}
+impl<'a> TLDeserialize<'a> for MessageMediaRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x3ded_6320i32 => MessageMediaRef::MessageMediaEmpty {},
+            -0x373b_a5d6i32 => MessageMediaRef::MessageMediaPhoto {
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x5d2d_bd70i32 => MessageMediaRef::MessageMediaVideo {
+                video: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x56e0_d474i32 => MessageMediaRef::MessageMediaGeo {
+                geo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x5e7d_2f39i32 => MessageMediaRef::MessageMediaContact {
+                phone_number: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2fda_2204i32 => MessageMediaRef::MessageMediaDocument {
+                document: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x3949_7d00i32 => MessageMediaRef::MessageMediaAudio {
+                audio: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessageMedia",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct MessageMediaMessageMediaPhotoBuilder {
+    photo: super::tl::Photo,
+}
+impl MessageMediaMessageMediaPhotoBuilder {
+    pub fn new(photo: super::tl::Photo) -> Self {
+        MessageMediaMessageMediaPhotoBuilder { photo }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaPhoto { photo: self.photo }
+    }
+}
+pub struct MessageMediaMessageMediaVideoBuilder {
+    video: super::tl::Video,
+}
+impl MessageMediaMessageMediaVideoBuilder {
+    pub fn new(video: super::tl::Video) -> Self {
+        MessageMediaMessageMediaVideoBuilder { video }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaVideo { video: self.video }
+    }
+}
+pub struct MessageMediaMessageMediaGeoBuilder {
+    geo: super::tl::GeoPoint,
+}
+impl MessageMediaMessageMediaGeoBuilder {
+    pub fn new(geo: super::tl::GeoPoint) -> Self {
+        MessageMediaMessageMediaGeoBuilder { geo }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaGeo { geo: self.geo }
+    }
+}
+pub struct MessageMediaMessageMediaContactBuilder {
+    phone_number: String,
+    first_name: String,
+    last_name: String,
+    user_id: i32,
+}
+impl MessageMediaMessageMediaContactBuilder {
+    pub fn new(phone_number: String, first_name: String, last_name: String, user_id: i32) -> Self {
+        MessageMediaMessageMediaContactBuilder {
+            phone_number,
+            first_name,
+            last_name,
+            user_id,
+        }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaContact {
+            phone_number: self.phone_number,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            user_id: self.user_id,
+        }
+    }
+}
+pub struct MessageMediaMessageMediaDocumentBuilder {
+    document: super::tl::Document,
+}
+impl MessageMediaMessageMediaDocumentBuilder {
+    pub fn new(document: super::tl::Document) -> Self {
+        MessageMediaMessageMediaDocumentBuilder { document }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaDocument {
+            document: self.document,
+        }
+    }
+}
+pub struct MessageMediaMessageMediaAudioBuilder {
+    audio: super::tl::Audio,
+}
+impl MessageMediaMessageMediaAudioBuilder {
+    pub fn new(audio: super::tl::Audio) -> Self {
+        MessageMediaMessageMediaAudioBuilder { audio }
+    }
+    pub fn build(self) -> MessageMedia {
+        MessageMedia::MessageMediaAudio { audio: self.audio }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessagesFilter {
+    /// `inputMessagesFilterEmpty#57e2f66c = MessagesFilter`
+    InputMessagesFilterEmpty,
+    /// `inputMessagesFilterPhotos#9609a51c = MessagesFilter`
+    InputMessagesFilterPhotos,
+    /// `inputMessagesFilterVideo#9fc00e65 = MessagesFilter`
+    InputMessagesFilterVideo,
+    /// `inputMessagesFilterPhotoVideo#56e9f0e4 = MessagesFilter`
+    InputMessagesFilterPhotoVideo,
+    /// `inputMessagesFilterDocument#9eddf188 = MessagesFilter`
+    InputMessagesFilterDocument,
+    /// `inputMessagesFilterAudio#cfc87522 = MessagesFilter`
+    InputMessagesFilterAudio,
+    /// `inputMessagesFilterAudioDocuments#5afbf764 = MessagesFilter`
+    InputMessagesFilterAudioDocuments,
+    /// `inputMessagesFilterUrl#7ef0dd87 = MessagesFilter`
+    InputMessagesFilterUrl,
+    /// `inputMessagesFilterGif#ffc86587 = MessagesFilter`
+    InputMessagesFilterGif,
+}
+impl TLType for MessagesFilter {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x57e2_f66ci32 => MessagesFilter::InputMessagesFilterEmpty {},
+            -0x69f6_5ae4i32 => MessagesFilter::InputMessagesFilterPhotos {},
+            -0x603f_f19bi32 => MessagesFilter::InputMessagesFilterVideo {},
+            0x56e9_f0e4i32 => MessagesFilter::InputMessagesFilterPhotoVideo {},
+            -0x6122_0e78i32 => MessagesFilter::InputMessagesFilterDocument {},
+            -0x3037_8adei32 => MessagesFilter::InputMessagesFilterAudio {},
+            0x5afb_f764i32 => MessagesFilter::InputMessagesFilterAudioDocuments {},
+            0x7ef0_dd87i32 => MessagesFilter::InputMessagesFilterUrl {},
+            -0x0037_9a79i32 => MessagesFilter::InputMessagesFilterGif {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessagesFilter",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            MessagesFilter::InputMessagesFilterEmpty => {
+                (0x57e2_f66ci32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterPhotos => {
+                (-0x69f6_5ae4i32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterVideo => {
+                (-0x603f_f19bi32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterPhotoVideo => {
+                (0x56e9_f0e4i32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterDocument => {
+                (-0x6122_0e78i32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterAudio => {
+                (-0x3037_8adei32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterAudioDocuments => {
+                (0x5afb_f764i32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterUrl => {
+                (0x7ef0_dd87i32).tl_write(output)?;
+            }
+            MessagesFilter::InputMessagesFilterGif => {
+                (-0x0037_9a79i32).tl_write(output)?;
+            }
+        }
+        Ok(4usize)
+    }
+    fn serialized_len(&self) -> usize {
+        4usize
+    }
+}
+impl<'a> TLDeserialize<'a> for MessagesFilter {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x57e2_f66ci32 => MessagesFilter::InputMessagesFilterEmpty {},
+            -0x69f6_5ae4i32 => MessagesFilter::InputMessagesFilterPhotos {},
+            -0x603f_f19bi32 => MessagesFilter::InputMessagesFilterVideo {},
+            0x56e9_f0e4i32 => MessagesFilter::InputMessagesFilterPhotoVideo {},
+            -0x6122_0e78i32 => MessagesFilter::InputMessagesFilterDocument {},
+            -0x3037_8adei32 => MessagesFilter::InputMessagesFilterAudio {},
+            0x5afb_f764i32 => MessagesFilter::InputMessagesFilterAudioDocuments {},
+            0x7ef0_dd87i32 => MessagesFilter::InputMessagesFilterUrl {},
+            -0x0037_9a79i32 => MessagesFilter::InputMessagesFilterGif {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "MessagesFilter",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+/// `nearestDc#8e1a1775 country:string this_dc:int nearest_dc:int = NearestDc`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NearestDc {
+    pub country: String,
+    pub this_dc: i32,
+    pub nearest_dc: i32,
+}
+impl TLType for NearestDc {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x71e5_e88bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "NearestDc",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x71e5_e88bi32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for NearestDc {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = NearestDc {
+            country: TLType::tl_read_with_context(input, context)?,
+            this_dc: TLType::tl_read_with_context(input, context)?,
+            nearest_dc: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.country.tl_write(output)?;
+        result += self.this_dc.tl_write(output)?;
+        result += self.nearest_dc.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.country.serialized_len();
+        result += self.this_dc.serialized_len();
+        result += self.nearest_dc.serialized_len();
+        result
+    }
+}
+/// `nearestDc#8e1a1775 country:string this_dc:int nearest_dc:int = NearestDc`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NearestDcRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub country: &'a str,
+    pub this_dc: i32,
+    pub nearest_dc: i32,
+}
+impl<'a> TLDeserialize<'a> for NearestDcRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x71e5_e88bi32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "NearestDc",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for NearestDcRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = NearestDcRef {
+            country: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            this_dc: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            nearest_dc: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct NearestDcBuilder {
+    country: String,
+    this_dc: i32,
+    nearest_dc: i32,
+}
+impl NearestDcBuilder {
+    pub fn new(country: String, this_dc: i32, nearest_dc: i32) -> Self {
+        NearestDcBuilder {
+            country,
+            this_dc,
+            nearest_dc,
+        }
+    }
+    pub fn build(self) -> NearestDc {
+        NearestDc {
+            country: self.country,
+            this_dc: self.this_dc,
+            nearest_dc: self.nearest_dc,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NotifyPeer {
+    /// `notifyPeer#9fd40bd8 peer:Peer = NotifyPeer`
+    NotifyPeer { peer: super::tl::Peer },
+    /// `notifyUsers#b4c83b4c = NotifyPeer`
+    NotifyUsers,
+    /// `notifyChats#c007cec3 = NotifyPeer`
+    NotifyChats,
+    /// `notifyAll#74d07c60 = NotifyPeer`
+    NotifyAll,
+}
+impl TLType for NotifyPeer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x602b_f428i32 => NotifyPeer::NotifyPeer {
+                peer: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x4b37_c4b4i32 => NotifyPeer::NotifyUsers {},
+            -0x3ff8_313di32 => NotifyPeer::NotifyChats {},
+            0x74d0_7c60i32 => NotifyPeer::NotifyAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "NotifyPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            NotifyPeer::NotifyPeer { peer } => {
+                (-0x602b_f428i32).tl_write(output)?;
+                result += peer.tl_write(output)?;
+            }
+            NotifyPeer::NotifyUsers => {
+                (-0x4b37_c4b4i32).tl_write(output)?;
+            }
+            NotifyPeer::NotifyChats => {
+                (-0x3ff8_313di32).tl_write(output)?;
+            }
+            NotifyPeer::NotifyAll => {
+                (0x74d0_7c60i32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            NotifyPeer::NotifyPeer { peer } => {
+                result += peer.serialized_len();
+            }
+            NotifyPeer::NotifyUsers => {}
+            NotifyPeer::NotifyChats => {}
+            NotifyPeer::NotifyAll => {}
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for NotifyPeer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x602b_f428i32 => NotifyPeer::NotifyPeer {
+                peer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x4b37_c4b4i32 => NotifyPeer::NotifyUsers {},
+            -0x3ff8_313di32 => NotifyPeer::NotifyChats {},
+            0x74d0_7c60i32 => NotifyPeer::NotifyAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "NotifyPeer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct NotifyPeerNotifyPeerBuilder {
+    peer: super::tl::Peer,
+}
+impl NotifyPeerNotifyPeerBuilder {
+    pub fn new(peer: super::tl::Peer) -> Self {
+        NotifyPeerNotifyPeerBuilder { peer }
+    }
+    pub fn build(self) -> NotifyPeer {
+        NotifyPeer::NotifyPeer { peer: self.peer }
+    }
+}
+/// `null#56730bcc = Null`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Null;
+impl TLType for Null {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x5673_0bcci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Null",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x5673_0bcci32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for Null {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Null {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl<'a> TLDeserialize<'a> for Null {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x5673_0bcci32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Null",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for Null {
+    fn tl_deserialize_bare_with_context(
+        _input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = Null {};
+        context.leave();
+        Ok(result)
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Peer {
+    /// `peerUser#9db1bc6d user_id:int = Peer`
+    PeerUser { user_id: i32 },
+    /// `peerChat#bad0e5bb chat_id:int = Peer`
+    PeerChat { chat_id: i32 },
+}
+impl TLType for Peer {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x624e_4393i32 => Peer::PeerUser {
+                user_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x452f_1a45i32 => Peer::PeerChat {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Peer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Peer::PeerUser { user_id } => {
+                (-0x624e_4393i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+            }
+            Peer::PeerChat { chat_id } => {
+                (-0x452f_1a45i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Peer::PeerUser { user_id } => {
+                result += user_id.serialized_len();
+            }
+            Peer::PeerChat { chat_id } => {
+                result += chat_id.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for Peer {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x624e_4393i32 => Peer::PeerUser {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x452f_1a45i32 => Peer::PeerChat {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Peer",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PeerPeerUserBuilder {
+    user_id: i32,
+}
+impl PeerPeerUserBuilder {
+    pub fn new(user_id: i32) -> Self {
+        PeerPeerUserBuilder { user_id }
+    }
+    pub fn build(self) -> Peer {
+        Peer::PeerUser {
+            user_id: self.user_id,
+        }
+    }
+}
+pub struct PeerPeerChatBuilder {
+    chat_id: i32,
+}
+impl PeerPeerChatBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        PeerPeerChatBuilder { chat_id }
+    }
+    pub fn build(self) -> Peer {
+        Peer::PeerChat {
+            chat_id: self.chat_id,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeerNotifyEvents {
+    /// `peerNotifyEventsEmpty#add53cb3 = PeerNotifyEvents`
+    PeerNotifyEventsEmpty,
+    /// `peerNotifyEventsAll#6d1ded88 = PeerNotifyEvents`
+    PeerNotifyEventsAll,
+}
+impl TLType for PeerNotifyEvents {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x522a_c34di32 => PeerNotifyEvents::PeerNotifyEventsEmpty {},
+            0x6d1d_ed88i32 => PeerNotifyEvents::PeerNotifyEventsAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PeerNotifyEvents",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            PeerNotifyEvents::PeerNotifyEventsEmpty => {
+                (-0x522a_c34di32).tl_write(output)?;
+            }
+            PeerNotifyEvents::PeerNotifyEventsAll => {
+                (0x6d1d_ed88i32).tl_write(output)?;
+            }
+        }
+        Ok(4usize)
+    }
+    fn serialized_len(&self) -> usize {
+        4usize
+    }
+}
+impl<'a> TLDeserialize<'a> for PeerNotifyEvents {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x522a_c34di32 => PeerNotifyEvents::PeerNotifyEventsEmpty {},
+            0x6d1d_ed88i32 => PeerNotifyEvents::PeerNotifyEventsAll {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PeerNotifyEvents",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+/// `peerNotifySettings#8d5e11ee mute_until:int sound:string show_previews:Bool events_mask:int = PeerNotifySettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerNotifySettings {
+    pub mute_until: i32,
+    pub sound: String,
+    pub show_previews: bool,
+    pub events_mask: i32,
+}
+impl TLType for PeerNotifySettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x72a1_ee12i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PeerNotifySettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x72a1_ee12i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PeerNotifySettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PeerNotifySettings {
+            mute_until: TLType::tl_read_with_context(input, context)?,
+            sound: TLType::tl_read_with_context(input, context)?,
+            show_previews: TLType::tl_read_with_context(input, context)?,
+            events_mask: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.mute_until.tl_write(output)?;
+        result += self.sound.tl_write(output)?;
+        result += self.show_previews.tl_write(output)?;
+        result += self.events_mask.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.mute_until.serialized_len();
+        result += self.sound.serialized_len();
+        result += self.show_previews.serialized_len();
+        result += self.events_mask.serialized_len();
+        result
+    }
+}
+/// `peerNotifySettings#8d5e11ee mute_until:int sound:string show_previews:Bool events_mask:int = PeerNotifySettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerNotifySettingsRef<'a> {
+    pub mute_until: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub sound: &'a str,
+    pub show_previews: bool,
+    pub events_mask: i32,
+}
+impl<'a> TLDeserialize<'a> for PeerNotifySettingsRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x72a1_ee12i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PeerNotifySettings",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for PeerNotifySettingsRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PeerNotifySettingsRef {
+            mute_until: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            sound: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            show_previews: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            events_mask: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PeerNotifySettingsBuilder {
+    mute_until: i32,
+    sound: String,
+    show_previews: bool,
+    events_mask: i32,
+}
+impl PeerNotifySettingsBuilder {
+    pub fn new(mute_until: i32, sound: String, show_previews: bool, events_mask: i32) -> Self {
+        PeerNotifySettingsBuilder {
+            mute_until,
+            sound,
+            show_previews,
+            events_mask,
+        }
+    }
+    pub fn build(self) -> PeerNotifySettings {
+        PeerNotifySettings {
+            mute_until: self.mute_until,
+            sound: self.sound,
+            show_previews: self.show_previews,
+            events_mask: self.events_mask,
+        }
+    }
+}
+/// `peerSettings#818426cd flags:# report_spam:flags.0?true = PeerSettings`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerSettings {
+    pub report_spam: bool,
+}
+impl TLType for PeerSettings {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x7e7b_d933i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PeerSettings",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x7e7b_d933i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PeerSettings {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = {
+            let flags: u32 = TLType::tl_read_with_context(input, context)?;
+            let report_spam = flags & (1 << 0) != 0;
+            PeerSettings { report_spam }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += (if self.report_spam { 1u32 << 0 } else { 0 }).tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += 4;
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for PeerSettings {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x7e7b_d933i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PeerSettings",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for PeerSettings {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = {
+            let flags: u32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+            let report_spam = flags & (1 << 0) != 0;
+            PeerSettings { report_spam }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PeerSettingsBuilder {
+    report_spam: bool,
+}
+impl Default for PeerSettingsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PeerSettingsBuilder {
+    pub fn new() -> Self {
+        PeerSettingsBuilder { report_spam: false }
+    }
+    pub fn report_spam(mut self, value: bool) -> Self {
+        self.report_spam = value;
+        self
+    }
+    pub fn build(self) -> PeerSettings {
+        PeerSettings {
+            report_spam: self.report_spam,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Photo {
+    /// `photoEmpty#2331b22d id:long = Photo`
+    PhotoEmpty { id: i64 },
+    /// `photo#22b56751 id:long access_hash:long user_id:int date:int caption:string geo:GeoPoint sizes:Vector<PhotoSize> = Photo`
+    Photo {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        caption: String,
+        geo: super::tl::GeoPoint,
+        sizes: Vec<super::tl::PhotoSize>,
+    },
+}
+impl TLType for Photo {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x2331_b22di32 => Photo::PhotoEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x22b5_6751i32 => Photo::Photo {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                caption: TLType::tl_read_with_context(input, context)?,
+                geo: TLType::tl_read_with_context(input, context)?,
+                sizes: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Photo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Photo::PhotoEmpty { id } => {
+                (0x2331_b22di32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Photo::Photo {
+                id,
+                access_hash,
+                user_id,
+                date,
+                caption,
+                geo,
+                sizes,
+            } => {
+                (0x22b5_6751i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += caption.tl_write(output)?;
+                result += geo.tl_write(output)?;
+                result += sizes.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Photo::PhotoEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Photo::Photo {
+                id,
+                access_hash,
+                user_id,
+                date,
+                caption,
+                geo,
+                sizes,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += user_id.serialized_len();
+                result += date.serialized_len();
+                result += caption.serialized_len();
+                result += geo.serialized_len();
+                result += sizes.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhotoRef<'a> {
+    /// `photoEmpty#2331b22d id:long = Photo`
+    PhotoEmpty { id: i64 },
+    /// `photo#22b56751 id:long access_hash:long user_id:int date:int caption:string geo:GeoPoint sizes:Vector<PhotoSize> = Photo`
+    Photo {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        caption: &'a str,
+        geo: super::tl::GeoPoint,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        sizes: Vec<super::tl::PhotoSizeRef<'a>>,
+    },
+}
+impl<'a> TLDeserialize<'a> for PhotoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x2331_b22di32 => PhotoRef::PhotoEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x22b5_6751i32 => PhotoRef::Photo {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                caption: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                geo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                sizes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Photo",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PhotoPhotoEmptyBuilder {
+    id: i64,
+}
+impl PhotoPhotoEmptyBuilder {
+    pub fn new(id: i64) -> Self {
+        PhotoPhotoEmptyBuilder { id }
+    }
+    pub fn build(self) -> Photo {
+        Photo::PhotoEmpty { id: self.id }
+    }
+}
+pub struct PhotoPhotoBuilder {
+    id: i64,
+    access_hash: i64,
+    user_id: i32,
+    date: i32,
+    caption: String,
+    geo: super::tl::GeoPoint,
+    sizes: Vec<super::tl::PhotoSize>,
+}
+impl PhotoPhotoBuilder {
+    pub fn new(
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        caption: String,
+        geo: super::tl::GeoPoint,
+        sizes: Vec<super::tl::PhotoSize>,
+    ) -> Self {
+        PhotoPhotoBuilder {
+            id,
+            access_hash,
+            user_id,
+            date,
+            caption,
+            geo,
+            sizes,
+        }
+    }
+    pub fn build(self) -> Photo {
+        Photo::Photo {
+            id: self.id,
+            access_hash: self.access_hash,
+            user_id: self.user_id,
+            date: self.date,
+            caption: self.caption,
+            geo: self.geo,
+            sizes: self.sizes,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhotoSize {
+    /// `photoSizeEmpty#0e17e23c type:string = PhotoSize`
+    PhotoSizeEmpty { ty: String },
+    /// `photoSize#77bfb61b type:string location:FileLocation w:int h:int size:int = PhotoSize`
+    PhotoSize {
+        ty: String,
+        location: super::tl::FileLocation,
+        w: i32,
+        h: i32,
+        size: i32,
+    },
+    /// `photoCachedSize#e9a734fa type:string location:FileLocation w:int h:int bytes:bytes = PhotoSize`
+    PhotoCachedSize {
+        ty: String,
+        location: super::tl::FileLocation,
+        w: i32,
+        h: i32,
+        bytes: TLBytes,
+    },
+}
+impl TLType for PhotoSize {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x0e17_e23ci32 => PhotoSize::PhotoSizeEmpty {
+                ty: TLType::tl_read_with_context(input, context)?,
+            },
+            0x77bf_b61bi32 => PhotoSize::PhotoSize {
+                ty: TLType::tl_read_with_context(input, context)?,
+                location: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+                size: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x1658_cb06i32 => PhotoSize::PhotoCachedSize {
+                ty: TLType::tl_read_with_context(input, context)?,
+                location: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+                bytes: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PhotoSize",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            PhotoSize::PhotoSizeEmpty { ty } => {
+                (0x0e17_e23ci32).tl_write(output)?;
+                result += ty.tl_write(output)?;
+            }
+            PhotoSize::PhotoSize {
+                ty,
+                location,
+                w,
+                h,
+                size,
+            } => {
+                (0x77bf_b61bi32).tl_write(output)?;
+                result += ty.tl_write(output)?;
+                result += location.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+                result += size.tl_write(output)?;
+            }
+            PhotoSize::PhotoCachedSize {
+                ty,
+                location,
+                w,
+                h,
+                bytes,
+            } => {
+                (-0x1658_cb06i32).tl_write(output)?;
+                result += ty.tl_write(output)?;
+                result += location.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+                result += bytes.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            PhotoSize::PhotoSizeEmpty { ty } => {
+                result += ty.serialized_len();
+            }
+            PhotoSize::PhotoSize {
+                ty,
+                location,
+                w,
+                h,
+                size,
+            } => {
+                result += ty.serialized_len();
+                result += location.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+                result += size.serialized_len();
+            }
+            PhotoSize::PhotoCachedSize {
+                ty,
+                location,
+                w,
+                h,
+                bytes,
+            } => {
+                result += ty.serialized_len();
+                result += location.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+                result += bytes.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhotoSizeRef<'a> {
+    /// `photoSizeEmpty#0e17e23c type:string = PhotoSize`
+    PhotoSizeEmpty {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        ty: &'a str,
+    },
+    /// `photoSize#77bfb61b type:string location:FileLocation w:int h:int size:int = PhotoSize`
+    PhotoSize {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        ty: &'a str,
+        location: super::tl::FileLocation,
+        w: i32,
+        h: i32,
+        size: i32,
+    },
+    /// `photoCachedSize#e9a734fa type:string location:FileLocation w:int h:int bytes:bytes = PhotoSize`
+    PhotoCachedSize {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        ty: &'a str,
+        location: super::tl::FileLocation,
+        w: i32,
+        h: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        bytes: &'a [u8],
+    },
+}
+impl<'a> TLDeserialize<'a> for PhotoSizeRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x0e17_e23ci32 => PhotoSizeRef::PhotoSizeEmpty {
+                ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x77bf_b61bi32 => PhotoSizeRef::PhotoSize {
+                ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                location: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x1658_cb06i32 => PhotoSizeRef::PhotoCachedSize {
+                ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                location: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PhotoSize",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PhotoSizePhotoSizeEmptyBuilder {
+    ty: String,
+}
+impl PhotoSizePhotoSizeEmptyBuilder {
+    pub fn new(ty: String) -> Self {
+        PhotoSizePhotoSizeEmptyBuilder { ty }
+    }
+    pub fn build(self) -> PhotoSize {
+        PhotoSize::PhotoSizeEmpty { ty: self.ty }
+    }
+}
+pub struct PhotoSizePhotoSizeBuilder {
+    ty: String,
+    location: super::tl::FileLocation,
+    w: i32,
+    h: i32,
+    size: i32,
+}
+impl PhotoSizePhotoSizeBuilder {
+    pub fn new(ty: String, location: super::tl::FileLocation, w: i32, h: i32, size: i32) -> Self {
+        PhotoSizePhotoSizeBuilder {
+            ty,
+            location,
+            w,
+            h,
+            size,
+        }
+    }
+    pub fn build(self) -> PhotoSize {
+        PhotoSize::PhotoSize {
+            ty: self.ty,
+            location: self.location,
+            w: self.w,
+            h: self.h,
+            size: self.size,
+        }
+    }
+}
+pub struct PhotoSizePhotoCachedSizeBuilder {
+    ty: String,
+    location: super::tl::FileLocation,
+    w: i32,
+    h: i32,
+    bytes: TLBytes,
+}
+impl PhotoSizePhotoCachedSizeBuilder {
+    pub fn new(
+        ty: String,
+        location: super::tl::FileLocation,
+        w: i32,
+        h: i32,
+        bytes: TLBytes,
+    ) -> Self {
+        PhotoSizePhotoCachedSizeBuilder {
+            ty,
+            location,
+            w,
+            h,
+            bytes,
+        }
+    }
+    pub fn build(self) -> PhotoSize {
+        PhotoSize::PhotoCachedSize {
+            ty: self.ty,
+            location: self.location,
+            w: self.w,
+            h: self.h,
+            bytes: self.bytes,
+        }
+    }
+}
+/// `privacyKeyStatusTimestamp#bc2eab30 = PrivacyKey`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrivacyKey;
+impl TLType for PrivacyKey {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x43d1_54d0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PrivacyKey",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x43d1_54d0i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for PrivacyKey {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PrivacyKey {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl<'a> TLDeserialize<'a> for PrivacyKey {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x43d1_54d0i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "PrivacyKey",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for PrivacyKey {
+    fn tl_deserialize_bare_with_context(
+        _input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = PrivacyKey {};
+        context.leave();
+        Ok(result)
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivacyRule {
+    /// `privacyValueAllowContacts#fffe1bac = PrivacyRule`
+    PrivacyValueAllowContacts,
+    /// `privacyValueAllowAll#65427b82 = PrivacyRule`
+    PrivacyValueAllowAll,
+    /// `privacyValueAllowUsers#4d5bbe0c users:Vector<int> = PrivacyRule`
+    PrivacyValueAllowUsers { users: Vec<i32> },
+    /// `privacyValueDisallowContacts#f888fa1a = PrivacyRule`
+    PrivacyValueDisallowContacts,
+    /// `privacyValueDisallowAll#8b73e763 = PrivacyRule`
+    PrivacyValueDisallowAll,
+    /// `privacyValueDisallowUsers#0c7f49b7 users:Vector<int> = PrivacyRule`
+    PrivacyValueDisallowUsers { users: Vec<i32> },
+}
+impl TLType for PrivacyRule {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x0001_e454i32 => PrivacyRule::PrivacyValueAllowContacts {},
+            0x6542_7b82i32 => PrivacyRule::PrivacyValueAllowAll {},
+            0x4d5b_be0ci32 => PrivacyRule::PrivacyValueAllowUsers {
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x0777_05e6i32 => PrivacyRule::PrivacyValueDisallowContacts {},
+            -0x748c_189di32 => PrivacyRule::PrivacyValueDisallowAll {},
+            0x0c7f_49b7i32 => PrivacyRule::PrivacyValueDisallowUsers {
+                users: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PrivacyRule",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            PrivacyRule::PrivacyValueAllowContacts => {
+                (-0x0001_e454i32).tl_write(output)?;
+            }
+            PrivacyRule::PrivacyValueAllowAll => {
+                (0x6542_7b82i32).tl_write(output)?;
+            }
+            PrivacyRule::PrivacyValueAllowUsers { users } => {
+                (0x4d5b_be0ci32).tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+            PrivacyRule::PrivacyValueDisallowContacts => {
+                (-0x0777_05e6i32).tl_write(output)?;
+            }
+            PrivacyRule::PrivacyValueDisallowAll => {
+                (-0x748c_189di32).tl_write(output)?;
+            }
+            PrivacyRule::PrivacyValueDisallowUsers { users } => {
+                (0x0c7f_49b7i32).tl_write(output)?;
+                result += users.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            PrivacyRule::PrivacyValueAllowContacts => {}
+            PrivacyRule::PrivacyValueAllowAll => {}
+            PrivacyRule::PrivacyValueAllowUsers { users } => {
+                result += users.serialized_len();
+            }
+            PrivacyRule::PrivacyValueDisallowContacts => {}
+            PrivacyRule::PrivacyValueDisallowAll => {}
+            PrivacyRule::PrivacyValueDisallowUsers { users } => {
+                result += users.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for PrivacyRule {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x0001_e454i32 => PrivacyRule::PrivacyValueAllowContacts {},
+            0x6542_7b82i32 => PrivacyRule::PrivacyValueAllowAll {},
+            0x4d5b_be0ci32 => PrivacyRule::PrivacyValueAllowUsers {
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x0777_05e6i32 => PrivacyRule::PrivacyValueDisallowContacts {},
+            -0x748c_189di32 => PrivacyRule::PrivacyValueDisallowAll {},
+            0x0c7f_49b7i32 => PrivacyRule::PrivacyValueDisallowUsers {
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "PrivacyRule",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct PrivacyRulePrivacyValueAllowUsersBuilder {
+    users: Vec<i32>,
+}
+impl PrivacyRulePrivacyValueAllowUsersBuilder {
+    pub fn new(users: Vec<i32>) -> Self {
+        PrivacyRulePrivacyValueAllowUsersBuilder { users }
+    }
+    pub fn build(self) -> PrivacyRule {
+        PrivacyRule::PrivacyValueAllowUsers { users: self.users }
+    }
+}
+pub struct PrivacyRulePrivacyValueDisallowUsersBuilder {
+    users: Vec<i32>,
+}
+impl PrivacyRulePrivacyValueDisallowUsersBuilder {
+    pub fn new(users: Vec<i32>) -> Self {
+        PrivacyRulePrivacyValueDisallowUsersBuilder { users }
+    }
+    pub fn build(self) -> PrivacyRule {
+        PrivacyRule::PrivacyValueDisallowUsers { users: self.users }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReportReason {
+    /// `inputReportReasonSpam#58dbcab8 = ReportReason`
+    InputReportReasonSpam,
+    /// `inputReportReasonViolence#1e22c78d = ReportReason`
+    InputReportReasonViolence,
+    /// `inputReportReasonPornography#2e59d922 = ReportReason`
+    InputReportReasonPornography,
+    /// `inputReportReasonOther#e1746d0a text:string = ReportReason`
+    InputReportReasonOther { text: String },
+}
+impl TLType for ReportReason {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x58db_cab8i32 => ReportReason::InputReportReasonSpam {},
+            0x1e22_c78di32 => ReportReason::InputReportReasonViolence {},
+            0x2e59_d922i32 => ReportReason::InputReportReasonPornography {},
+            -0x1e8b_92f6i32 => ReportReason::InputReportReasonOther {
+                text: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ReportReason",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            ReportReason::InputReportReasonSpam => {
+                (0x58db_cab8i32).tl_write(output)?;
+            }
+            ReportReason::InputReportReasonViolence => {
+                (0x1e22_c78di32).tl_write(output)?;
+            }
+            ReportReason::InputReportReasonPornography => {
+                (0x2e59_d922i32).tl_write(output)?;
+            }
+            ReportReason::InputReportReasonOther { text } => {
+                (-0x1e8b_92f6i32).tl_write(output)?;
+                result += text.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            ReportReason::InputReportReasonSpam => {}
+            ReportReason::InputReportReasonViolence => {}
+            ReportReason::InputReportReasonPornography => {}
+            ReportReason::InputReportReasonOther { text } => {
+                result += text.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReportReasonRef<'a> {
+    /// `inputReportReasonSpam#58dbcab8 = ReportReason`
+    InputReportReasonSpam,
+    /// `inputReportReasonViolence#1e22c78d = ReportReason`
+    InputReportReasonViolence,
+    /// `inputReportReasonPornography#2e59d922 = ReportReason`
+    InputReportReasonPornography,
+    /// `inputReportReasonOther#e1746d0a text:string = ReportReason`
+    InputReportReasonOther {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        text: &'a str,
+    },
+}
+impl<'a> TLDeserialize<'a> for ReportReasonRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x58db_cab8i32 => ReportReasonRef::InputReportReasonSpam {},
+            0x1e22_c78di32 => ReportReasonRef::InputReportReasonViolence {},
+            0x2e59_d922i32 => ReportReasonRef::InputReportReasonPornography {},
+            -0x1e8b_92f6i32 => ReportReasonRef::InputReportReasonOther {
+                text: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "ReportReason",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct ReportReasonInputReportReasonOtherBuilder {
+    text: String,
+}
+impl ReportReasonInputReportReasonOtherBuilder {
+    pub fn new(text: String) -> Self {
+        ReportReasonInputReportReasonOtherBuilder { text }
+    }
+    pub fn build(self) -> ReportReason {
+        ReportReason::InputReportReasonOther { text: self.text }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SendMessageAction {
+    /// `sendMessageTypingAction#16bf744e = SendMessageAction`
+    SendMessageTypingAction,
+    /// `sendMessageCancelAction#fd5ec8f5 = SendMessageAction`
+    SendMessageCancelAction,
+    /// `sendMessageRecordVideoAction#a187d66f = SendMessageAction`
+    SendMessageRecordVideoAction,
+    /// `sendMessageUploadVideoAction#92042ff7 = SendMessageAction`
+    SendMessageUploadVideoAction,
+    /// `sendMessageRecordAudioAction#d52f73f7 = SendMessageAction`
+    SendMessageRecordAudioAction,
+    /// `sendMessageUploadAudioAction#e6ac8a6f = SendMessageAction`
+    SendMessageUploadAudioAction,
+    /// `sendMessageUploadPhotoAction#990a3c1a = SendMessageAction`
+    SendMessageUploadPhotoAction,
+    /// `sendMessageUploadDocumentAction#8faee98e = SendMessageAction`
+    SendMessageUploadDocumentAction,
+    /// `sendMessageGeoLocationAction#176f8ba1 = SendMessageAction`
+    SendMessageGeoLocationAction,
+    /// `sendMessageChooseContactAction#628cbc6f = SendMessageAction`
+    SendMessageChooseContactAction,
+}
+impl TLType for SendMessageAction {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x16bf_744ei32 => SendMessageAction::SendMessageTypingAction {},
+            -0x02a1_370bi32 => SendMessageAction::SendMessageCancelAction {},
+            -0x5e78_2991i32 => SendMessageAction::SendMessageRecordVideoAction {},
+            -0x6dfb_d009i32 => SendMessageAction::SendMessageUploadVideoAction {},
+            -0x2ad0_8c09i32 => SendMessageAction::SendMessageRecordAudioAction {},
+            -0x1953_7591i32 => SendMessageAction::SendMessageUploadAudioAction {},
+            -0x66f5_c3e6i32 => SendMessageAction::SendMessageUploadPhotoAction {},
+            -0x7051_1672i32 => SendMessageAction::SendMessageUploadDocumentAction {},
+            0x176f_8ba1i32 => SendMessageAction::SendMessageGeoLocationAction {},
+            0x628c_bc6fi32 => SendMessageAction::SendMessageChooseContactAction {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "SendMessageAction",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        match self {
+            SendMessageAction::SendMessageTypingAction => {
+                (0x16bf_744ei32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageCancelAction => {
+                (-0x02a1_370bi32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageRecordVideoAction => {
+                (-0x5e78_2991i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageUploadVideoAction => {
+                (-0x6dfb_d009i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageRecordAudioAction => {
+                (-0x2ad0_8c09i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageUploadAudioAction => {
+                (-0x1953_7591i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageUploadPhotoAction => {
+                (-0x66f5_c3e6i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageUploadDocumentAction => {
+                (-0x7051_1672i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageGeoLocationAction => {
+                (0x176f_8ba1i32).tl_write(output)?;
+            }
+            SendMessageAction::SendMessageChooseContactAction => {
+                (0x628c_bc6fi32).tl_write(output)?;
+            }
+        }
+        Ok(4usize)
+    }
+    fn serialized_len(&self) -> usize {
+        4usize
+    }
+}
+impl<'a> TLDeserialize<'a> for SendMessageAction {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x16bf_744ei32 => SendMessageAction::SendMessageTypingAction {},
+            -0x02a1_370bi32 => SendMessageAction::SendMessageCancelAction {},
+            -0x5e78_2991i32 => SendMessageAction::SendMessageRecordVideoAction {},
+            -0x6dfb_d009i32 => SendMessageAction::SendMessageUploadVideoAction {},
+            -0x2ad0_8c09i32 => SendMessageAction::SendMessageRecordAudioAction {},
+            -0x1953_7591i32 => SendMessageAction::SendMessageUploadAudioAction {},
+            -0x66f5_c3e6i32 => SendMessageAction::SendMessageUploadPhotoAction {},
+            -0x7051_1672i32 => SendMessageAction::SendMessageUploadDocumentAction {},
+            0x176f_8ba1i32 => SendMessageAction::SendMessageGeoLocationAction {},
+            0x628c_bc6fi32 => SendMessageAction::SendMessageChooseContactAction {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "SendMessageAction",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+/// `stickerPack#12b299d4 emoticon:string documents:Vector<long> = StickerPack`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StickerPack {
+    pub emoticon: String,
+    pub documents: Vec<i64>,
+}
+impl TLType for StickerPack {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x12b2_99d4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "StickerPack",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x12b2_99d4i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for StickerPack {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = StickerPack {
+            emoticon: TLType::tl_read_with_context(input, context)?,
+            documents: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.emoticon.tl_write(output)?;
+        result += self.documents.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.emoticon.serialized_len();
+        result += self.documents.serialized_len();
+        result
+    }
+}
+/// `stickerPack#12b299d4 emoticon:string documents:Vector<long> = StickerPack`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StickerPackRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub emoticon: &'a str,
+    pub documents: Vec<i64>,
+}
+impl<'a> TLDeserialize<'a> for StickerPackRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x12b2_99d4i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "StickerPack",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for StickerPackRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = StickerPackRef {
+            emoticon: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            documents: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct StickerPackBuilder {
+    emoticon: String,
+    documents: Vec<i64>,
+}
+impl StickerPackBuilder {
+    pub fn new(emoticon: String, documents: Vec<i64>) -> Self {
+        StickerPackBuilder {
+            emoticon,
+            documents,
+        }
+    }
+    pub fn build(self) -> StickerPack {
+        StickerPack {
+            emoticon: self.emoticon,
+            documents: self.documents,
+        }
+    }
+}
+/// `true#3fedd339 = True`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct True;
+impl TLType for True {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x3fed_d339i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "True",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x3fed_d339i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for True {
+    fn tl_read_bare_with_context(
+        _input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = True {};
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, _output: &mut dyn std::io::Write) -> MyResult<usize> {
+        Ok(0usize)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        0usize
+    }
+}
+impl<'a> TLDeserialize<'a> for True {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x3fed_d339i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "True",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for True {
+    fn tl_deserialize_bare_with_context(
+        _input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = True {};
+        context.leave();
+        Ok(result)
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Update {
+    /// `updateNewMessage#013abdb3 message:Message pts:int = Update`
+    UpdateNewMessage {
+        message: super::tl::Message,
+        pts: i32,
+    },
+    /// `updateMessageID#4e90bfd6 id:int random_id:long = Update`
+    UpdateMessageId { id: i32, random_id: i64 },
+    /// `updateReadMessages#c6649e31 messages:Vector<int> pts:int = Update`
+    UpdateReadMessages { messages: Vec<i32>, pts: i32 },
+    /// `updateDeleteMessages#a92bfe26 messages:Vector<int> pts:int = Update`
+    UpdateDeleteMessages { messages: Vec<i32>, pts: i32 },
+    /// `updateUserTyping#5c486927 user_id:int action:SendMessageAction = Update`
+    UpdateUserTyping {
+        user_id: i32,
+        action: super::tl::SendMessageAction,
+    },
+    /// `updateChatUserTyping#9a65ea1f chat_id:int user_id:int action:SendMessageAction = Update`
+    UpdateChatUserTyping {
+        chat_id: i32,
+        user_id: i32,
+        action: super::tl::SendMessageAction,
+    },
+    /// `updateChatParticipants#07761198 participants:ChatParticipants = Update`
+    UpdateChatParticipants {
+        participants: super::tl::ChatParticipants,
+    },
+    /// `updateUserStatus#1bfbd823 user_id:int status:UserStatus = Update`
+    UpdateUserStatus {
+        user_id: i32,
+        status: super::tl::UserStatus,
+    },
+    /// `updateUserName#a7332b73 user_id:int first_name:string last_name:string username:string = Update`
+    UpdateUserName {
+        user_id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+    },
+    /// `updateUserPhoto#95313b0c user_id:int date:int photo:UserProfilePhoto previous:Bool = Update`
+    UpdateUserPhoto {
+        user_id: i32,
+        date: i32,
+        photo: super::tl::UserProfilePhoto,
+        previous: bool,
+    },
+    /// `updateContactRegistered#2575bbb9 user_id:int date:int = Update`
+    UpdateContactRegistered { user_id: i32, date: i32 },
+    /// `updateContactLink#51a48a9a user_id:int my_link:contacts.MyLink foreign_link:contacts.ForeignLink = Update`
+    UpdateContactLink {
+        user_id: i32,
+        my_link: super::contacts::MyLink,
+        foreign_link: super::contacts::ForeignLink,
+    },
+    /// `updateNewAuthorization#8f06529a auth_key_id:long date:int device:string location:string = Update`
+    UpdateNewAuthorization {
+        auth_key_id: i64,
+        date: i32,
+        device: String,
+        location: String,
+    },
+    /// `updateNewEncryptedMessage#12bcbd9a message:EncryptedMessage qts:int = Update`
+    UpdateNewEncryptedMessage {
+        message: super::tl::EncryptedMessage,
+        qts: i32,
+    },
+    /// `updateEncryptedChatTyping#1710f156 chat_id:int = Update`
+    UpdateEncryptedChatTyping { chat_id: i32 },
+    /// `updateEncryption#b4a2e88d chat:EncryptedChat date:int = Update`
+    UpdateEncryption {
+        chat: super::tl::EncryptedChat,
+        date: i32,
+    },
+    /// `updateEncryptedMessagesRead#38fe25b7 chat_id:int max_date:int date:int = Update`
+    UpdateEncryptedMessagesRead {
+        chat_id: i32,
+        max_date: i32,
+        date: i32,
+    },
+    /// `updateChatParticipantAdd#3a0eeb22 chat_id:int user_id:int inviter_id:int version:int = Update`
+    UpdateChatParticipantAdd {
+        chat_id: i32,
+        user_id: i32,
+        inviter_id: i32,
+        version: i32,
+    },
+    /// `updateChatParticipantDelete#6e5f8c22 chat_id:int user_id:int version:int = Update`
+    UpdateChatParticipantDelete {
+        chat_id: i32,
+        user_id: i32,
+        version: i32,
+    },
+    /// `updateDcOptions#8e5e9873 dc_options:Vector<DcOption> = Update`
+    UpdateDcOptions {
+        dc_options: Vec<super::tl::DcOption>,
+    },
+    /// `updateUserBlocked#80ece81a user_id:int blocked:Bool = Update`
+    UpdateUserBlocked { user_id: i32, blocked: bool },
+    /// `updateNotifySettings#bec268ef peer:NotifyPeer notify_settings:PeerNotifySettings = Update`
+    UpdateNotifySettings {
+        peer: super::tl::NotifyPeer,
+        notify_settings: super::tl::PeerNotifySettings,
+    },
+    /// `updateServiceNotification#382dd3e4 type:string message:string media:MessageMedia popup:Bool = Update`
+    UpdateServiceNotification {
+        ty: String,
+        message: String,
+        media: super::tl::MessageMedia,
+        popup: bool,
+    },
+    /// `updatePrivacy#ee3b272a key:PrivacyKey rules:Vector<PrivacyRule> = Update`
+    UpdatePrivacy {
+        key: super::tl::PrivacyKey,
+        rules: Vec<super::tl::PrivacyRule>,
+    },
+    /// `updateUserPhone#12b9417b user_id:int phone:string = Update`
+    UpdateUserPhone { user_id: i32, phone: String },
+}
+impl TLType for Update {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x013a_bdb3i32 => Update::UpdateNewMessage {
+                message: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+            },
+            0x4e90_bfd6i32 => Update::UpdateMessageId {
+                id: TLType::tl_read_with_context(input, context)?,
+                random_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x399b_61cfi32 => Update::UpdateReadMessages {
+                messages: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x56d4_01dai32 => Update::UpdateDeleteMessages {
+                messages: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+            },
+            0x5c48_6927i32 => Update::UpdateUserTyping {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                action: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x659a_15e1i32 => Update::UpdateChatUserTyping {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                action: TLType::tl_read_with_context(input, context)?,
+            },
+            0x0776_1198i32 => Update::UpdateChatParticipants {
+                participants: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1bfb_d823i32 => Update::UpdateUserStatus {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x58cc_d48di32 => Update::UpdateUserName {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x6ace_c4f4i32 => Update::UpdateUserPhoto {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                previous: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2575_bbb9i32 => Update::UpdateContactRegistered {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            0x51a4_8a9ai32 => Update::UpdateContactLink {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                my_link: TLType::tl_read_with_context(input, context)?,
+                foreign_link: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x70f9_ad66i32 => Update::UpdateNewAuthorization {
+                auth_key_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                device: TLType::tl_read_with_context(input, context)?,
+                location: TLType::tl_read_with_context(input, context)?,
+            },
+            0x12bc_bd9ai32 => Update::UpdateNewEncryptedMessage {
+                message: TLType::tl_read_with_context(input, context)?,
+                qts: TLType::tl_read_with_context(input, context)?,
+            },
+            0x1710_f156i32 => Update::UpdateEncryptedChatTyping {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x4b5d_1773i32 => Update::UpdateEncryption {
+                chat: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            0x38fe_25b7i32 => Update::UpdateEncryptedMessagesRead {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                max_date: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            0x3a0e_eb22i32 => Update::UpdateChatParticipantAdd {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                inviter_id: TLType::tl_read_with_context(input, context)?,
+                version: TLType::tl_read_with_context(input, context)?,
+            },
+            0x6e5f_8c22i32 => Update::UpdateChatParticipantDelete {
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                version: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x71a1_678di32 => Update::UpdateDcOptions {
+                dc_options: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x7f13_17e6i32 => Update::UpdateUserBlocked {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                blocked: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x413d_9711i32 => Update::UpdateNotifySettings {
+                peer: TLType::tl_read_with_context(input, context)?,
+                notify_settings: TLType::tl_read_with_context(input, context)?,
+            },
+            0x382d_d3e4i32 => Update::UpdateServiceNotification {
+                ty: TLType::tl_read_with_context(input, context)?,
+                message: TLType::tl_read_with_context(input, context)?,
+                media: TLType::tl_read_with_context(input, context)?,
+                popup: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x11c4_d8d6i32 => Update::UpdatePrivacy {
+                key: TLType::tl_read_with_context(input, context)?,
+                rules: TLType::tl_read_with_context(input, context)?,
+            },
+            0x12b9_417bi32 => Update::UpdateUserPhone {
+                user_id: TLType::tl_read_with_context(input, context)?,
+                phone: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Update",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Update::UpdateNewMessage { message, pts } => {
+                (0x013a_bdb3i32).tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += pts.tl_write(output)?;
+            }
+            Update::UpdateMessageId { id, random_id } => {
+                (0x4e90_bfd6i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += random_id.tl_write(output)?;
+            }
+            Update::UpdateReadMessages { messages, pts } => {
+                (-0x399b_61cfi32).tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += pts.tl_write(output)?;
+            }
+            Update::UpdateDeleteMessages { messages, pts } => {
+                (-0x56d4_01dai32).tl_write(output)?;
+                result += messages.tl_write(output)?;
+                result += pts.tl_write(output)?;
+            }
+            Update::UpdateUserTyping { user_id, action } => {
+                (0x5c48_6927i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += action.tl_write(output)?;
+            }
+            Update::UpdateChatUserTyping {
+                chat_id,
+                user_id,
+                action,
+            } => {
+                (-0x659a_15e1i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += action.tl_write(output)?;
+            }
+            Update::UpdateChatParticipants { participants } => {
+                (0x0776_1198i32).tl_write(output)?;
+                result += participants.tl_write(output)?;
+            }
+            Update::UpdateUserStatus { user_id, status } => {
+                (0x1bfb_d823i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+            Update::UpdateUserName {
+                user_id,
+                first_name,
+                last_name,
+                username,
+            } => {
+                (-0x58cc_d48di32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+            }
+            Update::UpdateUserPhoto {
+                user_id,
+                date,
+                photo,
+                previous,
+            } => {
+                (-0x6ace_c4f4i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += previous.tl_write(output)?;
+            }
+            Update::UpdateContactRegistered { user_id, date } => {
+                (0x2575_bbb9i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+            Update::UpdateContactLink {
+                user_id,
+                my_link,
+                foreign_link,
+            } => {
+                (0x51a4_8a9ai32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += my_link.tl_write(output)?;
+                result += foreign_link.tl_write(output)?;
+            }
+            Update::UpdateNewAuthorization {
+                auth_key_id,
+                date,
+                device,
+                location,
+            } => {
+                (-0x70f9_ad66i32).tl_write(output)?;
+                result += auth_key_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += device.tl_write(output)?;
+                result += location.tl_write(output)?;
+            }
+            Update::UpdateNewEncryptedMessage { message, qts } => {
+                (0x12bc_bd9ai32).tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += qts.tl_write(output)?;
+            }
+            Update::UpdateEncryptedChatTyping { chat_id } => {
+                (0x1710_f156i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+            }
+            Update::UpdateEncryption { chat, date } => {
+                (-0x4b5d_1773i32).tl_write(output)?;
+                result += chat.tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+            Update::UpdateEncryptedMessagesRead {
+                chat_id,
+                max_date,
+                date,
+            } => {
+                (0x38fe_25b7i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += max_date.tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+            Update::UpdateChatParticipantAdd {
+                chat_id,
+                user_id,
+                inviter_id,
+                version,
+            } => {
+                (0x3a0e_eb22i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += inviter_id.tl_write(output)?;
+                result += version.tl_write(output)?;
+            }
+            Update::UpdateChatParticipantDelete {
+                chat_id,
+                user_id,
+                version,
+            } => {
+                (0x6e5f_8c22i32).tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += version.tl_write(output)?;
+            }
+            Update::UpdateDcOptions { dc_options } => {
+                (-0x71a1_678di32).tl_write(output)?;
+                result += dc_options.tl_write(output)?;
+            }
+            Update::UpdateUserBlocked { user_id, blocked } => {
+                (-0x7f13_17e6i32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += blocked.tl_write(output)?;
+            }
+            Update::UpdateNotifySettings {
+                peer,
+                notify_settings,
+            } => {
+                (-0x413d_9711i32).tl_write(output)?;
+                result += peer.tl_write(output)?;
+                result += notify_settings.tl_write(output)?;
+            }
+            Update::UpdateServiceNotification {
+                ty,
+                message,
+                media,
+                popup,
+            } => {
+                (0x382d_d3e4i32).tl_write(output)?;
+                result += ty.tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += media.tl_write(output)?;
+                result += popup.tl_write(output)?;
+            }
+            Update::UpdatePrivacy { key, rules } => {
+                (-0x11c4_d8d6i32).tl_write(output)?;
+                result += key.tl_write(output)?;
+                result += rules.tl_write(output)?;
+            }
+            Update::UpdateUserPhone { user_id, phone } => {
+                (0x12b9_417bi32).tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += phone.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Update::UpdateNewMessage { message, pts } => {
+                result += message.serialized_len();
+                result += pts.serialized_len();
+            }
+            Update::UpdateMessageId { id, random_id } => {
+                result += id.serialized_len();
+                result += random_id.serialized_len();
+            }
+            Update::UpdateReadMessages { messages, pts } => {
+                result += messages.serialized_len();
+                result += pts.serialized_len();
+            }
+            Update::UpdateDeleteMessages { messages, pts } => {
+                result += messages.serialized_len();
+                result += pts.serialized_len();
+            }
+            Update::UpdateUserTyping { user_id, action } => {
+                result += user_id.serialized_len();
+                result += action.serialized_len();
+            }
+            Update::UpdateChatUserTyping {
+                chat_id,
+                user_id,
+                action,
+            } => {
+                result += chat_id.serialized_len();
+                result += user_id.serialized_len();
+                result += action.serialized_len();
+            }
+            Update::UpdateChatParticipants { participants } => {
+                result += participants.serialized_len();
+            }
+            Update::UpdateUserStatus { user_id, status } => {
+                result += user_id.serialized_len();
+                result += status.serialized_len();
+            }
+            Update::UpdateUserName {
+                user_id,
+                first_name,
+                last_name,
+                username,
+            } => {
+                result += user_id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+            }
+            Update::UpdateUserPhoto {
+                user_id,
+                date,
+                photo,
+                previous,
+            } => {
+                result += user_id.serialized_len();
+                result += date.serialized_len();
+                result += photo.serialized_len();
+                result += previous.serialized_len();
+            }
+            Update::UpdateContactRegistered { user_id, date } => {
+                result += user_id.serialized_len();
+                result += date.serialized_len();
+            }
+            Update::UpdateContactLink {
+                user_id,
+                my_link,
+                foreign_link,
+            } => {
+                result += user_id.serialized_len();
+                result += my_link.serialized_len();
+                result += foreign_link.serialized_len();
+            }
+            Update::UpdateNewAuthorization {
+                auth_key_id,
+                date,
+                device,
+                location,
+            } => {
+                result += auth_key_id.serialized_len();
+                result += date.serialized_len();
+                result += device.serialized_len();
+                result += location.serialized_len();
+            }
+            Update::UpdateNewEncryptedMessage { message, qts } => {
+                result += message.serialized_len();
+                result += qts.serialized_len();
+            }
+            Update::UpdateEncryptedChatTyping { chat_id } => {
+                result += chat_id.serialized_len();
+            }
+            Update::UpdateEncryption { chat, date } => {
+                result += chat.serialized_len();
+                result += date.serialized_len();
+            }
+            Update::UpdateEncryptedMessagesRead {
+                chat_id,
+                max_date,
+                date,
+            } => {
+                result += chat_id.serialized_len();
+                result += max_date.serialized_len();
+                result += date.serialized_len();
+            }
+            Update::UpdateChatParticipantAdd {
+                chat_id,
+                user_id,
+                inviter_id,
+                version,
+            } => {
+                result += chat_id.serialized_len();
+                result += user_id.serialized_len();
+                result += inviter_id.serialized_len();
+                result += version.serialized_len();
+            }
+            Update::UpdateChatParticipantDelete {
+                chat_id,
+                user_id,
+                version,
+            } => {
+                result += chat_id.serialized_len();
+                result += user_id.serialized_len();
+                result += version.serialized_len();
+            }
+            Update::UpdateDcOptions { dc_options } => {
+                result += dc_options.serialized_len();
+            }
+            Update::UpdateUserBlocked { user_id, blocked } => {
+                result += user_id.serialized_len();
+                result += blocked.serialized_len();
+            }
+            Update::UpdateNotifySettings {
+                peer,
+                notify_settings,
+            } => {
+                result += peer.serialized_len();
+                result += notify_settings.serialized_len();
+            }
+            Update::UpdateServiceNotification {
+                ty,
+                message,
+                media,
+                popup,
+            } => {
+                result += ty.serialized_len();
+                result += message.serialized_len();
+                result += media.serialized_len();
+                result += popup.serialized_len();
+            }
+            Update::UpdatePrivacy { key, rules } => {
+                result += key.serialized_len();
+                result += rules.serialized_len();
+            }
+            Update::UpdateUserPhone { user_id, phone } => {
+                result += user_id.serialized_len();
+                result += phone.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpdateRef<'a> {
+    /// `updateNewMessage#013abdb3 message:Message pts:int = Update`
+    UpdateNewMessage {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: super::tl::MessageRef<'a>,
+        pts: i32,
+    },
+    /// `updateMessageID#4e90bfd6 id:int random_id:long = Update`
+    UpdateMessageId { id: i32, random_id: i64 },
+    /// `updateReadMessages#c6649e31 messages:Vector<int> pts:int = Update`
+    UpdateReadMessages { messages: Vec<i32>, pts: i32 },
+    /// `updateDeleteMessages#a92bfe26 messages:Vector<int> pts:int = Update`
+    UpdateDeleteMessages { messages: Vec<i32>, pts: i32 },
+    /// `updateUserTyping#5c486927 user_id:int action:SendMessageAction = Update`
+    UpdateUserTyping {
+        user_id: i32,
+        action: super::tl::SendMessageAction,
+    },
+    /// `updateChatUserTyping#9a65ea1f chat_id:int user_id:int action:SendMessageAction = Update`
+    UpdateChatUserTyping {
+        chat_id: i32,
+        user_id: i32,
+        action: super::tl::SendMessageAction,
+    },
+    /// `updateChatParticipants#07761198 participants:ChatParticipants = Update`
+    UpdateChatParticipants {
+        participants: super::tl::ChatParticipants,
+    },
+    /// `updateUserStatus#1bfbd823 user_id:int status:UserStatus = Update`
+    UpdateUserStatus {
+        user_id: i32,
+        status: super::tl::UserStatus,
+    },
+    /// `updateUserName#a7332b73 user_id:int first_name:string last_name:string username:string = Update`
+    UpdateUserName {
+        user_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+    },
+    /// `updateUserPhoto#95313b0c user_id:int date:int photo:UserProfilePhoto previous:Bool = Update`
+    UpdateUserPhoto {
+        user_id: i32,
+        date: i32,
+        photo: super::tl::UserProfilePhoto,
+        previous: bool,
+    },
+    /// `updateContactRegistered#2575bbb9 user_id:int date:int = Update`
+    UpdateContactRegistered { user_id: i32, date: i32 },
+    /// `updateContactLink#51a48a9a user_id:int my_link:contacts.MyLink foreign_link:contacts.ForeignLink = Update`
+    UpdateContactLink {
+        user_id: i32,
+        my_link: super::contacts::MyLink,
+        foreign_link: super::contacts::ForeignLink,
+    },
+    /// `updateNewAuthorization#8f06529a auth_key_id:long date:int device:string location:string = Update`
+    UpdateNewAuthorization {
+        auth_key_id: i64,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        device: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        location: &'a str,
+    },
+    /// `updateNewEncryptedMessage#12bcbd9a message:EncryptedMessage qts:int = Update`
+    UpdateNewEncryptedMessage {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: super::tl::EncryptedMessageRef<'a>,
+        qts: i32,
+    },
+    /// `updateEncryptedChatTyping#1710f156 chat_id:int = Update`
+    UpdateEncryptedChatTyping { chat_id: i32 },
+    /// `updateEncryption#b4a2e88d chat:EncryptedChat date:int = Update`
+    UpdateEncryption {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chat: super::tl::EncryptedChatRef<'a>,
+        date: i32,
+    },
+    /// `updateEncryptedMessagesRead#38fe25b7 chat_id:int max_date:int date:int = Update`
+    UpdateEncryptedMessagesRead {
+        chat_id: i32,
+        max_date: i32,
+        date: i32,
+    },
+    /// `updateChatParticipantAdd#3a0eeb22 chat_id:int user_id:int inviter_id:int version:int = Update`
+    UpdateChatParticipantAdd {
+        chat_id: i32,
+        user_id: i32,
+        inviter_id: i32,
+        version: i32,
+    },
+    /// `updateChatParticipantDelete#6e5f8c22 chat_id:int user_id:int version:int = Update`
+    UpdateChatParticipantDelete {
+        chat_id: i32,
+        user_id: i32,
+        version: i32,
+    },
+    /// `updateDcOptions#8e5e9873 dc_options:Vector<DcOption> = Update`
+    UpdateDcOptions {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        dc_options: Vec<super::tl::DcOptionRef<'a>>,
+    },
+    /// `updateUserBlocked#80ece81a user_id:int blocked:Bool = Update`
+    UpdateUserBlocked { user_id: i32, blocked: bool },
+    /// `updateNotifySettings#bec268ef peer:NotifyPeer notify_settings:PeerNotifySettings = Update`
+    UpdateNotifySettings {
+        peer: super::tl::NotifyPeer,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        notify_settings: super::tl::PeerNotifySettingsRef<'a>,
+    },
+    /// `updateServiceNotification#382dd3e4 type:string message:string media:MessageMedia popup:Bool = Update`
+    UpdateServiceNotification {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        ty: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        media: super::tl::MessageMediaRef<'a>,
+        popup: bool,
+    },
+    /// `updatePrivacy#ee3b272a key:PrivacyKey rules:Vector<PrivacyRule> = Update`
+    UpdatePrivacy {
+        key: super::tl::PrivacyKey,
+        rules: Vec<super::tl::PrivacyRule>,
+    },
+    /// `updateUserPhone#12b9417b user_id:int phone:string = Update`
+    UpdateUserPhone {
+        user_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone: &'a str,
+    },
+}
+impl<'a> TLDeserialize<'a> for UpdateRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x013a_bdb3i32 => UpdateRef::UpdateNewMessage {
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x4e90_bfd6i32 => UpdateRef::UpdateMessageId {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                random_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x399b_61cfi32 => UpdateRef::UpdateReadMessages {
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x56d4_01dai32 => UpdateRef::UpdateDeleteMessages {
+                messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x5c48_6927i32 => UpdateRef::UpdateUserTyping {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                action: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x659a_15e1i32 => UpdateRef::UpdateChatUserTyping {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                action: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x0776_1198i32 => UpdateRef::UpdateChatParticipants {
+                participants: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1bfb_d823i32 => UpdateRef::UpdateUserStatus {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x58cc_d48di32 => UpdateRef::UpdateUserName {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x6ace_c4f4i32 => UpdateRef::UpdateUserPhoto {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                previous: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2575_bbb9i32 => UpdateRef::UpdateContactRegistered {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x51a4_8a9ai32 => UpdateRef::UpdateContactLink {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                my_link: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                foreign_link: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x70f9_ad66i32 => UpdateRef::UpdateNewAuthorization {
+                auth_key_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                device: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                location: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x12bc_bd9ai32 => UpdateRef::UpdateNewEncryptedMessage {
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                qts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x1710_f156i32 => UpdateRef::UpdateEncryptedChatTyping {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x4b5d_1773i32 => UpdateRef::UpdateEncryption {
+                chat: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x38fe_25b7i32 => UpdateRef::UpdateEncryptedMessagesRead {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                max_date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x3a0e_eb22i32 => UpdateRef::UpdateChatParticipantAdd {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                inviter_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                version: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x6e5f_8c22i32 => UpdateRef::UpdateChatParticipantDelete {
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                version: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x71a1_678di32 => UpdateRef::UpdateDcOptions {
+                dc_options: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x7f13_17e6i32 => UpdateRef::UpdateUserBlocked {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                blocked: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x413d_9711i32 => UpdateRef::UpdateNotifySettings {
+                peer: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                notify_settings: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x382d_d3e4i32 => UpdateRef::UpdateServiceNotification {
+                ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                media: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                popup: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x11c4_d8d6i32 => UpdateRef::UpdatePrivacy {
+                key: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                rules: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x12b9_417bi32 => UpdateRef::UpdateUserPhone {
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Update",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UpdateUpdateNewMessageBuilder {
+    message: super::tl::Message,
+    pts: i32,
+}
+impl UpdateUpdateNewMessageBuilder {
+    pub fn new(message: super::tl::Message, pts: i32) -> Self {
+        UpdateUpdateNewMessageBuilder { message, pts }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateNewMessage {
+            message: self.message,
+            pts: self.pts,
+        }
+    }
+}
+pub struct UpdateUpdateMessageIdBuilder {
+    id: i32,
+    random_id: i64,
+}
+impl UpdateUpdateMessageIdBuilder {
+    pub fn new(id: i32, random_id: i64) -> Self {
+        UpdateUpdateMessageIdBuilder { id, random_id }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateMessageId {
+            id: self.id,
+            random_id: self.random_id,
+        }
+    }
+}
+pub struct UpdateUpdateReadMessagesBuilder {
+    messages: Vec<i32>,
+    pts: i32,
+}
+impl UpdateUpdateReadMessagesBuilder {
+    pub fn new(messages: Vec<i32>, pts: i32) -> Self {
+        UpdateUpdateReadMessagesBuilder { messages, pts }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateReadMessages {
+            messages: self.messages,
+            pts: self.pts,
+        }
+    }
+}
+pub struct UpdateUpdateDeleteMessagesBuilder {
+    messages: Vec<i32>,
+    pts: i32,
+}
+impl UpdateUpdateDeleteMessagesBuilder {
+    pub fn new(messages: Vec<i32>, pts: i32) -> Self {
+        UpdateUpdateDeleteMessagesBuilder { messages, pts }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateDeleteMessages {
+            messages: self.messages,
+            pts: self.pts,
+        }
+    }
+}
+pub struct UpdateUpdateUserTypingBuilder {
+    user_id: i32,
+    action: super::tl::SendMessageAction,
+}
+impl UpdateUpdateUserTypingBuilder {
+    pub fn new(user_id: i32, action: super::tl::SendMessageAction) -> Self {
+        UpdateUpdateUserTypingBuilder { user_id, action }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserTyping {
+            user_id: self.user_id,
+            action: self.action,
+        }
+    }
+}
+pub struct UpdateUpdateChatUserTypingBuilder {
+    chat_id: i32,
+    user_id: i32,
+    action: super::tl::SendMessageAction,
+}
+impl UpdateUpdateChatUserTypingBuilder {
+    pub fn new(chat_id: i32, user_id: i32, action: super::tl::SendMessageAction) -> Self {
+        UpdateUpdateChatUserTypingBuilder {
+            chat_id,
+            user_id,
+            action,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateChatUserTyping {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+            action: self.action,
+        }
+    }
+}
+pub struct UpdateUpdateChatParticipantsBuilder {
+    participants: super::tl::ChatParticipants,
+}
+impl UpdateUpdateChatParticipantsBuilder {
+    pub fn new(participants: super::tl::ChatParticipants) -> Self {
+        UpdateUpdateChatParticipantsBuilder { participants }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateChatParticipants {
+            participants: self.participants,
+        }
+    }
+}
+pub struct UpdateUpdateUserStatusBuilder {
+    user_id: i32,
+    status: super::tl::UserStatus,
+}
+impl UpdateUpdateUserStatusBuilder {
+    pub fn new(user_id: i32, status: super::tl::UserStatus) -> Self {
+        UpdateUpdateUserStatusBuilder { user_id, status }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserStatus {
+            user_id: self.user_id,
+            status: self.status,
+        }
+    }
+}
+pub struct UpdateUpdateUserNameBuilder {
+    user_id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+}
+impl UpdateUpdateUserNameBuilder {
+    pub fn new(user_id: i32, first_name: String, last_name: String, username: String) -> Self {
+        UpdateUpdateUserNameBuilder {
+            user_id,
+            first_name,
+            last_name,
+            username,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserName {
+            user_id: self.user_id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+        }
+    }
+}
+pub struct UpdateUpdateUserPhotoBuilder {
+    user_id: i32,
+    date: i32,
+    photo: super::tl::UserProfilePhoto,
+    previous: bool,
+}
+impl UpdateUpdateUserPhotoBuilder {
+    pub fn new(
+        user_id: i32,
+        date: i32,
+        photo: super::tl::UserProfilePhoto,
+        previous: bool,
+    ) -> Self {
+        UpdateUpdateUserPhotoBuilder {
+            user_id,
+            date,
+            photo,
+            previous,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserPhoto {
+            user_id: self.user_id,
+            date: self.date,
+            photo: self.photo,
+            previous: self.previous,
+        }
+    }
+}
+pub struct UpdateUpdateContactRegisteredBuilder {
+    user_id: i32,
+    date: i32,
+}
+impl UpdateUpdateContactRegisteredBuilder {
+    pub fn new(user_id: i32, date: i32) -> Self {
+        UpdateUpdateContactRegisteredBuilder { user_id, date }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateContactRegistered {
+            user_id: self.user_id,
+            date: self.date,
+        }
+    }
+}
+pub struct UpdateUpdateContactLinkBuilder {
+    user_id: i32,
+    my_link: super::contacts::MyLink,
+    foreign_link: super::contacts::ForeignLink,
+}
+impl UpdateUpdateContactLinkBuilder {
+    pub fn new(
+        user_id: i32,
+        my_link: super::contacts::MyLink,
+        foreign_link: super::contacts::ForeignLink,
+    ) -> Self {
+        UpdateUpdateContactLinkBuilder {
+            user_id,
+            my_link,
+            foreign_link,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateContactLink {
+            user_id: self.user_id,
+            my_link: self.my_link,
+            foreign_link: self.foreign_link,
+        }
+    }
+}
+pub struct UpdateUpdateNewAuthorizationBuilder {
+    auth_key_id: i64,
+    date: i32,
+    device: String,
+    location: String,
+}
+impl UpdateUpdateNewAuthorizationBuilder {
+    pub fn new(auth_key_id: i64, date: i32, device: String, location: String) -> Self {
+        UpdateUpdateNewAuthorizationBuilder {
+            auth_key_id,
+            date,
+            device,
+            location,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateNewAuthorization {
+            auth_key_id: self.auth_key_id,
+            date: self.date,
+            device: self.device,
+            location: self.location,
+        }
+    }
+}
+pub struct UpdateUpdateNewEncryptedMessageBuilder {
+    message: super::tl::EncryptedMessage,
+    qts: i32,
+}
+impl UpdateUpdateNewEncryptedMessageBuilder {
+    pub fn new(message: super::tl::EncryptedMessage, qts: i32) -> Self {
+        UpdateUpdateNewEncryptedMessageBuilder { message, qts }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateNewEncryptedMessage {
+            message: self.message,
+            qts: self.qts,
+        }
+    }
+}
+pub struct UpdateUpdateEncryptedChatTypingBuilder {
+    chat_id: i32,
+}
+impl UpdateUpdateEncryptedChatTypingBuilder {
+    pub fn new(chat_id: i32) -> Self {
+        UpdateUpdateEncryptedChatTypingBuilder { chat_id }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateEncryptedChatTyping {
+            chat_id: self.chat_id,
+        }
+    }
+}
+pub struct UpdateUpdateEncryptionBuilder {
+    chat: super::tl::EncryptedChat,
+    date: i32,
+}
+impl UpdateUpdateEncryptionBuilder {
+    pub fn new(chat: super::tl::EncryptedChat, date: i32) -> Self {
+        UpdateUpdateEncryptionBuilder { chat, date }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateEncryption {
+            chat: self.chat,
+            date: self.date,
+        }
+    }
+}
+pub struct UpdateUpdateEncryptedMessagesReadBuilder {
+    chat_id: i32,
+    max_date: i32,
+    date: i32,
+}
+impl UpdateUpdateEncryptedMessagesReadBuilder {
+    pub fn new(chat_id: i32, max_date: i32, date: i32) -> Self {
+        UpdateUpdateEncryptedMessagesReadBuilder {
+            chat_id,
+            max_date,
+            date,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateEncryptedMessagesRead {
+            chat_id: self.chat_id,
+            max_date: self.max_date,
+            date: self.date,
+        }
+    }
+}
+pub struct UpdateUpdateChatParticipantAddBuilder {
+    chat_id: i32,
+    user_id: i32,
+    inviter_id: i32,
+    version: i32,
+}
+impl UpdateUpdateChatParticipantAddBuilder {
+    pub fn new(chat_id: i32, user_id: i32, inviter_id: i32, version: i32) -> Self {
+        UpdateUpdateChatParticipantAddBuilder {
+            chat_id,
+            user_id,
+            inviter_id,
+            version,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateChatParticipantAdd {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+            inviter_id: self.inviter_id,
+            version: self.version,
+        }
+    }
+}
+pub struct UpdateUpdateChatParticipantDeleteBuilder {
+    chat_id: i32,
+    user_id: i32,
+    version: i32,
+}
+impl UpdateUpdateChatParticipantDeleteBuilder {
+    pub fn new(chat_id: i32, user_id: i32, version: i32) -> Self {
+        UpdateUpdateChatParticipantDeleteBuilder {
+            chat_id,
+            user_id,
+            version,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateChatParticipantDelete {
+            chat_id: self.chat_id,
+            user_id: self.user_id,
+            version: self.version,
+        }
+    }
+}
+pub struct UpdateUpdateDcOptionsBuilder {
+    dc_options: Vec<super::tl::DcOption>,
+}
+impl UpdateUpdateDcOptionsBuilder {
+    pub fn new(dc_options: Vec<super::tl::DcOption>) -> Self {
+        UpdateUpdateDcOptionsBuilder { dc_options }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateDcOptions {
+            dc_options: self.dc_options,
+        }
+    }
+}
+pub struct UpdateUpdateUserBlockedBuilder {
+    user_id: i32,
+    blocked: bool,
+}
+impl UpdateUpdateUserBlockedBuilder {
+    pub fn new(user_id: i32, blocked: bool) -> Self {
+        UpdateUpdateUserBlockedBuilder { user_id, blocked }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserBlocked {
+            user_id: self.user_id,
+            blocked: self.blocked,
+        }
+    }
+}
+pub struct UpdateUpdateNotifySettingsBuilder {
+    peer: super::tl::NotifyPeer,
+    notify_settings: super::tl::PeerNotifySettings,
+}
+impl UpdateUpdateNotifySettingsBuilder {
+    pub fn new(
+        peer: super::tl::NotifyPeer,
+        notify_settings: super::tl::PeerNotifySettings,
+    ) -> Self {
+        UpdateUpdateNotifySettingsBuilder {
+            peer,
+            notify_settings,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateNotifySettings {
+            peer: self.peer,
+            notify_settings: self.notify_settings,
+        }
+    }
+}
+pub struct UpdateUpdateServiceNotificationBuilder {
+    ty: String,
+    message: String,
+    media: super::tl::MessageMedia,
+    popup: bool,
+}
+impl UpdateUpdateServiceNotificationBuilder {
+    pub fn new(ty: String, message: String, media: super::tl::MessageMedia, popup: bool) -> Self {
+        UpdateUpdateServiceNotificationBuilder {
+            ty,
+            message,
+            media,
+            popup,
+        }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateServiceNotification {
+            ty: self.ty,
+            message: self.message,
+            media: self.media,
+            popup: self.popup,
+        }
+    }
+}
+pub struct UpdateUpdatePrivacyBuilder {
+    key: super::tl::PrivacyKey,
+    rules: Vec<super::tl::PrivacyRule>,
+}
+impl UpdateUpdatePrivacyBuilder {
+    pub fn new(key: super::tl::PrivacyKey, rules: Vec<super::tl::PrivacyRule>) -> Self {
+        UpdateUpdatePrivacyBuilder { key, rules }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdatePrivacy {
+            key: self.key,
+            rules: self.rules,
+        }
+    }
+}
+pub struct UpdateUpdateUserPhoneBuilder {
+    user_id: i32,
+    phone: String,
+}
+impl UpdateUpdateUserPhoneBuilder {
+    pub fn new(user_id: i32, phone: String) -> Self {
+        UpdateUpdateUserPhoneBuilder { user_id, phone }
+    }
+    pub fn build(self) -> Update {
+        Update::UpdateUserPhone {
+            user_id: self.user_id,
+            phone: self.phone,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Updates {
+    /// `updatesTooLong#e317af7e = Updates`
+    UpdatesTooLong,
+    /// `updateShortMessage#d3f45784 id:int from_id:int message:string pts:int date:int seq:int = Updates`
+    UpdateShortMessage {
+        id: i32,
+        from_id: i32,
+        message: String,
+        pts: i32,
+        date: i32,
+        seq: i32,
+    },
+    /// `updateShortChatMessage#2b2fbd4e id:int from_id:int chat_id:int message:string pts:int date:int seq:int = Updates`
+    UpdateShortChatMessage {
+        id: i32,
+        from_id: i32,
+        chat_id: i32,
+        message: String,
+        pts: i32,
+        date: i32,
+        seq: i32,
+    },
+    /// `updateShort#78d4dec1 update:Update date:int = Updates`
+    UpdateShort {
+        update: super::tl::Update,
+        date: i32,
+    },
+    /// `updatesCombined#725b04c3 updates:Vector<Update> users:Vector<User> chats:Vector<Chat> date:int seq_start:int seq:int = Updates`
+    UpdatesCombined {
+        updates: Vec<super::tl::Update>,
+        users: Vec<super::tl::User>,
+        chats: Vec<super::tl::Chat>,
+        date: i32,
+        seq_start: i32,
+        seq: i32,
+    },
+    /// `updates#74ae4240 updates:Vector<Update> users:Vector<User> chats:Vector<Chat> date:int seq:int = Updates`
+    Updates {
+        updates: Vec<super::tl::Update>,
+        users: Vec<super::tl::User>,
+        chats: Vec<super::tl::Chat>,
+        date: i32,
+        seq: i32,
+    },
+}
+impl TLType for Updates {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x1ce8_5082i32 => Updates::UpdatesTooLong {},
+            -0x2c0b_a87ci32 => Updates::UpdateShortMessage {
+                id: TLType::tl_read_with_context(input, context)?,
+                from_id: TLType::tl_read_with_context(input, context)?,
+                message: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            0x2b2f_bd4ei32 => Updates::UpdateShortChatMessage {
+                id: TLType::tl_read_with_context(input, context)?,
+                from_id: TLType::tl_read_with_context(input, context)?,
+                chat_id: TLType::tl_read_with_context(input, context)?,
+                message: TLType::tl_read_with_context(input, context)?,
+                pts: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            0x78d4_dec1i32 => Updates::UpdateShort {
+                update: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+            },
+            0x725b_04c3i32 => Updates::UpdatesCombined {
+                updates: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                seq_start: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            0x74ae_4240i32 => Updates::Updates {
+                updates: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Updates",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Updates::UpdatesTooLong => {
+                (-0x1ce8_5082i32).tl_write(output)?;
+            }
+            Updates::UpdateShortMessage {
+                id,
+                from_id,
+                message,
+                pts,
+                date,
+                seq,
+            } => {
+                (-0x2c0b_a87ci32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += from_id.tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            Updates::UpdateShortChatMessage {
+                id,
+                from_id,
+                chat_id,
+                message,
+                pts,
+                date,
+                seq,
+            } => {
+                (0x2b2f_bd4ei32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += from_id.tl_write(output)?;
+                result += chat_id.tl_write(output)?;
+                result += message.tl_write(output)?;
+                result += pts.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            Updates::UpdateShort { update, date } => {
+                (0x78d4_dec1i32).tl_write(output)?;
+                result += update.tl_write(output)?;
+                result += date.tl_write(output)?;
+            }
+            Updates::UpdatesCombined {
+                updates,
+                users,
+                chats,
+                date,
+                seq_start,
+                seq,
+            } => {
+                (0x725b_04c3i32).tl_write(output)?;
+                result += updates.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += seq_start.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            Updates::Updates {
+                updates,
+                users,
+                chats,
+                date,
+                seq,
+            } => {
+                (0x74ae_4240i32).tl_write(output)?;
+                result += updates.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Updates::UpdatesTooLong => {}
+            Updates::UpdateShortMessage {
+                id,
+                from_id,
+                message,
+                pts,
+                date,
+                seq,
+            } => {
+                result += id.serialized_len();
+                result += from_id.serialized_len();
+                result += message.serialized_len();
+                result += pts.serialized_len();
+                result += date.serialized_len();
+                result += seq.serialized_len();
+            }
+            Updates::UpdateShortChatMessage {
+                id,
+                from_id,
+                chat_id,
+                message,
+                pts,
+                date,
+                seq,
+            } => {
+                result += id.serialized_len();
+                result += from_id.serialized_len();
+                result += chat_id.serialized_len();
+                result += message.serialized_len();
+                result += pts.serialized_len();
+                result += date.serialized_len();
+                result += seq.serialized_len();
+            }
+            Updates::UpdateShort { update, date } => {
+                result += update.serialized_len();
+                result += date.serialized_len();
+            }
+            Updates::UpdatesCombined {
+                updates,
+                users,
+                chats,
+                date,
+                seq_start,
+                seq,
+            } => {
+                result += updates.serialized_len();
+                result += users.serialized_len();
+                result += chats.serialized_len();
+                result += date.serialized_len();
+                result += seq_start.serialized_len();
+                result += seq.serialized_len();
+            }
+            Updates::Updates {
+                updates,
+                users,
+                chats,
+                date,
+                seq,
+            } => {
+                result += updates.serialized_len();
+                result += users.serialized_len();
+                result += chats.serialized_len();
+                result += date.serialized_len();
+                result += seq.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpdatesRef<'a> {
+    /// `updatesTooLong#e317af7e = Updates`
+    UpdatesTooLong,
+    /// `updateShortMessage#d3f45784 id:int from_id:int message:string pts:int date:int seq:int = Updates`
+    UpdateShortMessage {
+        id: i32,
+        from_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: &'a str,
+        pts: i32,
+        date: i32,
+        seq: i32,
+    },
+    /// `updateShortChatMessage#2b2fbd4e id:int from_id:int chat_id:int message:string pts:int date:int seq:int = Updates`
+    UpdateShortChatMessage {
+        id: i32,
+        from_id: i32,
+        chat_id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        message: &'a str,
+        pts: i32,
+        date: i32,
+        seq: i32,
+    },
+    /// `updateShort#78d4dec1 update:Update date:int = Updates`
+    UpdateShort {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        update: super::tl::UpdateRef<'a>,
+        date: i32,
+    },
+    /// `updatesCombined#725b04c3 updates:Vector<Update> users:Vector<User> chats:Vector<Chat> date:int seq_start:int seq:int = Updates`
+    UpdatesCombined {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        updates: Vec<super::tl::UpdateRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        date: i32,
+        seq_start: i32,
+        seq: i32,
+    },
+    /// `updates#74ae4240 updates:Vector<Update> users:Vector<User> chats:Vector<Chat> date:int seq:int = Updates`
+    Updates {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        updates: Vec<super::tl::UpdateRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        date: i32,
+        seq: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for UpdatesRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x1ce8_5082i32 => UpdatesRef::UpdatesTooLong {},
+            -0x2c0b_a87ci32 => UpdatesRef::UpdateShortMessage {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x2b2f_bd4ei32 => UpdatesRef::UpdateShortChatMessage {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                from_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chat_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                message: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x78d4_dec1i32 => UpdatesRef::UpdateShort {
+                update: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x725b_04c3i32 => UpdatesRef::UpdatesCombined {
+                updates: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq_start: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x74ae_4240i32 => UpdatesRef::Updates {
+                updates: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Updates",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UpdatesUpdateShortMessageBuilder {
+    id: i32,
+    from_id: i32,
+    message: String,
+    pts: i32,
+    date: i32,
+    seq: i32,
+}
+impl UpdatesUpdateShortMessageBuilder {
+    pub fn new(id: i32, from_id: i32, message: String, pts: i32, date: i32, seq: i32) -> Self {
+        UpdatesUpdateShortMessageBuilder {
+            id,
+            from_id,
+            message,
+            pts,
+            date,
+            seq,
+        }
+    }
+    pub fn build(self) -> Updates {
+        Updates::UpdateShortMessage {
+            id: self.id,
+            from_id: self.from_id,
+            message: self.message,
+            pts: self.pts,
+            date: self.date,
+            seq: self.seq,
+        }
+    }
+}
+pub struct UpdatesUpdateShortChatMessageBuilder {
+    id: i32,
+    from_id: i32,
+    chat_id: i32,
+    message: String,
+    pts: i32,
+    date: i32,
+    seq: i32,
+}
+impl UpdatesUpdateShortChatMessageBuilder {
+    pub fn new(
+        id: i32,
+        from_id: i32,
+        chat_id: i32,
+        message: String,
+        pts: i32,
+        date: i32,
+        seq: i32,
+    ) -> Self {
+        UpdatesUpdateShortChatMessageBuilder {
+            id,
+            from_id,
+            chat_id,
+            message,
+            pts,
+            date,
+            seq,
+        }
+    }
+    pub fn build(self) -> Updates {
+        Updates::UpdateShortChatMessage {
+            id: self.id,
+            from_id: self.from_id,
+            chat_id: self.chat_id,
+            message: self.message,
+            pts: self.pts,
+            date: self.date,
+            seq: self.seq,
+        }
+    }
+}
+pub struct UpdatesUpdateShortBuilder {
+    update: super::tl::Update,
+    date: i32,
+}
+impl UpdatesUpdateShortBuilder {
+    pub fn new(update: super::tl::Update, date: i32) -> Self {
+        UpdatesUpdateShortBuilder { update, date }
+    }
+    pub fn build(self) -> Updates {
+        Updates::UpdateShort {
+            update: self.update,
+            date: self.date,
+        }
+    }
+}
+pub struct UpdatesUpdatesCombinedBuilder {
+    updates: Vec<super::tl::Update>,
+    users: Vec<super::tl::User>,
+    chats: Vec<super::tl::Chat>,
+    date: i32,
+    seq_start: i32,
+    seq: i32,
+}
+impl UpdatesUpdatesCombinedBuilder {
+    pub fn new(
+        updates: Vec<super::tl::Update>,
+        users: Vec<super::tl::User>,
+        chats: Vec<super::tl::Chat>,
+        date: i32,
+        seq_start: i32,
+        seq: i32,
+    ) -> Self {
+        UpdatesUpdatesCombinedBuilder {
+            updates,
+            users,
+            chats,
+            date,
+            seq_start,
+            seq,
+        }
+    }
+    pub fn build(self) -> Updates {
+        Updates::UpdatesCombined {
+            updates: self.updates,
+            users: self.users,
+            chats: self.chats,
+            date: self.date,
+            seq_start: self.seq_start,
+            seq: self.seq,
+        }
+    }
+}
+pub struct UpdatesUpdatesBuilder {
+    updates: Vec<super::tl::Update>,
+    users: Vec<super::tl::User>,
+    chats: Vec<super::tl::Chat>,
+    date: i32,
+    seq: i32,
+}
+impl UpdatesUpdatesBuilder {
+    pub fn new(
+        updates: Vec<super::tl::Update>,
+        users: Vec<super::tl::User>,
+        chats: Vec<super::tl::Chat>,
+        date: i32,
+        seq: i32,
+    ) -> Self {
+        UpdatesUpdatesBuilder {
+            updates,
+            users,
+            chats,
+            date,
+            seq,
+        }
+    }
+    pub fn build(self) -> Updates {
+        Updates::Updates {
+            updates: self.updates,
+            users: self.users,
+            chats: self.chats,
+            date: self.date,
+            seq: self.seq,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum User {
+    /// `userEmpty#200250ba id:int = User`
+    UserEmpty { id: i32 },
+    /// `userSelf#7007b451 id:int first_name:string last_name:string username:string phone:string photo:UserProfilePhoto status:UserStatus inactive:Bool = User`
+    UserSelf {
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+        inactive: bool,
+    },
+    /// `userContact#cab35e18 id:int first_name:string last_name:string username:string access_hash:long phone:string photo:UserProfilePhoto status:UserStatus = User`
+    UserContact {
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userRequest#d9ccc4ef id:int first_name:string last_name:string username:string access_hash:long phone:string photo:UserProfilePhoto status:UserStatus = User`
+    UserRequest {
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userForeign#075cf7a8 id:int first_name:string last_name:string username:string access_hash:long photo:UserProfilePhoto status:UserStatus = User`
+    UserForeign {
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userDeleted#d6016d7a id:int first_name:string last_name:string username:string = User`
+    UserDeleted {
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+    },
+}
+impl TLType for User {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x2002_50bai32 => User::UserEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x7007_b451i32 => User::UserSelf {
+                id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+                phone: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+                inactive: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x354c_a1e8i32 => User::UserContact {
+                id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                phone: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x2633_3b11i32 => User::UserRequest {
+                id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                phone: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            0x075c_f7a8i32 => User::UserForeign {
+                id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                photo: TLType::tl_read_with_context(input, context)?,
+                status: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x29fe_9286i32 => User::UserDeleted {
+                id: TLType::tl_read_with_context(input, context)?,
+                first_name: TLType::tl_read_with_context(input, context)?,
+                last_name: TLType::tl_read_with_context(input, context)?,
+                username: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "User",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            User::UserEmpty { id } => {
+                (0x2002_50bai32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            User::UserSelf {
+                id,
+                first_name,
+                last_name,
+                username,
+                phone,
+                photo,
+                status,
+                inactive,
+            } => {
+                (0x7007_b451i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+                result += phone.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += status.tl_write(output)?;
+                result += inactive.tl_write(output)?;
+            }
+            User::UserContact {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                phone,
+                photo,
+                status,
+            } => {
+                (-0x354c_a1e8i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += phone.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+            User::UserRequest {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                phone,
+                photo,
+                status,
+            } => {
+                (-0x2633_3b11i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += phone.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+            User::UserForeign {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                photo,
+                status,
+            } => {
+                (0x075c_f7a8i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += photo.tl_write(output)?;
+                result += status.tl_write(output)?;
+            }
+            User::UserDeleted {
+                id,
+                first_name,
+                last_name,
+                username,
+            } => {
+                (-0x29fe_9286i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += first_name.tl_write(output)?;
+                result += last_name.tl_write(output)?;
+                result += username.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            User::UserEmpty { id } => {
+                result += id.serialized_len();
+            }
+            User::UserSelf {
+                id,
+                first_name,
+                last_name,
+                username,
+                phone,
+                photo,
+                status,
+                inactive,
+            } => {
+                result += id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+                result += phone.serialized_len();
+                result += photo.serialized_len();
+                result += status.serialized_len();
+                result += inactive.serialized_len();
+            }
+            User::UserContact {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                phone,
+                photo,
+                status,
+            } => {
+                result += id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+                result += access_hash.serialized_len();
+                result += phone.serialized_len();
+                result += photo.serialized_len();
+                result += status.serialized_len();
+            }
+            User::UserRequest {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                phone,
+                photo,
+                status,
+            } => {
+                result += id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+                result += access_hash.serialized_len();
+                result += phone.serialized_len();
+                result += photo.serialized_len();
+                result += status.serialized_len();
+            }
+            User::UserForeign {
+                id,
+                first_name,
+                last_name,
+                username,
+                access_hash,
+                photo,
+                status,
+            } => {
+                result += id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+                result += access_hash.serialized_len();
+                result += photo.serialized_len();
+                result += status.serialized_len();
+            }
+            User::UserDeleted {
+                id,
+                first_name,
+                last_name,
+                username,
+            } => {
+                result += id.serialized_len();
+                result += first_name.serialized_len();
+                result += last_name.serialized_len();
+                result += username.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserRef<'a> {
+    /// `userEmpty#200250ba id:int = User`
+    UserEmpty { id: i32 },
+    /// `userSelf#7007b451 id:int first_name:string last_name:string username:string phone:string photo:UserProfilePhoto status:UserStatus inactive:Bool = User`
+    UserSelf {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone: &'a str,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+        inactive: bool,
+    },
+    /// `userContact#cab35e18 id:int first_name:string last_name:string username:string access_hash:long phone:string photo:UserProfilePhoto status:UserStatus = User`
+    UserContact {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+        access_hash: i64,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone: &'a str,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userRequest#d9ccc4ef id:int first_name:string last_name:string username:string access_hash:long phone:string photo:UserProfilePhoto status:UserStatus = User`
+    UserRequest {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+        access_hash: i64,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        phone: &'a str,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userForeign#075cf7a8 id:int first_name:string last_name:string username:string access_hash:long photo:UserProfilePhoto status:UserStatus = User`
+    UserForeign {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+        access_hash: i64,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    },
+    /// `userDeleted#d6016d7a id:int first_name:string last_name:string username:string = User`
+    UserDeleted {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        first_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        last_name: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        username: &'a str,
+    },
+}
+impl<'a> TLDeserialize<'a> for UserRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x2002_50bai32 => UserRef::UserEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x7007_b451i32 => UserRef::UserSelf {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                inactive: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x354c_a1e8i32 => UserRef::UserContact {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x2633_3b11i32 => UserRef::UserRequest {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                phone: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x075c_f7a8i32 => UserRef::UserForeign {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                status: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x29fe_9286i32 => UserRef::UserDeleted {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                username: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "User",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UserUserEmptyBuilder {
+    id: i32,
+}
+impl UserUserEmptyBuilder {
+    pub fn new(id: i32) -> Self {
+        UserUserEmptyBuilder { id }
+    }
+    pub fn build(self) -> User {
+        User::UserEmpty { id: self.id }
+    }
+}
+pub struct UserUserSelfBuilder {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+    phone: String,
+    photo: super::tl::UserProfilePhoto,
+    status: super::tl::UserStatus,
+    inactive: bool,
+}
+impl UserUserSelfBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+        inactive: bool,
+    ) -> Self {
+        UserUserSelfBuilder {
+            id,
+            first_name,
+            last_name,
+            username,
+            phone,
+            photo,
+            status,
+            inactive,
+        }
+    }
+    pub fn build(self) -> User {
+        User::UserSelf {
+            id: self.id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+            phone: self.phone,
+            photo: self.photo,
+            status: self.status,
+            inactive: self.inactive,
+        }
+    }
+}
+pub struct UserUserContactBuilder {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+    access_hash: i64,
+    phone: String,
+    photo: super::tl::UserProfilePhoto,
+    status: super::tl::UserStatus,
+}
+impl UserUserContactBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    ) -> Self {
+        UserUserContactBuilder {
+            id,
+            first_name,
+            last_name,
+            username,
+            access_hash,
+            phone,
+            photo,
+            status,
+        }
+    }
+    pub fn build(self) -> User {
+        User::UserContact {
+            id: self.id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+            access_hash: self.access_hash,
+            phone: self.phone,
+            photo: self.photo,
+            status: self.status,
+        }
+    }
+}
+pub struct UserUserRequestBuilder {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+    access_hash: i64,
+    phone: String,
+    photo: super::tl::UserProfilePhoto,
+    status: super::tl::UserStatus,
+}
+impl UserUserRequestBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        phone: String,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    ) -> Self {
+        UserUserRequestBuilder {
+            id,
+            first_name,
+            last_name,
+            username,
+            access_hash,
+            phone,
+            photo,
+            status,
+        }
+    }
+    pub fn build(self) -> User {
+        User::UserRequest {
+            id: self.id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+            access_hash: self.access_hash,
+            phone: self.phone,
+            photo: self.photo,
+            status: self.status,
+        }
+    }
+}
+pub struct UserUserForeignBuilder {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+    access_hash: i64,
+    photo: super::tl::UserProfilePhoto,
+    status: super::tl::UserStatus,
+}
+impl UserUserForeignBuilder {
+    pub fn new(
+        id: i32,
+        first_name: String,
+        last_name: String,
+        username: String,
+        access_hash: i64,
+        photo: super::tl::UserProfilePhoto,
+        status: super::tl::UserStatus,
+    ) -> Self {
+        UserUserForeignBuilder {
+            id,
+            first_name,
+            last_name,
+            username,
+            access_hash,
+            photo,
+            status,
+        }
+    }
+    pub fn build(self) -> User {
+        User::UserForeign {
+            id: self.id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+            access_hash: self.access_hash,
+            photo: self.photo,
+            status: self.status,
+        }
+    }
+}
+pub struct UserUserDeletedBuilder {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    username: String,
+}
+impl UserUserDeletedBuilder {
+    pub fn new(id: i32, first_name: String, last_name: String, username: String) -> Self {
+        UserUserDeletedBuilder {
+            id,
+            first_name,
+            last_name,
+            username,
+        }
+    }
+    pub fn build(self) -> User {
+        User::UserDeleted {
+            id: self.id,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            username: self.username,
+        }
+    }
+}
+/// `userFull#771095da user:User link:contacts.Link profile_photo:Photo notify_settings:PeerNotifySettings blocked:Bool real_first_name:string real_last_name:string = UserFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserFull {
+    pub user: super::tl::User,
+    pub link: super::contacts::Link,
+    pub profile_photo: super::tl::Photo,
+    pub notify_settings: super::tl::PeerNotifySettings,
+    pub blocked: bool,
+    pub real_first_name: String,
+    pub real_last_name: String,
+}
+impl TLType for UserFull {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x7710_95dai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "UserFull",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x7710_95dai32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for UserFull {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UserFull {
+            user: TLType::tl_read_with_context(input, context)?,
+            link: TLType::tl_read_with_context(input, context)?,
+            profile_photo: TLType::tl_read_with_context(input, context)?,
+            notify_settings: TLType::tl_read_with_context(input, context)?,
+            blocked: TLType::tl_read_with_context(input, context)?,
+            real_first_name: TLType::tl_read_with_context(input, context)?,
+            real_last_name: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.user.tl_write(output)?;
+        result += self.link.tl_write(output)?;
+        result += self.profile_photo.tl_write(output)?;
+        result += self.notify_settings.tl_write(output)?;
+        result += self.blocked.tl_write(output)?;
+        result += self.real_first_name.tl_write(output)?;
+        result += self.real_last_name.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.user.serialized_len();
+        result += self.link.serialized_len();
+        result += self.profile_photo.serialized_len();
+        result += self.notify_settings.serialized_len();
+        result += self.blocked.serialized_len();
+        result += self.real_first_name.serialized_len();
+        result += self.real_last_name.serialized_len();
+        result
+    }
+}
+/// `userFull#771095da user:User link:contacts.Link profile_photo:Photo notify_settings:PeerNotifySettings blocked:Bool real_first_name:string real_last_name:string = UserFull`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserFullRef<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub user: super::tl::UserRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub link: super::contacts::LinkRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub profile_photo: super::tl::PhotoRef<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub notify_settings: super::tl::PeerNotifySettingsRef<'a>,
+    pub blocked: bool,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub real_first_name: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub real_last_name: &'a str,
+}
+impl<'a> TLDeserialize<'a> for UserFullRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x7710_95dai32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "UserFull",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for UserFullRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = UserFullRef {
+            user: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            link: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            profile_photo: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            notify_settings: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            blocked: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            real_first_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            real_last_name: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UserFullBuilder {
+    user: super::tl::User,
+    link: super::contacts::Link,
+    profile_photo: super::tl::Photo,
+    notify_settings: super::tl::PeerNotifySettings,
+    blocked: bool,
+    real_first_name: String,
+    real_last_name: String,
+}
+impl UserFullBuilder {
+    pub fn new(
+        user: super::tl::User,
+        link: super::contacts::Link,
+        profile_photo: super::tl::Photo,
+        notify_settings: super::tl::PeerNotifySettings,
+        blocked: bool,
+        real_first_name: String,
+        real_last_name: String,
+    ) -> Self {
+        UserFullBuilder {
+            user,
+            link,
+            profile_photo,
+            notify_settings,
+            blocked,
+            real_first_name,
+            real_last_name,
+        }
+    }
+    pub fn build(self) -> UserFull {
+        UserFull {
+            user: self.user,
+            link: self.link,
+            profile_photo: self.profile_photo,
+            notify_settings: self.notify_settings,
+            blocked: self.blocked,
+            real_first_name: self.real_first_name,
+            real_last_name: self.real_last_name,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserProfilePhoto {
+    /// `userProfilePhotoEmpty#4f11bae1 = UserProfilePhoto`
+    UserProfilePhotoEmpty,
+    /// `userProfilePhoto#d559d8c8 photo_id:long photo_small:FileLocation photo_big:FileLocation = UserProfilePhoto`
+    UserProfilePhoto {
+        photo_id: i64,
+        photo_small: super::tl::FileLocation,
+        photo_big: super::tl::FileLocation,
+    },
+}
+impl TLType for UserProfilePhoto {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x4f11_bae1i32 => UserProfilePhoto::UserProfilePhotoEmpty {},
+            -0x2aa6_2738i32 => UserProfilePhoto::UserProfilePhoto {
+                photo_id: TLType::tl_read_with_context(input, context)?,
+                photo_small: TLType::tl_read_with_context(input, context)?,
+                photo_big: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "UserProfilePhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            UserProfilePhoto::UserProfilePhotoEmpty => {
+                (0x4f11_bae1i32).tl_write(output)?;
+            }
+            UserProfilePhoto::UserProfilePhoto {
+                photo_id,
+                photo_small,
+                photo_big,
+            } => {
+                (-0x2aa6_2738i32).tl_write(output)?;
+                result += photo_id.tl_write(output)?;
+                result += photo_small.tl_write(output)?;
+                result += photo_big.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            UserProfilePhoto::UserProfilePhotoEmpty => {}
+            UserProfilePhoto::UserProfilePhoto {
+                photo_id,
+                photo_small,
+                photo_big,
+            } => {
+                result += photo_id.serialized_len();
+                result += photo_small.serialized_len();
+                result += photo_big.serialized_len();
+            }
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for UserProfilePhoto {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x4f11_bae1i32 => UserProfilePhoto::UserProfilePhotoEmpty {},
+            -0x2aa6_2738i32 => UserProfilePhoto::UserProfilePhoto {
+                photo_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo_small: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                photo_big: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "UserProfilePhoto",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UserProfilePhotoUserProfilePhotoBuilder {
+    photo_id: i64,
+    photo_small: super::tl::FileLocation,
+    photo_big: super::tl::FileLocation,
+}
+impl UserProfilePhotoUserProfilePhotoBuilder {
+    pub fn new(
+        photo_id: i64,
+        photo_small: super::tl::FileLocation,
+        photo_big: super::tl::FileLocation,
+    ) -> Self {
+        UserProfilePhotoUserProfilePhotoBuilder {
+            photo_id,
+            photo_small,
+            photo_big,
+        }
+    }
+    pub fn build(self) -> UserProfilePhoto {
+        UserProfilePhoto::UserProfilePhoto {
+            photo_id: self.photo_id,
+            photo_small: self.photo_small,
+            photo_big: self.photo_big,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserStatus {
+    /// `userStatusEmpty#09d05049 = UserStatus`
+    UserStatusEmpty,
+    /// `userStatusOnline#edb93949 expires:int = UserStatus`
+    UserStatusOnline { expires: i32 },
+    /// `userStatusOffline#008c703f was_online:int = UserStatus`
+    UserStatusOffline { was_online: i32 },
+    /// `userStatusRecently#e26f42f1 = UserStatus`
+    UserStatusRecently,
+    /// `userStatusLastWeek#07bf09fc = UserStatus`
+    UserStatusLastWeek,
+    /// `userStatusLastMonth#77ebc742 = UserStatus`
+    UserStatusLastMonth,
+}
+impl TLType for UserStatus {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x09d0_5049i32 => UserStatus::UserStatusEmpty {},
+            -0x1246_c6b7i32 => UserStatus::UserStatusOnline {
+                expires: TLType::tl_read_with_context(input, context)?,
+            },
+            0x008c_703fi32 => UserStatus::UserStatusOffline {
+                was_online: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x1d90_bd0fi32 => UserStatus::UserStatusRecently {},
+            0x07bf_09fci32 => UserStatus::UserStatusLastWeek {},
+            0x77eb_c742i32 => UserStatus::UserStatusLastMonth {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "UserStatus",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            UserStatus::UserStatusEmpty => {
+                (0x09d0_5049i32).tl_write(output)?;
+            }
+            UserStatus::UserStatusOnline { expires } => {
+                (-0x1246_c6b7i32).tl_write(output)?;
+                result += expires.tl_write(output)?;
+            }
+            UserStatus::UserStatusOffline { was_online } => {
+                (0x008c_703fi32).tl_write(output)?;
+                result += was_online.tl_write(output)?;
+            }
+            UserStatus::UserStatusRecently => {
+                (-0x1d90_bd0fi32).tl_write(output)?;
+            }
+            UserStatus::UserStatusLastWeek => {
+                (0x07bf_09fci32).tl_write(output)?;
+            }
+            UserStatus::UserStatusLastMonth => {
+                (0x77eb_c742i32).tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            UserStatus::UserStatusEmpty => {}
+            UserStatus::UserStatusOnline { expires } => {
+                result += expires.serialized_len();
+            }
+            UserStatus::UserStatusOffline { was_online } => {
+                result += was_online.serialized_len();
+            }
+            UserStatus::UserStatusRecently => {}
+            UserStatus::UserStatusLastWeek => {}
+            UserStatus::UserStatusLastMonth => {}
+        }
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for UserStatus {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x09d0_5049i32 => UserStatus::UserStatusEmpty {},
+            -0x1246_c6b7i32 => UserStatus::UserStatusOnline {
+                expires: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x008c_703fi32 => UserStatus::UserStatusOffline {
+                was_online: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x1d90_bd0fi32 => UserStatus::UserStatusRecently {},
+            0x07bf_09fci32 => UserStatus::UserStatusLastWeek {},
+            0x77eb_c742i32 => UserStatus::UserStatusLastMonth {},
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "UserStatus",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct UserStatusUserStatusOnlineBuilder {
+    expires: i32,
+}
+impl UserStatusUserStatusOnlineBuilder {
+    pub fn new(expires: i32) -> Self {
+        UserStatusUserStatusOnlineBuilder { expires }
+    }
+    pub fn build(self) -> UserStatus {
+        UserStatus::UserStatusOnline {
+            expires: self.expires,
+        }
+    }
+}
+pub struct UserStatusUserStatusOfflineBuilder {
+    was_online: i32,
+}
+impl UserStatusUserStatusOfflineBuilder {
+    pub fn new(was_online: i32) -> Self {
+        UserStatusUserStatusOfflineBuilder { was_online }
+    }
+    pub fn build(self) -> UserStatus {
+        UserStatus::UserStatusOffline {
+            was_online: self.was_online,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Video {
+    /// `videoEmpty#c10658a8 id:long = Video`
+    VideoEmpty { id: i64 },
+    /// `video#388fa391 id:long access_hash:long user_id:int date:int caption:string duration:int mime_type:string size:int thumb:PhotoSize dc_id:int w:int h:int = Video`
+    Video {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        caption: String,
+        duration: i32,
+        mime_type: String,
+        size: i32,
+        thumb: super::tl::PhotoSize,
+        dc_id: i32,
+        w: i32,
+        h: i32,
+    },
+}
+impl TLType for Video {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x3ef9_a758i32 => Video::VideoEmpty {
+                id: TLType::tl_read_with_context(input, context)?,
+            },
+            0x388f_a391i32 => Video::Video {
+                id: TLType::tl_read_with_context(input, context)?,
+                access_hash: TLType::tl_read_with_context(input, context)?,
+                user_id: TLType::tl_read_with_context(input, context)?,
+                date: TLType::tl_read_with_context(input, context)?,
+                caption: TLType::tl_read_with_context(input, context)?,
+                duration: TLType::tl_read_with_context(input, context)?,
+                mime_type: TLType::tl_read_with_context(input, context)?,
+                size: TLType::tl_read_with_context(input, context)?,
+                thumb: TLType::tl_read_with_context(input, context)?,
+                dc_id: TLType::tl_read_with_context(input, context)?,
+                w: TLType::tl_read_with_context(input, context)?,
+                h: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Video",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Video::VideoEmpty { id } => {
+                (-0x3ef9_a758i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+            }
+            Video::Video {
+                id,
+                access_hash,
+                user_id,
+                date,
+                caption,
+                duration,
+                mime_type,
+                size,
+                thumb,
+                dc_id,
+                w,
+                h,
+            } => {
+                (0x388f_a391i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += access_hash.tl_write(output)?;
+                result += user_id.tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += caption.tl_write(output)?;
+                result += duration.tl_write(output)?;
+                result += mime_type.tl_write(output)?;
+                result += size.tl_write(output)?;
+                result += thumb.tl_write(output)?;
+                result += dc_id.tl_write(output)?;
+                result += w.tl_write(output)?;
+                result += h.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Video::VideoEmpty { id } => {
+                result += id.serialized_len();
+            }
+            Video::Video {
+                id,
+                access_hash,
+                user_id,
+                date,
+                caption,
+                duration,
+                mime_type,
+                size,
+                thumb,
+                dc_id,
+                w,
+                h,
+            } => {
+                result += id.serialized_len();
+                result += access_hash.serialized_len();
+                result += user_id.serialized_len();
+                result += date.serialized_len();
+                result += caption.serialized_len();
+                result += duration.serialized_len();
+                result += mime_type.serialized_len();
+                result += size.serialized_len();
+                result += thumb.serialized_len();
+                result += dc_id.serialized_len();
+                result += w.serialized_len();
+                result += h.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VideoRef<'a> {
+    /// `videoEmpty#c10658a8 id:long = Video`
+    VideoEmpty { id: i64 },
+    /// `video#388fa391 id:long access_hash:long user_id:int date:int caption:string duration:int mime_type:string size:int thumb:PhotoSize dc_id:int w:int h:int = Video`
+    Video {
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        caption: &'a str,
+        duration: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        mime_type: &'a str,
+        size: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        thumb: super::tl::PhotoSizeRef<'a>,
+        dc_id: i32,
+        w: i32,
+        h: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for VideoRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x3ef9_a758i32 => VideoRef::VideoEmpty {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x388f_a391i32 => VideoRef::Video {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                access_hash: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                user_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                caption: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                duration: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                mime_type: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                size: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                thumb: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                dc_id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                w: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                h: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "Video",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct VideoVideoEmptyBuilder {
+    id: i64,
+}
+impl VideoVideoEmptyBuilder {
+    pub fn new(id: i64) -> Self {
+        VideoVideoEmptyBuilder { id }
+    }
+    pub fn build(self) -> Video {
+        Video::VideoEmpty { id: self.id }
+    }
+}
+pub struct VideoVideoBuilder {
+    id: i64,
+    access_hash: i64,
+    user_id: i32,
+    date: i32,
+    caption: String,
+    duration: i32,
+    mime_type: String,
+    size: i32,
+    thumb: super::tl::PhotoSize,
+    dc_id: i32,
+    w: i32,
+    h: i32,
+}
+impl VideoVideoBuilder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: i64,
+        access_hash: i64,
+        user_id: i32,
+        date: i32,
+        caption: String,
+        duration: i32,
+        mime_type: String,
+        size: i32,
+        thumb: super::tl::PhotoSize,
+        dc_id: i32,
+        w: i32,
+        h: i32,
+    ) -> Self {
+        VideoVideoBuilder {
+            id,
+            access_hash,
+            user_id,
+            date,
+            caption,
+            duration,
+            mime_type,
+            size,
+            thumb,
+            dc_id,
+            w,
+            h,
+        }
+    }
+    pub fn build(self) -> Video {
+        Video::Video {
+            id: self.id,
+            access_hash: self.access_hash,
+            user_id: self.user_id,
+            date: self.date,
+            caption: self.caption,
+            duration: self.duration,
+            mime_type: self.mime_type,
+            size: self.size,
+            thumb: self.thumb,
+            dc_id: self.dc_id,
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WallPaper {
+    /// `wallPaper#ccb03657 id:int title:string sizes:Vector<PhotoSize> color:int = WallPaper`
+    WallPaper {
+        id: i32,
+        title: String,
+        sizes: Vec<super::tl::PhotoSize>,
+        color: i32,
+    },
+    /// `wallPaperSolid#63117f24 id:int title:string bg_color:int color:int = WallPaper`
+    WallPaperSolid {
+        id: i32,
+        title: String,
+        bg_color: i32,
+        color: i32,
+    },
+}
+impl TLType for WallPaper {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            -0x334f_c9a9i32 => WallPaper::WallPaper {
+                id: TLType::tl_read_with_context(input, context)?,
+                title: TLType::tl_read_with_context(input, context)?,
+                sizes: TLType::tl_read_with_context(input, context)?,
+                color: TLType::tl_read_with_context(input, context)?,
+            },
+            0x6311_7f24i32 => WallPaper::WallPaperSolid {
+                id: TLType::tl_read_with_context(input, context)?,
+                title: TLType::tl_read_with_context(input, context)?,
+                bg_color: TLType::tl_read_with_context(input, context)?,
+                color: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "WallPaper",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            WallPaper::WallPaper {
+                id,
+                title,
+                sizes,
+                color,
+            } => {
+                (-0x334f_c9a9i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += title.tl_write(output)?;
+                result += sizes.tl_write(output)?;
+                result += color.tl_write(output)?;
+            }
+            WallPaper::WallPaperSolid {
+                id,
+                title,
+                bg_color,
+                color,
+            } => {
+                (0x6311_7f24i32).tl_write(output)?;
+                result += id.tl_write(output)?;
+                result += title.tl_write(output)?;
+                result += bg_color.tl_write(output)?;
+                result += color.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            WallPaper::WallPaper {
+                id,
+                title,
+                sizes,
+                color,
+            } => {
+                result += id.serialized_len();
+                result += title.serialized_len();
+                result += sizes.serialized_len();
+                result += color.serialized_len();
+            }
+            WallPaper::WallPaperSolid {
+                id,
+                title,
+                bg_color,
+                color,
+            } => {
+                result += id.serialized_len();
+                result += title.serialized_len();
+                result += bg_color.serialized_len();
+                result += color.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WallPaperRef<'a> {
+    /// `wallPaper#ccb03657 id:int title:string sizes:Vector<PhotoSize> color:int = WallPaper`
+    WallPaper {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        sizes: Vec<super::tl::PhotoSizeRef<'a>>,
+        color: i32,
+    },
+    /// `wallPaperSolid#63117f24 id:int title:string bg_color:int color:int = WallPaper`
+    WallPaperSolid {
+        id: i32,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        title: &'a str,
+        bg_color: i32,
+        color: i32,
+    },
+}
+impl<'a> TLDeserialize<'a> for WallPaperRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            -0x334f_c9a9i32 => WallPaperRef::WallPaper {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                sizes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                color: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x6311_7f24i32 => WallPaperRef::WallPaperSolid {
+                id: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                title: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                bg_color: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                color: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "WallPaper",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct WallPaperWallPaperBuilder {
+    id: i32,
+    title: String,
+    sizes: Vec<super::tl::PhotoSize>,
+    color: i32,
+}
+impl WallPaperWallPaperBuilder {
+    pub fn new(id: i32, title: String, sizes: Vec<super::tl::PhotoSize>, color: i32) -> Self {
+        WallPaperWallPaperBuilder {
+            id,
+            title,
+            sizes,
+            color,
+        }
+    }
+    pub fn build(self) -> WallPaper {
+        WallPaper::WallPaper {
+            id: self.id,
+            title: self.title,
+            sizes: self.sizes,
+            color: self.color,
+        }
+    }
+}
+pub struct WallPaperWallPaperSolidBuilder {
+    id: i32,
+    title: String,
+    bg_color: i32,
+    color: i32,
+}
+impl WallPaperWallPaperSolidBuilder {
+    pub fn new(id: i32, title: String, bg_color: i32, color: i32) -> Self {
+        WallPaperWallPaperSolidBuilder {
+            id,
+            title,
+            bg_color,
+            color,
+        }
+    }
+    pub fn build(self) -> WallPaper {
+        WallPaper::WallPaperSolid {
+            id: self.id,
+            title: self.title,
+            bg_color: self.bg_color,
+            color: self.color,
+        }
+    }
+}
@@ -0,0 +1,437 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Difference {
+    /// `updates.differenceEmpty#5d75a138 date:int seq:int = updates.Difference`
+    DifferenceEmpty { date: i32, seq: i32 },
+    /// `updates.difference#00f49ca0 new_messages:Vector<Message> new_encrypted_messages:Vector<EncryptedMessage> other_updates:Vector<Update> chats:Vector<Chat> users:Vector<User> state:updates.State = updates.Difference`
+    Difference {
+        new_messages: Vec<super::tl::Message>,
+        new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+        other_updates: Vec<super::tl::Update>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        state: super::updates::State,
+    },
+    /// `updates.differenceSlice#a8fb1981 new_messages:Vector<Message> new_encrypted_messages:Vector<EncryptedMessage> other_updates:Vector<Update> chats:Vector<Chat> users:Vector<User> intermediate_state:updates.State = updates.Difference`
+    DifferenceSlice {
+        new_messages: Vec<super::tl::Message>,
+        new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+        other_updates: Vec<super::tl::Update>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        intermediate_state: super::updates::State,
+    },
+}
+impl TLType for Difference {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        let result = match id {
+            0x5d75_a138i32 => Difference::DifferenceEmpty {
+                date: TLType::tl_read_with_context(input, context)?,
+                seq: TLType::tl_read_with_context(input, context)?,
+            },
+            0x00f4_9ca0i32 => Difference::Difference {
+                new_messages: TLType::tl_read_with_context(input, context)?,
+                new_encrypted_messages: TLType::tl_read_with_context(input, context)?,
+                other_updates: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                state: TLType::tl_read_with_context(input, context)?,
+            },
+            -0x5704_e67fi32 => Difference::DifferenceSlice {
+                new_messages: TLType::tl_read_with_context(input, context)?,
+                new_encrypted_messages: TLType::tl_read_with_context(input, context)?,
+                other_updates: TLType::tl_read_with_context(input, context)?,
+                chats: TLType::tl_read_with_context(input, context)?,
+                users: TLType::tl_read_with_context(input, context)?,
+                intermediate_state: TLType::tl_read_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "updates.Difference",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 4usize;
+        match self {
+            Difference::DifferenceEmpty { date, seq } => {
+                (0x5d75_a138i32).tl_write(output)?;
+                result += date.tl_write(output)?;
+                result += seq.tl_write(output)?;
+            }
+            Difference::Difference {
+                new_messages,
+                new_encrypted_messages,
+                other_updates,
+                chats,
+                users,
+                state,
+            } => {
+                (0x00f4_9ca0i32).tl_write(output)?;
+                result += new_messages.tl_write(output)?;
+                result += new_encrypted_messages.tl_write(output)?;
+                result += other_updates.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += state.tl_write(output)?;
+            }
+            Difference::DifferenceSlice {
+                new_messages,
+                new_encrypted_messages,
+                other_updates,
+                chats,
+                users,
+                intermediate_state,
+            } => {
+                (-0x5704_e67fi32).tl_write(output)?;
+                result += new_messages.tl_write(output)?;
+                result += new_encrypted_messages.tl_write(output)?;
+                result += other_updates.tl_write(output)?;
+                result += chats.tl_write(output)?;
+                result += users.tl_write(output)?;
+                result += intermediate_state.tl_write(output)?;
+            }
+        }
+        Ok(result)
+    }
+    fn serialized_len(&self) -> usize {
+        let mut result = 4usize;
+        match self {
+            Difference::DifferenceEmpty { date, seq } => {
+                result += date.serialized_len();
+                result += seq.serialized_len();
+            }
+            Difference::Difference {
+                new_messages,
+                new_encrypted_messages,
+                other_updates,
+                chats,
+                users,
+                state,
+            } => {
+                result += new_messages.serialized_len();
+                result += new_encrypted_messages.serialized_len();
+                result += other_updates.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += state.serialized_len();
+            }
+            Difference::DifferenceSlice {
+                new_messages,
+                new_encrypted_messages,
+                other_updates,
+                chats,
+                users,
+                intermediate_state,
+            } => {
+                result += new_messages.serialized_len();
+                result += new_encrypted_messages.serialized_len();
+                result += other_updates.serialized_len();
+                result += chats.serialized_len();
+                result += users.serialized_len();
+                result += intermediate_state.serialized_len();
+            }
+        }
+        result
+    }
+}
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifferenceRef<'a> {
+    /// `updates.differenceEmpty#5d75a138 date:int seq:int = updates.Difference`
+    DifferenceEmpty { date: i32, seq: i32 },
+    /// `updates.difference#00f49ca0 new_messages:Vector<Message> new_encrypted_messages:Vector<EncryptedMessage> other_updates:Vector<Update> chats:Vector<Chat> users:Vector<User> state:updates.State = updates.Difference`
+    Difference {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        new_messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        new_encrypted_messages: Vec<super::tl::EncryptedMessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        other_updates: Vec<super::tl::UpdateRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        state: super::updates::State,
+    },
+    /// `updates.differenceSlice#a8fb1981 new_messages:Vector<Message> new_encrypted_messages:Vector<EncryptedMessage> other_updates:Vector<Update> chats:Vector<Chat> users:Vector<User> intermediate_state:updates.State = updates.Difference`
+    DifferenceSlice {
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        new_messages: Vec<super::tl::MessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        new_encrypted_messages: Vec<super::tl::EncryptedMessageRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        other_updates: Vec<super::tl::UpdateRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        chats: Vec<super::tl::ChatRef<'a>>,
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        users: Vec<super::tl::UserRef<'a>>,
+        intermediate_state: super::updates::State,
+    },
+}
+impl<'a> TLDeserialize<'a> for DifferenceRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        let result = match id {
+            0x5d75_a138i32 => DifferenceRef::DifferenceEmpty {
+                date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            0x00f4_9ca0i32 => DifferenceRef::Difference {
+                new_messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_encrypted_messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                other_updates: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                state: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            -0x5704_e67fi32 => DifferenceRef::DifferenceSlice {
+                new_messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                new_encrypted_messages: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                other_updates: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                chats: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                users: TLDeserialize::tl_deserialize_with_context(input, context)?,
+                intermediate_state: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            },
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id,
+                    expected: "updates.Difference",
+                })
+            }
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct DifferenceDifferenceEmptyBuilder {
+    date: i32,
+    seq: i32,
+}
+impl DifferenceDifferenceEmptyBuilder {
+    pub fn new(date: i32, seq: i32) -> Self {
+        DifferenceDifferenceEmptyBuilder { date, seq }
+    }
+    pub fn build(self) -> Difference {
+        Difference::DifferenceEmpty {
+            date: self.date,
+            seq: self.seq,
+        }
+    }
+}
+pub struct DifferenceDifferenceBuilder {
+    new_messages: Vec<super::tl::Message>,
+    new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+    other_updates: Vec<super::tl::Update>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    state: super::updates::State,
+}
+impl DifferenceDifferenceBuilder {
+    pub fn new(
+        new_messages: Vec<super::tl::Message>,
+        new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+        other_updates: Vec<super::tl::Update>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        state: super::updates::State,
+    ) -> Self {
+        DifferenceDifferenceBuilder {
+            new_messages,
+            new_encrypted_messages,
+            other_updates,
+            chats,
+            users,
+            state,
+        }
+    }
+    pub fn build(self) -> Difference {
+        Difference::Difference {
+            new_messages: self.new_messages,
+            new_encrypted_messages: self.new_encrypted_messages,
+            other_updates: self.other_updates,
+            chats: self.chats,
+            users: self.users,
+            state: self.state,
+        }
+    }
+}
+pub struct DifferenceDifferenceSliceBuilder {
+    new_messages: Vec<super::tl::Message>,
+    new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+    other_updates: Vec<super::tl::Update>,
+    chats: Vec<super::tl::Chat>,
+    users: Vec<super::tl::User>,
+    intermediate_state: super::updates::State,
+}
+impl DifferenceDifferenceSliceBuilder {
+    pub fn new(
+        new_messages: Vec<super::tl::Message>,
+        new_encrypted_messages: Vec<super::tl::EncryptedMessage>,
+        other_updates: Vec<super::tl::Update>,
+        chats: Vec<super::tl::Chat>,
+        users: Vec<super::tl::User>,
+        intermediate_state: super::updates::State,
+    ) -> Self {
+        DifferenceDifferenceSliceBuilder {
+            new_messages,
+            new_encrypted_messages,
+            other_updates,
+            chats,
+            users,
+            intermediate_state,
+        }
+    }
+    pub fn build(self) -> Difference {
+        Difference::DifferenceSlice {
+            new_messages: self.new_messages,
+            new_encrypted_messages: self.new_encrypted_messages,
+            other_updates: self.other_updates,
+            chats: self.chats,
+            users: self.users,
+            intermediate_state: self.intermediate_state,
+        }
+    }
+}
+/// `updates.state#a56c2a3e pts:int qts:int date:int seq:int unread_count:int = updates.State`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub pts: i32,
+    pub qts: i32,
+    pub date: i32,
+    pub seq: i32,
+    pub unread_count: i32,
+}
+impl TLType for State {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != -0x5a93_d5c2i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "updates.State",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (-0x5a93_d5c2i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for State {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = State {
+            pts: TLType::tl_read_with_context(input, context)?,
+            qts: TLType::tl_read_with_context(input, context)?,
+            date: TLType::tl_read_with_context(input, context)?,
+            seq: TLType::tl_read_with_context(input, context)?,
+            unread_count: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.pts.tl_write(output)?;
+        result += self.qts.tl_write(output)?;
+        result += self.date.tl_write(output)?;
+        result += self.seq.tl_write(output)?;
+        result += self.unread_count.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.pts.serialized_len();
+        result += self.qts.serialized_len();
+        result += self.date.serialized_len();
+        result += self.seq.serialized_len();
+        result += self.unread_count.serialized_len();
+        result
+    }
+}
+impl<'a> TLDeserialize<'a> for State {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != -0x5a93_d5c2i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "updates.State",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for State {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = State {
+            pts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            qts: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            date: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            seq: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            unread_count: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct StateBuilder {
+    pts: i32,
+    qts: i32,
+    date: i32,
+    seq: i32,
+    unread_count: i32,
+}
+impl StateBuilder {
+    pub fn new(pts: i32, qts: i32, date: i32, seq: i32, unread_count: i32) -> Self {
+        StateBuilder {
+            pts,
+            qts,
+            date,
+            seq,
+            unread_count,
+        }
+    }
+    pub fn build(self) -> State {
+        State {
+            pts: self.pts,
+            qts: self.qts,
+            date: self.date,
+            seq: self.seq,
+            unread_count: self.unread_count,
+        }
+    }
+}
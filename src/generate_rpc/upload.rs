@@ -0,0 +1,118 @@
+// Generated by code_gen from the MTProto schema, do not edit by hand.
+
+use super::prelude::*;
+/// `upload.file#096a18d5 type:storage.FileType mtime:int bytes:bytes = upload.File`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct File {
+    pub ty: super::storage::FileType,
+    pub mtime: i32,
+    pub bytes: TLBytes,
+}
+impl TLType for File {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLType::tl_read_with_context(input, context)?;
+        if id != 0x096a_18d5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "upload.File",
+            });
+        }
+        TLBareType::tl_read_bare_with_context(input, context)
+    }
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        (0x096a_18d5i32).tl_write(output)?;
+        Ok(4 + self.tl_write_bare(output)?)
+    }
+    fn serialized_len(&self) -> usize {
+        4 + self.bare_serialized_len()
+    }
+}
+impl TLBareType for File {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = File {
+            ty: TLType::tl_read_with_context(input, context)?,
+            mtime: TLType::tl_read_with_context(input, context)?,
+            bytes: TLType::tl_read_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        let mut result = 0usize;
+        result += self.ty.tl_write(output)?;
+        result += self.mtime.tl_write(output)?;
+        result += self.bytes.tl_write(output)?;
+        Ok(result)
+    }
+    fn bare_serialized_len(&self) -> usize {
+        let mut result = 0usize;
+        result += self.ty.serialized_len();
+        result += self.mtime.serialized_len();
+        result += self.bytes.serialized_len();
+        result
+    }
+}
+/// `upload.file#096a18d5 type:storage.FileType mtime:int bytes:bytes = upload.File`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileRef<'a> {
+    pub ty: super::storage::FileType,
+    pub mtime: i32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bytes: &'a [u8],
+}
+impl<'a> TLDeserialize<'a> for FileRef<'a> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id: i32 = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        if id != 0x096a_18d5i32 {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "upload.File",
+            });
+        }
+        TLBareDeserialize::tl_deserialize_bare_with_context(input, context)
+    }
+}
+impl<'a> TLBareDeserialize<'a> for FileRef<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.enter()?;
+        let result = FileRef {
+            ty: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            mtime: TLDeserialize::tl_deserialize_with_context(input, context)?,
+            bytes: TLDeserialize::tl_deserialize_with_context(input, context)?,
+        };
+        context.leave();
+        Ok(result)
+    }
+}
+pub struct FileBuilder {
+    ty: super::storage::FileType,
+    mtime: i32,
+    bytes: TLBytes,
+}
+impl FileBuilder {
+    pub fn new(ty: super::storage::FileType, mtime: i32, bytes: TLBytes) -> Self {
+        FileBuilder { ty, mtime, bytes }
+    }
+    pub fn build(self) -> File {
+        File {
+            ty: self.ty,
+            mtime: self.mtime,
+            bytes: self.bytes,
+        }
+    }
+}
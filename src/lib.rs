@@ -1,3 +1,6 @@
+pub mod generate_proto;
+pub mod generate_rpc;
+pub mod protocol;
 pub mod tl_types;
 pub mod transport;
 pub mod utils;
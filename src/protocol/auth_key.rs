@@ -0,0 +1,840 @@
+//! Auth key generation, see https://core.telegram.org/mtproto/auth_key
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use openssl::{
+    bn::{BigNum, BigNumContext, BigNumRef},
+    sha::sha1,
+};
+use rand::Rng;
+
+use crate::{
+    protocol::message_id::MessageIdGenerator,
+    tl_types::{tl_bytes::TLBytes, TLType},
+    transport::Transport,
+    utils::{
+        aes::{aes_ige_decrypt, aes_ige_encrypt},
+        kdf::tmp_aes_key_iv,
+        prime_numbers::split_pq,
+        rsa::{rsa, server_public_key_fingerprint},
+        MyError, MyResult,
+    },
+};
+
+const REQ_PQ_ID: u32 = 0x6046_9778;
+const RES_PQ_ID: u32 = 0x0516_2463;
+const P_Q_INNER_DATA_ID: u32 = 0x83c9_5aec;
+const REQ_DH_PARAMS_ID: u32 = 0xd712_e4be;
+const SERVER_DH_PARAMS_OK_ID: u32 = 0xd0e8_075c;
+const SERVER_DH_PARAMS_FAIL_ID: u32 = 0x79cb_045d;
+const SERVER_DH_INNER_DATA_ID: u32 = 0xb589_0dba;
+const CLIENT_DH_INNER_DATA_ID: u32 = 0x6643_b654;
+const SET_CLIENT_DH_PARAMS_ID: u32 = 0xf504_5f1f;
+const DH_GEN_OK_ID: u32 = 0x3bcb_f734;
+const DH_GEN_RETRY_ID: u32 = 0x46dc_1fb9;
+const DH_GEN_FAIL_ID: u32 = 0xa69d_ae02;
+
+/// 2048-bit safe prime sent by Telegram servers, checked once instead of on every handshake
+const KNOWN_DH_PRIME: &str = "C71CAEB9C6B1C9048E6C522F70F13F73980D40238E3E21C14934D037563D930F\
+                              48198A0AA7C14058229493D22530F4DBFA336F6E0AC925139543AED44CCE7C37\
+                              20FD51F69458705AC68CD4FE6B6B13ABDC9746512969328454F18FAF8C595F64\
+                              2477FE96BB2A941D5BCD1D4AC8CC49880708FA9B378E3C4F3A9060BEE67CF9A4\
+                              A4A695811051907E162753B56B0F6B410DBA74D8A84B2A14B3144E0EF1284754\
+                              FD17ED950D5965B4B9DD46582DB1178D169C6BC465B0D6FF9CA3928FEF5B9AE4\
+                              E418FC15E83EBEA0F87FA9FF5EED70050DED2849F47BF959D956850CE929851F\
+                              0D8115F635B105EE2E4E15D04B2454BF6F4FADF034B10403119CD8E3B92FCC5B";
+
+/// Result of the DH key exchange
+#[derive(Clone)]
+pub struct AuthKey {
+    pub key: [u8; 256],
+    /// Lower 64 bits of SHA1 of `key`
+    pub id: u64,
+    /// First salt, `new_nonce` XOR `server_nonce`
+    pub server_salt: i64,
+    /// Unix time of the server when it sent DH parameters
+    pub server_time: i32,
+}
+
+impl std::fmt::Debug for AuthKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AuthKey")
+            .field("id", &format_args!("{:016x}", self.id))
+            .field("server_salt", &self.server_salt)
+            .field("server_time", &self.server_time)
+            .finish()
+    }
+}
+
+impl AuthKey {
+    pub fn new(key: [u8; 256], server_salt: i64, server_time: i32) -> Self {
+        AuthKey {
+            id: LittleEndian::read_u64(&sha1(&key)[12..]),
+            key,
+            server_salt,
+            server_time,
+        }
+    }
+}
+
+/// Runs `req_pq` -> `req_DH_params` -> `set_client_DH_params` with unencrypted messages
+pub struct AuthKeyGenerator<'a> {
+    transport: &'a mut dyn Transport,
+    /// `dh_gen_retry` accepted before giving up
+    pub max_retries: usize,
+    message_ids: MessageIdGenerator,
+}
+
+/// `server_DH_inner_data`, with values checked to be safe
+struct DhParams {
+    g: u32,
+    dh_prime: BigNum,
+    g_a: BigNum,
+    server_time: i32,
+}
+
+impl<'a> AuthKeyGenerator<'a> {
+    pub fn new(transport: &'a mut dyn Transport) -> Self {
+        AuthKeyGenerator {
+            transport,
+            max_retries: 5,
+            message_ids: MessageIdGenerator::new(),
+        }
+    }
+
+    pub fn generate(&mut self) -> MyResult<AuthKey> {
+        let nonce: [u8; 16] = rand::random();
+        let mut request = vec![];
+        REQ_PQ_ID.tl_write(&mut request)?;
+        nonce.tl_write(&mut request)?;
+        let answer = self.call(&request)?;
+        let input = &mut answer.as_slice();
+
+        expect_id(input, RES_PQ_ID, "ResPQ")?;
+        check_nonce(input, &nonce)?;
+        let server_nonce = <[u8; 16]>::tl_read(input)?;
+        let pq = TLBytes::tl_read(input)?.into_bytes();
+        let fingerprints = Vec::<i64>::tl_read(input)?;
+        let fingerprint = server_public_key_fingerprint();
+        if !fingerprints.contains(&fingerprint) {
+            return Err(MyError::UnknownServerKey { fingerprints });
+        }
+        let (p, q) = factorize_pq(&pq)?;
+
+        let new_nonce: [u8; 32] = rand::random();
+        let mut inner = vec![];
+        P_Q_INNER_DATA_ID.tl_write(&mut inner)?;
+        TLBytes::from_bytes(pq).tl_write(&mut inner)?;
+        TLBytes::from_bytes(be_bytes(p)).tl_write(&mut inner)?;
+        TLBytes::from_bytes(be_bytes(q)).tl_write(&mut inner)?;
+        nonce.tl_write(&mut inner)?;
+        server_nonce.tl_write(&mut inner)?;
+        new_nonce.tl_write(&mut inner)?;
+        let mut data_with_hash = [0u8; 255];
+        data_with_hash[..20].copy_from_slice(&sha1(&inner));
+        data_with_hash[20..20 + inner.len()].copy_from_slice(&inner);
+        rand::thread_rng().fill(&mut data_with_hash[20 + inner.len()..]);
+
+        let mut request = vec![];
+        REQ_DH_PARAMS_ID.tl_write(&mut request)?;
+        nonce.tl_write(&mut request)?;
+        server_nonce.tl_write(&mut request)?;
+        TLBytes::from_bytes(be_bytes(p)).tl_write(&mut request)?;
+        TLBytes::from_bytes(be_bytes(q)).tl_write(&mut request)?;
+        fingerprint.tl_write(&mut request)?;
+        TLBytes::from_bytes(rsa(&data_with_hash).to_vec()).tl_write(&mut request)?;
+        let answer = self.call(&request)?;
+        self.exchange_keys(&nonce, &server_nonce, &new_nonce, &answer)
+    }
+
+    /// Check the `Server_DH_Params` answer and run `set_client_DH_params` until `dh_gen_ok`
+    fn exchange_keys(
+        &mut self,
+        nonce: &[u8; 16],
+        server_nonce: &[u8; 16],
+        new_nonce: &[u8; 32],
+        answer: &[u8],
+    ) -> MyResult<AuthKey> {
+        let input = &mut &answer[..];
+        let id = u32::tl_read(input)?;
+        check_nonce(input, nonce)?;
+        check_nonce(input, server_nonce)?;
+        match id {
+            SERVER_DH_PARAMS_OK_ID => {}
+            SERVER_DH_PARAMS_FAIL_ID => return Err(MyError::DhFailed),
+            _ => {
+                return Err(MyError::UnknownConstructor {
+                    id: id as i32,
+                    expected: "Server_DH_Params",
+                })
+            }
+        }
+        let encrypted_answer = TLBytes::tl_read(input)?.into_bytes();
+        let (key, iv) = tmp_aes_key_iv(server_nonce, new_nonce);
+        if encrypted_answer.len() & 15 != 0 {
+            return Err(MyError::BadMessageLength {
+                length: encrypted_answer.len(),
+            });
+        }
+        let answer = aes_ige_decrypt(&key, &iv, &encrypted_answer);
+        let params = parse_dh_params(&answer, nonce, server_nonce)?;
+        self.message_ids.set_server_time(params.server_time.into());
+
+        let mut context = BigNumContext::new()?;
+        let mut retry_id = 0i64;
+        for _ in 0..=self.max_retries {
+            let mut b = [0u8; 256];
+            rand::thread_rng().fill(&mut b[..]);
+            let b = BigNum::from_slice(&b)?;
+            let mut g_b = BigNum::new()?;
+            g_b.mod_exp(
+                &*BigNum::from_u32(params.g)?,
+                &b,
+                &params.dh_prime,
+                &mut context,
+            )?;
+            check_dh_value(&g_b, &params.dh_prime)?;
+            let mut auth_key = BigNum::new()?;
+            auth_key.mod_exp(&params.g_a, &b, &params.dh_prime, &mut context)?;
+            let auth_key = left_pad_256(&auth_key.to_vec());
+
+            let mut inner = vec![];
+            CLIENT_DH_INNER_DATA_ID.tl_write(&mut inner)?;
+            nonce.tl_write(&mut inner)?;
+            server_nonce.tl_write(&mut inner)?;
+            retry_id.tl_write(&mut inner)?;
+            TLBytes::from_bytes(g_b.to_vec()).tl_write(&mut inner)?;
+            let mut data_with_hash = sha1(&inner).to_vec();
+            data_with_hash.extend_from_slice(&inner);
+            let padding = (16 - data_with_hash.len() % 16) % 16;
+            data_with_hash.extend((0..padding).map(|_| rand::random::<u8>()));
+
+            let mut request = vec![];
+            SET_CLIENT_DH_PARAMS_ID.tl_write(&mut request)?;
+            nonce.tl_write(&mut request)?;
+            server_nonce.tl_write(&mut request)?;
+            TLBytes::from_bytes(aes_ige_encrypt(&key, &iv, &data_with_hash))
+                .tl_write(&mut request)?;
+            let answer = self.call(&request)?;
+            let input = &mut answer.as_slice();
+
+            let id = u32::tl_read(input)?;
+            check_nonce(input, nonce)?;
+            check_nonce(input, server_nonce)?;
+            let new_nonce_hash = <[u8; 16]>::tl_read(input)?;
+            let number = match id {
+                DH_GEN_OK_ID => 1,
+                DH_GEN_RETRY_ID => 2,
+                DH_GEN_FAIL_ID => 3,
+                _ => {
+                    return Err(MyError::UnknownConstructor {
+                        id: id as i32,
+                        expected: "Set_client_DH_params_answer",
+                    })
+                }
+            };
+            let aux_hash = &sha1(&auth_key)[..8];
+            if new_nonce_hash != expected_new_nonce_hash(new_nonce, number, aux_hash) {
+                return Err(MyError::HashMismatch);
+            }
+            match number {
+                1 => {
+                    let server_salt = LittleEndian::read_i64(&new_nonce[..8])
+                        ^ LittleEndian::read_i64(&server_nonce[..8]);
+                    return Ok(AuthKey::new(auth_key, server_salt, params.server_time));
+                }
+                2 => retry_id = LittleEndian::read_i64(aux_hash),
+                _ => return Err(MyError::DhFailed),
+            }
+        }
+        Err(MyError::DhFailed)
+    }
+
+    /// Send `body` as an unencrypted message and return the body of the answer
+    fn call(&mut self, body: &[u8]) -> MyResult<Vec<u8>> {
+        let mut request = vec![];
+        0u64.tl_write(&mut request)?;
+        self.message_ids.next_id().tl_write(&mut request)?;
+        (body.len() as u32).tl_write(&mut request)?;
+        request.extend_from_slice(body);
+        self.transport.send_package(&request)?;
+
+        let answer = self.transport.recv_package()?;
+        if answer.len() == 4 {
+            return Err(MyError::Transport {
+                code: LittleEndian::read_i32(&answer),
+            });
+        }
+        let input = &mut answer.as_slice();
+        if u64::tl_read(input)? != 0 {
+            return Err(MyError::UnknownConstructor {
+                id: 0,
+                expected: "unencrypted message",
+            });
+        }
+        let _message_id = i64::tl_read(input)?;
+        let length = u32::tl_read(input)? as usize;
+        if length > input.len() {
+            return Err(MyError::Truncated);
+        }
+        Ok(input[..length].to_vec())
+    }
+}
+
+fn expect_id(input: &mut &[u8], expected_id: u32, expected: &'static str) -> MyResult<()> {
+    let id = u32::tl_read(input)?;
+    if id != expected_id {
+        return Err(MyError::UnknownConstructor {
+            id: id as i32,
+            expected,
+        });
+    }
+    Ok(())
+}
+
+fn check_nonce(input: &mut &[u8], nonce: &[u8; 16]) -> MyResult<()> {
+    if <[u8; 16]>::tl_read(input)? != *nonce {
+        return Err(MyError::NonceMismatch);
+    }
+    Ok(())
+}
+
+/// Parse decrypted `answer_with_hash` of `server_DH_params_ok`
+fn parse_dh_params(answer: &[u8], nonce: &[u8; 16], server_nonce: &[u8; 16]) -> MyResult<DhParams> {
+    if answer.len() < 20 {
+        return Err(MyError::Truncated);
+    }
+    let (hash, answer) = answer.split_at(20);
+    let input = &mut &answer[..];
+    expect_id(input, SERVER_DH_INNER_DATA_ID, "Server_DH_inner_data")?;
+    check_nonce(input, nonce)?;
+    check_nonce(input, server_nonce)?;
+    let g = i32::tl_read(input)?;
+    let dh_prime = BigNum::from_slice(TLBytes::tl_read(input)?.as_bytes())?;
+    let g_a = BigNum::from_slice(TLBytes::tl_read(input)?.as_bytes())?;
+    let server_time = i32::tl_read(input)?;
+    // the rest is padding
+    let length = answer.len() - input.len();
+    if sha1(&answer[..length])[..] != *hash {
+        return Err(MyError::HashMismatch);
+    }
+
+    check_dh_prime(g, &dh_prime)?;
+    check_dh_value(&g_a, &dh_prime)?;
+    Ok(DhParams {
+        g: g as u32,
+        dh_prime,
+        g_a,
+        server_time,
+    })
+}
+
+/// `dh_prime` must be a 2048-bit safe prime, for which `g` generates a subgroup of order
+/// `(dh_prime - 1) / 2`
+fn check_dh_prime(g: i32, dh_prime: &BigNumRef) -> MyResult<()> {
+    if dh_prime.num_bits() != 2048 {
+        return Err(MyError::UnsafeDhParams {
+            reason: "dh_prime is not 2048-bit",
+        });
+    }
+    let residue = |m: u32| -> MyResult<u32> {
+        let mut context = BigNumContext::new()?;
+        let mut result = BigNum::new()?;
+        result.checked_rem(dh_prime, &*BigNum::from_u32(m)?, &mut context)?;
+        Ok(result
+            .to_vec()
+            .iter()
+            .fold(0, |x, y| x << 8 | u32::from(*y)))
+    };
+    let generates = match g {
+        2 => residue(8)? == 7,
+        3 => residue(3)? == 2,
+        4 => true,
+        5 => [1, 4].contains(&residue(5)?),
+        6 => [19, 23].contains(&residue(24)?),
+        7 => [3, 5, 6].contains(&residue(7)?),
+        _ => false,
+    };
+    if !generates {
+        return Err(MyError::UnsafeDhParams {
+            reason: "g does not generate a subgroup of order (dh_prime - 1) / 2",
+        });
+    }
+    if *dh_prime == *BigNum::from_hex_str(KNOWN_DH_PRIME)? {
+        return Ok(());
+    }
+    let mut context = BigNumContext::new()?;
+    let mut half = BigNum::new()?;
+    half.rshift1(dh_prime)?;
+    if !dh_prime.is_prime(64, &mut context)? || !half.is_prime(64, &mut context)? {
+        return Err(MyError::UnsafeDhParams {
+            reason: "dh_prime is not a safe prime",
+        });
+    }
+    Ok(())
+}
+
+/// `g_a` and `g_b` must be within `2^(2048-64) ..= dh_prime - 2^(2048-64)`
+fn check_dh_value(value: &BigNumRef, dh_prime: &BigNumRef) -> MyResult<()> {
+    let mut low = BigNum::new()?;
+    low.lshift(&*BigNum::from_u32(1)?, 2048 - 64)?;
+    let mut high = BigNum::new()?;
+    high.checked_sub(dh_prime, &low)?;
+    if *value < *low || *value > *high {
+        return Err(MyError::UnsafeDhParams {
+            reason: "g_a or g_b is out of the safe range",
+        });
+    }
+    Ok(())
+}
+
+/// `new_nonce_hash1`, `2` or `3` of `dh_gen_ok`, `dh_gen_retry` or `dh_gen_fail`
+fn expected_new_nonce_hash(new_nonce: &[u8; 32], number: u8, aux_hash: &[u8]) -> [u8; 16] {
+    let hash = sha1(&[&new_nonce[..], &[number], aux_hash].concat());
+    let mut result = [0u8; 16];
+    result.copy_from_slice(&hash[4..]);
+    result
+}
+
+/// Big endian bytes without leading zeros
+fn be_bytes(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|x| *x != 0).unwrap_or(7);
+    bytes[start..].to_vec()
+}
+
+/// Factors `p < q` of `pq` sent by the server, a big endian number of at most 64 bits
+fn factorize_pq(pq: &[u8]) -> MyResult<(u64, u64)> {
+    if pq.is_empty() || pq.len() > 8 {
+        return Err(MyError::InvalidResponse {
+            reason: "pq is empty or longer than 64 bits",
+        });
+    }
+    let value = BigEndian::read_uint(pq, pq.len());
+    if value <= 1 {
+        return Err(MyError::InvalidResponse {
+            reason: "pq is not greater than 1",
+        });
+    }
+    let invalid = MyError::InvalidResponse {
+        reason: "pq is not a product of two factors",
+    };
+    if BigNum::from_slice(pq)?.is_prime(64, &mut *BigNumContext::new()?)? {
+        return Err(invalid);
+    }
+    match split_pq(value) {
+        Some((p, q)) if 1 < p && p < q && p.checked_mul(q) == Some(value) => Ok((p, q)),
+        _ => Err(invalid),
+    }
+}
+
+fn left_pad_256(bytes: &[u8]) -> [u8; 256] {
+    let mut result = [0u8; 256];
+    result[256 - bytes.len()..].copy_from_slice(bytes);
+    result
+}
+
+#[cfg(test)]
+struct MockTransport {
+    sent: Vec<Vec<u8>>,
+    answers: Vec<Vec<u8>>,
+}
+
+#[cfg(test)]
+impl Transport for MockTransport {
+    fn send_package(&mut self, input: &[u8]) -> MyResult<()> {
+        self.sent.push(input.to_vec());
+        Ok(())
+    }
+
+    fn recv_package(&mut self) -> MyResult<Vec<u8>> {
+        Ok(self.answers.remove(0))
+    }
+}
+
+/// Server side of the handshake, answering with the given `set_client_DH_params` results
+#[cfg(test)]
+struct MockServer {
+    nonce: [u8; 16],
+    server_nonce: [u8; 16],
+    new_nonce: [u8; 32],
+    a: BigNum,
+    /// `1`, `2` or `3` for `dh_gen_ok`, `dh_gen_retry` or `dh_gen_fail`
+    numbers: Vec<u8>,
+    corrupt_hash: bool,
+    requests: Vec<Vec<u8>>,
+    retry_ids: Vec<i64>,
+    auth_keys: Vec<[u8; 256]>,
+    answer: Vec<u8>,
+}
+
+#[cfg(test)]
+impl MockServer {
+    fn new(numbers: Vec<u8>) -> Self {
+        MockServer {
+            nonce: [1u8; 16],
+            server_nonce: [2u8; 16],
+            new_nonce: [3u8; 32],
+            a: BigNum::from_slice(&[0x5au8; 256]).unwrap(),
+            numbers,
+            corrupt_hash: false,
+            requests: vec![],
+            retry_ids: vec![],
+            auth_keys: vec![],
+            answer: vec![],
+        }
+    }
+
+    fn g_a(&self) -> BigNum {
+        let dh_prime = BigNum::from_hex_str(KNOWN_DH_PRIME).unwrap();
+        let mut g_a = BigNum::new().unwrap();
+        g_a.mod_exp(
+            &BigNum::from_u32(3).unwrap(),
+            &self.a,
+            &dh_prime,
+            &mut BigNumContext::new().unwrap(),
+        )
+        .unwrap();
+        g_a
+    }
+
+    /// Decrypted `answer_with_hash` of `server_DH_params_ok`
+    fn answer_with_hash(&self, g: i32, dh_prime: &BigNumRef, g_a: &BigNumRef) -> Vec<u8> {
+        let mut inner = vec![];
+        SERVER_DH_INNER_DATA_ID.tl_write(&mut inner).unwrap();
+        self.nonce.tl_write(&mut inner).unwrap();
+        self.server_nonce.tl_write(&mut inner).unwrap();
+        g.tl_write(&mut inner).unwrap();
+        TLBytes::from_bytes(dh_prime.to_vec())
+            .tl_write(&mut inner)
+            .unwrap();
+        TLBytes::from_bytes(g_a.to_vec())
+            .tl_write(&mut inner)
+            .unwrap();
+        1_500_000_000i32.tl_write(&mut inner).unwrap();
+        let mut result = sha1(&inner).to_vec();
+        result.extend_from_slice(&inner);
+        result.resize((result.len() + 15) & !15, 0xff);
+        result
+    }
+
+    fn server_dh_params_ok(&self, answer_with_hash: &[u8]) -> Vec<u8> {
+        let (key, iv) = tmp_aes_key_iv(&self.server_nonce, &self.new_nonce);
+        let mut body = vec![];
+        SERVER_DH_PARAMS_OK_ID.tl_write(&mut body).unwrap();
+        self.nonce.tl_write(&mut body).unwrap();
+        self.server_nonce.tl_write(&mut body).unwrap();
+        TLBytes::from_bytes(aes_ige_encrypt(&key, &iv, answer_with_hash))
+            .tl_write(&mut body)
+            .unwrap();
+        body
+    }
+
+    fn set_client_dh_params_answer(&mut self, body: &[u8]) -> Vec<u8> {
+        let input = &mut &body[..];
+        assert_eq!(u32::tl_read(input).unwrap(), SET_CLIENT_DH_PARAMS_ID);
+        check_nonce(input, &self.nonce).unwrap();
+        check_nonce(input, &self.server_nonce).unwrap();
+        let encrypted = TLBytes::tl_read(input).unwrap().into_bytes();
+        assert_eq!(encrypted.len() & 15, 0);
+        let (key, iv) = tmp_aes_key_iv(&self.server_nonce, &self.new_nonce);
+        let data_with_hash = aes_ige_decrypt(&key, &iv, &encrypted);
+        let input = &mut &data_with_hash[20..];
+        expect_id(input, CLIENT_DH_INNER_DATA_ID, "Client_DH_Inner_Data").unwrap();
+        check_nonce(input, &self.nonce).unwrap();
+        check_nonce(input, &self.server_nonce).unwrap();
+        self.retry_ids.push(i64::tl_read(input).unwrap());
+        let g_b = BigNum::from_slice(TLBytes::tl_read(input).unwrap().as_bytes()).unwrap();
+        // random padding to a multiple of 16 bytes follows the hashed data
+        let length = data_with_hash.len() - 20 - input.len();
+        assert!(input.len() < 16);
+        assert_eq!(
+            sha1(&data_with_hash[20..20 + length])[..],
+            data_with_hash[..20]
+        );
+
+        let mut auth_key = BigNum::new().unwrap();
+        auth_key
+            .mod_exp(
+                &g_b,
+                &self.a,
+                &BigNum::from_hex_str(KNOWN_DH_PRIME).unwrap(),
+                &mut BigNumContext::new().unwrap(),
+            )
+            .unwrap();
+        let auth_key = left_pad_256(&auth_key.to_vec());
+        self.auth_keys.push(auth_key);
+        let number = self.numbers.remove(0);
+        let mut hash = expected_new_nonce_hash(&self.new_nonce, number, &sha1(&auth_key)[..8]);
+        if self.corrupt_hash {
+            hash[0] ^= 1;
+        }
+        let mut answer = vec![];
+        [DH_GEN_OK_ID, DH_GEN_RETRY_ID, DH_GEN_FAIL_ID][usize::from(number) - 1]
+            .tl_write(&mut answer)
+            .unwrap();
+        self.nonce.tl_write(&mut answer).unwrap();
+        self.server_nonce.tl_write(&mut answer).unwrap();
+        hash.tl_write(&mut answer).unwrap();
+        answer
+    }
+}
+
+#[cfg(test)]
+impl Transport for MockServer {
+    fn send_package(&mut self, input: &[u8]) -> MyResult<()> {
+        let body = &input[20..];
+        self.requests.push(body.to_vec());
+        let mut answer = vec![];
+        match LittleEndian::read_u32(body) {
+            REQ_PQ_ID => {
+                self.nonce.copy_from_slice(&body[4..20]);
+                RES_PQ_ID.tl_write(&mut answer)?;
+                self.nonce.tl_write(&mut answer)?;
+                self.server_nonce.tl_write(&mut answer)?;
+                TLBytes::from_bytes(vec![0x17, 0xed, 0x48, 0x94, 0x1a, 0x08, 0xf9, 0x81])
+                    .tl_write(&mut answer)?;
+                vec![server_public_key_fingerprint()].tl_write(&mut answer)?;
+            }
+            REQ_DH_PARAMS_ID => {
+                SERVER_DH_PARAMS_FAIL_ID.tl_write(&mut answer)?;
+                self.nonce.tl_write(&mut answer)?;
+                self.server_nonce.tl_write(&mut answer)?;
+                [0u8; 16].tl_write(&mut answer)?;
+            }
+            _ => answer = self.set_client_dh_params_answer(body),
+        }
+        self.answer = unencrypted_message(&answer);
+        Ok(())
+    }
+
+    fn recv_package(&mut self) -> MyResult<Vec<u8>> {
+        Ok(std::mem::take(&mut self.answer))
+    }
+}
+
+#[cfg(test)]
+fn unencrypted_message(body: &[u8]) -> Vec<u8> {
+    let mut result = vec![];
+    0u64.tl_write(&mut result).unwrap();
+    MessageIdGenerator::new()
+        .next_id()
+        .tl_write(&mut result)
+        .unwrap();
+    (body.len() as u32).tl_write(&mut result).unwrap();
+    result.extend_from_slice(body);
+    result
+}
+
+#[test]
+fn test_known_dh_prime() {
+    let dh_prime = BigNum::from_hex_str(KNOWN_DH_PRIME).unwrap();
+    let mut context = BigNumContext::new().unwrap();
+    let mut half = BigNum::new().unwrap();
+    half.rshift1(&dh_prime).unwrap();
+    assert!(dh_prime.is_prime(64, &mut context).unwrap());
+    assert!(half.is_prime(64, &mut context).unwrap());
+    check_dh_prime(3, &dh_prime).unwrap();
+    assert!(check_dh_prime(2, &dh_prime).is_err());
+
+    let g_a = BigNum::from_u32(2).unwrap();
+    assert!(check_dh_value(&g_a, &dh_prime).is_err());
+    let mut g_a = BigNum::new().unwrap();
+    g_a.rshift1(&dh_prime).unwrap();
+    check_dh_value(&g_a, &dh_prime).unwrap();
+}
+
+#[test]
+fn test_res_pq_nonce_mismatch() {
+    let mut body = vec![];
+    RES_PQ_ID.tl_write(&mut body).unwrap();
+    [1u8; 16].tl_write(&mut body).unwrap();
+    [2u8; 16].tl_write(&mut body).unwrap();
+    TLBytes::from_bytes(vec![0x17, 0xed, 0x48, 0x94, 0x1a, 0x08, 0xf9, 0x81])
+        .tl_write(&mut body)
+        .unwrap();
+    vec![server_public_key_fingerprint()]
+        .tl_write(&mut body)
+        .unwrap();
+    let mut transport = MockTransport {
+        sent: vec![],
+        answers: vec![unencrypted_message(&body)],
+    };
+    match AuthKeyGenerator::new(&mut transport).generate() {
+        Err(MyError::NonceMismatch) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    let sent = &transport.sent[0];
+    assert_eq!(sent.len(), 8 + 8 + 4 + 20);
+    assert_eq!(&sent[..8], &[0u8; 8]);
+    assert_eq!(LittleEndian::read_u32(&sent[20..24]), REQ_PQ_ID);
+}
+
+#[test]
+fn test_factorize_pq() {
+    assert_eq!(
+        factorize_pq(&[0x17, 0xed, 0x48, 0x94, 0x1a, 0x08, 0xf9, 0x81]).unwrap(),
+        (0x494c_553b, 0x5391_1073)
+    );
+    for pq in [
+        &[][..],
+        &[0x01],
+        &[0x00, 0x01],
+        &[0x07],
+        &[0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xe7],
+        &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+    ]
+    .iter()
+    {
+        match factorize_pq(pq) {
+            Err(MyError::InvalidResponse { .. }) => {}
+            x => panic!("{:x?}: {:?}", pq, x),
+        }
+    }
+    // p = q is rejected too
+    match factorize_pq(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x31]) {
+        Err(MyError::InvalidResponse { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+}
+
+#[test]
+fn test_new_nonce_hash() {
+    let new_nonce = [3u8; 32];
+    let aux_hash = [4u8; 8];
+    let hash = sha1(&[&new_nonce[..], &[1], &aux_hash[..]].concat());
+    assert_eq!(
+        expected_new_nonce_hash(&new_nonce, 1, &aux_hash)[..],
+        hash[4..]
+    );
+    assert_eq!(be_bytes(0x494c_553b), vec![0x49, 0x4c, 0x55, 0x3b]);
+    assert_eq!(left_pad_256(&[1])[255], 1);
+}
+
+#[test]
+fn test_req_dh_params() {
+    let mut server = MockServer::new(vec![]);
+    match AuthKeyGenerator::new(&mut server).generate() {
+        Err(MyError::DhFailed) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    let input = &mut &server.requests[1][..];
+    assert_eq!(u32::tl_read(input).unwrap(), REQ_DH_PARAMS_ID);
+    check_nonce(input, &server.nonce).unwrap();
+    check_nonce(input, &server.server_nonce).unwrap();
+    // p and q are big endian without leading zeros
+    assert_eq!(
+        TLBytes::tl_read(input).unwrap().as_bytes(),
+        &[0x49, 0x4c, 0x55, 0x3b]
+    );
+    assert_eq!(
+        TLBytes::tl_read(input).unwrap().as_bytes(),
+        &[0x53, 0x91, 0x10, 0x73]
+    );
+    assert_eq!(
+        i64::tl_read(input).unwrap(),
+        server_public_key_fingerprint()
+    );
+    assert_eq!(TLBytes::tl_read(input).unwrap().as_bytes().len(), 256);
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_server_dh_params() {
+    let server = MockServer::new(vec![]);
+    let dh_prime = BigNum::from_hex_str(KNOWN_DH_PRIME).unwrap();
+    let g_a = server.g_a();
+    let answer = server.answer_with_hash(3, &dh_prime, &g_a);
+    let params = parse_dh_params(&answer, &server.nonce, &server.server_nonce).unwrap();
+    assert_eq!(params.g, 3);
+    assert_eq!(params.dh_prime, dh_prime);
+    assert_eq!(params.g_a, g_a);
+    assert_eq!(params.server_time, 1_500_000_000);
+
+    let check = |answer: &[u8]| parse_dh_params(answer, &server.nonce, &server.server_nonce);
+    let mut corrupted = answer.clone();
+    corrupted[0] ^= 1;
+    match check(&corrupted) {
+        Err(MyError::HashMismatch) => {}
+        x => panic!("{:?}", x.map(|_| ())),
+    }
+    match parse_dh_params(&answer, &[9u8; 16], &server.server_nonce) {
+        Err(MyError::NonceMismatch) => {}
+        x => panic!("{:?}", x.map(|_| ())),
+    }
+    let small_prime = BigNum::from_u32(23).unwrap();
+    let unsafe_answers = [
+        server.answer_with_hash(2, &dh_prime, &g_a),
+        server.answer_with_hash(3, &small_prime, &g_a),
+        server.answer_with_hash(3, &dh_prime, &BigNum::from_u32(3).unwrap()),
+    ];
+    for answer in unsafe_answers.iter() {
+        match check(answer) {
+            Err(MyError::UnsafeDhParams { .. }) => {}
+            x => panic!("{:?}", x.map(|_| ())),
+        }
+    }
+
+    let mut server = MockServer::new(vec![]);
+    let mut body = vec![];
+    SERVER_DH_PARAMS_OK_ID.tl_write(&mut body).unwrap();
+    server.nonce.tl_write(&mut body).unwrap();
+    server.server_nonce.tl_write(&mut body).unwrap();
+    TLBytes::from_bytes(vec![0u8; 600])
+        .tl_write(&mut body)
+        .unwrap();
+    let (nonce, server_nonce, new_nonce) = (server.nonce, server.server_nonce, server.new_nonce);
+    match AuthKeyGenerator::new(&mut server).exchange_keys(&nonce, &server_nonce, &new_nonce, &body)
+    {
+        Err(MyError::BadMessageLength { length }) => assert_eq!(length, 600),
+        x => panic!("unexpected {:?}", x),
+    }
+}
+
+#[cfg(test)]
+fn exchange_keys(server: &mut MockServer, max_retries: usize) -> MyResult<AuthKey> {
+    let dh_prime = BigNum::from_hex_str(KNOWN_DH_PRIME).unwrap();
+    let answer = server.server_dh_params_ok(&server.answer_with_hash(3, &dh_prime, &server.g_a()));
+    let (nonce, server_nonce, new_nonce) = (server.nonce, server.server_nonce, server.new_nonce);
+    let mut generator = AuthKeyGenerator::new(server);
+    generator.max_retries = max_retries;
+    generator.exchange_keys(&nonce, &server_nonce, &new_nonce, &answer)
+}
+
+#[test]
+fn test_dh_gen_retry() {
+    let mut server = MockServer::new(vec![2, 1]);
+    let auth_key = exchange_keys(&mut server, 5).unwrap();
+    assert_eq!(auth_key.key[..], server.auth_keys[1][..]);
+    assert_eq!(auth_key.server_time, 1_500_000_000);
+    assert_eq!(
+        auth_key.server_salt,
+        0x0303_0303_0303_0303 ^ 0x0202_0202_0202_0202
+    );
+    // the retry carries auth_key_aux_hash of the key of the failed attempt
+    let aux_hash = LittleEndian::read_i64(&sha1(&server.auth_keys[0])[..8]);
+    assert_eq!(server.retry_ids, vec![0, aux_hash]);
+
+    let mut server = MockServer::new(vec![2, 2, 2]);
+    match exchange_keys(&mut server, 1) {
+        Err(MyError::DhFailed) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    assert_eq!(server.retry_ids.len(), 2);
+}
+
+#[test]
+fn test_new_nonce_hash_mismatch() {
+    for number in 1..=3 {
+        let mut server = MockServer::new(vec![number]);
+        server.corrupt_hash = true;
+        match exchange_keys(&mut server, 5) {
+            Err(MyError::HashMismatch) => {}
+            x => panic!("{}: unexpected {:?}", number, x),
+        }
+    }
+    let mut server = MockServer::new(vec![3]);
+    match exchange_keys(&mut server, 5) {
+        Err(MyError::DhFailed) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+}
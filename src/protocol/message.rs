@@ -0,0 +1,205 @@
+//! Encrypted messages of MTProto 2.0, see https://core.telegram.org/mtproto/description
+
+use byteorder::{ByteOrder, LittleEndian};
+use rand::Rng;
+
+use crate::{
+    protocol::auth_key::AuthKey,
+    tl_types::TLType,
+    utils::{
+        aes::{aes_ige_decrypt, aes_ige_encrypt},
+        kdf::{aes_key_iv, msg_key, Sender},
+        MyError, MyResult,
+    },
+};
+
+/// `server_salt`, `session_id`, `msg_id`, `seq_no` and `message_data_length`
+const HEADER_LENGTH: usize = 32;
+const MIN_PADDING: usize = 12;
+const MAX_PADDING: usize = 1024;
+
+/// Plaintext of an encrypted message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub salt: i64,
+    pub session_id: i64,
+    pub msg_id: i64,
+    pub seq_no: i32,
+    /// Serialized payload
+    pub body: Vec<u8>,
+}
+
+impl Message {
+    pub fn new<T: TLType>(
+        salt: i64,
+        session_id: i64,
+        msg_id: i64,
+        seq_no: i32,
+        payload: &T,
+    ) -> MyResult<Self> {
+        let mut body = Vec::with_capacity(payload.serialized_len());
+        payload.tl_write(&mut body)?;
+        Ok(Message {
+            salt,
+            session_id,
+            msg_id,
+            seq_no,
+            body,
+        })
+    }
+
+    pub fn payload<T: TLType>(&self) -> MyResult<T> {
+        T::tl_read(&mut self.body.as_slice())
+    }
+
+    /// `auth_key_id`, `msg_key` and the encrypted message with random padding
+    pub fn encrypt(&self, auth_key: &AuthKey, sender: Sender) -> MyResult<Vec<u8>> {
+        let mut plaintext = Vec::with_capacity(HEADER_LENGTH + self.body.len() + MAX_PADDING);
+        self.salt.tl_write(&mut plaintext)?;
+        self.session_id.tl_write(&mut plaintext)?;
+        self.msg_id.tl_write(&mut plaintext)?;
+        self.seq_no.tl_write(&mut plaintext)?;
+        (self.body.len() as i32).tl_write(&mut plaintext)?;
+        plaintext.extend_from_slice(&self.body);
+        let padding = padding_length(plaintext.len());
+        let mut rng = rand::thread_rng();
+        plaintext.extend((0..padding).map(|_| rng.gen::<u8>()));
+
+        let msg_key = msg_key(&auth_key.key, sender, &plaintext);
+        let (key, iv) = aes_key_iv(&auth_key.key, &msg_key, sender);
+        let mut result = Vec::with_capacity(24 + plaintext.len());
+        auth_key.id.tl_write(&mut result)?;
+        msg_key.tl_write(&mut result)?;
+        result.extend_from_slice(&aes_ige_encrypt(&key, &iv, &plaintext));
+        Ok(result)
+    }
+
+    /// Decrypt a message from `sender`, checking it belongs to `auth_key` and `session_id`
+    pub fn decrypt(
+        auth_key: &AuthKey,
+        sender: Sender,
+        session_id: i64,
+        data: &[u8],
+    ) -> MyResult<Self> {
+        if data.len() == 4 {
+            return Err(MyError::Transport {
+                code: LittleEndian::read_i32(data),
+            });
+        }
+        let input = &mut &data[..];
+        let auth_key_id = u64::tl_read(input)?;
+        if auth_key_id != auth_key.id {
+            return Err(MyError::AuthKeyMismatch { auth_key_id });
+        }
+        let msg_key = <[u8; 16]>::tl_read(input)?;
+        let encrypted = *input;
+        let length = encrypted.len();
+        if length < HEADER_LENGTH + MIN_PADDING || length & 15 != 0 {
+            return Err(MyError::BadMessageLength { length });
+        }
+        let (key, iv) = aes_key_iv(&auth_key.key, &msg_key, sender);
+        let plaintext = aes_ige_decrypt(&key, &iv, encrypted);
+        if msg_key != self::msg_key(&auth_key.key, sender, &plaintext) {
+            return Err(MyError::MsgKeyMismatch);
+        }
+
+        let input = &mut plaintext.as_slice();
+        let salt = i64::tl_read(input)?;
+        let message_session_id = i64::tl_read(input)?;
+        if message_session_id != session_id {
+            return Err(MyError::SessionMismatch {
+                session_id: message_session_id,
+            });
+        }
+        let msg_id = i64::tl_read(input)?;
+        let seq_no = i32::tl_read(input)?;
+        let length = i32::tl_read(input)?;
+        let padding = input.len() as i64 - i64::from(length);
+        if length < 0
+            || length % 4 != 0
+            || padding < MIN_PADDING as i64
+            || padding > MAX_PADDING as i64
+        {
+            return Err(MyError::BadMessageLength {
+                length: length as usize,
+            });
+        }
+        Ok(Message {
+            salt,
+            session_id,
+            msg_id,
+            seq_no,
+            body: input[..length as usize].to_vec(),
+        })
+    }
+}
+
+/// Random padding of 12 to 1024 bytes, making `length` a multiple of 16
+fn padding_length(length: usize) -> usize {
+    let min = MIN_PADDING + (16 - (length + MIN_PADDING) % 16) % 16;
+    let blocks = rand::thread_rng().gen_range(0, (MAX_PADDING - min) / 16 + 1);
+    min + blocks * 16
+}
+
+#[cfg(test)]
+fn test_auth_key() -> AuthKey {
+    let mut key = [0u8; 256];
+    for (i, x) in key.iter_mut().enumerate() {
+        *x = (i * 13 + 5) as u8;
+    }
+    AuthKey::new(key, 0x1122_3344, 0)
+}
+
+#[test]
+fn test_round_trip() {
+    let auth_key = test_auth_key();
+    let message = Message::new(7, 42, 0x5e0b_7000_0000_0004, 1, &vec![1i64, 2, 3]).unwrap();
+    let data = message.encrypt(&auth_key, Sender::Server).unwrap();
+    assert_eq!(LittleEndian::read_u64(&data), auth_key.id);
+    assert_eq!((data.len() - 24) % 16, 0);
+
+    let decrypted = Message::decrypt(&auth_key, Sender::Server, 42, &data).unwrap();
+    assert_eq!(decrypted, message);
+    assert_eq!(decrypted.payload::<Vec<i64>>().unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_rejected() {
+    let auth_key = test_auth_key();
+    let message = Message::new(7, 42, 4, 1, &1i32).unwrap();
+    let data = message.encrypt(&auth_key, Sender::Client).unwrap();
+
+    match Message::decrypt(&auth_key, Sender::Client, 43, &data) {
+        Err(MyError::SessionMismatch { session_id: 42 }) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    match Message::decrypt(&auth_key, Sender::Server, 42, &data) {
+        Err(MyError::MsgKeyMismatch) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    let mut tampered = data.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    match Message::decrypt(&auth_key, Sender::Client, 42, &tampered) {
+        Err(MyError::MsgKeyMismatch) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    tampered = data.clone();
+    tampered[0] ^= 1;
+    match Message::decrypt(&auth_key, Sender::Client, 42, &tampered) {
+        Err(MyError::AuthKeyMismatch { .. }) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    match Message::decrypt(&auth_key, Sender::Client, 42, &(-404i32).to_le_bytes()) {
+        Err(MyError::Transport { code: -404 }) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+}
+
+#[test]
+fn test_padding_length() {
+    for length in 32..96 {
+        let padding = padding_length(length);
+        assert!((MIN_PADDING..=MAX_PADDING).contains(&padding));
+        assert_eq!((length + padding) % 16, 0);
+    }
+}
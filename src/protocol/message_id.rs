@@ -0,0 +1,169 @@
+//! `msg_id` and `seq_no` of messages, see https://core.telegram.org/mtproto/description
+
+use std::{
+    sync::atomic::{AtomicI32, AtomicI64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Time based, strictly increasing `msg_id` and `seq_no` of one session, shared between threads
+#[derive(Debug, Default)]
+pub struct MessageIdGenerator {
+    /// Server time minus local time, in nanoseconds
+    time_offset: AtomicI64,
+    last_id: AtomicI64,
+    /// Content-related messages sent in this session
+    content_related: AtomicI32,
+}
+
+impl MessageIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unix time of server (approximately) times 2^32, divisible by 4 and greater than any id
+    /// given before
+    pub fn next_id(&self) -> i64 {
+        let candidate = to_msg_id(local_time() + self.time_offset.load(Ordering::SeqCst));
+        let mut last = self.last_id.load(Ordering::SeqCst);
+        loop {
+            let id = if candidate > last {
+                candidate
+            } else {
+                last + 4
+            };
+            match self
+                .last_id
+                .compare_exchange(last, id, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => return id,
+                Err(x) => last = x,
+            }
+        }
+    }
+
+    /// `seq_no` of the next message, content-related ones (requiring an acknowledgment) are
+    /// counted
+    pub fn next_seq_no(&self, content_related: bool) -> i32 {
+        if content_related {
+            self.content_related.fetch_add(1, Ordering::SeqCst) * 2 + 1
+        } else {
+            self.content_related.load(Ordering::SeqCst) * 2
+        }
+    }
+
+    /// Start counting `seq_no` from zero, e.g. after `new_session_created`
+    pub fn reset_seq_no(&self) {
+        self.content_related.store(0, Ordering::SeqCst);
+    }
+
+    /// Correct time to `server_time` in seconds, e.g. `server_time` of `server_DH_inner_data`
+    pub fn set_server_time(&self, server_time: i64) {
+        self.time_offset.store(
+            server_time * NANOS_PER_SECOND - local_time(),
+            Ordering::SeqCst,
+        );
+    }
+
+    /// Correct time to the one `msg_id` of a server message was generated at
+    pub fn sync_with_msg_id(&self, msg_id: i64) {
+        self.time_offset
+            .store(from_msg_id(msg_id) - local_time(), Ordering::SeqCst);
+    }
+
+    /// Unix time of server in seconds
+    pub fn server_time(&self) -> i64 {
+        (local_time() + self.time_offset.load(Ordering::SeqCst)) / NANOS_PER_SECOND
+    }
+
+    /// Let the next id follow the clock even if it went back, e.g. after `msg_id too high`,
+    /// ids given before are no longer a lower bound
+    pub fn reset_last_id(&self) {
+        self.last_id.store(0, Ordering::SeqCst);
+    }
+
+    /// Server time minus local time, in seconds
+    pub fn time_offset(&self) -> i64 {
+        self.time_offset.load(Ordering::SeqCst) / NANOS_PER_SECOND
+    }
+}
+
+/// Unix time in nanoseconds
+fn local_time() -> i64 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    now.as_secs() as i64 * NANOS_PER_SECOND + i64::from(now.subsec_nanos())
+}
+
+fn to_msg_id(time: i64) -> i64 {
+    let seconds = time / NANOS_PER_SECOND;
+    let fraction = ((time % NANOS_PER_SECOND) << 32) / NANOS_PER_SECOND;
+    ((seconds << 32) | fraction) & !3
+}
+
+fn from_msg_id(msg_id: i64) -> i64 {
+    (msg_id >> 32) * NANOS_PER_SECOND + (((msg_id & 0xffff_ffff) * NANOS_PER_SECOND) >> 32)
+}
+
+#[test]
+fn test_next_id() {
+    let generator = MessageIdGenerator::new();
+    let first = generator.next_id();
+    assert_eq!(first % 4, 0);
+    assert!(((first >> 32) - local_time() / NANOS_PER_SECOND).abs() <= 1);
+    assert!(generator.next_id() > first);
+
+    let generator = std::sync::Arc::new(MessageIdGenerator::new());
+    let handles = (0..4)
+        .map(|_| {
+            let generator = generator.clone();
+            std::thread::spawn(move || (0..1000).map(|_| generator.next_id()).collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    let mut ids = vec![];
+    for handle in handles {
+        let thread_ids = handle.join().unwrap();
+        assert!(thread_ids.windows(2).all(|x| x[0] < x[1]));
+        ids.extend(thread_ids);
+    }
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 4000);
+    assert!(ids.iter().all(|x| x % 4 == 0));
+}
+
+#[test]
+fn test_time_offset() {
+    let generator = MessageIdGenerator::new();
+    let server_time = local_time() / NANOS_PER_SECOND + 3600;
+    generator.set_server_time(server_time);
+    assert!((generator.time_offset() - 3600).abs() <= 1);
+    assert!(((generator.next_id() >> 32) - server_time).abs() <= 1);
+
+    // an earlier server time still gives increasing ids
+    let last = generator.next_id();
+    generator.sync_with_msg_id((server_time - 7200) << 32);
+    assert!((generator.time_offset() + 3600).abs() <= 1);
+    assert!(generator.next_id() > last);
+    generator.reset_last_id();
+    assert!(((generator.next_id() >> 32) - (server_time - 7200)).abs() <= 1);
+}
+
+#[test]
+fn test_seq_no() {
+    let generator = MessageIdGenerator::new();
+    assert_eq!(generator.next_seq_no(false), 0);
+    assert_eq!(generator.next_seq_no(true), 1);
+    assert_eq!(generator.next_seq_no(true), 3);
+    assert_eq!(generator.next_seq_no(false), 4);
+    generator.reset_seq_no();
+    assert_eq!(generator.next_seq_no(true), 1);
+}
+
+#[test]
+fn test_msg_id_time() {
+    let time = 1_500_000_000 * NANOS_PER_SECOND + 250_000_000;
+    let msg_id = to_msg_id(time);
+    assert_eq!(msg_id, (1_500_000_000 << 32) | 0x4000_0000);
+    assert_eq!(from_msg_id(msg_id), time);
+}
@@ -0,0 +1,4 @@
+pub mod auth_key;
+pub mod message;
+pub mod message_id;
+pub mod session;
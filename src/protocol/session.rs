@@ -0,0 +1,818 @@
+//! Service messages of a session, see https://core.telegram.org/mtproto/service_messages
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    generate_proto::{functions, object::ObjectRef, registry, tl},
+    protocol::{auth_key::AuthKey, message::Message, message_id::MessageIdGenerator},
+    tl_types::{TLDeserialize, TLType},
+    utils::{kdf::Sender, MyError, MyResult},
+};
+
+/// Constructor id and `req_msg_id` of `rpc_result`, followed by the result
+const RPC_RESULT_HEADER: usize = 12;
+/// `msg_id` too low or too high, the local clock is off
+const MSG_ID_TOO_LOW: i32 = 16;
+const MSG_ID_TOO_HIGH: i32 = 17;
+/// Content-related server messages remembered to drop repeated ones
+const MAX_RECEIVED: usize = 1024;
+
+/// Server message left for the caller once service messages are handled
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// `rpc_result` of the request sent as `req_msg_id`, `body` is the serialized result
+    Result {
+        req_msg_id: i64,
+        body: Vec<u8>,
+    },
+    /// Content-related message which is no answer, e.g. an update
+    Update {
+        msg_id: i64,
+        body: Vec<u8>,
+    },
+    Pong {
+        ping_id: i64,
+    },
+    /// The server created a new session, updates since `first_msg_id` may have been lost
+    NewSession {
+        first_msg_id: i64,
+    },
+    /// The request sent as `msg_id` was rejected, see `bad_msg_notification`
+    BadMessage {
+        msg_id: i64,
+        error_code: i32,
+    },
+}
+
+/// Content-related message kept until answered, or acknowledged if no answer is expected
+#[derive(Debug)]
+struct Pending {
+    /// `msg_id` given to the caller, kept when the message is resent
+    original_id: i64,
+    body: Vec<u8>,
+    acknowledged: bool,
+    expects_answer: bool,
+}
+
+/// Encrypts requests and handles service messages of the server, resending requests when needed
+#[derive(Debug)]
+pub struct Session {
+    auth_key: AuthKey,
+    id: i64,
+    salt: i64,
+    /// Ordered by `valid_since`
+    future_salts: Vec<tl::FutureSalt>,
+    message_ids: MessageIdGenerator,
+    /// Messages sent and not done yet, by current `msg_id`
+    pending: BTreeMap<i64, Pending>,
+    /// Content-related server messages not acknowledged yet
+    to_ack: Vec<i64>,
+    received: BTreeSet<i64>,
+    /// Encrypted messages not taken yet
+    outgoing: Vec<Vec<u8>>,
+}
+
+impl Session {
+    pub fn new(auth_key: AuthKey) -> Self {
+        let message_ids = MessageIdGenerator::new();
+        message_ids.set_server_time(auth_key.server_time.into());
+        Session {
+            id: rand::random(),
+            salt: auth_key.server_salt,
+            auth_key,
+            future_salts: vec![],
+            message_ids,
+            pending: BTreeMap::new(),
+            to_ack: vec![],
+            received: BTreeSet::new(),
+            outgoing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn salt(&self) -> i64 {
+        self.salt
+    }
+
+    pub fn future_salts(&self) -> &[tl::FutureSalt] {
+        &self.future_salts
+    }
+
+    /// Queue `request` and return its `msg_id`, which its `Event::Result` refers to
+    pub fn send<T: TLType>(&mut self, request: &T) -> MyResult<i64> {
+        self.send_content(request, true)
+    }
+
+    pub fn ping(&mut self, ping_id: i64) -> MyResult<i64> {
+        self.send(&functions::Ping { ping_id })
+    }
+
+    /// Ask for `num` salts, which replace the current one as time goes by
+    pub fn request_future_salts(&mut self, num: i32) -> MyResult<i64> {
+        self.send(&functions::GetFutureSalts { num })
+    }
+
+    /// Queue again messages the server did not acknowledge, e.g. after reconnecting
+    pub fn resend_unacknowledged(&mut self) -> MyResult<()> {
+        let ids = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| !pending.acknowledged)
+            .map(|(&msg_id, _)| msg_id)
+            .collect::<Vec<_>>();
+        for msg_id in ids {
+            self.resend(msg_id)?;
+        }
+        Ok(())
+    }
+
+    /// Encrypted messages to send, ending with `msgs_ack` of server messages received so far
+    pub fn take_outgoing(&mut self) -> MyResult<Vec<Vec<u8>>> {
+        if !self.to_ack.is_empty() {
+            let ack = tl::MsgsAck {
+                msg_ids: std::mem::take(&mut self.to_ack),
+            };
+            self.push(&serialize(&ack)?, false)?;
+        }
+        Ok(self.outgoing.drain(..).collect())
+    }
+
+    /// Decrypt a package from the server and handle the service messages in it
+    pub fn handle(&mut self, data: &[u8]) -> MyResult<Vec<Event>> {
+        let message = Message::decrypt(&self.auth_key, Sender::Server, self.id, data)?;
+        let mut events = vec![];
+        if is_container(&message.body) {
+            self.handle_container(&message.body[4..], &mut events)?;
+        } else if self.receive(message.msg_id, message.seq_no) {
+            let object = ObjectRef::tl_deserialize(&mut &message.body[..])?;
+            self.handle_object(message.msg_id, &message.body, object, &mut events)?;
+        }
+        Ok(events)
+    }
+
+    /// Handle messages of a `msg_container`, one whose body does not decode is skipped so that
+    /// events of the others are not lost, and a broken container is handled up to where it breaks
+    fn handle_container(&mut self, body: &[u8], events: &mut Vec<Event>) -> MyResult<()> {
+        let input = &mut &body[..];
+        let count = match i32::tl_read(input) {
+            Ok(count) => count,
+            Err(_) => return Ok(()),
+        };
+        for _ in 0..count {
+            let (msg_id, seq_no, body) = match container_item(input) {
+                Ok(item) => item,
+                Err(_) => return Ok(()),
+            };
+            if !self.receive(msg_id, seq_no) {
+                continue;
+            }
+            if let Ok(object) = ObjectRef::tl_deserialize(&mut &body[..]) {
+                self.handle_object(msg_id, body, object, events)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a server message to acknowledge, `false` if it is content-related and seen already
+    fn receive(&mut self, msg_id: i64, seq_no: i32) -> bool {
+        if seq_no & 1 == 0 {
+            return true;
+        }
+        self.to_ack.push(msg_id);
+        if !self.received.insert(msg_id) {
+            return false;
+        }
+        if self.received.len() > MAX_RECEIVED {
+            let oldest = *self.received.iter().next().unwrap();
+            self.received.remove(&oldest);
+        }
+        true
+    }
+
+    /// Handle `object` decoded from `body` of server message `msg_id`
+    fn handle_object(
+        &mut self,
+        msg_id: i64,
+        body: &[u8],
+        object: ObjectRef,
+        events: &mut Vec<Event>,
+    ) -> MyResult<()> {
+        match object {
+            ObjectRef::MessageContainer(_) => {
+                return Err(MyError::InvalidResponse {
+                    reason: "msg_container inside msg_container",
+                })
+            }
+            ObjectRef::RpcResult(result) => {
+                let req_msg_id = self.complete(result.req_msg_id);
+                events.push(Event::Result {
+                    req_msg_id,
+                    body: body[RPC_RESULT_HEADER..].to_vec(),
+                });
+            }
+            ObjectRef::NewSession(session) => {
+                self.salt = session.server_salt;
+                self.message_ids.reset_seq_no();
+                events.push(Event::NewSession {
+                    first_msg_id: session.first_msg_id,
+                });
+            }
+            ObjectRef::BadMsgNotification(notification) => match *notification {
+                tl::BadMsgNotification::BadServerSalt {
+                    bad_msg_id,
+                    new_server_salt,
+                    ..
+                } => {
+                    self.salt = new_server_salt;
+                    self.resend(bad_msg_id)?;
+                }
+                tl::BadMsgNotification::BadMsgNotification {
+                    bad_msg_id,
+                    error_code,
+                    ..
+                } => match error_code {
+                    MSG_ID_TOO_LOW => {
+                        self.message_ids.sync_with_msg_id(msg_id);
+                        self.resend(bad_msg_id)?;
+                    }
+                    // ids sent before are too high as well
+                    MSG_ID_TOO_HIGH => {
+                        self.message_ids.sync_with_msg_id(msg_id);
+                        self.message_ids.reset_last_id();
+                        self.resend(bad_msg_id)?;
+                    }
+                    _ => events.push(Event::BadMessage {
+                        msg_id: self.complete(bad_msg_id),
+                        error_code,
+                    }),
+                },
+            },
+            ObjectRef::MsgsAck(ack) => {
+                for msg_id in ack.msg_ids {
+                    self.acknowledge(msg_id);
+                }
+            }
+            ObjectRef::MsgResendReq(request) => {
+                for msg_id in request.msg_ids {
+                    self.resend(msg_id)?;
+                }
+            }
+            // the request stays pending until its answer `answer_msg_id` is received
+            ObjectRef::MsgDetailedInfo(info) => match *info {
+                tl::MsgDetailedInfo::MsgDetailedInfo {
+                    msg_id,
+                    answer_msg_id,
+                    ..
+                } => {
+                    if let Some(pending) = self.pending.get_mut(&msg_id) {
+                        pending.acknowledged = true;
+                    }
+                    self.request_answer(answer_msg_id)?;
+                }
+                // answers no request of this session, there is nothing pending to update
+                tl::MsgDetailedInfo::MsgNewDetailedInfo { answer_msg_id, .. } => {
+                    self.request_answer(answer_msg_id)?
+                }
+            },
+            ObjectRef::Pong(pong) => {
+                self.complete(pong.msg_id);
+                events.push(Event::Pong {
+                    ping_id: pong.ping_id,
+                });
+            }
+            ObjectRef::FutureSalts(answer) => {
+                let tl::FutureSalts {
+                    req_msg_id, salts, ..
+                } = *answer;
+                let mut salts = salts.0.into_iter().map(|x| x.0).collect::<Vec<_>>();
+                salts.sort_by_key(|x| x.valid_since);
+                self.complete(req_msg_id);
+                self.future_salts = salts;
+                self.rotate_salt();
+            }
+            _ => events.push(Event::Update {
+                msg_id,
+                body: body.to_vec(),
+            }),
+        }
+        Ok(())
+    }
+
+    /// Queue a content-related message, kept until answered if `expects_answer`,
+    /// otherwise until acknowledged
+    fn send_content<T: TLType>(&mut self, message: &T, expects_answer: bool) -> MyResult<i64> {
+        let body = serialize(message)?;
+        let msg_id = self.push(&body, true)?;
+        self.pending.insert(
+            msg_id,
+            Pending {
+                original_id: msg_id,
+                body,
+                acknowledged: false,
+                expects_answer,
+            },
+        );
+        Ok(msg_id)
+    }
+
+    /// Queue the message sent as `msg_id` again with a new `msg_id`, current salt and time
+    fn resend(&mut self, msg_id: i64) -> MyResult<()> {
+        if let Some(pending) = self.pending.remove(&msg_id) {
+            let msg_id = self.push(&pending.body, true)?;
+            self.pending.insert(
+                msg_id,
+                Pending {
+                    acknowledged: false,
+                    ..pending
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The server received message `msg_id`, which is done unless it expects an answer
+    fn acknowledge(&mut self, msg_id: i64) {
+        if let Some(pending) = self.pending.get_mut(&msg_id) {
+            pending.acknowledged = true;
+            if !pending.expects_answer {
+                self.pending.remove(&msg_id);
+            }
+        }
+    }
+
+    /// Forget the request answered and return the `msg_id` it was sent as first
+    fn complete(&mut self, msg_id: i64) -> i64 {
+        self.pending
+            .remove(&msg_id)
+            .map_or(msg_id, |pending| pending.original_id)
+    }
+
+    /// Acknowledge an answer received already or ask the server to send it again
+    fn request_answer(&mut self, answer_msg_id: i64) -> MyResult<()> {
+        if self.received.contains(&answer_msg_id) {
+            self.to_ack.push(answer_msg_id);
+            return Ok(());
+        }
+        let request = tl::MsgResendReq {
+            msg_ids: vec![answer_msg_id],
+        };
+        self.send_content(&request, false)?;
+        Ok(())
+    }
+
+    /// Drop expired salts and use the earliest valid one
+    fn rotate_salt(&mut self) {
+        let now = self.message_ids.server_time();
+        self.future_salts.retain(|x| i64::from(x.valid_until) > now);
+        if let Some(salt) = self
+            .future_salts
+            .iter()
+            .find(|x| i64::from(x.valid_since) <= now)
+        {
+            self.salt = salt.salt;
+        }
+    }
+
+    fn push(&mut self, body: &[u8], content_related: bool) -> MyResult<i64> {
+        self.rotate_salt();
+        let message = Message {
+            salt: self.salt,
+            session_id: self.id,
+            msg_id: self.message_ids.next_id(),
+            seq_no: self.message_ids.next_seq_no(content_related),
+            body: body.to_vec(),
+        };
+        self.outgoing
+            .push(message.encrypt(&self.auth_key, Sender::Client)?);
+        Ok(message.msg_id)
+    }
+}
+
+fn serialize<T: TLType>(object: &T) -> MyResult<Vec<u8>> {
+    let mut result = Vec::with_capacity(object.serialized_len());
+    object.tl_write(&mut result)?;
+    Ok(result)
+}
+
+/// Whether `body` is a `msg_container`, whose messages are read one by one with
+/// `container_item` rather than decoded at once
+fn is_container(body: &[u8]) -> bool {
+    i32::tl_read(&mut &body[..])
+        .ok()
+        .and_then(registry::by_id)
+        .map_or(false, |x| x.name == "msg_container")
+}
+
+/// `msg_id`, `seqno` and body of a message in `msg_container`
+fn container_item<'a>(input: &mut &'a [u8]) -> MyResult<(i64, i32, &'a [u8])> {
+    let msg_id = i64::tl_read(input)?;
+    let seq_no = i32::tl_read(input)?;
+    let length = i32::tl_read(input)?;
+    if length < 0 {
+        return Err(MyError::NegativeLength { length });
+    }
+    if length as usize > input.len() {
+        return Err(MyError::Truncated);
+    }
+    let (body, rest) = input.split_at(length as usize);
+    *input = rest;
+    Ok((msg_id, seq_no, body))
+}
+
+#[cfg(test)]
+fn test_session() -> Session {
+    let mut key = [0u8; 256];
+    for (i, x) in key.iter_mut().enumerate() {
+        *x = (i * 7 + 3) as u8;
+    }
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    Session::new(AuthKey::new(key, 0x1111, now as i32))
+}
+
+#[cfg(test)]
+fn server_message(session: &Session, msg_id: i64, seq_no: i32, body: Vec<u8>) -> Vec<u8> {
+    Message {
+        salt: session.salt,
+        session_id: session.id,
+        msg_id,
+        seq_no,
+        body,
+    }
+    .encrypt(&session.auth_key, Sender::Server)
+    .unwrap()
+}
+
+#[cfg(test)]
+fn sent_messages(session: &mut Session) -> Vec<Message> {
+    let (auth_key, id) = (session.auth_key.clone(), session.id);
+    session
+        .take_outgoing()
+        .unwrap()
+        .iter()
+        .map(|data| Message::decrypt(&auth_key, Sender::Client, id, data).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+fn container(messages: Vec<(i64, i32, Vec<u8>)>) -> Vec<u8> {
+    let mut container = vec![];
+    let id = registry::by_name("msg_container").unwrap().id;
+    id.tl_write(&mut container).unwrap();
+    (messages.len() as i32).tl_write(&mut container).unwrap();
+    for (msg_id, seq_no, body) in messages {
+        msg_id.tl_write(&mut container).unwrap();
+        seq_no.tl_write(&mut container).unwrap();
+        (body.len() as i32).tl_write(&mut container).unwrap();
+        container.extend_from_slice(&body);
+    }
+    container
+}
+
+#[cfg(test)]
+fn rpc_result(req_msg_id: i64) -> Vec<u8> {
+    let result = tl::RpcResult {
+        req_msg_id,
+        result: crate::generate_proto::object::Object::Bool(true),
+    };
+    serialize(&result).unwrap()
+}
+
+#[cfg(test)]
+fn update() -> Vec<u8> {
+    serialize(&crate::generate_rpc::tl::Updates::UpdatesTooLong).unwrap()
+}
+
+#[test]
+fn test_bad_server_salt() {
+    let mut session = test_session();
+    let msg_id = session.send(&42i32).unwrap();
+    let sent = sent_messages(&mut session);
+    assert_eq!(sent.len(), 1);
+    assert_eq!(
+        (sent[0].msg_id, sent[0].seq_no, sent[0].salt),
+        (msg_id, 1, 0x1111)
+    );
+    assert_eq!(sent[0].payload::<i32>().unwrap(), 42);
+
+    let notification = tl::BadMsgNotification::BadServerSalt {
+        bad_msg_id: msg_id,
+        bad_msg_seqno: 1,
+        error_code: 48,
+        new_server_salt: 0x2222,
+    };
+    let data = server_message(&session, 5, 2, serialize(&notification).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    assert_eq!(session.salt(), 0x2222);
+    let resent = sent_messages(&mut session);
+    assert_eq!(resent.len(), 1);
+    assert!(resent[0].msg_id > msg_id);
+    assert_eq!((resent[0].seq_no, resent[0].salt), (3, 0x2222));
+    assert_eq!(resent[0].body, sent[0].body);
+
+    let data = server_message(&session, 9, 1, rpc_result(resent[0].msg_id));
+    assert_eq!(
+        session.handle(&data).unwrap(),
+        vec![Event::Result {
+            req_msg_id: msg_id,
+            body: serialize(&true).unwrap()
+        }]
+    );
+    let acks = sent_messages(&mut session);
+    assert_eq!(acks.len(), 1);
+    assert_eq!(acks[0].seq_no, 4);
+    assert_eq!(acks[0].payload::<tl::MsgsAck>().unwrap().msg_ids, vec![9]);
+    assert!(session.pending.is_empty());
+}
+
+#[test]
+fn test_bad_msg_notification() {
+    let mut session = test_session();
+    let msg_id = session.send(&42i32).unwrap();
+    sent_messages(&mut session);
+
+    let notification = |bad_msg_id, bad_msg_seqno, error_code| {
+        serialize(&tl::BadMsgNotification::BadMsgNotification {
+            bad_msg_id,
+            bad_msg_seqno,
+            error_code,
+        })
+        .unwrap()
+    };
+    let server_msg_id = ((msg_id >> 32) + 3600) << 32 | 1;
+    let data = server_message(
+        &session,
+        server_msg_id,
+        2,
+        notification(msg_id, 1, MSG_ID_TOO_LOW),
+    );
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    let resent = sent_messages(&mut session);
+    assert_eq!(resent.len(), 1);
+    assert!(((resent[0].msg_id >> 32) - (server_msg_id >> 32)).abs() <= 1);
+
+    // the clock goes back again, below the ids sent so far
+    let server_msg_id = (msg_id & !0xffff_ffff) | 5;
+    let data = server_message(
+        &session,
+        server_msg_id,
+        2,
+        notification(resent[0].msg_id, 3, MSG_ID_TOO_HIGH),
+    );
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    let resent = sent_messages(&mut session);
+    assert_eq!(resent.len(), 1);
+    assert!(((resent[0].msg_id >> 32) - (msg_id >> 32)).abs() <= 1);
+    assert_eq!(resent[0].payload::<i32>().unwrap(), 42);
+
+    let data = server_message(
+        &session,
+        server_msg_id + 4,
+        2,
+        notification(resent[0].msg_id, 5, 64),
+    );
+    assert_eq!(
+        session.handle(&data).unwrap(),
+        vec![Event::BadMessage {
+            msg_id,
+            error_code: 64
+        }]
+    );
+    assert!(session.pending.is_empty());
+}
+
+#[test]
+fn test_container() {
+    let mut session = test_session();
+    let ping_msg_id = session.ping(77).unwrap();
+    let request_msg_id = session.send(&42i32).unwrap();
+    assert_eq!(sent_messages(&mut session).len(), 2);
+
+    let new_session = tl::NewSession {
+        first_msg_id: ping_msg_id,
+        unique_id: 99,
+        server_salt: 0x3333,
+    };
+    let ack = tl::MsgsAck {
+        msg_ids: vec![request_msg_id],
+    };
+    let pong = tl::Pong {
+        msg_id: ping_msg_id,
+        ping_id: 77,
+    };
+    let container = container(vec![
+        (4, 1, serialize(&new_session).unwrap()),
+        (8, 2, serialize(&ack).unwrap()),
+        (12, 1, serialize(&pong).unwrap()),
+        (16, 1, update()),
+        (16, 1, update()),
+    ]);
+    let data = server_message(&session, 20, 2, container);
+    assert_eq!(
+        session.handle(&data).unwrap(),
+        vec![
+            Event::NewSession {
+                first_msg_id: ping_msg_id
+            },
+            Event::Pong { ping_id: 77 },
+            Event::Update {
+                msg_id: 16,
+                body: update()
+            },
+        ]
+    );
+    assert_eq!(session.salt(), 0x3333);
+    assert!(session.pending[&request_msg_id].acknowledged);
+    assert_eq!(session.pending.len(), 1);
+
+    // acknowledged requests are not resent, and seq_no starts over
+    session.resend_unacknowledged().unwrap();
+    let acks = sent_messages(&mut session);
+    assert_eq!(acks.len(), 1);
+    assert_eq!(acks[0].seq_no, 0);
+    assert_eq!(
+        acks[0].payload::<tl::MsgsAck>().unwrap().msg_ids,
+        vec![4, 12, 16, 16]
+    );
+}
+
+#[test]
+fn test_malformed_container() {
+    let mut session = test_session();
+    let ping_msg_id = session.ping(77).unwrap();
+    let request_msg_id = session.send(&42i32).unwrap();
+    sent_messages(&mut session);
+
+    let pong = tl::Pong {
+        msg_id: ping_msg_id,
+        ping_id: 77,
+    };
+    let mut truncated_pong = serialize(&pong).unwrap();
+    truncated_pong.truncate(8);
+    let mut data = container(vec![
+        (4, 1, rpc_result(request_msg_id)),
+        (8, 1, truncated_pong),
+        (12, 1, vec![1, 2, 3, 4]),
+        (16, 1, update()),
+    ]);
+    // a last message longer than the container is where handling stops
+    data[4] = 5;
+    data.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 64, 0, 0, 0]);
+    let data = server_message(&session, 20, 2, data);
+    assert_eq!(
+        session.handle(&data).unwrap(),
+        vec![
+            Event::Result {
+                req_msg_id: request_msg_id,
+                body: serialize(&true).unwrap()
+            },
+            Event::Update {
+                msg_id: 16,
+                body: update()
+            },
+        ]
+    );
+    // the ping is still waiting for a valid pong
+    assert_eq!(
+        session.pending.keys().collect::<Vec<_>>(),
+        vec![&ping_msg_id]
+    );
+
+    // outside of a container a message which does not decode is an error
+    let data = server_message(&session, 24, 1, vec![1, 2, 3, 4]);
+    match session.handle(&data) {
+        Err(MyError::UnknownConstructor { .. }) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+}
+
+#[test]
+fn test_container_handling_error() {
+    let mut session = test_session();
+    let request_msg_id = session.send(&42i32).unwrap();
+    sent_messages(&mut session);
+
+    let nested = container(vec![(24, 1, update())]);
+    let data = container(vec![
+        (4, 1, rpc_result(request_msg_id)),
+        (8, 2, nested),
+        (12, 1, update()),
+    ]);
+    let data = server_message(&session, 20, 2, data);
+    match session.handle(&data) {
+        Err(MyError::InvalidResponse { reason }) => {
+            assert_eq!(reason, "msg_container inside msg_container")
+        }
+        x => panic!("unexpected {:?}", x),
+    }
+    // messages before the failing one are handled
+    assert!(session.pending.is_empty());
+    assert_eq!(session.to_ack, vec![4]);
+}
+
+#[test]
+fn test_future_salts() {
+    let mut session = test_session();
+    let msg_id = session.request_future_salts(3).unwrap();
+    let sent = sent_messages(&mut session);
+    assert_eq!(
+        sent[0].body,
+        serialize(&functions::GetFutureSalts { num: 3 }).unwrap()
+    );
+
+    let now = session.message_ids.server_time() as i32;
+    let salts = [
+        (now + 1800, now + 5400, 3i64),
+        (now - 3600, now - 1, 1),
+        (now - 1800, now + 1800, 2),
+    ]
+    .iter()
+    .map(|&(valid_since, valid_until, salt)| {
+        crate::tl_types::tl_bare::TLBare(tl::FutureSalt {
+            valid_since,
+            valid_until,
+            salt,
+        })
+    })
+    .collect();
+    let answer = tl::FutureSalts {
+        req_msg_id: msg_id,
+        now,
+        salts: crate::tl_types::tl_bare_vector::TLBareVector(salts),
+    };
+    let data = server_message(&session, 4, 1, serialize(&answer).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    assert_eq!(session.salt(), 2);
+    assert_eq!(
+        session
+            .future_salts()
+            .iter()
+            .map(|x| x.salt)
+            .collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+    assert!(session.pending.is_empty());
+}
+
+#[test]
+fn test_resend_requests() {
+    let mut session = test_session();
+    let msg_id = session.send(&42i32).unwrap();
+    sent_messages(&mut session);
+
+    let data = server_message(&session, 4, 1, update());
+    session.handle(&data).unwrap();
+    let request = tl::MsgResendReq {
+        msg_ids: vec![msg_id],
+    };
+    let data = server_message(&session, 8, 1, serialize(&request).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    let resent_id = *session.pending.keys().next().unwrap();
+    assert!(resent_id > msg_id);
+    // the answer of the request was received already, the other one was not
+    let info = tl::MsgDetailedInfo::MsgDetailedInfo {
+        msg_id: resent_id,
+        answer_msg_id: 4,
+        bytes: 4,
+        status: 0,
+    };
+    let data = server_message(&session, 12, 1, serialize(&info).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    let info = tl::MsgDetailedInfo::MsgNewDetailedInfo {
+        answer_msg_id: 40,
+        bytes: 4,
+        status: 0,
+    };
+    let data = server_message(&session, 16, 1, serialize(&info).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+
+    let sent = sent_messages(&mut session);
+    assert_eq!(sent.len(), 3);
+    assert_eq!(sent[0].payload::<i32>().unwrap(), 42);
+    assert_eq!(
+        sent[1].payload::<tl::MsgResendReq>().unwrap().msg_ids,
+        vec![40]
+    );
+    assert_eq!(
+        sent[2].payload::<tl::MsgsAck>().unwrap().msg_ids,
+        vec![4, 8, 12, 4, 16]
+    );
+    assert!(session.pending[&resent_id].acknowledged);
+
+    // `msg_resend_req` is sent again until acknowledged, and needs no answer
+    session.resend_unacknowledged().unwrap();
+    let resent = sent_messages(&mut session);
+    assert_eq!(resent.len(), 1);
+    assert_eq!(resent[0].body, sent[1].body);
+    let ack = tl::MsgsAck {
+        msg_ids: vec![resent[0].msg_id],
+    };
+    let data = server_message(&session, 20, 2, serialize(&ack).unwrap());
+    assert_eq!(session.handle(&data).unwrap(), vec![]);
+    assert_eq!(session.pending.keys().collect::<Vec<_>>(), vec![&resent_id]);
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Constructor or method of a schema, listed by the generated `registry` module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstructorInfo {
+    pub id: i32,
+    /// Name in schema, e.g. `resPQ`, `auth.sendCode`
+    pub name: &'static str,
+    pub params: &'static [ParamInfo],
+    /// Type in schema, e.g. `ResPQ`
+    pub return_type: &'static str,
+    /// Path relative to the generated module, `None` if it is not generated
+    pub rust_path: Option<&'static str>,
+    pub is_method: bool,
+    /// Layer which introduced it, if the schema records it
+    pub layer: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    /// Type in schema, e.g. `flags.0?Vector<int>`
+    pub kind: &'static str,
+}
+
+impl ConstructorInfo {
+    /// Declaration in TL text format, e.g. `req_pq#60469778 nonce:int128 = ResPQ`
+    pub fn signature(&self) -> String {
+        let mut result = format!("{}#{:08x}", self.name, self.id as u32);
+        for param in self.params {
+            result.push_str(&format!(" {}:{}", param.name, param.kind));
+        }
+        result.push_str(&format!(" = {}", self.return_type));
+        result
+    }
+}
+
+/// `0x05162463 = resPQ`
+impl fmt::Display for ConstructorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#010x} = {}", self.id as u32, self.name)
+    }
+}
+
+#[test]
+fn test_format() {
+    let info = ConstructorInfo {
+        id: 0x6046_9778,
+        name: "req_pq",
+        params: &[ParamInfo {
+            name: "nonce",
+            kind: "int128",
+        }],
+        return_type: "ResPQ",
+        rust_path: Some("functions::ReqPq"),
+        is_method: true,
+        layer: None,
+    };
+    assert_eq!(info.to_string(), "0x60469778 = req_pq");
+    assert_eq!(info.signature(), "req_pq#60469778 nonce:int128 = ResPQ");
+}
@@ -0,0 +1,150 @@
+use crate::utils::{MyError, MyResult};
+
+/// Limits applied while decoding untrusted input, shared by every nested `tl_read_with_context`
+#[derive(Debug, Clone)]
+pub struct DecodeContext {
+    max_total_bytes: usize,
+    max_vector_length: usize,
+    max_depth: usize,
+    consumed: usize,
+    depth: usize,
+}
+
+impl DecodeContext {
+    pub const DEFAULT_MAX_TOTAL_BYTES: usize = 16 * 1024 * 1024;
+    pub const DEFAULT_MAX_VECTOR_LENGTH: usize = 1024 * 1024;
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+    pub fn new(max_total_bytes: usize, max_vector_length: usize, max_depth: usize) -> Self {
+        DecodeContext {
+            max_total_bytes,
+            max_vector_length,
+            max_depth,
+            consumed: 0,
+            depth: 0,
+        }
+    }
+
+    /// Bytes read so far
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Bytes that may still be read before `max_total_bytes` is hit
+    pub fn remaining(&self) -> usize {
+        self.max_total_bytes - self.consumed
+    }
+
+    /// Reserve `length` bytes of the budget, must be called before reading or allocating them
+    pub fn consume(&mut self, length: usize) -> MyResult<()> {
+        if length > self.remaining() {
+            return Err(MyError::OversizedLength {
+                length: self.consumed.saturating_add(length),
+                limit: self.max_total_bytes,
+            });
+        }
+        self.consumed += length;
+        Ok(())
+    }
+
+    /// Split `length` bytes off the front of `input`, counting them against the budget
+    pub fn take<'a>(&mut self, input: &mut &'a [u8], length: usize) -> MyResult<&'a [u8]> {
+        if input.len() < length {
+            return Err(MyError::Truncated);
+        }
+        self.consume(length)?;
+        let (head, tail) = input.split_at(length);
+        *input = tail;
+        Ok(head)
+    }
+
+    pub fn check_vector_length(&self, length: usize) -> MyResult<()> {
+        if length > self.max_vector_length {
+            return Err(MyError::OversizedLength {
+                length,
+                limit: self.max_vector_length,
+            });
+        }
+        Ok(())
+    }
+
+    /// Called when starting to decode a boxed object or vector, pair with `leave`
+    pub fn enter(&mut self) -> MyResult<()> {
+        if self.depth >= self.max_depth {
+            return Err(MyError::TooDeep {
+                limit: self.max_depth,
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl Default for DecodeContext {
+    fn default() -> Self {
+        DecodeContext::new(
+            Self::DEFAULT_MAX_TOTAL_BYTES,
+            Self::DEFAULT_MAX_VECTOR_LENGTH,
+            Self::DEFAULT_MAX_DEPTH,
+        )
+    }
+}
+
+#[test]
+fn test_total_bytes_limit() {
+    use crate::tl_types::{tl_bytes::TLBytes, TLType};
+    use std::io::Cursor;
+
+    // Long string claims 0x100000 bytes, but only 16 are allowed
+    let buffer = [254u8, 0x00, 0x00, 0x10];
+    let mut context = DecodeContext::new(16, 16, 16);
+    match TLBytes::tl_read_with_context(&mut Cursor::new(&buffer), &mut context) {
+        Err(MyError::OversizedLength { length, limit }) => {
+            assert_eq!(4 + 0x10_0000, length);
+            assert_eq!(16, limit);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_vector_length_limit() {
+    use crate::tl_types::TLType;
+    use std::io::Cursor;
+
+    let buffer = [0x15, 0xc4, 0xb5, 0x1c, 0xff, 0xff, 0xff, 0x7f];
+    let mut context = DecodeContext::default();
+    match Vec::<i32>::tl_read_with_context(&mut Cursor::new(&buffer), &mut context) {
+        Err(MyError::OversizedLength { length, limit }) => {
+            assert_eq!(0x7fff_ffff, length);
+            assert_eq!(DecodeContext::DEFAULT_MAX_VECTOR_LENGTH, limit);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_depth_limit() {
+    use crate::tl_types::TLType;
+    use std::io::Cursor;
+
+    // Vector<Vector<int>> with a single empty inner vector
+    let buffer = [
+        0x15, 0xc4, 0xb5, 0x1c, 0x01, 0x00, 0x00, 0x00, 0x15, 0xc4, 0xb5, 0x1c, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+    let mut context = DecodeContext::new(1024, 16, 1);
+    match Vec::<Vec<i32>>::tl_read_with_context(&mut Cursor::new(&buffer), &mut context) {
+        Err(MyError::TooDeep { limit }) => assert_eq!(1, limit),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let mut context = DecodeContext::new(1024, 16, 2);
+    let result = Vec::<Vec<i32>>::tl_read_with_context(&mut Cursor::new(&buffer), &mut context);
+    assert_eq!(vec![Vec::<i32>::new()], result.unwrap());
+    assert_eq!(16, context.consumed());
+}
@@ -1,9 +1,13 @@
 use std::fmt::Debug;
 
-use crate::utils::MyResult;
+use crate::{tl_types::decode_context::DecodeContext, utils::MyResult};
 
+pub mod constructor_info;
+pub mod decode_context;
 pub mod tl_16_bytes;
 pub mod tl_32_bytes;
+pub mod tl_bare;
+pub mod tl_bare_vector;
 pub mod tl_bool;
 pub mod tl_bytes;
 pub mod tl_f64;
@@ -15,6 +19,50 @@ pub mod tl_u64;
 pub mod tl_vector;
 
 pub trait TLType: Sized + Debug {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self>;
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize>;
+    /// Read with default `DecodeContext` limits
+    fn tl_read(input: &mut dyn std::io::Read) -> MyResult<Self> {
+        Self::tl_read_with_context(input, &mut DecodeContext::default())
+    }
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self>;
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize>;
+    /// Number of bytes `tl_write` will produce
+    fn serialized_len(&self) -> usize;
+}
+
+/// Zero-copy counterpart of `TLType::tl_read`, `bytes` and `string` may borrow from the input
+pub trait TLDeserialize<'a>: Sized {
+    /// Read with default `DecodeContext` limits
+    fn tl_deserialize(input: &mut &'a [u8]) -> MyResult<Self> {
+        Self::tl_deserialize_with_context(input, &mut DecodeContext::default())
+    }
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self>;
+}
+
+/// Type with a single constructor, which may also appear bare (without constructor id) in schema
+pub trait TLBareType: TLType {
+    fn tl_read_bare_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self>;
+    fn tl_write_bare(&self, output: &mut dyn std::io::Write) -> MyResult<usize>;
+    fn bare_serialized_len(&self) -> usize;
+}
+
+/// Zero-copy counterpart of `TLBareType`
+pub trait TLBareDeserialize<'a>: TLDeserialize<'a> {
+    fn tl_deserialize_bare_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self>;
+}
+
+/// RPC method sent to the server, answered with `Return`
+pub trait RemoteCall: TLType {
+    type Return: TLType;
 }
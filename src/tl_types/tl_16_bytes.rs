@@ -1,14 +1,36 @@
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::MyResult,
+};
 
 impl TLType for [u8; 16] {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.consume(16)?;
         let mut result = [0u8; 16];
         input.read_exact(&mut result)?;
         Ok(result)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
         output.write_all(&self[..])?;
         Ok(16)
     }
+
+    fn serialized_len(&self) -> usize {
+        16
+    }
+}
+
+impl<'a> TLDeserialize<'a> for [u8; 16] {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let mut result = [0u8; 16];
+        result.copy_from_slice(context.take(input, 16)?);
+        Ok(result)
+    }
 }
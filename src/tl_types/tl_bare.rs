@@ -0,0 +1,37 @@
+use crate::{
+    tl_types::{
+        decode_context::DecodeContext, TLBareDeserialize, TLBareType, TLDeserialize, TLType,
+    },
+    utils::MyResult,
+};
+
+/// `%Type` or a lowercase constructor name in schema, encoded without constructor id
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TLBare<T>(pub T);
+
+impl<T: TLBareType> TLType for TLBare<T> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        Ok(TLBare(T::tl_read_bare_with_context(input, context)?))
+    }
+
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        self.0.tl_write_bare(output)
+    }
+
+    fn serialized_len(&self) -> usize {
+        self.0.bare_serialized_len()
+    }
+}
+
+impl<'a, T: TLBareDeserialize<'a>> TLDeserialize<'a> for TLBare<T> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        Ok(TLBare(T::tl_deserialize_bare_with_context(input, context)?))
+    }
+}
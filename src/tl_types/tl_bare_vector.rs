@@ -0,0 +1,126 @@
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::{MyError, MyResult},
+};
+
+/// `vector<t>` in schema, a `Vector t` without the leading constructor id
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TLBareVector<T>(pub Vec<T>);
+
+/// Empty, unlike derived `Default` this does not require `T: Default`
+impl<T> Default for TLBareVector<T> {
+    fn default() -> Self {
+        TLBareVector(vec![])
+    }
+}
+
+impl<T> TLBareVector<T> {
+    fn check_length(length: i32, context: &DecodeContext) -> MyResult<usize> {
+        if length < 0 {
+            return Err(MyError::NegativeLength { length });
+        }
+        let length = length as usize;
+        context.check_vector_length(length)?;
+        Ok(length)
+    }
+
+    /// At most `length` items, taking no more memory than `available` bytes of input could
+    /// encode, as every item takes at least 4 bytes; the vector grows past it when needed
+    fn initial_capacity(length: usize, available: usize) -> usize {
+        length.min(available / std::mem::size_of::<T>().max(4))
+    }
+}
+
+impl<T: TLType> TLType for TLBareVector<T> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let length = i32::tl_read_with_context(input, context)?;
+        let length = Self::check_length(length, context)?;
+        context.enter()?;
+        let mut result: Vec<T> =
+            Vec::with_capacity(Self::initial_capacity(length, context.remaining()));
+        for _ in 0..length {
+            let item = T::tl_read_with_context(input, context)?;
+            result.push(item);
+        }
+        context.leave();
+        Ok(TLBareVector(result))
+    }
+
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        output.write_u32::<LittleEndian>(self.0.len() as u32)?;
+        let mut size = 4usize;
+        for i in &self.0 {
+            size += i.tl_write(output)?;
+        }
+        Ok(size)
+    }
+
+    fn serialized_len(&self) -> usize {
+        4 + self.0.iter().map(TLType::serialized_len).sum::<usize>()
+    }
+}
+
+impl<'a, T: TLDeserialize<'a>> TLDeserialize<'a> for TLBareVector<T> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let length = i32::tl_deserialize_with_context(input, context)?;
+        let length = Self::check_length(length, context)?;
+        context.enter()?;
+        let mut result: Vec<T> = Vec::with_capacity(Self::initial_capacity(length, input.len()));
+        for _ in 0..length {
+            let item = T::tl_deserialize_with_context(input, context)?;
+            result.push(item);
+        }
+        context.leave();
+        Ok(TLBareVector(result))
+    }
+}
+
+#[test]
+fn test_read_tl_bare_vector_long() {
+    use std::io::Cursor;
+    let buffer = [
+        0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let mut cursor = Cursor::new(&buffer);
+    assert_eq!(
+        TLBareVector(vec![1i64, 2]),
+        TLBareVector::<i64>::tl_read(&mut cursor).unwrap()
+    );
+    assert_eq!(
+        TLBareVector(vec![1i64, 2]),
+        TLBareVector::<i64>::tl_deserialize(&mut &buffer[..]).unwrap()
+    );
+}
+
+#[test]
+fn test_write_tl_bare_vector_long() {
+    let data = TLBareVector(vec![1i64, 2]);
+    let mut buffer = vec![];
+    assert_eq!(20, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(20, data.serialized_len());
+    assert_eq!(
+        buffer,
+        vec![
+            0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]
+    )
+}
+
+#[test]
+fn test_initial_capacity() {
+    assert_eq!(TLBareVector::<i32>::initial_capacity(1000, 40), 10);
+    assert_eq!(TLBareVector::<i64>::initial_capacity(1000, 40), 5);
+    assert_eq!(TLBareVector::<[u8; 256]>::initial_capacity(1000, 4096), 16);
+    assert_eq!(TLBareVector::<u8>::initial_capacity(3, 4096), 3);
+}
@@ -1,19 +1,28 @@
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::{MyError, MyResult},
+};
 
 const BOOL_TRUE: i32 = -1_720_552_011;
 const BOOL_FALSE: i32 = -1_132_882_121;
 
 impl TLType for bool {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-        let code = i32::tl_read(input)?;
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let code = i32::tl_read_with_context(input, context)?;
         match code {
             BOOL_FALSE => Ok(false),
             BOOL_TRUE => Ok(true),
-            _ => unreachable!(),
+            id => Err(MyError::UnknownConstructor {
+                id,
+                expected: "Bool",
+            }),
         }
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
         if *self {
             BOOL_TRUE.tl_write(output)?;
         } else {
@@ -21,4 +30,40 @@ impl TLType for bool {
         }
         Ok(4)
     }
+
+    fn serialized_len(&self) -> usize {
+        4
+    }
+}
+
+impl<'a> TLDeserialize<'a> for bool {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let code = i32::tl_deserialize_with_context(input, context)?;
+        match code {
+            BOOL_FALSE => Ok(false),
+            BOOL_TRUE => Ok(true),
+            id => Err(MyError::UnknownConstructor {
+                id,
+                expected: "Bool",
+            }),
+        }
+    }
+}
+
+#[test]
+fn test_read_tl_bool_unknown_constructor() {
+    use std::io::Cursor;
+
+    let buffer = [1u8, 2, 3, 4];
+    let mut cursor = Cursor::new(&buffer);
+    match bool::tl_read(&mut cursor) {
+        Err(MyError::UnknownConstructor { id, expected }) => {
+            assert_eq!(0x04030201, id);
+            assert_eq!("Bool", expected);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
 }
@@ -1,14 +1,19 @@
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::{MyError, MyResult},
+};
 
 const DIVIDING_SIZE: u8 = 254;
+const MAX_LENGTH: usize = (1 << 24) - 1;
 
 const fn padding_size(current: u64, step: u64) -> u64 {
     (step - (current % step)) % step
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TLBytes(Vec<u8>);
 
 impl TLBytes {
@@ -20,35 +25,56 @@ impl TLBytes {
         self.0
     }
 
+    /// Length is checked on `tl_write`, anything longer than 16 MiB can not be encoded
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
-        let _ = TLStringType::new_by_size(bytes.len()); // Check length
         TLBytes(bytes)
     }
+
+    /// Encoded size of `length` bytes: length prefix, data and padding
+    ///
+    /// Computed as with a long prefix past 16 MiB too, which `tl_write` rejects with
+    /// `OversizedLength`, so callers sizing a buffer must check the length themselves
+    pub fn serialized_len_of(length: usize) -> usize {
+        let prefix_length = if length < DIVIDING_SIZE as usize {
+            TLStringType::Short.length()
+        } else {
+            TLStringType::Long.length()
+        };
+        let padding_size = padding_size((prefix_length + length) as u64, 4) as usize;
+        prefix_length + length + padding_size
+    }
 }
 
 impl TLType for TLBytes {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
         let first_byte = input.read_u8()?;
-        let string_type = TLStringType::new_by_first_byte(first_byte);
+        let string_type = TLStringType::new_by_first_byte(first_byte)?;
         let length = match string_type {
             TLStringType::Short => first_byte as usize,
             TLStringType::Long => input.read_u24::<LittleEndian>()? as usize,
         };
+        let padding_size = padding_size((length + string_type.length()) as u64, 4);
+        context.consume(string_type.length() + length + padding_size as usize)?;
+
         let mut result = vec![0u8; length];
         input.read_exact(&mut result)?;
 
-        let padding_size = padding_size((length + string_type.length()) as u64, 4);
         for _ in 0..padding_size {
-            let padding_byte = input.read_u8()?;
-            assert_eq!(0, padding_byte);
+            let byte = input.read_u8()?;
+            if byte != 0 {
+                return Err(MyError::BadPadding { byte });
+            }
         }
 
         Ok(TLBytes(result))
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
         let length = self.0.len();
-        let string_type = TLStringType::new_by_size(length);
+        let string_type = TLStringType::new_by_size(length)?;
 
         match string_type {
             TLStringType::Short => {
@@ -69,6 +95,44 @@ impl TLType for TLBytes {
 
         Ok(padding_size + string_type.length() + length)
     }
+
+    fn serialized_len(&self) -> usize {
+        Self::serialized_len_of(self.0.len())
+    }
+}
+
+impl<'a> TLDeserialize<'a> for &'a [u8] {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let first_byte = context.take(input, 1)?[0];
+        let string_type = TLStringType::new_by_first_byte(first_byte)?;
+        let length = match string_type {
+            TLStringType::Short => first_byte as usize,
+            TLStringType::Long => LittleEndian::read_u24(context.take(input, 3)?) as usize,
+        };
+        let result = context.take(input, length)?;
+
+        let padding_size = padding_size((length + string_type.length()) as u64, 4) as usize;
+        for &byte in context.take(input, padding_size)? {
+            if byte != 0 {
+                return Err(MyError::BadPadding { byte });
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a> TLDeserialize<'a> for TLBytes {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let bytes: &'a [u8] = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        Ok(TLBytes(bytes.to_vec()))
+    }
 }
 
 #[test]
@@ -83,40 +147,90 @@ fn test_read_tl_string() {
     );
 }
 
+#[test]
+fn test_deserialize_borrowed_bytes() {
+    let mut buffer = vec![254, 0, 1, 0];
+    buffer.extend((0..=255).map(|x| x as u8));
+    buffer.extend(&[0xab; 4]);
+    let mut input = &buffer[..];
+    let bytes = <&[u8]>::tl_deserialize(&mut input).unwrap();
+    assert_eq!(256, bytes.len());
+    assert_eq!(buffer[4..260].as_ptr(), bytes.as_ptr());
+    assert_eq!(&[0xab; 4][..], input);
+}
+
+#[test]
+fn test_read_tl_string_bad_input() {
+    use std::io::Cursor;
+
+    let buffer = [2, b'1', b'2', 0xff];
+    match TLBytes::tl_read(&mut Cursor::new(&buffer)) {
+        Err(MyError::BadPadding { byte }) => assert_eq!(0xff, byte),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let buffer = [0xff, 0, 0, 0];
+    match TLBytes::tl_read(&mut Cursor::new(&buffer)) {
+        Err(MyError::BadLengthPrefix { byte }) => assert_eq!(0xff, byte),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let buffer = [8, b'1', b'2', b'3'];
+    match TLBytes::tl_read(&mut Cursor::new(&buffer)) {
+        Err(MyError::Truncated) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
 #[test]
 fn test_write_tl_string() {
     let data = TLBytes(b"12345678".to_vec());
     let mut buffer = vec![];
     assert_eq!(12, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(12, data.serialized_len());
     assert_eq!(
         buffer,
         vec![8, b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', 0, 0, 0]
     );
 }
 
+#[test]
+fn test_serialized_len() {
+    for &length in &[0, 1, 3, 4, 253, 254, 255, 256, 1000] {
+        let data = TLBytes(vec![0xab; length]);
+        let mut buffer = vec![];
+        data.tl_write(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), data.serialized_len());
+        assert_eq!(0, buffer.len() % 4);
+    }
+}
+
 enum TLStringType {
     Short,
     Long,
 }
 
 impl TLStringType {
-    fn new_by_size(length: usize) -> Self {
+    fn new_by_size(length: usize) -> MyResult<Self> {
         if length < DIVIDING_SIZE as usize {
-            TLStringType::Short
-        } else if length < 2usize.pow(24) {
-            TLStringType::Long
+            Ok(TLStringType::Short)
+        } else if length <= MAX_LENGTH {
+            Ok(TLStringType::Long)
         } else {
-            unreachable!()
+            Err(MyError::OversizedLength {
+                length,
+                limit: MAX_LENGTH,
+            })
         }
     }
 
-    fn new_by_first_byte(lead_byte: u8) -> Self {
+    fn new_by_first_byte(lead_byte: u8) -> MyResult<Self> {
         if lead_byte < DIVIDING_SIZE {
-            TLStringType::Short
+            Ok(TLStringType::Short)
         } else if lead_byte == DIVIDING_SIZE {
-            TLStringType::Long
+            Ok(TLStringType::Long)
         } else {
-            unreachable!()
+            Err(MyError::BadLengthPrefix { byte: lead_byte })
         }
     }
 
@@ -127,3 +241,13 @@ impl TLStringType {
         }
     }
 }
+
+#[test]
+fn test_oversized_len() {
+    let bytes = TLBytes::from_bytes(vec![0u8; MAX_LENGTH + 1]);
+    assert_eq!(bytes.serialized_len(), 4 + MAX_LENGTH + 1);
+    match bytes.tl_write(&mut vec![]) {
+        Err(MyError::OversizedLength { length, .. }) => assert_eq!(length, MAX_LENGTH + 1),
+        x => panic!("unexpected {:?}", x),
+    }
+}
@@ -1,16 +1,36 @@
-use byteorder::{ReadBytesExt, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::MyResult,
+};
 
 impl TLType for f64 {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.consume(8)?;
         Ok(input.read_f64::<byteorder::LittleEndian>()?)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
-        output.write_f64::<byteorder::LittleEndian>(self.clone())?;
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        output.write_f64::<byteorder::LittleEndian>(*self)?;
         Ok(8)
     }
+
+    fn serialized_len(&self) -> usize {
+        8
+    }
+}
+
+impl<'a> TLDeserialize<'a> for f64 {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        Ok(LittleEndian::read_f64(context.take(input, 8)?))
+    }
 }
 
 #[test]
@@ -27,5 +47,6 @@ fn test_write_tl_f64() {
     let data = std::f64::consts::PI;
     let mut buffer = vec![];
     assert_eq!(8, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(8, data.serialized_len());
     assert_eq!(buffer, vec![24, 45, 68, 84, 251, 33, 9, 64]);
 }
@@ -1,16 +1,36 @@
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::MyResult,
+};
 
 impl TLType for i32 {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.consume(4)?;
         Ok(input.read_i32::<LittleEndian>()?)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
-        output.write_i32::<LittleEndian>(self.clone())?;
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        output.write_i32::<LittleEndian>(*self)?;
         Ok(4)
     }
+
+    fn serialized_len(&self) -> usize {
+        4
+    }
+}
+
+impl<'a> TLDeserialize<'a> for i32 {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        Ok(LittleEndian::read_i32(context.take(input, 4)?))
+    }
 }
 
 #[test]
@@ -27,5 +47,6 @@ fn test_write_tl_i32() {
     let data = 0x04030201i32;
     let mut buffer = vec![];
     assert_eq!(4, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(4, data.serialized_len());
     assert_eq!(buffer, vec![1, 2, 3, 4]);
 }
@@ -1,18 +1,59 @@
 use crate::{
-    tl_types::{tl_bytes::TLBytes, TLType},
+    tl_types::{decode_context::DecodeContext, tl_bytes::TLBytes, TLDeserialize, TLType},
     utils::MyResult,
 };
 
 impl TLType for String {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-        let tl_bytes: TLBytes = TLBytes::tl_read(input)?;
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let tl_bytes: TLBytes = TLBytes::tl_read_with_context(input, context)?;
         let bytes = tl_bytes.into_bytes();
         Ok(String::from_utf8(bytes)?)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
         let bytes = self.as_bytes().to_vec();
         let tl_bytes = TLBytes::from_bytes(bytes);
         tl_bytes.tl_write(output)
     }
+
+    fn serialized_len(&self) -> usize {
+        TLBytes::serialized_len_of(self.len())
+    }
+}
+
+impl<'a> TLDeserialize<'a> for &'a str {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let bytes: &'a [u8] = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        Ok(std::str::from_utf8(bytes)?)
+    }
+}
+
+impl<'a> TLDeserialize<'a> for String {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let text: &'a str = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        Ok(text.to_string())
+    }
+}
+
+#[test]
+fn test_deserialize_borrowed_str() {
+    let buffer = [5, b'h', b'e', b'l', b'l', b'o', 0, 0, 0xff];
+    let mut input = &buffer[..];
+    assert_eq!("hello", <&str>::tl_deserialize(&mut input).unwrap());
+    assert_eq!(&[0xff][..], input);
+
+    let buffer = [2, 0xc3, 0x28, 0];
+    match <&str>::tl_deserialize(&mut &buffer[..]) {
+        Err(crate::utils::MyError::InvalidUtf8(_)) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
 }
@@ -1,16 +1,36 @@
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{decode_context::DecodeContext, TLDeserialize, TLType},
+    utils::MyResult,
+};
 
 impl TLType for u64 {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        context.consume(8)?;
         Ok(input.read_u64::<LittleEndian>()?)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
-        output.write_u64::<LittleEndian>(self.clone())?;
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        output.write_u64::<LittleEndian>(*self)?;
         Ok(8)
     }
+
+    fn serialized_len(&self) -> usize {
+        8
+    }
+}
+
+impl<'a> TLDeserialize<'a> for u64 {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        Ok(LittleEndian::read_u64(context.take(input, 8)?))
+    }
 }
 
 #[test]
@@ -27,5 +47,6 @@ fn test_write_tl_long() {
     let data = 0x0807060504030201u64;
     let mut buffer = vec![];
     assert_eq!(8, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(8, data.serialized_len());
     assert_eq!(buffer, vec![1, 2, 3, 4, 5, 6, 7, 8]);
 }
@@ -1,25 +1,32 @@
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{LittleEndian, WriteBytesExt};
 
-use crate::{tl_types::TLType, utils::MyResult};
+use crate::{
+    tl_types::{
+        decode_context::DecodeContext, tl_bare_vector::TLBareVector, TLDeserialize, TLType,
+    },
+    utils::{MyError, MyResult},
+};
 
-const TL_VECTOR_ID: u32 = 0x1cb5_c415;
+const TL_VECTOR_ID: i32 = 0x1cb5_c415;
 
 impl<T: TLType> TLType for Vec<T> {
-    fn tl_read(input: &mut std::io::Read) -> MyResult<Self> {
-        let id_code = input.read_u32::<LittleEndian>()?;
-        assert_eq!(TL_VECTOR_ID, id_code);
-        let length = input.read_i32::<LittleEndian>()?;
-        assert_eq!(true, length >= 0);
-        let mut result: Vec<T> = Vec::with_capacity(length as usize);
-        for _ in 0..length {
-            let item = T::tl_read(input)?;
-            result.push(item);
+    fn tl_read_with_context(
+        input: &mut dyn std::io::Read,
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id = i32::tl_read_with_context(input, context)?;
+        if id != TL_VECTOR_ID {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Vector",
+            });
         }
+        let TLBareVector(result) = TLType::tl_read_with_context(input, context)?;
         Ok(result)
     }
 
-    fn tl_write(&self, output: &mut std::io::Write) -> MyResult<usize> {
-        output.write_u32::<LittleEndian>(TL_VECTOR_ID)?;
+    fn tl_write(&self, output: &mut dyn std::io::Write) -> MyResult<usize> {
+        output.write_i32::<LittleEndian>(TL_VECTOR_ID)?;
         output.write_u32::<LittleEndian>(self.len() as u32)?;
         let mut size = 8usize;
         for i in self {
@@ -27,6 +34,27 @@ impl<T: TLType> TLType for Vec<T> {
         }
         Ok(size)
     }
+
+    fn serialized_len(&self) -> usize {
+        8 + self.iter().map(TLType::serialized_len).sum::<usize>()
+    }
+}
+
+impl<'a, T: TLDeserialize<'a>> TLDeserialize<'a> for Vec<T> {
+    fn tl_deserialize_with_context(
+        input: &mut &'a [u8],
+        context: &mut DecodeContext,
+    ) -> MyResult<Self> {
+        let id = i32::tl_deserialize_with_context(input, context)?;
+        if id != TL_VECTOR_ID {
+            return Err(MyError::UnknownConstructor {
+                id,
+                expected: "Vector",
+            });
+        }
+        let TLBareVector(result) = TLDeserialize::tl_deserialize_with_context(input, context)?;
+        Ok(result)
+    }
 }
 
 #[test]
@@ -40,11 +68,23 @@ fn test_read_tl_vector_int() {
     assert_eq!(vec![1, 2, 3], Vec::<i32>::tl_read(&mut cursor).unwrap())
 }
 
+#[test]
+fn test_read_tl_vector_negative_length() {
+    use std::io::Cursor;
+    let buffer = [0x15, 0xc4, 0xb5, 0x1c, 0xff, 0xff, 0xff, 0xff];
+    let mut cursor = Cursor::new(&buffer);
+    match Vec::<i32>::tl_read(&mut cursor) {
+        Err(MyError::NegativeLength { length }) => assert_eq!(-1, length),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
 #[test]
 fn test_write_tl_vector_int() {
     let data = vec![1i32, 2, 3];
     let mut buffer = vec![];
     assert_eq!(20, data.tl_write(&mut buffer).unwrap());
+    assert_eq!(20, data.serialized_len());
     assert_eq!(
         buffer,
         vec![
@@ -1 +1,9 @@
+use crate::utils::MyResult;
+
 pub mod tcp_client;
+
+/// Carries whole packages to and from the server, e.g. `TcpClient`
+pub trait Transport {
+    fn send_package(&mut self, input: &[u8]) -> MyResult<()>;
+    fn recv_package(&mut self) -> MyResult<Vec<u8>>;
+}
@@ -5,7 +5,7 @@ use std::{
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::utils::MyResult;
+use crate::{transport::Transport, utils::MyResult};
 
 pub struct TcpClient {
     stream: TcpStream,
@@ -34,20 +34,6 @@ impl TcpClient {
         Ok(TcpClient { stream, version })
     }
 
-    pub fn send_package(&mut self, input: &[u8]) -> MyResult<()> {
-        match self.version {
-            TransporterVersion::Intermediate => self.send_package_intermediate(input),
-            TransporterVersion::Abridged => self.send_package_abridged(input),
-        }
-    }
-
-    pub fn recv_package(&mut self) -> MyResult<Vec<u8>> {
-        match self.version {
-            TransporterVersion::Intermediate => self.recv_package_intermediate(),
-            TransporterVersion::Abridged => self.recv_package_abridged(),
-        }
-    }
-
     fn recv_package_abridged(&mut self) -> MyResult<Vec<u8>> {
         let first_byte = self.stream.read_u8()?;
 
@@ -87,3 +73,19 @@ impl TcpClient {
         Ok(())
     }
 }
+
+impl Transport for TcpClient {
+    fn send_package(&mut self, input: &[u8]) -> MyResult<()> {
+        match self.version {
+            TransporterVersion::Intermediate => self.send_package_intermediate(input),
+            TransporterVersion::Abridged => self.send_package_abridged(input),
+        }
+    }
+
+    fn recv_package(&mut self) -> MyResult<Vec<u8>> {
+        match self.version {
+            TransporterVersion::Intermediate => self.recv_package_intermediate(),
+            TransporterVersion::Abridged => self.recv_package_abridged(),
+        }
+    }
+}
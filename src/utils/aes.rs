@@ -0,0 +1,53 @@
+use openssl::aes::{aes_ige, AesKey};
+use openssl::symm::Mode;
+
+/// AES-256 in IGE mode, as used by MTProto, `data` must be a multiple of 16 bytes
+pub fn aes_ige_encrypt(key: &[u8; 32], iv: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let key = AesKey::new_encrypt(key).unwrap();
+    aes_ige_with(&key, iv, data, Mode::Encrypt)
+}
+
+pub fn aes_ige_decrypt(key: &[u8; 32], iv: &[u8; 32], data: &[u8]) -> Vec<u8> {
+    let key = AesKey::new_decrypt(key).unwrap();
+    aes_ige_with(&key, iv, data, Mode::Decrypt)
+}
+
+fn aes_ige_with(key: &AesKey, iv: &[u8; 32], data: &[u8], mode: Mode) -> Vec<u8> {
+    assert_eq!(
+        data.len() % 16,
+        0,
+        "AES-IGE input must be padded to 16 bytes"
+    );
+    let mut iv = *iv;
+    let mut result = vec![0u8; data.len()];
+    aes_ige(data, &mut result, key, &mut iv, mode);
+    result
+}
+
+#[test]
+fn test_aes_ige_vector() {
+    let key: Vec<u8> = (0..32).collect();
+    let iv: Vec<u8> = (32..64).collect();
+    let data: Vec<u8> = (0..64).collect();
+    let mut key_array = [0u8; 32];
+    key_array.copy_from_slice(&key);
+    let mut iv_array = [0u8; 32];
+    iv_array.copy_from_slice(&iv);
+    let encrypted = aes_ige_encrypt(&key_array, &iv_array, &data);
+    assert_eq!(
+        hex::encode(&encrypted),
+        "42e66e1a756cccf5b27acc47523ad074ee39bf54e3db37bbdf415df6b400fca9\
+         77f708327c9e9341cc3dc8efd31e76463daa65b1f0d0252f790d77f1824a662c"
+    );
+    assert_eq!(aes_ige_decrypt(&key_array, &iv_array, &encrypted), data);
+}
+
+#[test]
+fn test_aes_ige_round_trip() {
+    let key = [7u8; 32];
+    let iv = [9u8; 32];
+    let data: Vec<u8> = (0..48).collect();
+    let encrypted = aes_ige_encrypt(&key, &iv, &data);
+    assert_ne!(encrypted, data);
+    assert_eq!(aes_ige_decrypt(&key, &iv, &encrypted), data);
+}
@@ -0,0 +1,144 @@
+use std::{fmt, io, str::Utf8Error, string::FromUtf8Error};
+
+use failure::Fail;
+use openssl::error::ErrorStack;
+
+/// Errors raised while encoding or decoding TL data, or by the MTProto protocol
+#[derive(Debug)]
+pub enum MyError {
+    UnknownConstructor {
+        id: i32,
+        expected: &'static str,
+    },
+    BadPadding {
+        byte: u8,
+    },
+    BadLengthPrefix {
+        byte: u8,
+    },
+    OversizedLength {
+        length: usize,
+        limit: usize,
+    },
+    NegativeLength {
+        length: i32,
+    },
+    TooDeep {
+        limit: usize,
+    },
+    InvalidUtf8(Utf8Error),
+    Truncated,
+    Io(io::Error),
+    /// Transport level error sent by the server instead of a package, e.g. -404
+    Transport {
+        code: i32,
+    },
+    /// Answer carries a nonce or server_nonce other than the ones of the request
+    NonceMismatch,
+    /// None of the fingerprints offered by the server belongs to a known RSA key
+    UnknownServerKey {
+        fingerprints: Vec<i64>,
+    },
+    UnsafeDhParams {
+        reason: &'static str,
+    },
+    /// Answer of the server is malformed, e.g. a `pq` which can not be factorized
+    InvalidResponse {
+        reason: &'static str,
+    },
+    /// SHA1 of a decrypted answer or `new_nonce_hash` does not match
+    HashMismatch,
+    /// `server_DH_params_fail`, `dh_gen_fail`, or too many `dh_gen_retry`
+    DhFailed,
+    /// Encrypted message for another auth key
+    AuthKeyMismatch {
+        auth_key_id: u64,
+    },
+    /// `msg_key` does not match the decrypted message
+    MsgKeyMismatch,
+    /// Encrypted message of another session
+    SessionMismatch {
+        session_id: i64,
+    },
+    /// Encrypted message or its `message_data_length` has an invalid size or padding
+    BadMessageLength {
+        length: usize,
+    },
+    Crypto(ErrorStack),
+}
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MyError::UnknownConstructor { id, expected } => {
+                write!(f, "unknown constructor {:#010x} for type {}", id, expected)
+            }
+            MyError::BadPadding { byte } => write!(f, "non-zero padding byte {:#04x}", byte),
+            MyError::BadLengthPrefix { byte } => write!(f, "invalid length prefix {:#04x}", byte),
+            MyError::OversizedLength { length, limit } => {
+                write!(f, "length {} exceeds limit {}", length, limit)
+            }
+            MyError::NegativeLength { length } => write!(f, "negative length {}", length),
+            MyError::TooDeep { limit } => write!(f, "nesting deeper than {}", limit),
+            MyError::InvalidUtf8(error) => write!(f, "invalid UTF-8: {}", error),
+            MyError::Truncated => write!(f, "unexpected end of input"),
+            MyError::Io(error) => write!(f, "I/O error: {}", error),
+            MyError::Transport { code } => write!(f, "transport error {}", code),
+            MyError::NonceMismatch => write!(f, "nonce of answer does not match request"),
+            MyError::UnknownServerKey { fingerprints } => {
+                write!(f, "no known server key among {:x?}", fingerprints)
+            }
+            MyError::UnsafeDhParams { reason } => write!(f, "unsafe DH parameters: {}", reason),
+            MyError::InvalidResponse { reason } => write!(f, "invalid response: {}", reason),
+            MyError::HashMismatch => write!(f, "hash of answer does not match"),
+            MyError::DhFailed => write!(f, "server failed DH key exchange"),
+            MyError::AuthKeyMismatch { auth_key_id } => {
+                write!(f, "message for unknown auth key {:016x}", auth_key_id)
+            }
+            MyError::MsgKeyMismatch => write!(f, "msg_key does not match message"),
+            MyError::SessionMismatch { session_id } => {
+                write!(f, "message of another session {}", session_id)
+            }
+            MyError::BadMessageLength { length } => write!(f, "invalid message length {}", length),
+            MyError::Crypto(error) => write!(f, "crypto error: {}", error),
+        }
+    }
+}
+
+impl Fail for MyError {
+    fn cause(&self) -> Option<&dyn Fail> {
+        match self {
+            MyError::InvalidUtf8(error) => Some(error),
+            MyError::Io(error) => Some(error),
+            MyError::Crypto(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MyError {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::UnexpectedEof => MyError::Truncated,
+            _ => MyError::Io(error),
+        }
+    }
+}
+
+impl From<ErrorStack> for MyError {
+    fn from(error: ErrorStack) -> Self {
+        MyError::Crypto(error)
+    }
+}
+
+impl From<Utf8Error> for MyError {
+    fn from(error: Utf8Error) -> Self {
+        MyError::InvalidUtf8(error)
+    }
+}
+
+impl From<FromUtf8Error> for MyError {
+    fn from(error: FromUtf8Error) -> Self {
+        MyError::InvalidUtf8(error.utf8_error())
+    }
+}
@@ -0,0 +1,124 @@
+//! Key derivation of MTProto 2.0, see https://core.telegram.org/mtproto/description
+
+use openssl::sha::{sha1, sha256};
+
+/// Sender of a message, selecting the part of the auth key it is encrypted with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sender {
+    Client,
+    Server,
+}
+
+impl Sender {
+    fn offset(self) -> usize {
+        match self {
+            Sender::Client => 0,
+            Sender::Server => 8,
+        }
+    }
+}
+
+/// Middle 128 bits of SHA256 of part of the auth key and `plaintext`, padding included
+pub fn msg_key(auth_key: &[u8; 256], sender: Sender, plaintext: &[u8]) -> [u8; 16] {
+    let x = sender.offset();
+    let large = sha256(&[&auth_key[88 + x..120 + x], plaintext].concat());
+    let mut result = [0u8; 16];
+    result.copy_from_slice(&large[8..24]);
+    result
+}
+
+/// AES-IGE key and IV of a message with `msg_key`
+pub fn aes_key_iv(
+    auth_key: &[u8; 256],
+    msg_key: &[u8; 16],
+    sender: Sender,
+) -> ([u8; 32], [u8; 32]) {
+    let x = sender.offset();
+    let a = sha256(&[&msg_key[..], &auth_key[x..x + 36]].concat());
+    let b = sha256(&[&auth_key[40 + x..76 + x], &msg_key[..]].concat());
+    let mut key = [0u8; 32];
+    key[..8].copy_from_slice(&a[..8]);
+    key[8..24].copy_from_slice(&b[8..24]);
+    key[24..].copy_from_slice(&a[24..]);
+    let mut iv = [0u8; 32];
+    iv[..8].copy_from_slice(&b[..8]);
+    iv[8..24].copy_from_slice(&a[8..24]);
+    iv[24..].copy_from_slice(&b[24..]);
+    (key, iv)
+}
+
+/// AES-IGE key and IV of `server_DH_inner_data` and `client_DH_inner_data` in the DH handshake
+pub fn tmp_aes_key_iv(server_nonce: &[u8; 16], new_nonce: &[u8; 32]) -> ([u8; 32], [u8; 32]) {
+    let new_server = sha1(&[&new_nonce[..], &server_nonce[..]].concat());
+    let server_new = sha1(&[&server_nonce[..], &new_nonce[..]].concat());
+    let new_new = sha1(&[&new_nonce[..], &new_nonce[..]].concat());
+    let mut key = [0u8; 32];
+    key[..20].copy_from_slice(&new_server);
+    key[20..].copy_from_slice(&server_new[..12]);
+    let mut iv = [0u8; 32];
+    iv[..8].copy_from_slice(&server_new[12..]);
+    iv[8..28].copy_from_slice(&new_new);
+    iv[28..].copy_from_slice(&new_nonce[..4]);
+    (key, iv)
+}
+
+#[cfg(test)]
+fn test_auth_key() -> [u8; 256] {
+    let mut result = [0u8; 256];
+    for (i, x) in result.iter_mut().enumerate() {
+        *x = (i * 7 + 3) as u8;
+    }
+    result
+}
+
+#[test]
+fn test_client_key_derivation() {
+    let auth_key = test_auth_key();
+    let plaintext: Vec<u8> = (0..48).collect();
+    let msg_key = msg_key(&auth_key, Sender::Client, &plaintext);
+    assert_eq!(hex::encode(msg_key), "713c50c7d8ce0e2e727d1840d0cbe8f3");
+    let (key, iv) = aes_key_iv(&auth_key, &msg_key, Sender::Client);
+    assert_eq!(
+        hex::encode(key),
+        "3c45957db98c69ecc1feeadbe46e0d9af55a9af19d153f2d426eea86441bc45f"
+    );
+    assert_eq!(
+        hex::encode(iv),
+        "beb60f5c6c4590ce0b557913cf8658a3ab592dbf67c48bae89fe0d5df76e85f5"
+    );
+}
+
+#[test]
+fn test_server_key_derivation() {
+    let auth_key = test_auth_key();
+    let plaintext: Vec<u8> = (0..48).collect();
+    let msg_key = msg_key(&auth_key, Sender::Server, &plaintext);
+    assert_eq!(hex::encode(msg_key), "3a56a510d5b01504aacb74beec4b3a20");
+    let (key, iv) = aes_key_iv(&auth_key, &msg_key, Sender::Server);
+    assert_eq!(
+        hex::encode(key),
+        "a8c0a5b3c8ffa2f975e24deeed6c4b8d933425df098670407c0764a2a2d7e55c"
+    );
+    assert_eq!(
+        hex::encode(iv),
+        "935cb4c44a804dd72ab870385442fc15f3ff1c4e88c856399d5dd4654c3aa6b6"
+    );
+}
+
+#[test]
+fn test_tmp_aes_key_iv() {
+    let server_nonce = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    let mut new_nonce = [0u8; 32];
+    for (i, x) in new_nonce.iter_mut().enumerate() {
+        *x = 100 + i as u8;
+    }
+    let (key, iv) = tmp_aes_key_iv(&server_nonce, &new_nonce);
+    assert_eq!(
+        hex::encode(key),
+        "e95c7c3ec6aeeae100f21acfd4a819230989731d44b134a83a2e9ca0ce56c585"
+    );
+    assert_eq!(
+        hex::encode(iv),
+        "da23077ca3f27dec3969124214fdeeacbd8c9de3143ab8ba9423116e64656667"
+    );
+}
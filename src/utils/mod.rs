@@ -1,6 +1,10 @@
+pub mod aes;
+pub mod error;
 pub mod int_bytes;
+pub mod kdf;
 pub mod prime_numbers;
 pub mod rsa;
 
-//TODO Replace failure::Error with MyError
-pub type MyResult<T> = std::result::Result<T, failure::Error>;
+pub use self::error::MyError;
+
+pub type MyResult<T> = std::result::Result<T, MyError>;
@@ -1,67 +1,57 @@
+/// Rounds of Pollard's rho, each with another random start, before giving up
+const MAX_ATTEMPTS: usize = 4;
+
+/// Split `pq` into its factors `(p, q)` with `p <= q`, `None` if none is found, e.g. for a prime
 #[allow(clippy::many_single_char_names)]
-pub fn split_pq(pq: u64) -> (u64, u64) {
-    fn random_u64() -> u64 {
-        rand::random()
+pub fn split_pq(pq: u64) -> Option<(u64, u64)> {
+    if pq < 4 {
+        return None;
     }
-    let mut g = 0u64;
-    while !(g > 1 && g < pq) {
-        let q = ((random_u64() & 15) & 17) % pq;
-
-        let mut x = (random_u64() % (pq - 1)) + 1;
-
+    for _ in 0..MAX_ATTEMPTS {
+        let q = u128::from(rand::random::<u64>() & 15) + 1;
+        let mut x = (rand::random::<u64>() % (pq - 1)) + 1;
         let mut y = x;
-        let mut j = 1;
 
-        while j < 1 << 18 {
-            let mut a = x;
-            let mut b = x;
-            let mut c = q;
-
-            while b > 0 {
-                if b % 2 == 1 {
-                    c += a;
-                    if c >= pq {
-                        c -= pq;
-                    }
-                }
-                a = a + a;
-                if a >= pq {
-                    a -= pq;
-                }
-                b >>= 1;
-            }
-            x = c;
-
-            let z = if x < y { pq + x - y } else { x - y };
-            g = num::integer::gcd(z, pq);
-
-            if (j & (j - 1)) == 0 {
-                y = x;
+        for j in 1..1u32 << 18 {
+            x = ((u128::from(x) * u128::from(x) + q) % u128::from(pq)) as u64;
+            let z = if x > y { x - y } else { y - x };
+            let g = num::integer::gcd(z, pq);
+            if g > 1 && g < pq {
+                let (p1, p2) = (g, pq / g);
+                return Some(if p1 < p2 { (p1, p2) } else { (p2, p1) });
             }
-            j += 1;
-
             if g != 1 {
                 break;
             }
+            if (j & (j - 1)) == 0 {
+                y = x;
+            }
         }
     }
-    let p1 = g;
-    let p2 = pq / g;
-    if p1 < p2 {
-        (p1, p2)
-    } else {
-        (p2, p1)
-    }
+    None
 }
 
 #[test]
 fn test_split_pq() {
-    assert_eq!((0x494c553b, 0x53911073), split_pq(0x17ED48941A08F981));
-    assert_eq!((0x40822411, 0x61577731), split_pq(0x188759ed8a73ce41));
-    assert_eq!((0x47897bed, 0x512cf1fb), split_pq(0x16af0f75db329e5f));
-    assert_eq!((0x5286cd49, 0x6111977f), split_pq(0x1f4abf92becee637));
-    assert_eq!((0x452fce43, 0x72a5fd79), split_pq(0x1efc262ab99fb4ab));
-    assert_eq!((0x421a6eeb, 0x516c00a9), split_pq(0x1506406ac9973923));
-    assert_eq!((0x5676b90f, 0x6bd1453f), split_pq(0x246a4da9a15795b1));
-    assert_eq!((0x48fe0c7d, 0x6c9d6085), split_pq(0x1ef80c9795545cf1));
+    assert_eq!(Some((0x494c553b, 0x53911073)), split_pq(0x17ED48941A08F981));
+    assert_eq!(Some((0x40822411, 0x61577731)), split_pq(0x188759ed8a73ce41));
+    assert_eq!(Some((0x47897bed, 0x512cf1fb)), split_pq(0x16af0f75db329e5f));
+    assert_eq!(Some((0x5286cd49, 0x6111977f)), split_pq(0x1f4abf92becee637));
+    assert_eq!(Some((0x452fce43, 0x72a5fd79)), split_pq(0x1efc262ab99fb4ab));
+    assert_eq!(Some((0x421a6eeb, 0x516c00a9)), split_pq(0x1506406ac9973923));
+    assert_eq!(Some((0x5676b90f, 0x6bd1453f)), split_pq(0x246a4da9a15795b1));
+    assert_eq!(Some((0x48fe0c7d, 0x6c9d6085)), split_pq(0x1ef80c9795545cf1));
+}
+
+#[test]
+fn test_split_pq_without_factors() {
+    assert_eq!(split_pq(0), None);
+    assert_eq!(split_pq(1), None);
+    assert_eq!(split_pq(3), None);
+    assert_eq!(split_pq(0x7fff_ffff_ffff_ffe7), None);
+    // products above 2^63 do not overflow
+    assert_eq!(
+        split_pq(0xffff_ffea_0000_0055),
+        Some((0xffff_ffef, 0xffff_fffb))
+    );
 }
@@ -1,10 +1,14 @@
+use byteorder::{ByteOrder, LittleEndian};
 use lazy_static::lazy_static;
 use openssl::{
     bn::{BigNum, BigNumContext, BigNumRef},
     pkey::Public,
     rsa::Rsa,
+    sha::sha1,
 };
 
+use crate::tl_types::{tl_bytes::TLBytes, TLType};
+
 lazy_static! {
     static ref TG_SERVER_PUBLIC_KEY: Rsa<Public> = telegram_server_public_key();
 }
@@ -21,6 +25,15 @@ Slv8kg9qv1m6XHVQY3PnEw+QQtqSIXklHwIDAQAB
     openssl::rsa::Rsa::public_key_from_pem_pkcs1(document).unwrap()
 }
 
+/// Lower 64 bits of SHA1 of `n` and `e` serialized as TL `bytes`, sent in `req_DH_params`
+pub fn server_public_key_fingerprint() -> i64 {
+    let mut data = vec![];
+    for x in [TG_SERVER_PUBLIC_KEY.n(), TG_SERVER_PUBLIC_KEY.e()].iter() {
+        TLBytes::from_bytes(x.to_vec()).tl_write(&mut data).unwrap();
+    }
+    LittleEndian::read_i64(&sha1(&data)[12..])
+}
+
 /// Text book RSA, only work for AuthKey generator
 pub fn rsa(data: &[u8; 255]) -> [u8; 256] {
     let mut context = BigNumContext::new().unwrap();
@@ -31,8 +44,28 @@ pub fn rsa(data: &[u8; 255]) -> [u8; 256] {
     let mut c = BigNum::new().unwrap();
     c.mod_exp(&z, e, n, &mut context).unwrap();
 
+    // `to_vec` drops leading zero bytes
     let result = c.to_vec();
     let mut output = [0u8; 256];
-    output.copy_from_slice(&result);
+    output[256 - result.len()..].copy_from_slice(&result);
     output
 }
+
+#[test]
+fn test_server_public_key_fingerprint() {
+    assert_eq!(
+        server_public_key_fingerprint() as u64,
+        0xc3b4_2b02_6ce8_6b21
+    );
+}
+
+#[test]
+fn test_rsa_small_ciphertext() {
+    // 1^e = 1, a ciphertext with 255 leading zero bytes
+    let mut data = [0u8; 255];
+    data[254] = 1;
+    let mut expected = [0u8; 256];
+    expected[255] = 1;
+    assert_eq!(rsa(&data)[..], expected[..]);
+    assert_eq!(rsa(&[0u8; 255])[..], [0u8; 256][..]);
+}